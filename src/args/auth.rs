@@ -0,0 +1,57 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Credentials sent with each request
+#[derive(clap::Args, Clone, Debug)]
+#[command(next_help_heading = "Authentication")]
+pub struct AuthArgs {
+    /// Basic authentication in `username:password` format.
+    #[arg(short = 'a', long = "auth")]
+    pub basic_auth: Option<String>,
+
+    /// OAuth2 token endpoint. A token is fetched with the client-credentials grant
+    /// before the test, sent as a Bearer header and refreshed before it expires.
+    #[arg(long = "oauth-token-url", value_name = "URL", conflicts_with = "basic_auth",
+          requires_all = ["oauth_client_id", "oauth_client_secret"])]
+    pub oauth_token_url: Option<String>,
+
+    /// OAuth2 client ID for --oauth-token-url.
+    #[arg(
+        long = "oauth-client-id",
+        value_name = "ID",
+        requires = "oauth_token_url"
+    )]
+    pub oauth_client_id: Option<String>,
+
+    /// OAuth2 client secret for --oauth-token-url.
+    #[arg(
+        long = "oauth-client-secret",
+        value_name = "SECRET",
+        requires = "oauth_token_url"
+    )]
+    pub oauth_client_secret: Option<String>,
+
+    /// Scope to request with the OAuth2 token (repeatable).
+    #[arg(long = "oauth-scope", value_name = "SCOPE", action = clap::ArgAction::Append,
+          requires = "oauth_token_url")]
+    pub oauth_scopes: Vec<String>,
+}
@@ -0,0 +1,128 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::fs;
+use std::path::Path;
+use url::form_urlencoded;
+
+use super::parse_size;
+use crate::tester::BodyEncoding;
+
+/// What each request sends as its body
+#[derive(clap::Args, Clone, Debug)]
+#[command(next_help_heading = "Request body")]
+pub struct BodyArgs {
+    /// HTTP request body as a string.
+    #[arg(short = 'd', long = "body")]
+    pub body: Option<String>,
+
+    /// Path to a file containing the HTTP request body.
+    #[arg(short = 'D', long = "body-file")]
+    pub body_file: Option<String>,
+
+    /// Form field as "KEY=VALUE" (repeatable), URL-encoded into an
+    /// application/x-www-form-urlencoded body the way curl does.
+    #[arg(long = "data-urlencode", value_name = "KEY=VALUE", action = clap::ArgAction::Append,
+          conflicts_with_all = ["body", "body_file", "content_type"])]
+    pub data_urlencode: Vec<String>,
+
+    /// Send random bytes of this size (e.g. 16KB) as the request body.
+    #[arg(long = "body-random", value_name = "SIZE", value_parser = parse_size,
+          conflicts_with_all = ["body", "body_file", "data_urlencode", "steps", "body_size_sweep"])]
+    pub body_random: Option<usize>,
+
+    /// Seed for --body-random, so the same bytes are sent on every run.
+    #[arg(
+        long = "body-random-seed",
+        value_name = "SEED",
+        requires = "body_random"
+    )]
+    pub body_random_seed: Option<u64>,
+
+    /// Draw new --body-random bytes for every request rather than once per test.
+    #[arg(long = "body-random-per-request", requires = "body_random")]
+    pub body_random_per_request: bool,
+
+    /// Compress the request body with "gzip" or "br" once, up front, and send it
+    /// with a matching Content-Encoding header.
+    #[arg(long = "compress-body", value_name = "ENCODING", value_parser = BodyEncoding::parse,
+          conflicts_with = "steps")]
+    pub compress_body: Option<BodyEncoding>,
+
+    /// Content-Type header. Defaults to "text/html".
+    #[arg(short = 'T', long = "content-type", default_value = "text/html")]
+    pub content_type: String,
+}
+
+impl BodyArgs {
+    /// Whether any option sets a body
+    pub fn is_set(&self) -> bool {
+        self.body.is_some()
+            || self.body_file.is_some()
+            || !self.data_urlencode.is_empty()
+            || self.body_random.is_some()
+    }
+
+    /// The body given as --body, read from --body-file, or built from --data-urlencode.
+    /// An unreadable body file is warned about and the request sent without a body.
+    pub fn read(&self) -> Option<Vec<u8>> {
+        match (&self.body, &self.body_file) {
+            (Some(content), _) => Some(content.clone().into_bytes()),
+            (None, Some(file_path)) => match fs::read(Path::new(file_path)) {
+                Ok(content) => Some(content),
+                Err(e) => {
+                    eprintln!("Warning: Failed to read body file '{file_path}': {e}. Request will be sent without a body.");
+                    None
+                }
+            },
+            _ => self.form_body().map(String::into_bytes),
+        }
+    }
+
+    /// The body the --data-urlencode fields build: each value is encoded, and a
+    /// field without a key is encoded whole.
+    pub fn form_body(&self) -> Option<String> {
+        if self.data_urlencode.is_empty() {
+            return None;
+        }
+        let encode = |text: &str| form_urlencoded::byte_serialize(text.as_bytes()).collect();
+        let fields: Vec<String> = self
+            .data_urlencode
+            .iter()
+            .map(|field| match field.split_once('=') {
+                Some(("", value)) => encode(value),
+                Some((key, value)) => format!("{key}={}", encode(value)),
+                None => encode(field),
+            })
+            .collect();
+        Some(fields.join("&"))
+    }
+
+    /// The Content-Type to send; --data-urlencode bodies are always form encoded.
+    pub fn content_type(&self) -> &str {
+        if self.data_urlencode.is_empty() {
+            &self.content_type
+        } else {
+            "application/x-www-form-urlencoded"
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{BodyChecksum, JsonAssertion};
+
+/// What counts as a failed response, and when failures end the test early
+#[derive(clap::Args, Clone, Debug)]
+#[command(next_help_heading = "Response checks")]
+pub struct CheckArgs {
+    /// Comma-separated status codes that count as success, e.g. "200,301,404".
+    /// Defaults to any 2xx status.
+    #[arg(long = "success-status", value_delimiter = ',')]
+    pub success_status: Vec<u16>,
+
+    /// Count responses slower than this many milliseconds as failures.
+    #[arg(long = "max-latency")]
+    pub max_latency: Option<f64>,
+
+    /// Count responses whose body does not contain this text as failures.
+    #[arg(long = "body-contains")]
+    pub body_contains: Option<String>,
+
+    /// Check each JSON response, e.g. '$.status == "ok"' (repeatable). Failures are counted separately from HTTP errors.
+    #[arg(long = "assert-json", value_parser = JsonAssertion::parse)]
+    pub assert_json: Vec<JsonAssertion>,
+
+    /// Flag responses whose body does not hash to this hex SHA-256 digest.
+    #[arg(long = "expect-body-sha256", value_parser = BodyChecksum::parse)]
+    pub expect_body_sha256: Option<BodyChecksum>,
+
+    /// Abort the test once p99 latency (over one-second windows) exceeds this many milliseconds
+    /// for --p99-limit-secs consecutive seconds.
+    #[arg(long = "p99-limit")]
+    pub p99_limit: Option<f64>,
+
+    /// Number of consecutive seconds over --p99-limit before the test is aborted.
+    #[arg(long = "p99-limit-secs", default_value = "5")]
+    pub p99_limit_secs: u64,
+
+    /// Abort the test once this many requests have failed, so a misconfigured run
+    /// doesn't keep hammering a broken endpoint.
+    #[arg(long = "abort-after-errors", value_name = "N",
+          value_parser = clap::value_parser!(u64).range(1..))]
+    pub abort_after_errors: Option<u64>,
+}
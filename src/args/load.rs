@@ -0,0 +1,91 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use super::parse_size;
+use crate::capacity::SearchStrategy;
+use crate::tester::{parse_duration, LoadPattern, LoadStage, Stagger, ThinkTime};
+
+/// How the arrival rate changes over the test
+#[derive(clap::Args, Clone, Debug)]
+#[command(next_help_heading = "Load shaping")]
+pub struct LoadArgs {
+    /// Load stage as DURATION:RATE, e.g. "60s:100rps" (repeatable). Stages run in order
+    /// at a total arrival rate across all workers, and the test ends after the last.
+    #[arg(long = "stage", value_name = "DURATION:RATE", value_parser = LoadStage::parse,
+          action = clap::ArgAction::Append, conflicts_with_all = ["duration_str", "requests"])]
+    pub stages: Vec<LoadStage>,
+
+    /// Fire this many requests at once every --burst-interval, to see how caches
+    /// and autoscalers react to spikes. Needs at least as many connections (-c).
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "stages")]
+    pub burst: Option<u32>,
+
+    /// Vary the total arrival rate over time, e.g. "sine:period=60s,min=50,max=500".
+    /// Runs until -z or -n is reached.
+    #[arg(long, value_name = "PATTERN", value_parser = LoadPattern::parse,
+          conflicts_with_all = ["stages", "burst"])]
+    pub pattern: Option<LoadPattern>,
+
+    /// Time between bursts (e.g. "5s").
+    #[arg(long = "burst-interval", default_value = "5s", value_parser = parse_duration, requires = "burst")]
+    pub burst_interval: u64,
+
+    /// Random delay range (e.g. 0-50ms) for each worker's start and before each request.
+    #[arg(long, value_parser = Stagger::parse)]
+    pub stagger: Option<Stagger>,
+
+    /// Pause each worker takes between its requests (e.g. "200ms"). Add ":uniform"
+    /// (0 to twice the mean) or ":exponential" to vary it around the mean.
+    #[arg(long = "think-time", value_name = "TIME", value_parser = ThinkTime::parse)]
+    pub think_time: Option<ThinkTime>,
+}
+
+/// Modes that run several text-mode stages and compare them
+#[derive(clap::Args, Clone, Debug)]
+#[command(next_help_heading = "Sweeps")]
+pub struct SweepArgs {
+    /// Run one text-mode stage per body size (e.g. 1KB,10KB,100KB,1MB) and report each.
+    #[arg(long = "body-size-sweep", value_delimiter = ',', value_parser = parse_size)]
+    pub body_size_sweep: Vec<usize>,
+
+    /// Run one text-mode stage per concurrency level (e.g. 1,10,50,100,200) and compare
+    /// throughput and p99 across them to find where adding load stops paying off.
+    #[arg(long, value_delimiter = ',', value_name = "LEVELS",
+          conflicts_with_all = ["body_size_sweep", "cache_compare", "find_capacity"])]
+    pub sweep: Vec<usize>,
+
+    /// Search for the most concurrency (up to -c) that keeps p99 latency under this many
+    /// ms with under 1% errors. Each level tried runs for -z (10s if not set).
+    #[arg(long = "find-capacity", value_name = "P99_MS",
+          conflicts_with_all = ["body_size_sweep", "cache_compare"])]
+    pub find_capacity: Option<f64>,
+
+    /// How --find-capacity picks the next level: "binary" search, or "aimd" to climb
+    /// in steps and halve on a miss.
+    #[arg(long = "capacity-search", default_value = "binary", value_parser = SearchStrategy::parse,
+          requires = "find_capacity")]
+    pub capacity_search: SearchStrategy,
+
+    /// Run a cold phase with --cache-bust, then a warm phase on the same URL, and compare them.
+    #[arg(long = "cache-compare", conflicts_with = "body_size_sweep")]
+    pub cache_compare: bool,
+}
@@ -0,0 +1,278 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod auth;
+mod body;
+mod checks;
+mod load;
+mod network;
+mod output;
+mod parse;
+
+pub use auth::AuthArgs;
+pub use body::BodyArgs;
+pub use checks::CheckArgs;
+pub use load::{LoadArgs, SweepArgs};
+pub use network::{ConnectionArgs, DnsArgs, ProxyArgs, TlsArgs};
+pub use output::OutputArgs;
+pub(crate) use parse::{parse_http_method, parse_size, parse_step};
+
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use crate::compare_cli::CompareArgs;
+use crate::debug_cli::DebugArgs;
+use crate::grpc_cli::GrpcArgs;
+use crate::replay_cli::ReplayArgs;
+use crate::tester::{
+    parse_duration, Extraction, FeedOrder, HttpMethod, ScenarioStep, UrlMix, UrlSelection,
+    DEFAULT_USER_AGENT,
+};
+
+#[derive(Parser, Clone, Debug)]
+#[command(
+    author,
+    version,
+    about = "A high-performance HTTP load testing tool.",
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true
+)]
+pub struct Args {
+    /// The URL to test.
+    #[arg(
+        required_unless_present = "url_list",
+        default_value = "",
+        hide_default_value = true
+    )]
+    pub url: String,
+
+    /// File listing URLs to test instead, one per line as "URL [WEIGHT]". Requests
+    /// are spread across them by weight, and the report breaks down stats per URL.
+    #[arg(long = "url-file", value_name = "PATH", value_parser = UrlMix::load,
+          group = "url_list", conflicts_with = "fallback_url")]
+    pub url_file: Option<UrlMix>,
+
+    /// Sitemap to fetch and test the pages of instead of a URL, weighting each page by
+    /// its <priority>. A sitemap index is followed one level down.
+    #[arg(
+        long = "sitemap",
+        value_name = "URL",
+        group = "url_list",
+        conflicts_with = "fallback_url"
+    )]
+    pub sitemap: Option<String>,
+
+    /// How --url-file or --sitemap URLs take turns: "random" by weight, "round-robin" to
+    /// interleave them, or "sequential" for each URL's weight in requests before the next.
+    #[arg(long = "url-order", value_name = "ORDER", default_value = "random",
+          value_parser = UrlSelection::parse, requires = "url_list")]
+    pub url_order: UrlSelection,
+
+    /// Number of requests to send [default: 200, or unlimited with -z]. With -z as well,
+    /// the test ends at whichever limit is reached first. If 0, the test runs indefinitely
+    /// or until the duration is met.
+    #[arg(short = 'n', long)]
+    pub requests: Option<usize>,
+
+    /// Number of concurrent connections to use.
+    #[arg(short, long, default_value = "50")]
+    pub concurrent: usize,
+
+    /// Duration of the test. If specified, the request count is ignored.
+    /// Examples: "10s", "1m", "2h".
+    #[arg(short = 'z', long = "duration", default_value = "0")]
+    pub duration_str: String,
+
+    /// Timeout for each request in seconds. Use 0 for no timeout.
+    #[arg(short = 't', long = "timeout", default_value = "20")]
+    pub timeout: u64,
+
+    /// Time allowed to open a connection, TLS handshake included (e.g. "3s").
+    /// Connect timeouts are counted separately from other errors.
+    #[arg(long = "connect-timeout", value_parser = parse_duration)]
+    pub connect_timeout: Option<u64>,
+
+    /// How long requests still in flight at the end may take to finish (e.g. "30s").
+    /// Requests that outlast it are abandoned and left out of the report.
+    #[arg(long = "drain-timeout", default_value = "10s", value_parser = parse_duration)]
+    pub drain_timeout: u64,
+
+    /// Stop reading each response body after this many bytes (e.g. "64KB"). Status
+    /// and time to first byte are still measured; the rest of the body is discarded.
+    #[arg(long = "max-body-bytes", value_parser = parse_size)]
+    pub max_body_bytes: Option<usize>,
+
+    /// Rate limit in requests per second (QPS) per worker. 0 means no limit.
+    #[arg(short = 'q', long, default_value = "0")]
+    pub rate_limit: f64,
+
+    #[command(flatten)]
+    pub load: LoadArgs,
+
+    /// HTTP method.
+    #[arg(short = 'm', long = "method", default_value = "GET", value_parser = parse_http_method)]
+    pub method: HttpMethod,
+
+    /// HTTP Accept header.
+    #[arg(short = 'A', long = "accept")]
+    pub accept: Option<String>,
+
+    /// Host header to send, independent of the host connected to, for name-based
+    /// virtual hosts behind a specific IP or load balancer.
+    #[arg(long = "host", value_name = "NAME")]
+    pub host: Option<String>,
+
+    /// User-Agent header, so server logs can pick out load-test traffic.
+    #[arg(short = 'U', long = "user-agent", default_value = DEFAULT_USER_AGENT)]
+    pub user_agent: String,
+
+    /// File of User-Agent strings, one per line, rotated across requests in turn.
+    #[arg(
+        long = "user-agent-file",
+        value_name = "PATH",
+        conflicts_with = "user_agent"
+    )]
+    pub user_agent_file: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub auth: AuthArgs,
+
+    /// Scenario step as "NAME METHOD URL [BODY]" (repeatable). Each worker sends the
+    /// steps in order, over and over; URLs may be paths relative to the test URL.
+    /// Steps with the same name share stats.
+    #[arg(long = "step", value_name = "STEP", value_parser = parse_step,
+          action = clap::ArgAction::Append)]
+    pub steps: Vec<ScenarioStep>,
+
+    /// Save a value from a step's responses as "STEP:VAR=SOURCE" (repeatable), where
+    /// SOURCE is a JSONPath like $.token, "header:NAME" or "regex:PATTERN". Later
+    /// step URLs, bodies and headers use it as {{VAR}}; each worker keeps its own.
+    #[arg(long = "extract", value_name = "EXTRACTION", value_parser = Extraction::parse,
+          action = clap::ArgAction::Append, requires = "steps")]
+    pub extractions: Vec<Extraction>,
+
+    /// CSV file whose first line names the columns; each request takes a row and
+    /// fills ${COLUMN} placeholders in the URL, headers and body with its values.
+    #[arg(long = "data-file", value_name = "PATH")]
+    pub data_file: Option<PathBuf>,
+
+    /// Order --data-file rows are used in: "cycle" through them, or "random".
+    #[arg(long = "data-order", default_value = "cycle", value_parser = FeedOrder::parse,
+          requires = "data_file")]
+    pub data_order: FeedOrder,
+
+    #[command(flatten)]
+    pub body: BodyArgs,
+
+    /// Custom HTTP header. Can be specified multiple times, or as @FILE to read
+    /// headers from a file, one per line.
+    /// Example: -H "Content-Type: application/json"
+    #[arg(short = 'H', long = "header", action = clap::ArgAction::Append)]
+    pub headers: Vec<String>,
+
+    #[command(flatten)]
+    pub proxy: ProxyArgs,
+
+    #[command(flatten)]
+    pub tls: TlsArgs,
+
+    #[command(flatten)]
+    pub dns: DnsArgs,
+
+    /// Fallback URL to switch to after sustained connection errors on the primary URL.
+    #[arg(long = "fallback-url")]
+    pub fallback_url: Option<String>,
+
+    /// Number of consecutive connection errors before switching to --fallback-url.
+    #[arg(long = "failover-after", default_value = "10")]
+    pub failover_after: usize,
+
+    #[command(flatten)]
+    pub connection: ConnectionArgs,
+
+    /// Treat each request as a Server-Sent Events stream: hold it open and count events.
+    /// The timeout applies to the first event rather than the whole stream.
+    #[arg(long)]
+    pub sse: bool,
+
+    /// Close each SSE stream after this long (e.g. "30s"). By default streams are held
+    /// until the server closes them or the test ends.
+    #[arg(long = "sse-hold", value_parser = parse_duration, requires = "sse")]
+    pub sse_hold: Option<u64>,
+
+    #[command(flatten)]
+    pub checks: CheckArgs,
+
+    /// Add a unique query parameter to every request so caches always miss.
+    #[arg(long = "cache-bust")]
+    pub cache_bust: bool,
+
+    #[command(flatten)]
+    pub sweeps: SweepArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+
+    /// Read options from a YAML file written by --save-config. Options given on the
+    /// command line override the file's.
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Write every option, defaults included, to a YAML file that --config can replay.
+    #[arg(long = "save-config", value_name = "FILE")]
+    pub save_config: Option<PathBuf>,
+
+    /// Take the request from a curl command, e.g. one copied from a browser's
+    /// network panel: its URL, method, headers, body and auth. Other options
+    /// still apply, and any given on the command line override the command's.
+    #[arg(long = "from-curl", value_name = "COMMAND")]
+    pub from_curl: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Modes with arguments of their own.
+#[derive(Subcommand, Clone, Debug)]
+pub enum Command {
+    /// Compare two saved JSON reports and fail if the second regressed.
+    Compare(CompareArgs),
+    /// Send a few requests and print every detail of each, curl -v style.
+    #[command(visible_alias = "probe")]
+    Debug(DebugArgs),
+    /// Load test a unary gRPC method.
+    Grpc(GrpcArgs),
+    /// Replay the requests of a browser-exported HAR file.
+    Replay(ReplayArgs),
+}
+
+impl Args {
+    /// The URL under test: the first of the --url-file mix, or the --sitemap itself
+    /// standing in for the pages it lists.
+    pub fn target_url(&self) -> &str {
+        match (&self.url_file, &self.sitemap) {
+            (Some(mix), _) => &mix.urls[0].url,
+            (None, Some(sitemap)) => sitemap,
+            (None, None) => &self.url,
+        }
+    }
+}
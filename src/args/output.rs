@@ -0,0 +1,127 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::path::PathBuf;
+
+use crate::headless::Verbosity;
+use crate::tester::{parse_duration, OutputSink, Theme};
+
+/// Where results go and how they are shown
+#[derive(clap::Args, Clone, Debug)]
+#[command(next_help_heading = "Output")]
+pub struct OutputArgs {
+    /// Disable the interactive UI and print a text report instead.
+    #[arg(long = "no-ui", default_value = "false")]
+    pub no_ui: bool,
+
+    /// Colour scheme of the interactive UI: default, dark, light, or monochrome.
+    /// Setting the NO_COLOR environment variable always means monochrome.
+    #[arg(long, default_value = "default", value_parser = Theme::parse)]
+    pub theme: Theme,
+
+    /// Draw the interactive UI without colour, the same as --theme monochrome.
+    #[arg(long = "no-color", conflicts_with = "theme")]
+    pub no_color: bool,
+
+    /// Also write a JSON summary of the run to this file (works with the UI or --no-ui).
+    #[arg(long = "json")]
+    pub json: Option<PathBuf>,
+
+    /// Also write one CSV row per request to this file (works with the UI or --no-ui).
+    #[arg(long = "csv")]
+    pub csv: Option<PathBuf>,
+
+    /// Write the URL, cause and latency of every failed request to this file, one JSON line each.
+    #[arg(long = "log-errors", value_name = "FILE")]
+    pub log_errors: Option<PathBuf>,
+
+    /// Save the status, headers and body of the first failed responses to this directory.
+    #[arg(long = "save-failures", value_name = "DIR")]
+    pub save_failures: Option<PathBuf>,
+
+    /// Number of failed responses --save-failures keeps.
+    #[arg(
+        long = "save-failures-limit",
+        value_name = "N",
+        default_value = "10",
+        requires = "save_failures"
+    )]
+    pub save_failures_limit: usize,
+
+    /// Write the status, headers and body of the first N responses to --sample-file.
+    #[arg(long = "sample-responses", value_name = "N", default_value = "0")]
+    pub sample_responses: usize,
+
+    /// File --sample-responses writes to.
+    #[arg(
+        long = "sample-file",
+        value_name = "FILE",
+        default_value = "whambam-samples.txt"
+    )]
+    pub sample_file: PathBuf,
+
+    /// Text mode: print only the final summary.
+    #[arg(long, group = "verbosity")]
+    pub quiet: bool,
+
+    /// Text mode: print periodic progress lines and the final summary (default).
+    #[arg(long, group = "verbosity")]
+    pub normal: bool,
+
+    /// Text mode: like --normal, plus warnings about errors seen in each interval.
+    #[arg(long, group = "verbosity")]
+    pub verbose: bool,
+
+    /// Text mode: how often to print a progress line (elapsed, requests, req/s, p99, errors).
+    #[arg(long, default_value = "5s", value_parser = parse_interval, conflicts_with = "quiet")]
+    pub interval: u64,
+}
+
+impl OutputArgs {
+    /// The result files requested on the command line.
+    pub fn outputs(&self) -> Vec<OutputSink> {
+        let json = self.json.clone().map(OutputSink::Json);
+        let csv = self.csv.clone().map(OutputSink::Csv);
+        let errors = self.log_errors.clone().map(OutputSink::ErrorLog);
+        json.into_iter().chain(csv).chain(errors).collect()
+    }
+
+    /// The text mode verbosity selected on the command line.
+    pub fn verbosity(&self) -> Verbosity {
+        if self.quiet {
+            Verbosity::Quiet
+        } else if self.verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        }
+    }
+}
+
+/// Parses a progress interval, which must be at least a second
+fn parse_interval(interval: &str) -> Result<u64> {
+    match parse_duration(interval)? {
+        0 => Err(anyhow!("Interval must be at least 1s")),
+        secs => Ok(secs),
+    }
+}
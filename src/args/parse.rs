@@ -0,0 +1,87 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+
+use crate::tester::{HttpMethod, ScenarioStep};
+
+/// Custom parser for HTTP methods. Unknown verbs made of letters and hyphens are passed through.
+pub(crate) fn parse_http_method(s: &str) -> Result<HttpMethod> {
+    match s.to_uppercase().as_str() {
+        "GET" => Ok(HttpMethod::GET),
+        "POST" => Ok(HttpMethod::POST),
+        "PUT" => Ok(HttpMethod::PUT),
+        "PATCH" => Ok(HttpMethod::PATCH),
+        "DELETE" => Ok(HttpMethod::DELETE),
+        "HEAD" => Ok(HttpMethod::HEAD),
+        "OPTIONS" => Ok(HttpMethod::OPTIONS),
+        "TRACE" => Ok(HttpMethod::TRACE),
+        verb if !verb.is_empty()
+            && verb.chars().all(|c| c.is_ascii_alphabetic() || c == '-') =>
+        {
+            Ok(HttpMethod::Custom(verb.into()))
+        }
+        _ => Err(anyhow!(
+            "Invalid HTTP method: {}. Use GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS, TRACE or a custom verb made of letters and hyphens",
+            s
+        )),
+    }
+}
+
+/// Parses a scenario step written as `NAME METHOD URL [BODY]`.
+pub(crate) fn parse_step(s: &str) -> Result<ScenarioStep> {
+    let mut parts = s.trim().splitn(4, ' ');
+    let (Some(name), Some(method), Some(url)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(anyhow!(
+            "Invalid step: '{s}'. Expected NAME METHOD URL [BODY], e.g. 'login POST /login'"
+        ));
+    };
+    Ok(ScenarioStep {
+        name: name.to_string(),
+        method: parse_http_method(method)?,
+        url: url.to_string(),
+        body: parts.next().map(str::to_string),
+        headers: Vec::new(),
+    })
+}
+
+/// Parses a size string (e.g., "512B", "10KB", "1MB") into a number of bytes.
+pub(crate) fn parse_size(size_str: &str) -> Result<usize> {
+    let upper = size_str.trim().to_uppercase();
+    let (num_part, multiplier) = [("GB", 1 << 30), ("MB", 1 << 20), ("KB", 1 << 10), ("B", 1)]
+        .iter()
+        .find_map(|(suffix, multiplier)| {
+            upper
+                .strip_suffix(suffix)
+                .map(|num_part| (num_part, *multiplier))
+        })
+        .unwrap_or((upper.as_str(), 1));
+
+    let num = num_part.trim().parse::<usize>().map_err(|_| {
+        anyhow!(
+            "Invalid size: {}. Expected e.g. 512B, 10KB or 1MB",
+            size_str
+        )
+    })?;
+    num.checked_mul(multiplier)
+        .ok_or_else(|| anyhow!("Size too large: {size_str}"))
+}
@@ -0,0 +1,192 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::io::{self, Write};
+
+use crate::headless::Verbosity;
+use crate::sweep::{run_stage, StageResult};
+use crate::tester::TestConfig;
+
+/// How long each level runs when -z is not given
+const DEFAULT_STEP_SECS: u64 = 10;
+
+/// Share of failed requests above which a level counts as unsustainable
+const MAX_ERROR_RATE: f64 = 0.01;
+
+/// How the capacity search picks the next concurrency level to try
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStrategy {
+    /// Halve the range between the best passing and lowest failing level
+    Binary,
+    /// Climb in steps while the SLO holds, back off by half when it breaks
+    Aimd,
+}
+
+impl SearchStrategy {
+    pub fn parse(strategy: &str) -> Result<Self> {
+        match strategy {
+            "binary" => Ok(SearchStrategy::Binary),
+            "aimd" => Ok(SearchStrategy::Aimd),
+            _ => Err(anyhow!(
+                "Invalid capacity search: '{strategy}'. Expected binary or aimd"
+            )),
+        }
+    }
+}
+
+/// Narrows in on the highest concurrency that meets the SLO
+#[derive(Debug, Clone)]
+pub struct CapacitySearch {
+    strategy: SearchStrategy,
+    /// Highest level that met the SLO, 0 if none has yet
+    best: usize,
+    /// Lowest level that broke the SLO, or one past the maximum
+    ceiling: usize,
+    /// Additive increase step for AIMD
+    step: usize,
+    next: Option<usize>,
+}
+
+impl CapacitySearch {
+    /// Search levels from 1 up to `max_concurrency`
+    pub fn new(strategy: SearchStrategy, max_concurrency: usize) -> Self {
+        let mut search = CapacitySearch {
+            strategy,
+            best: 0,
+            ceiling: max_concurrency + 1,
+            step: (max_concurrency / 10).max(1),
+            next: None,
+        };
+        search.next = search.within_bounds(match strategy {
+            SearchStrategy::Binary => search.ceiling / 2,
+            SearchStrategy::Aimd => 1,
+        });
+        search
+    }
+
+    /// The level to try next, or `None` once the search has converged
+    pub fn next_level(&self) -> Option<usize> {
+        self.next
+    }
+
+    /// Record whether `level` met the SLO and choose the next level
+    pub fn record(&mut self, level: usize, passed: bool) {
+        if passed {
+            self.best = self.best.max(level);
+        } else {
+            self.ceiling = self.ceiling.min(level);
+        }
+
+        let next = match (self.strategy, passed) {
+            (SearchStrategy::Binary, _) => (self.best + self.ceiling) / 2,
+            (SearchStrategy::Aimd, true) => level + self.step,
+            (SearchStrategy::Aimd, false) => {
+                self.step = (self.step / 2).max(1);
+                (level / 2).max(self.best + self.step)
+            }
+        };
+        self.next = self.within_bounds(next);
+    }
+
+    /// The highest level that met the SLO
+    pub fn best(&self) -> Option<usize> {
+        (self.best > 0).then_some(self.best)
+    }
+
+    /// Levels that are already known to pass or fail are not worth running again
+    fn within_bounds(&self, level: usize) -> Option<usize> {
+        let level = level.min(self.ceiling.saturating_sub(1));
+        (level > self.best && level < self.ceiling).then_some(level)
+    }
+}
+
+/// Whether a level kept p99 latency and errors within bounds
+pub fn meets_slo(result: &StageResult, p99_limit_ms: f64) -> bool {
+    result.completed > 0
+        && result.p99_ms <= p99_limit_ms
+        && result.errors as f64 <= MAX_ERROR_RATE * result.completed as f64
+}
+
+/// Run one step per concurrency level until the search converges, then report the optimum
+pub async fn run(
+    config: TestConfig,
+    p99_limit_ms: f64,
+    strategy: SearchStrategy,
+    verbosity: Verbosity,
+) -> Result<()> {
+    let duration = if config.duration > 0 {
+        config.duration
+    } else {
+        DEFAULT_STEP_SECS
+    };
+    let mut search = CapacitySearch::new(strategy, config.concurrent);
+    let mut results = Vec::new();
+
+    while let Some(level) = search.next_level() {
+        let step = TestConfig {
+            concurrent: level,
+            requests: 0,
+            duration,
+            ..config.clone()
+        };
+        let result = run_stage(format!("c={level}"), step, verbosity).await?;
+        search.record(level, meets_slo(&result, p99_limit_ms));
+        results.push((level, result));
+    }
+
+    print_capacity_report(&mut io::stdout(), p99_limit_ms, &results, search.best())?;
+    Ok(())
+}
+
+/// Print every level tried, in the order tried, and the highest one that met the SLO
+pub fn print_capacity_report<W: Write>(
+    w: &mut W,
+    p99_limit_ms: f64,
+    results: &[(usize, StageResult)],
+    best: Option<usize>,
+) -> io::Result<()> {
+    writeln!(w)?;
+    writeln!(w, "Capacity search (p99 <= {p99_limit_ms} ms):")?;
+    for (level, result) in results {
+        let verdict = if meets_slo(result, p99_limit_ms) {
+            "ok"
+        } else {
+            "over SLO"
+        };
+        writeln!(
+            w,
+            "  [c={level}]\t{:.4} req/s, p99 {:.3} ms, {} errors\t{verdict}",
+            result.requests_per_sec, result.p99_ms, result.errors
+        )?;
+    }
+
+    writeln!(w)?;
+    match best.and_then(|best| results.iter().find(|(level, _)| *level == best)) {
+        Some((level, result)) => writeln!(
+            w,
+            "Capacity: {level} concurrent, {:.4} req/s at p99 {:.3} ms",
+            result.requests_per_sec, result.p99_ms
+        ),
+        None => writeln!(w, "Capacity: no concurrency level met the SLO"),
+    }
+}
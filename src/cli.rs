@@ -0,0 +1,351 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::args::{Args, Command};
+use crate::headless::Verbosity;
+use crate::tester::{
+    fetch_sitemap, load_user_agents, parse_duration, parse_target_url, stages_duration, Burst,
+    CircuitBreaker, DataFeed, OAuthClient, RandomBody, ResponseClassifier, SseOptions, TestConfig,
+    Theme,
+};
+use crate::{capacity, compare_cli, debug_cli, grpc_cli, headless, replay_cli, sweep, validate};
+#[cfg(feature = "tui")]
+use crate::{
+    tester::{print_partial_summary, SharedState, StopReason, TestState, UnifiedRunner},
+    ui::App,
+};
+#[cfg(feature = "tui")]
+use std::{io, sync::Mutex};
+
+/// Runs a load test as described by the command-line arguments.
+pub async fn run(args: Args) -> Result<()> {
+    match &args.command {
+        Some(Command::Compare(compare)) => return compare_cli::run(compare.clone()).await,
+        Some(Command::Debug(debug)) => return debug_cli::run(debug.clone()).await,
+        Some(Command::Grpc(grpc)) => return grpc_cli::run(grpc.clone()).await,
+        Some(Command::Replay(replay)) => return replay_cli::run(replay.clone()).await,
+        None => {}
+    }
+
+    parse_target_url(args.target_url())?;
+    if let Some(fallback_url) = &args.fallback_url {
+        parse_target_url(fallback_url)?;
+    }
+
+    // A staged run lasts as long as its stages, rounded up to whole seconds
+    let duration_secs = if args.load.stages.is_empty() {
+        parse_duration(&args.duration_str)?
+    } else {
+        stages_duration(&args.load.stages).as_secs_f64().ceil() as u64
+    };
+    for warning in validate::check(&args, duration_secs)? {
+        eprintln!("Warning: {warning}");
+    }
+
+    let mut headers = parse_headers(&args.headers)?;
+
+    if let Some(accept) = &args.accept {
+        headers.push(("Accept".to_string(), accept.clone()));
+    }
+
+    if let Some(host) = &args.host {
+        headers.push(("Host".to_string(), host.clone()));
+    }
+
+    let step_bodies = args.steps.iter().any(|step| step.body.is_some());
+    if args.body.is_set() || !args.sweeps.body_size_sweep.is_empty() || step_bodies {
+        let content_type = args.body.content_type().to_string();
+        headers.push(("Content-Type".to_string(), content_type));
+    }
+
+    let body = args.body.read();
+
+    let url_mix = match (&args.url_file, &args.sitemap) {
+        (Some(mix), _) => Some(mix.clone()),
+        (None, Some(sitemap)) => {
+            Some(fetch_sitemap(sitemap, args.timeout, &args.user_agent).await?)
+        }
+        (None, None) => None,
+    };
+
+    let user_agents = match &args.user_agent_file {
+        Some(path) => load_user_agents(path)?,
+        None => Vec::new(),
+    };
+
+    let data_feed = match &args.data_file {
+        Some(path) => Some(Arc::new(DataFeed::load(path, args.data_order)?)),
+        None => None,
+    };
+
+    let basic_auth = args.auth.basic_auth.as_ref().and_then(|auth_str| {
+        let parsed = auth_str
+            .split_once(':')
+            .map(|(user, pass)| (user.to_string(), pass.to_string()));
+        if parsed.is_none() {
+            eprintln!(
+                "Warning: Invalid basic auth format: '{auth_str}'. Expected 'username:password'."
+            );
+        }
+        parsed
+    });
+
+    #[allow(deprecated)]
+    let config = TestConfig {
+        url: url_mix
+            .as_ref()
+            .map_or(args.target_url(), |mix| &mix.urls[0].url)
+            .to_string(),
+        method: args.method.clone(),
+        headers,
+        user_agent: args.user_agent.clone(),
+        user_agents,
+        body,
+        random_body: args.body.body_random.map(|size| RandomBody {
+            size,
+            seed: args.body.body_random_seed,
+            per_request: args.body.body_random_per_request,
+        }),
+        compress_body: args.body.compress_body,
+        basic_auth,
+        oauth: args
+            .auth
+            .oauth_token_url
+            .as_ref()
+            .map(|token_url| OAuthClient {
+                token_url: token_url.clone(),
+                client_id: args.auth.oauth_client_id.clone().unwrap_or_default(),
+                client_secret: args.auth.oauth_client_secret.clone().unwrap_or_default(),
+                scopes: args.auth.oauth_scopes.clone(),
+            }),
+        duration: duration_secs,
+        requests: request_limit(args.requests, args.concurrent, duration_secs),
+        concurrent: args.concurrent,
+        timeout: args.timeout,
+        rate_limit: args.rate_limit,
+        disable_compression: args.connection.disable_compression,
+        disable_keepalive: args.connection.disable_keepalive,
+        disable_redirects: args.connection.disable_redirects,
+        cookies: args.connection.cookies,
+        isolate_connections: args.connection.isolate_connections,
+        http2_prior_knowledge: args.connection.http2_prior_knowledge,
+        http3: args.connection.http3,
+        grpc: None,
+        sse: args.sse.then(|| SseOptions {
+            hold: args.sse_hold.map(Duration::from_secs),
+        }),
+        interactive: !args.output.no_ui,
+        theme: if args.output.no_color {
+            Theme::Monochrome
+        } else {
+            args.output.theme
+        },
+        output_format: String::new(), // Deprecated field
+        content_type: args.body.content_type().to_string(),
+        proxy: args.proxy.proxy.clone(),
+        proxy_headers: parse_headers(&args.proxy.proxy_headers)?,
+        insecure: args.tls.insecure,
+        ca_cert: args.tls.cacert.clone(),
+        client_cert: args.tls.cert.clone().zip(args.tls.key.clone()),
+        tls_min: args.tls.tls_min,
+        tls_max: args.tls.tls_max,
+        sni: args.tls.sni.clone(),
+        resolve: args.dns.resolve.clone(),
+        dns_server: args.dns.dns_server,
+        dns_cache_ttl: args.dns.dns_cache_ttl.map(Duration::from_secs),
+        no_dns_cache: args.dns.no_dns_cache,
+        connect_timeout: args
+            .connect_timeout
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs),
+        max_body_bytes: args.max_body_bytes,
+        stages: args.load.stages.clone(),
+        burst: args.load.burst.map(|size| Burst {
+            size: size as usize,
+            interval: Duration::from_secs(args.load.burst_interval),
+        }),
+        pattern: args.load.pattern,
+        drain_timeout: Duration::from_secs(args.drain_timeout),
+        progress_interval: Duration::from_secs(args.output.interval),
+        local_address: args.dns.local_address.clone(),
+        tls_keylog: args.tls.tls_keylog.clone(),
+        fallback_url: args.fallback_url.clone(),
+        failover_after: args.failover_after,
+        cache_bust: args.cache_bust,
+        json_assertions: args.checks.assert_json.clone(),
+        expected_body_sha256: args.checks.expect_body_sha256,
+        outputs: args.output.outputs(),
+        save_failures: args.output.save_failures.clone(),
+        save_failures_limit: args.output.save_failures_limit,
+        sample_responses: args.output.sample_responses,
+        sample_file: args.output.sample_file.clone(),
+        stagger: args.load.stagger,
+        think_time: args.load.think_time,
+        scenario: args.steps.clone(),
+        extractions: args.extractions.clone(),
+        data_feed,
+        url_mix,
+        url_selection: args.url_order,
+        circuit_breaker: args.checks.p99_limit.map(|p99_limit_ms| CircuitBreaker {
+            p99_limit_ms,
+            consecutive_secs: args.checks.p99_limit_secs,
+        }),
+        abort_after_errors: args.checks.abort_after_errors,
+        classifier: ResponseClassifier {
+            success_statuses: args.checks.success_status.clone(),
+            max_latency_ms: args.checks.max_latency,
+            body_contains: args.checks.body_contains.clone(),
+        },
+    };
+
+    let (sweeps, verbosity) = (&args.sweeps, args.output.verbosity());
+    if sweeps.cache_compare {
+        return sweep::run_cache_comparison(config, verbosity).await;
+    }
+
+    if !sweeps.sweep.is_empty() {
+        return sweep::run_concurrency(config, &sweeps.sweep, verbosity).await;
+    }
+
+    if let Some(p99_limit_ms) = sweeps.find_capacity {
+        return capacity::run(config, p99_limit_ms, sweeps.capacity_search, verbosity).await;
+    }
+
+    if !sweeps.body_size_sweep.is_empty() {
+        return sweep::run(config, &sweeps.body_size_sweep, verbosity).await;
+    }
+
+    launch(config, args.output.no_ui, verbosity).await
+}
+
+/// Works out the request limit for a run from -n, -c and the duration, where 0 means
+/// unlimited; shared by every command that runs a test
+pub(crate) fn request_limit(
+    requests: Option<usize>,
+    concurrent: usize,
+    duration_secs: u64,
+) -> usize {
+    match requests {
+        // -n 0 runs until the duration is met, or indefinitely without -z
+        Some(0) => 0,
+        // Without -n, the duration alone decides when the test ends
+        None if duration_secs > 0 => 0,
+        // Otherwise ensure requests are at least concurrency to avoid deadlock; with -z
+        // as well, whichever limit is reached first ends the test
+        requests => requests
+            .unwrap_or(validate::DEFAULT_REQUESTS)
+            .max(concurrent),
+    }
+}
+
+/// Splits `Name: Value` header arguments, warning about any without a colon. An
+/// `@FILE` argument reads headers from the file, one per line, skipping blank lines
+/// and `#` comments.
+pub(crate) fn parse_headers(headers: &[String]) -> Result<Vec<(String, String)>> {
+    let mut parsed = Vec::new();
+    for header in headers {
+        let Some(path) = header.strip_prefix('@') else {
+            push_header(&mut parsed, header);
+            continue;
+        };
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read headers file '{path}'"))?;
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .for_each(|line| push_header(&mut parsed, line));
+    }
+    Ok(parsed)
+}
+
+fn push_header(parsed: &mut Vec<(String, String)>, header: &str) {
+    if let Some((name, value)) = header.split_once(':') {
+        parsed.push((name.trim().to_string(), value.trim().to_string()));
+    } else {
+        eprintln!("Warning: Ignoring invalid header format: '{header}'. Expected 'Name: Value'.");
+    }
+}
+
+/// Runs a configured test behind the UI or in text mode.
+pub(crate) async fn launch(config: TestConfig, no_ui: bool, verbosity: Verbosity) -> Result<()> {
+    #[cfg(feature = "tui")]
+    if !no_ui {
+        return run_ui(config).await;
+    }
+    #[cfg(not(feature = "tui"))]
+    let _ = no_ui;
+
+    headless::run(config, verbosity).await
+}
+
+/// Run the test behind the interactive UI, stopping it when the user quits
+#[cfg(feature = "tui")]
+async fn run_ui(config: TestConfig) -> Result<()> {
+    let drain_timeout = config.drain_timeout;
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+
+    // The runner reports into the same state the UI renders
+    let mut runner = UnifiedRunner::with_state(config.clone(), shared_state.clone());
+    runner.start().await?;
+
+    let mut app = App::new(shared_state, config);
+    let ui = tokio::task::spawn_blocking(move || app.run().map(|()| app));
+    tokio::pin!(ui);
+    let mut app = tokio::select! {
+        app = &mut ui => app,
+        // SIGTERM closes the UI just as quitting does, restoring the terminal
+        () = headless::terminated() => {
+            {
+                let mut guard = state.lock().unwrap();
+                guard.should_quit = true;
+                guard.stop_reason.get_or_insert(StopReason::Terminated);
+            }
+            ui.await
+        }
+    }?
+    .map_err(|e| anyhow::anyhow!("UI error: {e:?}"))?;
+
+    // Quitting mid-run stops sending and lets requests in flight finish
+    let interrupted = !state.lock().unwrap().is_complete;
+    runner.stop();
+    app.stop_restarted();
+    if interrupted {
+        headless::wait_until_complete(&state, drain_timeout + headless::DRAIN_SLACK).await;
+    }
+    // Complete the output files without whatever is still in flight
+    runner.finish().await;
+    app.finish_restarted().await;
+    if interrupted {
+        // An aborted run still leaves the user with its numbers
+        print_partial_summary(&mut io::stdout(), &state.lock().unwrap())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::tester::{compare, Delta, RunSummary};
+
+/// Arguments for `whambam compare`.
+#[derive(clap::Args, Clone, Debug)]
+pub struct CompareArgs {
+    /// The report to compare against: a --json summary or a snapshot saved from the UI.
+    #[arg(required = true)]
+    pub baseline: PathBuf,
+
+    /// The report of the run being checked.
+    #[arg(required = true)]
+    pub current: PathBuf,
+
+    /// How much worse, in percent, a number may get before the comparison fails.
+    /// The error rate is allowed this many percentage points.
+    #[arg(long, value_name = "PERCENT", default_value = "5")]
+    pub tolerance: f64,
+}
+
+fn format_value(delta: &Delta, value: f64) -> String {
+    match delta.name {
+        "Requests/sec" => format!("{value:.2}"),
+        _ if delta.in_points => format!("{value:.2}%"),
+        _ => format!("{value:.2} ms"),
+    }
+}
+
+fn format_change(delta: &Delta) -> String {
+    match delta.change() {
+        Some(change) if delta.in_points => format!("{change:+.2} pts"),
+        Some(change) => format!("{change:+.1}%"),
+        None => "n/a".to_string(),
+    }
+}
+
+/// A table of every delta, flagging those beyond the tolerance, and a verdict
+/// line; the names of the regressed numbers come back alongside it
+pub fn comparison(deltas: &[Delta], tolerance: f64) -> (String, Vec<&'static str>) {
+    let mut out = format!(
+        "{:<14} {:>14} {:>14} {:>12}\n",
+        "Metric", "Baseline", "Current", "Change"
+    );
+    let mut regressed = Vec::new();
+    for delta in deltas {
+        let worse = delta.regressed(tolerance);
+        if worse {
+            regressed.push(delta.name);
+        }
+        let _ = writeln!(
+            out,
+            "{:<14} {:>14} {:>14} {:>12}{}",
+            delta.name,
+            format_value(delta, delta.baseline),
+            format_value(delta, delta.current),
+            format_change(delta),
+            if worse { "  regressed" } else { "" }
+        );
+    }
+
+    let _ = if regressed.is_empty() {
+        writeln!(out, "\nPASS: nothing got worse by more than {tolerance}%")
+    } else {
+        writeln!(
+            out,
+            "\nFAIL: {} got worse by more than {tolerance}%",
+            regressed.join(", ")
+        )
+    };
+    (out, regressed)
+}
+
+/// Compares two saved reports, failing when the current run regressed.
+pub async fn run(args: CompareArgs) -> Result<()> {
+    if args.tolerance < 0.0 {
+        return Err(anyhow!("--tolerance cannot be negative"));
+    }
+    let baseline = RunSummary::load(&args.baseline)?;
+    let current = RunSummary::load(&args.current)?;
+
+    let (table, regressed) = comparison(&compare(&baseline, &current), args.tolerance);
+    print!("{table}");
+    if regressed.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("{} regressed", args.current.display()))
+    }
+}
@@ -0,0 +1,223 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use clap::builder::Resettable;
+use clap::{ArgAction, ArgMatches, Command, CommandFactory, FromArgMatches, Id};
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::curl::parse_curl;
+use crate::Args;
+
+/// Options that manage config files rather than describe the run
+const NOT_SAVED: [&str; 5] = ["config", "save_config", "from_curl", "help", "version"];
+
+/// An option read from a config file: its name and one or more values
+type Entry = (String, Vec<String>);
+
+/// Parse the process arguments, exiting with clap's usual message on a usage error
+pub fn parse() -> Result<Args> {
+    let (command, argv) = prepare(std::env::args_os().collect())?;
+    let matches = command.clone().get_matches_from(argv);
+    finish(&command, &matches)
+}
+
+/// Parse `argv`, taking unset options from any `--config` file and writing any
+/// `--save-config` snapshot
+pub fn try_parse_from<I, T>(argv: I) -> Result<Args>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let (command, argv) = prepare(argv.into_iter().map(Into::into).collect())?;
+    let matches = command.clone().try_get_matches_from(argv)?;
+    finish(&command, &matches)
+}
+
+/// Build the command, with a `--config` file's options and then a `--from-curl`
+/// command's as its defaults so the command line still overrides them
+fn prepare(argv: Vec<OsString>) -> Result<(Command, Vec<OsString>)> {
+    let mut command = Args::command();
+    if let Some(path) = option_value(&argv, "--config").map(PathBuf::from) {
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("Could not read config file {}", path.display()))?;
+        let entries =
+            parse_yaml(&text).with_context(|| format!("Invalid config file {}", path.display()))?;
+        command = apply_defaults(command, entries)
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+    }
+    if let Some(curl) = option_value(&argv, "--from-curl") {
+        let entries = parse_curl(&curl).context("Invalid --from-curl command")?;
+        command = apply_defaults(command, entries).context("Invalid --from-curl command")?;
+    }
+    Ok((command, argv))
+}
+
+fn finish(command: &Command, matches: &ArgMatches) -> Result<Args> {
+    let args = Args::from_arg_matches(matches)?;
+    if let Some(path) = &args.save_config {
+        save(path, command, matches)?;
+    }
+    Ok(args)
+}
+
+/// Find `<option> <VALUE>` or `<option>=<VALUE>` before clap parses anything
+fn option_value(argv: &[OsString], option: &str) -> Option<String> {
+    let mut args = argv.iter().skip(1).map(|arg| arg.to_string_lossy());
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == option {
+            return args.next().map(|value| value.into_owned());
+        }
+        if let Some(value) = arg
+            .strip_prefix(option)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// Make each option in the file the default for that argument
+fn apply_defaults(mut command: Command, entries: Vec<Entry>) -> Result<Command> {
+    for (key, values) in entries {
+        let id = command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(key.as_str()) || arg.get_id() == key.as_str())
+            .filter(|arg| !NOT_SAVED.contains(&arg.get_id().as_str()))
+            .map(|arg| arg.get_id().clone())
+            .ok_or_else(|| anyhow!("Unknown option '{key}'"))?;
+        // A value from the file satisfies a required argument such as the URL
+        command = command.mut_arg(id, |arg| {
+            arg.default_values(values)
+                .required(false)
+                .required_unless_present(Resettable::<Id>::Reset)
+        });
+    }
+    Ok(command)
+}
+
+/// Write every option's resolved value, defaults included, so the run can be replayed
+pub fn save(path: &Path, command: &Command, matches: &ArgMatches) -> Result<()> {
+    let mut yaml = String::from("# Saved by whambam --save-config; replay with --config\n");
+    for arg in command.get_arguments() {
+        let id = arg.get_id().as_str();
+        if NOT_SAVED.contains(&id) {
+            continue;
+        }
+        let Some(values) = matches.get_raw(id) else {
+            continue;
+        };
+        let values: Vec<_> = values.map(|value| value.to_string_lossy()).collect();
+        let key = arg.get_long().unwrap_or(id);
+
+        let is_list =
+            matches!(arg.get_action(), ArgAction::Append) || arg.get_value_delimiter().is_some();
+        match values.as_slice() {
+            [] => {}
+            [value] if !is_list => {
+                let _ = writeln!(yaml, "{key}: {}", yaml_scalar(value));
+            }
+            values => {
+                let _ = writeln!(yaml, "{key}:");
+                for value in values {
+                    let _ = writeln!(yaml, "  - {}", yaml_scalar(value));
+                }
+            }
+        }
+    }
+    fs::write(path, yaml).with_context(|| format!("Could not write {}", path.display()))
+}
+
+/// Leave simple values bare and quote anything YAML might read differently
+fn yaml_scalar(value: &str) -> String {
+    let plain = value.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._/-".contains(c));
+    if plain {
+        value.to_string()
+    } else {
+        serde_json::Value::String(value.to_string()).to_string()
+    }
+}
+
+/// Read the flat YAML written by `save`: `key: value` lines and `key:` followed by
+/// `- value` items
+pub fn parse_yaml(text: &str) -> Result<Vec<Entry>> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let invalid = |message: &str| anyhow!("line {}: {message}", number + 1);
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or((trimmed == "-").then_some(""))
+        {
+            let (_, values) = entries
+                .last_mut()
+                .filter(|_| line.starts_with(char::is_whitespace))
+                .ok_or_else(|| invalid("list item outside a list"))?;
+            values.push(yaml_value(item).map_err(|e| invalid(&e.to_string()))?);
+            continue;
+        }
+
+        let (key, value) = trimmed
+            .split_once(':')
+            .ok_or_else(|| invalid("expected 'key: value'"))?;
+        let value = value.trim();
+        let values = match value {
+            "" | "[]" => Vec::new(),
+            value => vec![yaml_value(value).map_err(|e| invalid(&e.to_string()))?],
+        };
+        entries.push((key.trim().to_string(), values));
+    }
+    Ok(entries)
+}
+
+fn yaml_value(value: &str) -> Result<String> {
+    let value = value.trim();
+    if value.starts_with('"') {
+        return serde_json::from_str(value).map_err(|_| anyhow!("bad quoted value {value}"));
+    }
+    if let Some(quoted) = value.strip_prefix('\'') {
+        return quoted
+            .strip_suffix('\'')
+            .map(|inner| inner.replace("''", "'"))
+            .ok_or_else(|| anyhow!("unterminated quote in {value}"));
+    }
+    // A bare value ends where a comment starts
+    Ok(value
+        .split_once(" #")
+        .map_or(value, |(value, _)| value)
+        .trim_end()
+        .to_string())
+}
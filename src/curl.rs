@@ -0,0 +1,290 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+
+/// curl options that don't change what is sent, and take no value
+const IGNORED_FLAGS: [&str; 18] = [
+    "-s",
+    "--silent",
+    "-S",
+    "--show-error",
+    "-L",
+    "--location",
+    "-v",
+    "--verbose",
+    "-i",
+    "--include",
+    "-N",
+    "--no-buffer",
+    "--compressed",
+    "--http1.1",
+    "--http2",
+    "-f",
+    "--fail",
+    "-#",
+];
+
+/// curl options that don't change what is sent, but take a value
+const IGNORED_OPTIONS: [&str; 4] = ["-o", "--output", "-w", "--write-out"];
+
+/// Short options that take a value, which may be attached as in `-XPOST`
+const SHORT_WITH_VALUE: &str = "XHduAebmow";
+
+/// The request a curl command describes, as whambam options: each long option
+/// name with one or more values, ready to use as argument defaults
+pub fn parse_curl(command: &str) -> Result<Vec<(String, Vec<String>)>> {
+    let words = split_words(command)?;
+    let mut words = words.into_iter();
+    if words.next().as_deref() != Some("curl") {
+        return Err(anyhow!("Expected a command starting with 'curl'"));
+    }
+
+    let mut url = None;
+    let mut method = None;
+    let mut headers = Vec::new();
+    let mut data = Vec::new();
+    let mut options: Vec<(String, Vec<String>)> = Vec::new();
+    let mut get = false;
+
+    let mut pending: Vec<String> = Vec::new();
+    while let Some(word) = pending.pop().or_else(|| words.next()) {
+        // Split `--opt=value`, `-XPOST` and bundles such as `-sSL`
+        let (option, attached) = match word.split_once('=') {
+            Some((option, value)) if option.starts_with("--") => {
+                (option.to_string(), Some(value.to_string()))
+            }
+            _ if is_short_bundle(&word) => {
+                let (first, rest) = word.split_at(2);
+                if SHORT_WITH_VALUE.contains(&first[1..]) {
+                    (first.to_string(), Some(rest.to_string()))
+                } else {
+                    pending.push(format!("-{rest}"));
+                    (first.to_string(), None)
+                }
+            }
+            _ => (word, None),
+        };
+        let mut value = |option: &str| {
+            attached
+                .clone()
+                .or_else(|| words.next())
+                .ok_or_else(|| anyhow!("curl option {option} needs a value"))
+        };
+
+        match option.as_str() {
+            "-X" | "--request" => method = Some(value(&option)?),
+            "-H" | "--header" => headers.push(value(&option)?),
+            "-d" | "--data" | "--data-ascii" | "--data-binary" | "--data-raw"
+            | "--data-urlencode" => {
+                let text = value(&option)?;
+                if option == "--data-raw" {
+                    data.push(Data::Text(text));
+                } else {
+                    data.push(Data::parse(text));
+                }
+            }
+            "--json" => {
+                data.push(Data::parse(value(&option)?));
+                headers.push("Content-Type: application/json".to_string());
+                headers.push("Accept: application/json".to_string());
+            }
+            "-u" | "--user" => options.push(("auth".to_string(), vec![value(&option)?])),
+            "-A" | "--user-agent" => headers.push(format!("User-Agent: {}", value(&option)?)),
+            "-e" | "--referer" => headers.push(format!("Referer: {}", value(&option)?)),
+            "-b" | "--cookie" => headers.push(format!("Cookie: {}", value(&option)?)),
+            "-m" | "--max-time" => {
+                let secs: f64 = value(&option)?
+                    .parse()
+                    .map_err(|_| anyhow!("curl option {option} needs a number of seconds"))?;
+                options.push((
+                    "timeout".to_string(),
+                    vec![(secs.ceil() as u64).to_string()],
+                ));
+            }
+            "--connect-timeout" => {
+                let secs: f64 = value(&option)?
+                    .parse()
+                    .map_err(|_| anyhow!("curl option {option} needs a number of seconds"))?;
+                let millis = (secs * 1000.0).ceil() as u64;
+                options.push(("connect-timeout".to_string(), vec![format!("{millis}ms")]));
+            }
+            "-k" | "--insecure" => options.push(("insecure".to_string(), vec!["true".to_string()])),
+            "-I" | "--head" => method = Some("HEAD".to_string()),
+            "-G" | "--get" => get = true,
+            "--url" => url = Some(value(&option)?),
+            option if IGNORED_FLAGS.contains(&option) => {}
+            option if IGNORED_OPTIONS.contains(&option) => {
+                value(option)?;
+            }
+            option if option.starts_with('-') && option.len() > 1 => {
+                return Err(anyhow!("Unsupported curl option: {option}"));
+            }
+            _ => url = Some(option),
+        }
+    }
+
+    let mut url = url.ok_or_else(|| anyhow!("The curl command has no URL"))?;
+
+    // curl sends form data as a POST unless -G moves it into the query string
+    let mut body = None;
+    if get {
+        let query = data
+            .iter()
+            .map(|data| match data {
+                Data::Text(text) => Ok(text.as_str()),
+                Data::File(_) => Err(anyhow!("-G cannot send a file")),
+            })
+            .collect::<Result<Vec<_>>>()?
+            .join("&");
+        if !query.is_empty() {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&query);
+        }
+        method.get_or_insert_with(|| "GET".to_string());
+    } else if !data.is_empty() {
+        body = Some(match data.as_slice() {
+            [Data::File(path)] => ("body-file", path.clone()),
+            _ => {
+                let parts = data
+                    .iter()
+                    .map(|data| match data {
+                        Data::Text(text) => Ok(text.as_str()),
+                        Data::File(_) => Err(anyhow!("Only a single @file body is supported")),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                ("body", parts.join("&"))
+            }
+        });
+        method.get_or_insert_with(|| "POST".to_string());
+    }
+
+    // whambam sends its own Content-Type with a body, so carry curl's over to it
+    let mut content_type = body
+        .is_some()
+        .then(|| "application/x-www-form-urlencoded".to_string());
+    headers.retain(|header| match header.split_once(':') {
+        Some((name, value)) if name.trim().eq_ignore_ascii_case("content-type") => {
+            content_type = Some(value.trim().to_string());
+            false
+        }
+        _ => true,
+    });
+
+    let mut entries = vec![("url".to_string(), vec![url])];
+    if let Some(method) = method {
+        entries.push(("method".to_string(), vec![method]));
+    }
+    if !headers.is_empty() {
+        entries.push(("header".to_string(), headers));
+    }
+    if let Some((name, value)) = body {
+        entries.push((name.to_string(), vec![value]));
+    }
+    if let Some(content_type) = content_type {
+        entries.push(("content-type".to_string(), vec![content_type]));
+    }
+    entries.extend(options);
+    Ok(entries)
+}
+
+/// A `-d` value: text, or `@path` naming a file to send
+enum Data {
+    Text(String),
+    File(String),
+}
+
+impl Data {
+    fn parse(text: String) -> Self {
+        match text.strip_prefix('@') {
+            Some(path) => Data::File(path.to_string()),
+            None => Data::Text(text),
+        }
+    }
+}
+
+/// Whether a word holds more than one short option, or a short option and its value
+fn is_short_bundle(word: &str) -> bool {
+    word.len() > 2 && word.starts_with('-') && !word.starts_with("--")
+}
+
+/// Split a shell command into words, honouring quotes, backslash escapes, line
+/// continuations and bash's `$'...'` strings, as in browsers' "Copy as cURL"
+fn split_words(command: &str) -> Result<Vec<String>> {
+    let unterminated = || anyhow!("Unterminated quote in curl command");
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = command.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => words.extend(word.take()),
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some('\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                }
+                Some(escaped) => word.get_or_insert_with(String::new).push(escaped),
+                None => {}
+            },
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '\'' => break,
+                        c => word.push(c),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '"' => break,
+                        '\\' if matches!(chars.peek(), Some('"' | '\\' | '$' | '`')) => {
+                            word.extend(chars.next());
+                        }
+                        c => word.push(c),
+                    }
+                }
+            }
+            '$' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '\'' => break,
+                        '\\' => match chars.next().ok_or_else(unterminated)? {
+                            'n' => word.push('\n'),
+                            't' => word.push('\t'),
+                            'r' => word.push('\r'),
+                            escaped => word.push(escaped),
+                        },
+                        c => word.push(c),
+                    }
+                }
+            }
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word);
+    Ok(words)
+}
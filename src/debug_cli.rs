@@ -0,0 +1,136 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::Result;
+use std::fmt::Write;
+
+use crate::args::parse_http_method;
+use crate::cli::parse_headers;
+use crate::tester::{probe, HttpMethod, Probe, RequestError, TestConfig};
+
+/// Arguments for `whambam debug`.
+#[derive(clap::Args, Clone, Debug)]
+pub struct DebugArgs {
+    /// The URL to send the requests to.
+    #[arg(required = true)]
+    pub url: String,
+
+    /// Number of requests to send, one after another on the same client.
+    #[arg(short = 'n', long, default_value = "3")]
+    pub count: usize,
+
+    /// HTTP method.
+    #[arg(short = 'm', long = "method", default_value = "GET", value_parser = parse_http_method)]
+    pub method: HttpMethod,
+
+    /// Custom HTTP header in "Name: Value" form. Can be repeated.
+    #[arg(short = 'H', long = "header")]
+    pub headers: Vec<String>,
+
+    /// HTTP request body as a string.
+    #[arg(short = 'd', long = "body")]
+    pub body: Option<String>,
+
+    /// Timeout for each request in seconds. Use 0 for no timeout.
+    #[arg(short = 't', long = "timeout", default_value = "20")]
+    pub timeout: u64,
+
+    /// Skip TLS certificate verification, e.g. for self-signed staging certificates.
+    #[arg(short = 'k', long)]
+    pub insecure: bool,
+}
+
+/// What a request sent and got back, curl -v style: `>` lines were sent, `<`
+/// lines were received, and `*` lines are whambam's own notes.
+pub fn transcript(config: &TestConfig, result: &Result<Probe, RequestError>) -> String {
+    let mut out = format!("> {} {}\n", config.method, config.url);
+    let _ = writeln!(out, "> user-agent: {}", config.user_agent);
+    for (name, value) in &config.headers {
+        let _ = writeln!(out, "> {name}: {value}");
+    }
+    if let Some(body) = &config.body {
+        let _ = writeln!(out, "> ({} byte body)", body.len());
+    }
+
+    let probe = match result {
+        Ok(probe) => probe,
+        Err(error) => {
+            let _ = writeln!(out, "* Failed ({}): {}", error.kind, error.message);
+            return out;
+        }
+    };
+
+    if let Some(addr) = probe.remote_addr {
+        let _ = writeln!(out, "* Connected to {addr}");
+    }
+    let phases: Vec<String> = [
+        ("DNS", probe.timing.dns_lookup_ms),
+        ("connect", probe.timing.connect_ms),
+        ("TLS", probe.timing.tls_handshake_ms),
+    ]
+    .into_iter()
+    .filter_map(|(name, ms)| Some(format!("{name} {:.2} ms", ms?)))
+    .collect();
+    if !phases.is_empty() {
+        let _ = writeln!(out, "* Setup: {}", phases.join(", "));
+    }
+
+    let _ = writeln!(out, "< {:?} {}", probe.head.version, probe.head.status);
+    for (name, value) in &probe.head.headers {
+        let _ = writeln!(
+            out,
+            "< {name}: {}",
+            String::from_utf8_lossy(value.as_bytes())
+        );
+    }
+    let _ = writeln!(
+        out,
+        "* {} bytes; first byte {:.2} ms, total {:.2} ms",
+        probe.body_bytes, probe.first_byte_ms, probe.total_ms
+    );
+    out
+}
+
+/// Sends a few requests and prints everything about each, to check a target
+/// before load testing it.
+pub async fn run(args: DebugArgs) -> Result<()> {
+    let config = TestConfig {
+        url: args.url.clone(),
+        method: args.method.clone(),
+        headers: parse_headers(&args.headers)?,
+        body: args.body.clone().map(String::into_bytes),
+        timeout: args.timeout,
+        insecure: args.insecure,
+        concurrent: 1,
+        ..Default::default()
+    };
+
+    let results = probe(&config, args.count).await?;
+    for (index, result) in results.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!("* Request {} of {}", index + 1, results.len());
+        print!("{}", transcript(&config, result));
+    }
+    Ok(())
+}
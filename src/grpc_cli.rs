@@ -0,0 +1,136 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cli::{launch, parse_headers, request_limit};
+use crate::headless::Verbosity;
+use crate::tester::grpc::{fetch_schema, parse_proto, GrpcCall, GrpcClient};
+use crate::tester::{parse_duration, parse_target_url, HttpMethod, TestConfig};
+use crate::validate::check_load;
+
+/// Arguments for `whambam grpc`.
+#[derive(clap::Args, Clone, Debug)]
+pub struct GrpcArgs {
+    /// The server to call, e.g. http://localhost:50051 (cleartext HTTP/2) or https://api.example.com.
+    #[arg(required = true)]
+    pub url: String,
+
+    /// The method to call, as package.Service/Method.
+    #[arg(long)]
+    pub call: String,
+
+    /// The .proto file defining the method. Without it, the server's reflection service is asked.
+    #[arg(long)]
+    pub proto: Option<PathBuf>,
+
+    /// The request message as JSON.
+    #[arg(short = 'd', long, default_value = "{}")]
+    pub data: String,
+
+    /// Number of calls to make [default: 200, or unlimited with -z]. With -z as well, the
+    /// test ends at whichever limit is reached first. If 0, the test runs indefinitely or
+    /// until the duration is met.
+    #[arg(short = 'n', long)]
+    pub requests: Option<usize>,
+
+    /// Number of concurrent calls.
+    #[arg(short, long, default_value = "50")]
+    pub concurrent: usize,
+
+    /// Duration of the test, e.g. "10s", "1m" or "2h".
+    #[arg(short = 'z', long = "duration", default_value = "0")]
+    pub duration_str: String,
+
+    /// Timeout for each call in seconds. Use 0 for no timeout.
+    #[arg(short = 't', long = "timeout", default_value = "20")]
+    pub timeout: u64,
+
+    /// Rate limit in calls per second (QPS) per worker. 0 means no limit.
+    #[arg(short = 'q', long, default_value = "0")]
+    pub rate_limit: f64,
+
+    /// Custom metadata, sent as HTTP/2 headers. Can be repeated.
+    #[arg(short = 'H', long = "header")]
+    pub headers: Vec<String>,
+
+    /// Disable the interactive UI and print results to stdout.
+    #[arg(long)]
+    pub no_ui: bool,
+}
+
+/// Runs a unary gRPC load test.
+pub async fn run(args: GrpcArgs) -> Result<()> {
+    let url = parse_target_url(&args.url)?;
+    let (service, _) = args
+        .call
+        .split_once('/')
+        .ok_or_else(|| anyhow!("--call must look like package.Service/Method"))?;
+    let headers = parse_headers(&args.headers)?;
+
+    let duration_secs = parse_duration(&args.duration_str)?;
+    let warnings = check_load(
+        args.requests,
+        args.concurrent,
+        duration_secs,
+        args.timeout,
+        args.rate_limit,
+    )?;
+    for warning in warnings {
+        eprintln!("Warning: {warning}");
+    }
+
+    let schema = match &args.proto {
+        Some(path) => {
+            let source = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            parse_proto(&source)?
+        }
+        None => fetch_schema(&GrpcClient::new(), &url, service, &headers, args.timeout)
+            .await
+            .context("Could not load method definitions over gRPC reflection")?,
+    };
+    let input = schema.input_type(&args.call)?;
+    let request = serde_json::from_str(&args.data).context("--data must be valid JSON")?;
+    let message = schema.encode(input, &request)?;
+
+    let config = TestConfig {
+        url: args.url.clone(),
+        method: HttpMethod::POST,
+        headers,
+        duration: duration_secs,
+        requests: request_limit(args.requests, args.concurrent, duration_secs),
+        concurrent: args.concurrent,
+        timeout: args.timeout,
+        rate_limit: args.rate_limit,
+        interactive: !args.no_ui,
+        grpc: Some(GrpcCall {
+            method: args.call.clone(),
+            message,
+        }),
+        ..Default::default()
+    };
+
+    launch(config, args.no_ui, Verbosity::Normal).await
+}
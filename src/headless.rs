@@ -0,0 +1,242 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::Result;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::tester::{
+    print_hey_format_report, SharedState, StopReason, TestConfig, TestReport, TestState,
+    UnifiedRunner,
+};
+
+/// How often text mode checks the shared state
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long Ctrl-C waits for requests in flight before reporting without them
+const INTERRUPT_GRACE: Duration = Duration::from_secs(1);
+
+/// Time allowed past the drain timeout for the runner to flush its results
+pub(crate) const DRAIN_SLACK: Duration = Duration::from_secs(1);
+
+/// How much text mode prints while a test runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only the final summary
+    Quiet,
+    /// Periodic progress lines and the final summary
+    Normal,
+    /// Progress lines plus warnings about errors seen in each interval
+    Verbose,
+}
+
+/// Counters captured at the previous progress line
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Progress {
+    pub elapsed: f64,
+    pub completed: usize,
+    pub errors: usize,
+}
+
+impl Progress {
+    fn capture(state: &TestState) -> Self {
+        Progress {
+            elapsed: state.elapsed_secs(),
+            completed: state.completed_requests,
+            errors: state.error_count,
+        }
+    }
+}
+
+/// Run a test without the interactive UI, printing progress and a final report to
+/// stdout; Ctrl-C stops it early and reports whatever completed, like `hey`, and
+/// SIGTERM stops it after draining so output files are complete
+pub async fn run(config: TestConfig, verbosity: Verbosity) -> Result<()> {
+    let state = drive(config, verbosity, true).await?;
+
+    let guard = state.lock().unwrap();
+    print_hey_format_report(&mut io::stdout(), &guard)?;
+
+    Ok(())
+}
+
+/// Run a test until it finishes, printing progress lines, and return its final state
+pub async fn run_to_completion(
+    config: TestConfig,
+    verbosity: Verbosity,
+) -> Result<Arc<Mutex<TestState>>> {
+    drive(config, verbosity, false).await
+}
+
+/// Run a test until it finishes or, if `interruptible`, until Ctrl-C or SIGTERM
+async fn drive(
+    config: TestConfig,
+    verbosity: Verbosity,
+    interruptible: bool,
+) -> Result<Arc<Mutex<TestState>>> {
+    let drain_wait = config.drain_timeout + DRAIN_SLACK;
+    let progress_interval = config.progress_interval;
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let mut runner = UnifiedRunner::with_state(
+        config,
+        SharedState {
+            state: Arc::clone(&state),
+        },
+    );
+    runner.start().await?;
+
+    let mut last = Progress::default();
+    let mut next_progress = Instant::now() + progress_interval;
+    // Only listen once asked to, so library callers keep the default signal handling
+    let interrupt = async {
+        if !interruptible {
+            return std::future::pending().await;
+        }
+        tokio::select! {
+            Ok(()) = tokio::signal::ctrl_c() => (StopReason::Interrupted, INTERRUPT_GRACE),
+            () = terminated() => (StopReason::Terminated, drain_wait),
+        }
+    };
+    tokio::pin!(interrupt);
+
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep(POLL_INTERVAL) => {}
+            (reason, grace) = &mut interrupt => {
+                runner.stop();
+                state.lock().unwrap().stop_reason.get_or_insert(reason);
+                wait_until_complete(&state, grace).await;
+                // Report without whatever is still in flight, output files included
+                runner.finish().await;
+                break;
+            }
+        }
+
+        let mut guard = state.lock().unwrap();
+        if is_finished(&guard) {
+            guard.mark_complete();
+            break;
+        }
+
+        if verbosity != Verbosity::Quiet && Instant::now() >= next_progress {
+            next_progress += progress_interval;
+            println!("{}", progress_line(&guard, &last));
+            if verbosity == Verbosity::Verbose {
+                if let Some(warning) = interval_warning(&guard, &last) {
+                    eprintln!("{warning}");
+                }
+            }
+            last = Progress::capture(&guard);
+        }
+    }
+
+    runner.stop();
+
+    Ok(state)
+}
+
+/// Resolves once the process is sent SIGTERM, as schedulers such as Kubernetes do
+/// before killing it; never resolves where there is no SIGTERM
+pub(crate) async fn terminated() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        if let Ok(mut sigterm) = signal(SignalKind::terminate()) {
+            sigterm.recv().await;
+            return;
+        }
+    }
+    std::future::pending().await
+}
+
+/// Wait for a stopped test to finish the requests it had in flight, for at most `timeout`
+pub(crate) async fn wait_until_complete(state: &Mutex<TestState>, timeout: Duration) {
+    let deadline = Instant::now() + timeout;
+    while !is_finished(&state.lock().unwrap()) && Instant::now() < deadline {
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Whether the runner has finished, in-flight requests drained included
+fn is_finished(state: &TestState) -> bool {
+    state.is_complete
+}
+
+/// Format a one-line progress update covering the interval since `last`
+pub fn progress_line(state: &TestState, last: &Progress) -> String {
+    let now = Progress::capture(state);
+    let interval = now.elapsed - last.elapsed;
+    let rps = if interval > 0.0 {
+        (now.completed - last.completed) as f64 / interval
+    } else {
+        0.0
+    };
+
+    format!(
+        "[{:>7.1}s] {} requests, {:.1} req/s, p99 {:.3} ms, {} errors",
+        now.elapsed,
+        now.completed,
+        rps,
+        state.latency_at_quantile(0.99),
+        now.errors
+    )
+}
+
+/// Describe errors seen since `last`, if there were any
+pub fn interval_warning(state: &TestState, last: &Progress) -> Option<String> {
+    let errors = state.error_count - last.errors;
+    if errors == 0 {
+        return None;
+    }
+
+    let requests = state.completed_requests - last.completed;
+    Some(format!(
+        "Warning: {errors} of {requests} requests failed in the last {:.1}s",
+        state.elapsed_secs() - last.elapsed
+    ))
+}
+
+/// Run a load test to completion without any UI or printing and return its results.
+///
+/// ```no_run
+/// # async fn example() -> anyhow::Result<()> {
+/// let config = whambam::tester::TestConfig {
+///     url: "http://localhost:8080/".to_string(),
+///     requests: 1000,
+///     concurrent: 20,
+///     ..Default::default()
+/// };
+/// let report = whambam::run_test(config).await?;
+/// println!("{:.0} req/s, p99 {:.1} ms", report.requests_per_sec, report.latency_ms.p99);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn run_test(config: TestConfig) -> Result<TestReport> {
+    let config = TestConfig {
+        interactive: false,
+        ..config
+    };
+    let state = run_to_completion(config, Verbosity::Quiet).await?;
+    let guard = state.lock().unwrap();
+    Ok(TestReport::from_state(&guard))
+}
@@ -0,0 +1,180 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::args::parse_http_method;
+use crate::cli::{launch, request_limit};
+use crate::headless::Verbosity;
+use crate::tester::{parse_duration, parse_target_url, ScenarioStep, TestConfig};
+use crate::validate::check_load;
+
+/// Headers the client sets itself, or that only make sense on the original connection
+const SKIPPED_HEADERS: [&str; 4] = ["host", "content-length", "connection", "accept-encoding"];
+
+/// Arguments for `whambam replay`.
+#[derive(clap::Args, Clone, Debug)]
+pub struct ReplayArgs {
+    /// The HAR file to replay, as exported from a browser's network panel.
+    #[arg(required = true)]
+    pub file: PathBuf,
+
+    /// Number of requests to send [default: 200, or unlimited with -z]. With -z as well,
+    /// the test ends at whichever limit is reached first. If 0, the test runs indefinitely
+    /// or until the duration is met.
+    #[arg(short = 'n', long)]
+    pub requests: Option<usize>,
+
+    /// Number of concurrent users, each replaying the requests in order.
+    #[arg(short, long, default_value = "50")]
+    pub concurrent: usize,
+
+    /// Duration of the test, e.g. "10s", "1m" or "2h".
+    #[arg(short = 'z', long = "duration", default_value = "0")]
+    pub duration_str: String,
+
+    /// Timeout for each request in seconds. Use 0 for no timeout.
+    #[arg(short = 't', long = "timeout", default_value = "20")]
+    pub timeout: u64,
+
+    /// Rate limit in requests per second (QPS) per worker. 0 means no limit.
+    #[arg(short = 'q', long, default_value = "0")]
+    pub rate_limit: f64,
+
+    /// Disable the interactive UI and print results to stdout.
+    #[arg(long)]
+    pub no_ui: bool,
+}
+
+/// Turn the entries of a HAR file into scenario steps named by URL path, so
+/// requests to the same path share stats. Entries that aren't HTTP(S), such as
+/// `data:` URLs, are skipped and counted.
+pub fn parse_har(text: &str) -> Result<(Vec<ScenarioStep>, usize)> {
+    let har: Value = serde_json::from_str(text).context("Not a valid HAR file")?;
+    let entries = har
+        .pointer("/log/entries")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("HAR file has no log.entries"))?;
+
+    let mut steps = Vec::new();
+    let mut skipped = 0;
+    for (index, entry) in entries.iter().enumerate() {
+        let request = entry
+            .get("request")
+            .ok_or_else(|| anyhow!("HAR entry {index} has no request"))?;
+        let url = request
+            .get("url")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let Ok(parsed) = parse_target_url(url) else {
+            skipped += 1;
+            continue;
+        };
+        let method = request
+            .get("method")
+            .and_then(Value::as_str)
+            .unwrap_or("GET");
+
+        let mut headers: Vec<(String, String)> = request
+            .get("headers")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|header| {
+                let name = header.get("name")?.as_str()?;
+                let value = header.get("value")?.as_str()?;
+                let replayed = !name.starts_with(':')
+                    && !SKIPPED_HEADERS.contains(&name.to_ascii_lowercase().as_str());
+                replayed.then(|| (name.to_string(), value.to_string()))
+            })
+            .collect();
+
+        let post_data = request.get("postData");
+        let body = post_data
+            .and_then(|data| data.get("text"))
+            .and_then(Value::as_str)
+            .filter(|text| !text.is_empty());
+        let mime_type = post_data
+            .and_then(|data| data.get("mimeType"))
+            .and_then(Value::as_str);
+        let has_content_type = headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+        if let (Some(_), Some(mime_type), false) = (body, mime_type, has_content_type) {
+            headers.push(("Content-Type".to_string(), mime_type.to_string()));
+        }
+
+        steps.push(ScenarioStep {
+            name: parsed.path().to_string(),
+            method: parse_http_method(method)
+                .with_context(|| format!("HAR entry {index} for {url}"))?,
+            url: url.to_string(),
+            body: body.map(str::to_string),
+            headers,
+        });
+    }
+
+    Ok((steps, skipped))
+}
+
+/// Replays the requests of a HAR file as a load test.
+pub async fn run(args: ReplayArgs) -> Result<()> {
+    let text = fs::read_to_string(&args.file)
+        .with_context(|| format!("Failed to read {}", args.file.display()))?;
+    let (steps, skipped) = parse_har(&text)?;
+    if skipped > 0 {
+        eprintln!("Warning: skipped {skipped} HAR entries that are not HTTP(S) requests");
+    }
+    let first = steps
+        .first()
+        .ok_or_else(|| anyhow!("{} has no HTTP(S) requests to replay", args.file.display()))?;
+
+    let duration_secs = parse_duration(&args.duration_str)?;
+    let warnings = check_load(
+        args.requests,
+        args.concurrent,
+        duration_secs,
+        args.timeout,
+        args.rate_limit,
+    )?;
+    for warning in warnings {
+        eprintln!("Warning: {warning}");
+    }
+
+    let config = TestConfig {
+        url: first.url.clone(),
+        method: first.method.clone(),
+        duration: duration_secs,
+        requests: request_limit(args.requests, args.concurrent, duration_secs),
+        concurrent: args.concurrent,
+        timeout: args.timeout,
+        rate_limit: args.rate_limit,
+        interactive: !args.no_ui,
+        scenario: steps,
+        ..Default::default()
+    };
+
+    launch(config, args.no_ui, Verbosity::Normal).await
+}
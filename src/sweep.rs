@@ -0,0 +1,215 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::Result;
+use std::io::{self, Write};
+
+use crate::headless::{run_to_completion, Verbosity};
+use crate::tester::{TestConfig, TestReport, TestState};
+
+/// Throughput gain below which more concurrency is no longer paying off
+const KNEE_GAIN: f64 = 0.1;
+
+/// Throughput and latency measured for one stage of a staged run
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageResult {
+    pub label: String,
+    pub completed: usize,
+    pub errors: usize,
+    pub requests_per_sec: f64,
+    pub average_ms: f64,
+    pub p99_ms: f64,
+}
+
+impl StageResult {
+    /// Summarize a finished stage
+    pub fn from_state(label: impl Into<String>, state: &TestState) -> Self {
+        let report = TestReport::from_state(state);
+
+        StageResult {
+            label: label.into(),
+            completed: report.completed_requests,
+            errors: report.error_count,
+            requests_per_sec: report.requests_per_sec,
+            average_ms: report.latency_ms.average,
+            p99_ms: report.latency_ms.p99,
+        }
+    }
+}
+
+/// Run one stage per body size, then print the payload-size scaling table
+pub async fn run(config: TestConfig, sizes: &[usize], verbosity: Verbosity) -> Result<()> {
+    let mut results = Vec::with_capacity(sizes.len());
+
+    for &size in sizes {
+        let stage = TestConfig {
+            body: Some(synthetic_body(size)),
+            ..config.clone()
+        };
+        results.push(run_stage(format_size(size), stage, verbosity).await?);
+    }
+
+    print_stage_table(&mut io::stdout(), "Body size sweep:", &results)?;
+    Ok(())
+}
+
+/// Run one stage per concurrency level, then print the table and where throughput levels off
+pub async fn run_concurrency(
+    config: TestConfig,
+    levels: &[usize],
+    verbosity: Verbosity,
+) -> Result<()> {
+    let mut results = Vec::with_capacity(levels.len());
+
+    for &level in levels {
+        let stage = TestConfig {
+            concurrent: level,
+            // Keep every worker busy, as the single-run path does
+            requests: if config.requests > 0 {
+                config.requests.max(level)
+            } else {
+                0
+            },
+            ..config.clone()
+        };
+        results.push(run_stage(format!("c={level}"), stage, verbosity).await?);
+    }
+
+    let mut out = io::stdout();
+    print_stage_table(&mut out, "Concurrency sweep:", &results)?;
+    match knee(&results) {
+        Some(knee) => writeln!(
+            out,
+            "  Knee:\t\t{} (throughput grows less than {:.0}% past it)",
+            results[knee].label,
+            KNEE_GAIN * 100.0
+        )?,
+        None => writeln!(out, "  Knee:\t\tnot reached; throughput still rising")?,
+    }
+    Ok(())
+}
+
+/// The first stage after which throughput improves by less than `KNEE_GAIN`
+pub fn knee(results: &[StageResult]) -> Option<usize> {
+    results
+        .windows(2)
+        .position(|pair| pair[1].requests_per_sec < pair[0].requests_per_sec * (1.0 + KNEE_GAIN))
+}
+
+/// Run a cold phase with cache busting, then a warm phase repeating the same URL
+pub async fn run_cache_comparison(config: TestConfig, verbosity: Verbosity) -> Result<()> {
+    let cold = TestConfig {
+        cache_bust: true,
+        ..config.clone()
+    };
+    let warm = TestConfig {
+        cache_bust: false,
+        ..config
+    };
+
+    let cold = run_stage("cold".to_string(), cold, verbosity).await?;
+    let warm = run_stage("warm".to_string(), warm, verbosity).await?;
+
+    print_cache_comparison(&mut io::stdout(), &cold, &warm)?;
+    Ok(())
+}
+
+/// Run a single labelled stage to completion
+pub(crate) async fn run_stage(
+    label: String,
+    config: TestConfig,
+    verbosity: Verbosity,
+) -> Result<StageResult> {
+    if verbosity != Verbosity::Quiet {
+        println!("Stage: {label}");
+    }
+
+    let state = run_to_completion(config, verbosity).await?;
+    let guard = state.lock().unwrap();
+    Ok(StageResult::from_state(label, &guard))
+}
+
+/// A request body of exactly `size` bytes
+pub fn synthetic_body(size: usize) -> Vec<u8> {
+    vec![b'x'; size]
+}
+
+/// Format a byte count with the largest unit that divides it evenly
+pub fn format_size(bytes: usize) -> String {
+    const UNITS: [(usize, &str); 3] = [(1 << 30, "GB"), (1 << 20, "MB"), (1 << 10, "KB")];
+
+    UNITS
+        .iter()
+        .find(|(unit, _)| bytes >= *unit && bytes.is_multiple_of(*unit))
+        .map(|(unit, suffix)| format!("{}{suffix}", bytes / unit))
+        .unwrap_or_else(|| format!("{bytes}B"))
+}
+
+/// Print throughput and latency for each stage under a heading
+pub fn print_stage_table<W: Write>(
+    w: &mut W,
+    heading: &str,
+    results: &[StageResult],
+) -> io::Result<()> {
+    writeln!(w)?;
+    writeln!(w, "{heading}")?;
+    for result in results {
+        writeln!(
+            w,
+            "  [{}]\t{} requests, {:.4} req/s, average {:.3} ms, p99 {:.3} ms, {} errors",
+            result.label,
+            result.completed,
+            result.requests_per_sec,
+            result.average_ms,
+            result.p99_ms,
+            result.errors
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Print the cold and warm phases side by side with the warm/cold ratios
+pub fn print_cache_comparison<W: Write>(
+    w: &mut W,
+    cold: &StageResult,
+    warm: &StageResult,
+) -> io::Result<()> {
+    print_stage_table(w, "Cache comparison:", &[cold.clone(), warm.clone()])?;
+
+    writeln!(
+        w,
+        "  Warm/cold:\t{:.2}x average latency, {:.2}x p99 latency, {:.2}x req/s",
+        ratio(warm.average_ms, cold.average_ms),
+        ratio(warm.p99_ms, cold.p99_ms),
+        ratio(warm.requests_per_sec, cold.requests_per_sec)
+    )
+}
+
+/// `warm / cold`, or 0 when there is no cold measurement to compare against
+fn ratio(warm: f64, cold: f64) -> f64 {
+    if cold > 0.0 {
+        warm / cold
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,180 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use ring::digest;
+use serde_json::Value;
+
+/// One step of a JSONPath: an object key or an array index
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// A JSONPath subset: `$`, `.key` and `[index]` steps
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath(Vec<Segment>);
+
+impl JsonPath {
+    /// Parse a path such as `$.items[0].id`
+    pub fn parse(path: &str) -> Result<Self> {
+        parse_path(path).map(JsonPath)
+    }
+
+    /// The value the path points at in `document`, if there is one
+    pub fn lookup<'a>(&self, document: &'a Value) -> Option<&'a Value> {
+        self.0
+            .iter()
+            .try_fold(document, |value, segment| match segment {
+                Segment::Key(key) => value.get(key),
+                Segment::Index(index) => value.get(index),
+            })
+    }
+}
+
+/// A check such as `$.data.items[0].id == 42` evaluated against each JSON response body
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonAssertion {
+    expression: String,
+    path: JsonPath,
+    expected: Value,
+    negated: bool,
+}
+
+impl JsonAssertion {
+    /// Parse `<jsonpath> == <value>` or `<jsonpath> != <value>`, where the value is JSON or bare text
+    pub fn parse(expression: &str) -> Result<Self> {
+        // The first operator splits the path from the value, which may contain either
+        let Some((at, operator)) = ["==", "!="]
+            .into_iter()
+            .filter_map(|operator| expression.find(operator).map(|at| (at, operator)))
+            .min()
+        else {
+            return Err(anyhow!(
+                "Invalid JSON assertion: '{expression}'. Expected '<jsonpath> == <value>'"
+            ));
+        };
+        let (path, expected) = (&expression[..at], &expression[at + operator.len()..]);
+        let negated = operator == "!=";
+
+        let expected = expected.trim();
+        Ok(JsonAssertion {
+            expression: expression.trim().to_string(),
+            path: JsonPath::parse(path.trim())?,
+            expected: serde_json::from_str(expected)
+                .unwrap_or_else(|_| Value::String(expected.to_string())),
+            negated,
+        })
+    }
+
+    /// The assertion as written on the command line
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Whether a response body satisfies the assertion; bodies that aren't JSON never do
+    pub fn check(&self, body: &[u8]) -> bool {
+        let Ok(document) = serde_json::from_slice::<Value>(body) else {
+            return false;
+        };
+
+        let matches = self.path.lookup(&document) == Some(&self.expected);
+        matches != self.negated
+    }
+}
+
+/// Parse a JSONPath subset: `$`, `.key` and `[index]` steps
+fn parse_path(path: &str) -> Result<Vec<Segment>> {
+    let invalid = || anyhow!("Invalid JSONPath: '{path}'. Expected e.g. $.items[0].id");
+    let rest = path.strip_prefix('$').ok_or_else(invalid)?;
+
+    let mut segments = Vec::new();
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                let mut key = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next == '.' || next == '[' {
+                        break;
+                    }
+                    if !is_key_char(next) {
+                        return Err(invalid());
+                    }
+                    key.push(next);
+                    chars.next();
+                }
+                if key.is_empty() {
+                    return Err(invalid());
+                }
+                segments.push(Segment::Key(key));
+            }
+            '[' => {
+                let mut index = String::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(next) => index.push(next),
+                        None => return Err(invalid()),
+                    }
+                }
+                segments.push(Segment::Index(index.parse().map_err(|_| invalid())?));
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Characters a `.key` step may contain; anything else is a typo or a misparse
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// The SHA-256 digest every response body is expected to have
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyChecksum([u8; 32]);
+
+impl BodyChecksum {
+    /// Parse a hex-encoded SHA-256 digest
+    pub fn parse(hex: &str) -> Result<Self> {
+        let hex = hex.trim();
+        let invalid = || anyhow!("Invalid SHA-256 hash: '{hex}'. Expected 64 hex characters");
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut digest = [0u8; 32];
+        for (byte, pair) in digest.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+        }
+        Ok(BodyChecksum(digest))
+    }
+
+    /// Whether a response body hashes to the expected digest
+    pub fn matches(&self, body: &[u8]) -> bool {
+        digest::digest(&digest::SHA256, body).as_ref() == self.0
+    }
+}
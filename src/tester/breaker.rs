@@ -0,0 +1,110 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use hdrhistogram::Histogram;
+
+use super::types::{RequestMetric, StopReason};
+
+/// Circuit breaker settings: abort when windowed p99 latency exceeds a limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircuitBreaker {
+    /// p99 latency limit in milliseconds
+    pub p99_limit_ms: f64,
+
+    /// Consecutive one-second windows over the limit before the breaker trips
+    pub consecutive_secs: u64,
+}
+
+/// Tracks p99 latency per one-second window and trips once it stays over the limit
+pub struct LatencyBreaker {
+    config: CircuitBreaker,
+    window: Histogram<u64>,
+    window_second: Option<u64>,
+    strikes: u64,
+}
+
+impl LatencyBreaker {
+    pub fn new(config: CircuitBreaker) -> Self {
+        LatencyBreaker {
+            config,
+            window: Histogram::<u64>::new(3).unwrap(),
+            window_second: None,
+            strikes: 0,
+        }
+    }
+
+    /// Record a request, returning the stop reason once the breaker has tripped
+    pub fn observe(&mut self, metric: &RequestMetric) -> Option<StopReason> {
+        let second = metric.timestamp.max(0.0).floor() as u64;
+        match self.window_second {
+            Some(current) if second > current => {
+                self.close_window();
+                self.window_second = Some(second);
+            }
+            None => self.window_second = Some(second),
+            _ => {}
+        }
+
+        // Latencies are recorded in microseconds, like the main histogram
+        let _ = self.window.record((metric.latency_ms * 1000.0) as u64);
+
+        self.is_tripped().then_some(StopReason::LatencyBreaker {
+            limit_ms: self.config.p99_limit_ms,
+            seconds: self.config.consecutive_secs,
+        })
+    }
+
+    /// Whether enough consecutive windows have exceeded the limit
+    pub fn is_tripped(&self) -> bool {
+        self.strikes >= self.config.consecutive_secs.max(1)
+    }
+
+    fn close_window(&mut self) {
+        let p99_ms = self.window.value_at_quantile(0.99) as f64 / 1000.0;
+        if p99_ms > self.config.p99_limit_ms {
+            self.strikes += 1;
+        } else {
+            self.strikes = 0;
+        }
+        self.window.reset();
+    }
+}
+
+/// Trips once the total number of failed requests reaches a limit
+pub struct ErrorBreaker {
+    limit: u64,
+    errors: u64,
+}
+
+impl ErrorBreaker {
+    pub fn new(limit: u64) -> Self {
+        ErrorBreaker { limit, errors: 0 }
+    }
+
+    /// Record a request, returning the stop reason once the error limit is reached
+    pub fn observe(&mut self, metric: &RequestMetric) -> Option<StopReason> {
+        if metric.is_error {
+            self.errors += 1;
+        }
+        (self.errors >= self.limit).then_some(StopReason::ErrorLimit { errors: self.limit })
+    }
+}
@@ -0,0 +1,212 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+
+use super::error::parse_target_url;
+use super::failover::check_fallback;
+use super::theme::Theme;
+use super::types::{HttpMethod, TestConfig};
+
+/// Parses a duration string (e.g., "10s", "5m", "1h") into a total number of seconds.
+pub fn parse_duration(duration_str: &str) -> Result<u64> {
+    if duration_str.is_empty() {
+        return Err(anyhow!("Duration string cannot be empty."));
+    }
+    if duration_str == "0" {
+        return Ok(0);
+    }
+
+    let mut chars = duration_str.chars();
+    let multiplier: u64 = match chars.next_back() {
+        Some('s') => 1,
+        Some('m') => 60,
+        Some('h') => 3600,
+        // Assume the whole string is a number representing seconds.
+        _ => {
+            return duration_str
+                .parse::<u64>()
+                .map_err(|_| anyhow!("Invalid duration format"))
+        }
+    };
+
+    let num_part = chars.as_str();
+    if num_part.is_empty() {
+        return Err(anyhow!("Duration is missing a number."));
+    }
+    let num = num_part
+        .parse::<u64>()
+        .map_err(|_| anyhow!("Invalid number in duration"))?;
+    num.checked_mul(multiplier)
+        .ok_or_else(|| anyhow!("Duration too large: {duration_str}"))
+}
+
+/// Step-by-step construction of a [`TestConfig`] for library users, checked
+/// as a whole by [`TestConfigBuilder::build`]
+///
+/// ```
+/// use whambam::tester::{HttpMethod, TestConfig};
+///
+/// let config = TestConfig::builder()
+///     .url("http://localhost:8080/health")
+///     .method(HttpMethod::POST)
+///     .concurrent(10)
+///     .duration("30s")
+///     .basic_auth("user:secret")
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.duration, 30);
+/// ```
+#[derive(Default)]
+pub struct TestConfigBuilder {
+    config: TestConfig,
+    duration: Option<String>,
+    timeout: Option<String>,
+    basic_auth: Option<String>,
+}
+
+impl TestConfig {
+    /// Start building a config from the command-line defaults
+    pub fn builder() -> TestConfigBuilder {
+        TestConfigBuilder::default()
+    }
+}
+
+impl TestConfigBuilder {
+    /// URL to test; must be http:// or https://
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = url.into();
+        self
+    }
+
+    pub fn method(mut self, method: HttpMethod) -> Self {
+        self.config.method = method;
+        self
+    }
+
+    /// Number of requests to send (0 for unlimited)
+    pub fn requests(mut self, requests: usize) -> Self {
+        self.config.requests = requests;
+        self
+    }
+
+    /// Number of concurrent connections; at least 1
+    pub fn concurrent(mut self, concurrent: usize) -> Self {
+        self.config.concurrent = concurrent;
+        self
+    }
+
+    /// Test length such as "10s", "5m" or "1h" ("0" for unlimited)
+    pub fn duration(mut self, duration: impl Into<String>) -> Self {
+        self.duration = Some(duration.into());
+        self
+    }
+
+    /// Per-request timeout, written like `duration` ("0" for no timeout)
+    pub fn timeout(mut self, timeout: impl Into<String>) -> Self {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Queries per second per worker (0 for no limit)
+    pub fn rate_limit(mut self, rate_limit: f64) -> Self {
+        self.config.rate_limit = rate_limit;
+        self
+    }
+
+    /// Add a header sent with every request
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Request body, sent as is
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.config.body = Some(body.into());
+        self
+    }
+
+    /// Basic authentication in `username:password` format
+    pub fn basic_auth(mut self, auth: impl Into<String>) -> Self {
+        self.basic_auth = Some(auth.into());
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config.user_agent = user_agent.into();
+        self
+    }
+
+    /// Accept any TLS certificate
+    pub fn insecure(mut self, insecure: bool) -> Self {
+        self.config.insecure = insecure;
+        self
+    }
+
+    pub fn disable_keepalive(mut self, disable: bool) -> Self {
+        self.config.disable_keepalive = disable;
+        self
+    }
+
+    pub fn disable_redirects(mut self, disable: bool) -> Self {
+        self.config.disable_redirects = disable;
+        self
+    }
+
+    /// Whether a UI is watching; `false` prints a summary when the test ends
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.config.interactive = interactive;
+        self
+    }
+
+    /// Colour scheme of the interactive UI
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.config.theme = theme;
+        self
+    }
+
+    /// Check everything set so far and produce the config
+    pub fn build(self) -> Result<TestConfig> {
+        let mut config = self.config;
+        parse_target_url(&config.url)?;
+        check_fallback(&config)?;
+        if config.concurrent == 0 {
+            return Err(anyhow!("Concurrency must be at least 1"));
+        }
+        if !config.rate_limit.is_finite() || config.rate_limit < 0.0 {
+            return Err(anyhow!("Rate limit must be a non-negative number"));
+        }
+        if let Some(duration) = &self.duration {
+            config.duration = parse_duration(duration)?;
+        }
+        if let Some(timeout) = &self.timeout {
+            config.timeout = parse_duration(timeout)?;
+        }
+        if let Some(auth) = &self.basic_auth {
+            let (user, pass) = auth.split_once(':').ok_or_else(|| {
+                anyhow!("Invalid basic auth format: '{auth}'. Expected 'username:password'.")
+            })?;
+            config.basic_auth = Some((user.to_string(), pass.to_string()));
+        }
+        Ok(config)
+    }
+}
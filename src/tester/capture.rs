@@ -0,0 +1,144 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{Context, Result};
+use parking_lot::Mutex;
+use reqwest::header::HeaderMap;
+use reqwest::{Response, StatusCode, Version};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Everything about a response that arrives before its body
+#[derive(Debug, Clone)]
+pub struct ResponseHead {
+    pub version: Version,
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+}
+
+impl ResponseHead {
+    /// Copy the head of a response, before reading the body consumes it
+    pub fn of(response: &Response) -> Self {
+        ResponseHead {
+            version: response.version(),
+            status: response.status(),
+            headers: response.headers().clone(),
+        }
+    }
+}
+
+/// Hand out the next of `limit` slots, or None once they are all gone
+fn take(taken: &AtomicUsize, limit: usize) -> Option<usize> {
+    let index = taken.fetch_add(1, Ordering::SeqCst);
+    (index < limit).then_some(index)
+}
+
+/// Saves the first few failed responses to a directory, one file each, so
+/// errors that only show up under load can be inspected after the run
+#[derive(Debug)]
+pub struct FailureDump {
+    dir: PathBuf,
+    limit: usize,
+    saved: AtomicUsize,
+}
+
+impl FailureDump {
+    /// Create `dir` if need be, ready to save up to `limit` responses into it
+    pub fn create(dir: &Path, limit: usize) -> Result<Self> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create failure directory '{}'", dir.display()))?;
+        Ok(FailureDump {
+            dir: dir.to_path_buf(),
+            limit,
+            saved: AtomicUsize::new(0),
+        })
+    }
+
+    /// Reserve the file for the next failure, or None once `limit` are saved
+    pub fn claim(&self, status: StatusCode) -> Option<PathBuf> {
+        take(&self.saved, self.limit).map(|index| {
+            self.dir
+                .join(format!("failure-{:04}-{}.txt", index + 1, status.as_u16()))
+        })
+    }
+
+    /// Write a failed response to the next free file; the run carries on if it can't be
+    pub async fn save(&self, head: &ResponseHead, body: &[u8]) {
+        if let Some(path) = self.claim(head.status) {
+            let _ = tokio::fs::write(path, format_response(head, body)).await;
+        }
+    }
+}
+
+/// Writes the first few responses of a run to one file, as a check that the
+/// test is hitting the right endpoint with the right payload
+#[derive(Debug)]
+pub struct ResponseSample {
+    file: Mutex<File>,
+    limit: usize,
+    taken: AtomicUsize,
+}
+
+impl ResponseSample {
+    /// Create `path`, ready to hold up to `limit` responses
+    pub fn create(path: &Path, limit: usize) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create sample file '{}'", path.display()))?;
+        Ok(ResponseSample {
+            file: Mutex::new(file),
+            limit,
+            taken: AtomicUsize::new(0),
+        })
+    }
+
+    /// Reserve a place for the next response, numbered from 1, or None once `limit` are taken
+    pub fn claim(&self) -> Option<usize> {
+        take(&self.taken, self.limit).map(|index| index + 1)
+    }
+
+    /// Append a claimed response under a heading naming the request that got it;
+    /// the run carries on if it can't be written
+    pub fn write(&self, number: usize, request: &str, head: &ResponseHead, body: &[u8]) {
+        let mut entry = format!("### {number}: {request}\n").into_bytes();
+        entry.extend(format_response(head, body));
+        entry.extend_from_slice(b"\n\n");
+        let _ = self.file.lock().write_all(&entry);
+    }
+}
+
+/// A response laid out the way it came over the wire: status line, headers,
+/// a blank line, then the body
+pub fn format_response(head: &ResponseHead, body: &[u8]) -> Vec<u8> {
+    let mut out = format!("{:?} {}\n", head.version, head.status);
+    for (name, value) in &head.headers {
+        out.push_str(&format!(
+            "{name}: {}\n",
+            String::from_utf8_lossy(value.as_bytes())
+        ));
+    }
+    out.push('\n');
+    let mut out = out.into_bytes();
+    out.extend_from_slice(body);
+    out
+}
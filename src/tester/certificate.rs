@@ -0,0 +1,237 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rustls::{ClientConfig, ServerName};
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use url::Url;
+
+use super::types::TestState;
+
+/// Certificates expiring within this many days get a warning in the report
+pub const EXPIRY_WARNING_DAYS: i64 = 30;
+
+/// Give up on the certificate probe after this long
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The parts of a server certificate worth showing in a report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    #[serde(with = "rfc3339")]
+    pub not_after: DateTime<Utc>,
+}
+
+/// Expiry times in reports are RFC 3339 strings
+mod rfc3339 {
+    use chrono::{DateTime, Utc};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        time: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&time.to_rfc3339())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&text)
+            .map(|time| time.with_timezone(&Utc))
+            .map_err(D::Error::custom)
+    }
+}
+
+impl CertificateInfo {
+    /// Parse the subject, issuer and expiry out of a DER certificate
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let (certificate, _) = expect(der, SEQUENCE)?;
+        let (mut tbs, _) = expect(certificate, SEQUENCE)?;
+
+        // Skip the optional version, then the serial number and signature algorithm
+        if tbs.first() == Some(&0xa0) {
+            tbs = read_tlv(tbs)?.2;
+        }
+        let tbs = read_tlv(tbs)?.2;
+        let tbs = read_tlv(tbs)?.2;
+
+        let (issuer, tbs) = expect(tbs, SEQUENCE)?;
+        let (validity, tbs) = expect(tbs, SEQUENCE)?;
+        let (subject, _) = expect(tbs, SEQUENCE)?;
+        let not_after = parse_time(read_tlv(validity)?.2)?;
+
+        Ok(CertificateInfo {
+            subject: format_name(subject)?,
+            issuer: format_name(issuer)?,
+            not_after,
+        })
+    }
+
+    /// Whole days until expiry, negative once expired
+    pub fn days_left(&self, now: DateTime<Utc>) -> i64 {
+        (self.not_after - now).num_days()
+    }
+}
+
+/// Handshake once with the target and return its certificate chain, leaf first
+///
+/// `pinned` replaces the DNS lookup of the URL's host, as `--sni` does for requests.
+pub async fn fetch_chain(
+    tls: Arc<ClientConfig>,
+    url: &Url,
+    pinned: Option<Vec<SocketAddr>>,
+) -> Result<Vec<CertificateInfo>> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("URL has no host: {url}"))?
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let name =
+        ServerName::try_from(host).with_context(|| format!("Invalid server name: '{host}'"))?;
+    let addrs = match pinned {
+        Some(addrs) => addrs,
+        None => {
+            let port = url.port_or_known_default().unwrap_or(443);
+            tokio::net::lookup_host((host, port)).await?.collect()
+        }
+    };
+
+    let probe = async {
+        let tcp = TcpStream::connect(addrs.as_slice()).await?;
+        TlsConnector::from(tls).connect(name, tcp).await
+    };
+    let stream = tokio::time::timeout(PROBE_TIMEOUT, probe)
+        .await
+        .context("Timed out fetching the server certificate")??;
+
+    stream
+        .get_ref()
+        .1
+        .peer_certificates()
+        .unwrap_or_default()
+        .iter()
+        .map(|cert| CertificateInfo::from_der(&cert.0))
+        .collect()
+}
+
+/// Fetch the chain in the background and keep it for the report; a failed
+/// probe just leaves the report without one
+pub fn record_chain(
+    state: Arc<Mutex<TestState>>,
+    tls: Arc<ClientConfig>,
+    url: Url,
+    pinned: Option<Vec<SocketAddr>>,
+) {
+    tokio::spawn(async move {
+        if let Ok(chain) = fetch_chain(tls, &url, pinned).await {
+            state.lock().unwrap().certificates = chain;
+        }
+    });
+}
+
+const SEQUENCE: u8 = 0x30;
+const SET: u8 = 0x31;
+const OID: u8 = 0x06;
+const UTC_TIME: u8 = 0x17;
+const GENERALIZED_TIME: u8 = 0x18;
+
+/// Split one DER tag-length-value off the front of `der`
+fn read_tlv(der: &[u8]) -> Result<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = der.split_first().ok_or_else(|| anyhow!("Truncated DER"))?;
+    let (&first, rest) = rest.split_first().ok_or_else(|| anyhow!("Truncated DER"))?;
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 4 || rest.len() < count {
+            return Err(anyhow!("Unsupported DER length"));
+        }
+        let len = rest[..count]
+            .iter()
+            .fold(0usize, |len, &b| (len << 8) | b as usize);
+        (len, &rest[count..])
+    };
+    if rest.len() < len {
+        return Err(anyhow!("Truncated DER"));
+    }
+    Ok((tag, &rest[..len], &rest[len..]))
+}
+
+fn expect(der: &[u8], tag: u8) -> Result<(&[u8], &[u8])> {
+    match read_tlv(der)? {
+        (found, content, rest) if found == tag => Ok((content, rest)),
+        (found, _, _) => Err(anyhow!("Expected DER tag {tag:#04x}, found {found:#04x}")),
+    }
+}
+
+fn parse_time(der: &[u8]) -> Result<DateTime<Utc>> {
+    let (tag, content, _) = read_tlv(der)?;
+    let text = std::str::from_utf8(content)?;
+    // RFC 5280: two-digit years from 50 onwards are 19xx
+    let text = match tag {
+        UTC_TIME if text < "50" => format!("20{text}"),
+        UTC_TIME => format!("19{text}"),
+        GENERALIZED_TIME => text.to_string(),
+        _ => return Err(anyhow!("Expected a certificate time, found tag {tag:#04x}")),
+    };
+    let time = NaiveDateTime::parse_from_str(&text, "%Y%m%d%H%M%SZ")
+        .with_context(|| format!("Invalid certificate time: {text}"))?;
+    Ok(time.and_utc())
+}
+
+/// Render a distinguished name as `CN=example.com, O=Example`
+fn format_name(mut name: &[u8]) -> Result<String> {
+    let mut parts = Vec::new();
+    while !name.is_empty() {
+        let (set, rest) = expect(name, SET)?;
+        name = rest;
+        let (attribute, _) = expect(set, SEQUENCE)?;
+        let (oid, value) = expect(attribute, OID)?;
+        let (_, value, _) = read_tlv(value)?;
+        if let Some(label) = attribute_label(oid) {
+            parts.push(format!("{label}={}", String::from_utf8_lossy(value)));
+        }
+    }
+    Ok(parts.join(", "))
+}
+
+fn attribute_label(oid: &[u8]) -> Option<&'static str> {
+    // Attribute types under 2.5.4
+    match oid {
+        [0x55, 0x04, 0x03] => Some("CN"),
+        [0x55, 0x04, 0x06] => Some("C"),
+        [0x55, 0x04, 0x07] => Some("L"),
+        [0x55, 0x04, 0x08] => Some("ST"),
+        [0x55, 0x04, 0x0a] => Some("O"),
+        [0x55, 0x04, 0x0b] => Some("OU"),
+        _ => None,
+    }
+}
@@ -0,0 +1,129 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{Context, Result};
+use reqwest::Client;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::connection::TimedResolver;
+use super::dns::DnsCache;
+use super::proxy::build_proxy;
+use super::tls::client_config;
+use super::types::TestConfig;
+
+/// Create one client per `--local-address`, or a single unbound client, all
+/// sharing one resolver and DNS cache
+pub(super) fn create_http_clients(
+    config: &TestConfig,
+    pinned_hosts: &[(String, Vec<SocketAddr>)],
+) -> Result<Vec<Client>> {
+    let resolver = Arc::new(TimedResolver {
+        server: config.dns_server,
+        cache: config.dns_cache_ttl.map(|ttl| Arc::new(DnsCache::new(ttl))),
+    });
+
+    let local_addresses: Vec<Option<IpAddr>> = if config.local_address.is_empty() {
+        vec![None]
+    } else {
+        config.local_address.iter().copied().map(Some).collect()
+    };
+    // Isolated workers each get a client, and so a connection pool, of their own
+    let count = if config.isolate_connections {
+        config.concurrent.max(local_addresses.len())
+    } else {
+        local_addresses.len()
+    };
+    (0..count)
+        .map(|i| {
+            let addr = local_addresses[i % local_addresses.len()];
+            create_http_client(config, pinned_hosts, Arc::clone(&resolver), addr)
+        })
+        .collect()
+}
+
+/// Create an HTTP client with optimal configuration for load testing
+fn create_http_client(
+    config: &TestConfig,
+    pinned_hosts: &[(String, Vec<SocketAddr>)],
+    resolver: Arc<TimedResolver>,
+    local_address: Option<IpAddr>,
+) -> Result<Client> {
+    let mut client_builder = Client::builder()
+        .user_agent(&config.user_agent)
+        .dns_resolver(resolver)
+        .local_address(local_address);
+
+    for (host, addrs) in pinned_hosts {
+        client_builder = client_builder.resolve_to_addrs(host, addrs);
+    }
+
+    // Configure proxy if specified
+    if let Some(proxy) = &config.proxy {
+        client_builder = client_builder.proxy(build_proxy(proxy, &config.proxy_headers)?);
+    }
+
+    // Configure HTTP options
+    if config.disable_compression {
+        client_builder = client_builder.no_gzip().no_brotli().no_deflate();
+    }
+
+    if config.disable_keepalive {
+        client_builder = client_builder.tcp_nodelay(true);
+    }
+
+    if let Some(connect_timeout) = config.connect_timeout {
+        client_builder = client_builder.connect_timeout(connect_timeout);
+    }
+
+    // TLS trust, versions and client certificates live in the rustls config
+    client_builder = client_builder.use_preconfigured_tls(client_config(config)?);
+
+    if config.disable_redirects {
+        client_builder = client_builder.redirect(reqwest::redirect::Policy::none());
+    }
+
+    if config.http2_prior_knowledge {
+        client_builder = client_builder.http2_prior_knowledge();
+    }
+
+    #[cfg(feature = "http3")]
+    if config.http3 {
+        client_builder = client_builder.http3_prior_knowledge();
+    }
+
+    // Optimize connection pooling; a fresh lookup per request needs a fresh connection too
+    let max_idle = if config.disable_keepalive || config.no_dns_cache {
+        0
+    } else {
+        config.concurrent * 2
+    };
+    client_builder = client_builder
+        .pool_max_idle_per_host(max_idle)
+        .pool_idle_timeout(Duration::from_secs(300))
+        .tcp_keepalive(Duration::from_secs(60));
+
+    client_builder
+        .build()
+        .context("Failed to build HTTP client")
+}
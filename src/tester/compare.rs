@@ -0,0 +1,140 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// The numbers `whambam compare` checks, read from either a `--json` summary
+/// or a saved UI snapshot, which share these field names
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunSummary {
+    pub requests_per_sec: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    /// Percentage of completed requests that failed
+    pub error_rate: f64,
+}
+
+impl RunSummary {
+    /// Read the summary of a saved report
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read report '{}'", path.display()))?;
+        let report: Value = serde_json::from_str(&text)
+            .with_context(|| format!("'{}' is not a JSON report", path.display()))?;
+        Self::from_json(&report).with_context(|| format!("In report '{}'", path.display()))
+    }
+
+    /// Pick the compared numbers out of a JSON report
+    pub fn from_json(report: &Value) -> Result<Self> {
+        let number = |pointer: &str| {
+            report
+                .pointer(pointer)
+                .and_then(Value::as_f64)
+                .ok_or_else(|| anyhow!("missing {}", &pointer[1..].replace('/', ".")))
+        };
+        let completed = number("/completed_requests")?;
+        let errors = number("/error_count")?;
+        Ok(RunSummary {
+            requests_per_sec: number("/requests_per_sec")?,
+            p50_ms: number("/latency_ms/p50")?,
+            p95_ms: number("/latency_ms/p95")?,
+            p99_ms: number("/latency_ms/p99")?,
+            error_rate: if completed > 0.0 {
+                errors / completed * 100.0
+            } else {
+                0.0
+            },
+        })
+    }
+}
+
+/// How one number moved between a baseline run and the current one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta {
+    pub name: &'static str,
+    pub baseline: f64,
+    pub current: f64,
+    /// Change measured in percentage points rather than percent, for rates
+    /// whose baseline is often zero
+    pub in_points: bool,
+    pub higher_is_better: bool,
+}
+
+impl Delta {
+    /// Change from the baseline, in percent (or percentage points); None when
+    /// the baseline is zero and a relative change means nothing
+    pub fn change(&self) -> Option<f64> {
+        if self.in_points {
+            Some(self.current - self.baseline)
+        } else if self.baseline == 0.0 {
+            None
+        } else {
+            Some((self.current - self.baseline) / self.baseline * 100.0)
+        }
+    }
+
+    /// Whether the current run is worse than the baseline by more than `tolerance`
+    pub fn regressed(&self, tolerance: f64) -> bool {
+        let worse = |change: f64| {
+            if self.higher_is_better {
+                -change
+            } else {
+                change
+            }
+        };
+        match self.change() {
+            Some(change) => worse(change) > tolerance,
+            // From nothing to something is only a regression when less is better
+            None => !self.higher_is_better && self.current > 0.0,
+        }
+    }
+}
+
+/// Every compared number, baseline against current
+pub fn compare(baseline: &RunSummary, current: &RunSummary) -> Vec<Delta> {
+    let delta = |name, baseline, current, higher_is_better| Delta {
+        name,
+        baseline,
+        current,
+        in_points: false,
+        higher_is_better,
+    };
+    vec![
+        delta(
+            "Requests/sec",
+            baseline.requests_per_sec,
+            current.requests_per_sec,
+            true,
+        ),
+        delta("p50 latency", baseline.p50_ms, current.p50_ms, false),
+        delta("p95 latency", baseline.p95_ms, current.p95_ms, false),
+        delta("p99 latency", baseline.p99_ms, current.p99_ms, false),
+        Delta {
+            in_points: true,
+            ..delta("Error rate", baseline.error_rate, current.error_rate, false)
+        },
+    ]
+}
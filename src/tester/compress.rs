@@ -0,0 +1,70 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use anyhow::{anyhow, Result};
+use flate2::{write::GzEncoder, Compression};
+use std::io::Write;
+
+/// How request bodies are compressed before they are sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyEncoding {
+    Gzip,
+    Brotli,
+}
+
+impl BodyEncoding {
+    /// Parse `gzip` or `br`
+    pub fn parse(encoding: &str) -> Result<Self> {
+        match encoding.trim().to_ascii_lowercase().as_str() {
+            "gzip" => Ok(BodyEncoding::Gzip),
+            "br" | "brotli" => Ok(BodyEncoding::Brotli),
+            _ => Err(anyhow!(
+                "Invalid body encoding: '{encoding}'. Expected 'gzip' or 'br'"
+            )),
+        }
+    }
+
+    /// The Content-Encoding header value announcing this encoding
+    pub fn header_value(self) -> &'static str {
+        match self {
+            BodyEncoding::Gzip => "gzip",
+            BodyEncoding::Brotli => "br",
+        }
+    }
+
+    /// Compress a body; writing to memory cannot fail
+    pub fn compress(self, body: &[u8]) -> Vec<u8> {
+        match self {
+            BodyEncoding::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(body).expect("in-memory write");
+                encoder.finish().expect("in-memory write")
+            }
+            BodyEncoding::Brotli => {
+                let mut compressed = Vec::new();
+                let mut encoder = brotli::CompressorWriter::new(&mut compressed, 4096, 5, 22);
+                encoder.write_all(body).expect("in-memory write");
+                drop(encoder);
+                compressed
+            }
+        }
+    }
+}
@@ -0,0 +1,314 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Connection setup timing. reqwest does not say when it opens a connection,
+//! so the DNS and TLS hooks below stamp a task-local timer while each request runs.
+
+use anyhow::{Context, Result};
+use hdrhistogram::Histogram;
+use hyper::client::connect::dns::Name;
+use parking_lot::Mutex;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+use rustls::client::{
+    ClientSessionMemoryCache, ClientSessionStore, Tls12ClientSessionValue, Tls13ClientSessionValue,
+};
+use rustls::{KeyLog, NamedGroup, ServerName};
+use std::cell::Cell;
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+use url::Url;
+
+use super::dns::{lookup, DnsCache};
+use super::types::RequestError;
+
+tokio::task_local! {
+    static CONNECTION: ConnectionTimer;
+}
+
+/// Handshake milestones seen while one request runs
+#[derive(Default)]
+struct ConnectionTimer {
+    dns_start: Cell<Option<Instant>>,
+    dns_end: Cell<Option<Instant>>,
+    tls_start: Cell<Option<Instant>>,
+    tls_end: Cell<Option<Instant>>,
+}
+
+/// Connection setup cost paid by one request; empty when a pooled connection was reused
+///
+/// Connect time is only known for HTTPS, where it ends as the TLS handshake starts.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ConnectionTiming {
+    pub dns_lookup_ms: Option<f64>,
+    pub connect_ms: Option<f64>,
+    pub tls_handshake_ms: Option<f64>,
+}
+
+impl ConnectionTiming {
+    /// Total time spent before the request could be sent
+    pub fn setup_ms(&self) -> f64 {
+        [self.dns_lookup_ms, self.connect_ms, self.tls_handshake_ms]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
+
+/// Run a request and report the connection setup it had to do
+pub async fn timed<F: Future>(request: F) -> (F::Output, ConnectionTiming) {
+    CONNECTION
+        .scope(ConnectionTimer::default(), async move {
+            let started = Instant::now();
+            let output = request.await;
+            let timing = CONNECTION.with(|timer| ConnectionTiming {
+                dns_lookup_ms: elapsed_ms(timer.dns_start.get(), timer.dns_end.get()),
+                connect_ms: elapsed_ms(
+                    timer.dns_end.get().or(Some(started)),
+                    timer.tls_start.get(),
+                ),
+                tls_handshake_ms: elapsed_ms(timer.tls_start.get(), timer.tls_end.get()),
+            });
+            (output, timing)
+        })
+        .await
+}
+
+fn elapsed_ms(start: Option<Instant>, end: Option<Instant>) -> Option<f64> {
+    let (start, end) = (start?, end?);
+    Some(end.saturating_duration_since(start).as_secs_f64() * 1000.0)
+}
+
+/// Whether a request failed while its connection was still opening, rather than
+/// waiting on the server's response
+pub(crate) fn is_connect_timeout(err: &reqwest::Error) -> bool {
+    err.is_connect() && err.is_timeout()
+}
+
+/// Describe a request that got no usable response, with every underlying
+/// cause, since reqwest's own message leaves them out
+pub(crate) fn request_error(url: &Url, err: &reqwest::Error) -> RequestError {
+    let kind = if is_connect_timeout(err) {
+        "connect timeout"
+    } else if err.is_timeout() {
+        "timeout"
+    } else if err.is_connect() {
+        "connect"
+    } else if err.is_redirect() {
+        "redirect"
+    } else if err.is_body() || err.is_decode() {
+        "body"
+    } else {
+        "request"
+    };
+
+    let mut message = err.to_string();
+    let mut source = std::error::Error::source(err);
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+
+    RequestError {
+        url: err.url().unwrap_or(url).to_string(),
+        kind,
+        message,
+    }
+}
+
+/// Stamp a milestone; hooks running outside `timed` (a background connect) are ignored
+fn mark(field: fn(&ConnectionTimer) -> &Cell<Option<Instant>>) {
+    let _ = CONNECTION.try_with(|timer| field(timer).set(Some(Instant::now())));
+}
+
+/// Resolver that notes how long each lookup took
+///
+/// reqwest only resolves when it opens a connection, and never for IP literals.
+/// Lookups go to the system resolver unless `--dns-server` names another, and
+/// cache hits from `--dns-cache-ttl` are not lookups at all.
+#[derive(Default)]
+pub struct TimedResolver {
+    pub server: Option<SocketAddr>,
+    pub cache: Option<Arc<DnsCache>>,
+}
+
+impl Resolve for TimedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let (server, cache, host) = (self.server, self.cache.clone(), name.as_str().to_string());
+        Box::pin(async move {
+            if let Some(addrs) = cache.as_ref().and_then(|cache| cache.get(&host)) {
+                return Ok(Box::new(addrs.into_iter()) as Addrs);
+            }
+            mark(|timer| &timer.dns_start);
+            let addrs = lookup(server, &host).await?;
+            mark(|timer| &timer.dns_end);
+            if let Some(cache) = &cache {
+                cache.insert(&host, &addrs);
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Session cache that notes the start of every handshake
+///
+/// rustls looks up a resumption ticket before it writes the ClientHello,
+/// which makes the lookup a reliable "handshake started" signal.
+pub struct TimedSessionStore {
+    inner: ClientSessionMemoryCache,
+}
+
+impl TimedSessionStore {
+    pub fn new() -> Self {
+        TimedSessionStore {
+            inner: ClientSessionMemoryCache::new(256),
+        }
+    }
+}
+
+impl ClientSessionStore for TimedSessionStore {
+    fn set_kx_hint(&self, server_name: &ServerName, group: NamedGroup) {
+        self.inner.set_kx_hint(server_name, group)
+    }
+
+    fn kx_hint(&self, server_name: &ServerName) -> Option<NamedGroup> {
+        self.inner.kx_hint(server_name)
+    }
+
+    fn set_tls12_session(&self, server_name: &ServerName, value: Tls12ClientSessionValue) {
+        // Saved once the server's Finished arrives, so a TLS 1.2 handshake is over
+        mark(|timer| &timer.tls_end);
+        self.inner.set_tls12_session(server_name, value)
+    }
+
+    fn tls12_session(&self, server_name: &ServerName) -> Option<Tls12ClientSessionValue> {
+        self.inner.tls12_session(server_name)
+    }
+
+    fn remove_tls12_session(&self, server_name: &ServerName) {
+        self.inner.remove_tls12_session(server_name)
+    }
+
+    fn insert_tls13_ticket(&self, server_name: &ServerName, value: Tls13ClientSessionValue) {
+        self.inner.insert_tls13_ticket(server_name, value)
+    }
+
+    fn take_tls13_ticket(&self, server_name: &ServerName) -> Option<Tls13ClientSessionValue> {
+        mark(|timer| &timer.tls_start);
+        self.inner.take_tls13_ticket(server_name)
+    }
+}
+
+/// Key log that notes when the handshake secrets are final
+///
+/// TLS 1.3 derives the application secrets on the server's Finished. TLS 1.2
+/// logs its master secret one round trip earlier; a saved session moves the
+/// mark to the real end when the server hands one out.
+///
+/// With `--tls-keylog` every secret is also appended to a file in the
+/// SSLKEYLOGFILE format, so Wireshark can decrypt a capture of the run.
+#[derive(Default)]
+pub struct HandshakeKeyLog {
+    file: Option<Mutex<File>>,
+}
+
+impl HandshakeKeyLog {
+    /// Also write secrets to `path`, appending to whatever is already there
+    pub fn to_file(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open TLS key log {}", path.display()))?;
+        Ok(HandshakeKeyLog {
+            file: Some(Mutex::new(file)),
+        })
+    }
+}
+
+fn is_handshake_end(label: &str) -> bool {
+    matches!(label, "CLIENT_TRAFFIC_SECRET_0" | "CLIENT_RANDOM")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+impl KeyLog for HandshakeKeyLog {
+    fn log(&self, label: &str, client_random: &[u8], secret: &[u8]) {
+        if is_handshake_end(label) {
+            mark(|timer| &timer.tls_end);
+        }
+        if let Some(file) = &self.file {
+            // One write per line keeps lines whole when handshakes overlap
+            let line = format!("{label} {} {}\n", hex(client_random), hex(secret));
+            let _ = file.lock().write_all(line.as_bytes());
+        }
+    }
+
+    fn will_log(&self, label: &str) -> bool {
+        self.file.is_some() || is_handshake_end(label)
+    }
+}
+
+/// Distribution of one request phase, stored in µs
+#[derive(Debug, Clone)]
+pub struct PhaseStats {
+    pub samples: Histogram<u64>,
+}
+
+impl Default for PhaseStats {
+    fn default() -> Self {
+        PhaseStats {
+            samples: Histogram::<u64>::new(3).unwrap(),
+        }
+    }
+}
+
+impl PhaseStats {
+    pub fn record(&mut self, ms: f64) {
+        let _ = self.samples.record((ms * 1000.0) as u64);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.samples.len()
+    }
+
+    /// Latency at a quantile, in milliseconds
+    pub fn at_quantile_ms(&self, quantile: f64) -> f64 {
+        self.samples.value_at_quantile(quantile) as f64 / 1000.0
+    }
+
+    /// Average, fastest and slowest in milliseconds
+    pub fn average_min_max_ms(&self) -> (f64, f64, f64) {
+        (
+            self.samples.mean() / 1000.0,
+            self.samples.min() as f64 / 1000.0,
+            self.samples.max() as f64 / 1000.0,
+        )
+    }
+}
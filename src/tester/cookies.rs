@@ -0,0 +1,84 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, SET_COOKIE};
+use std::collections::HashMap;
+use url::Url;
+
+/// Cookies one virtual user has been given, kept per host. Domain and path
+/// attributes are ignored: a cookie goes back to the host that set it on every path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CookieJar {
+    hosts: HashMap<String, Vec<(String, String)>>,
+}
+
+impl CookieJar {
+    /// Keep the cookies a response from `url` sets, dropping any it expires
+    pub fn store(&mut self, url: &Url, headers: &HeaderMap) {
+        let Some(host) = url.host_str() else {
+            return;
+        };
+        for header in headers.get_all(SET_COOKIE) {
+            let Ok(header) = header.to_str() else {
+                continue;
+            };
+            let mut parts = header.split(';');
+            let Some((name, value)) = parts.next().and_then(|pair| pair.split_once('=')) else {
+                continue;
+            };
+            let (name, value) = (name.trim(), value.trim());
+            if name.is_empty() {
+                continue;
+            }
+
+            let cookies = self.hosts.entry(host.to_string()).or_default();
+            cookies.retain(|(existing, _)| existing != name);
+            if !parts.any(is_expiry_in_past) {
+                cookies.push((name.to_string(), value.to_string()));
+            }
+        }
+    }
+
+    /// The Cookie header to send to `url`, if the jar holds any for its host
+    pub fn header_value(&self, url: &Url) -> Option<String> {
+        let cookies = self.hosts.get(url.host_str()?)?;
+        let pairs: Vec<String> = cookies
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect();
+        (!pairs.is_empty()).then(|| pairs.join("; "))
+    }
+}
+
+/// Whether a Set-Cookie attribute deletes the cookie: `Max-Age` of zero or
+/// less, or an `Expires` date already gone
+fn is_expiry_in_past(attribute: &str) -> bool {
+    let Some((name, value)) = attribute.split_once('=') else {
+        return false;
+    };
+    let value = value.trim();
+    match name.trim().to_ascii_lowercase().as_str() {
+        "max-age" => value.parse::<i64>().is_ok_and(|secs| secs <= 0),
+        "expires" => DateTime::parse_from_rfc2822(value).is_ok_and(|at| at < Utc::now()),
+        _ => false,
+    }
+}
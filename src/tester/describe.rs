@@ -0,0 +1,124 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use super::types::TestConfig;
+
+/// Headers whose values are credentials, left out of descriptions
+const SECRET_HEADERS: [&str; 3] = ["authorization", "proxy-authorization", "cookie"];
+
+impl TestConfig {
+    /// The effective settings as (name, value) rows, for showing alongside the
+    /// results; credentials are masked and unset options are left out
+    pub fn describe(&self) -> Vec<(&'static str, String)> {
+        let limit = |n: u64, unit: &str| match n {
+            0 => "unlimited".to_string(),
+            n => format!("{n}{unit}"),
+        };
+        let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+
+        let mut rows = vec![
+            ("Method", self.method.to_string()),
+            ("URL", self.url.clone()),
+            ("Requests", limit(self.requests as u64, "")),
+            ("Concurrency", self.concurrent.to_string()),
+            ("Duration", limit(self.duration, "s")),
+            (
+                "Rate limit",
+                match self.rate_limit {
+                    rate if rate > 0.0 => format!(
+                        "{rate} QPS per worker ({} total)",
+                        rate * self.concurrent as f64
+                    ),
+                    _ => "unlimited".to_string(),
+                },
+            ),
+            ("Timeout", limit(self.timeout, "s")),
+        ];
+        if let Some(connect) = self.connect_timeout {
+            rows.push(("Connect timeout", format!("{}s", connect.as_secs_f64())));
+        }
+
+        for (name, value) in &self.headers {
+            let value = if SECRET_HEADERS.contains(&name.to_lowercase().as_str()) {
+                "***".to_string()
+            } else {
+                value.clone()
+            };
+            rows.push(("Header", format!("{name}: {value}")));
+        }
+        if self.user_agents.is_empty() {
+            rows.push(("User-Agent", self.user_agent.clone()));
+        } else {
+            let count = self.user_agents.len();
+            rows.push(("User-Agent", format!("rotating through {count}")));
+        }
+
+        match (&self.body, &self.random_body) {
+            (_, Some(random)) => rows.push(("Body", format!("{} random bytes", random.size))),
+            (Some(body), None) => rows.push(("Body", format!("{} bytes", body.len()))),
+            (None, None) => {}
+        }
+        if let Some(encoding) = self.compress_body {
+            rows.push(("Body encoding", encoding.header_value().to_string()));
+        }
+        if let Some((user, _)) = &self.basic_auth {
+            rows.push(("Basic auth", format!("{user}:***")));
+        }
+        if let Some(oauth) = &self.oauth {
+            rows.push(("OAuth2 token URL", oauth.token_url.clone()));
+        }
+        if let Some(proxy) = &self.proxy {
+            rows.push(("Proxy", proxy.clone()));
+        }
+
+        rows.push(("Verify TLS", on_off(!self.insecure)));
+        if let Some(ca_cert) = &self.ca_cert {
+            rows.push(("CA certificates", ca_cert.display().to_string()));
+        }
+        if let Some((cert, _)) = &self.client_cert {
+            rows.push(("Client certificate", cert.display().to_string()));
+        }
+        match (self.tls_min, self.tls_max) {
+            (None, None) => {}
+            (min, max) => {
+                let show = |v: Option<_>| v.map_or("any".to_string(), |v| format!("{v}"));
+                rows.push(("TLS versions", format!("{} to {}", show(min), show(max))));
+            }
+        }
+        if let Some(sni) = &self.sni {
+            rows.push(("SNI", sni.clone()));
+        }
+        if self.http2_prior_knowledge {
+            rows.push(("Protocol", "HTTP/2 prior knowledge".to_string()));
+        } else if self.http3 {
+            rows.push(("Protocol", "HTTP/3".to_string()));
+        }
+
+        rows.push(("Keep-alive", on_off(!self.disable_keepalive)));
+        rows.push(("Compression", on_off(!self.disable_compression)));
+        rows.push(("Follow redirects", on_off(!self.disable_redirects)));
+        if self.cookies {
+            rows.push(("Cookies", "kept per worker".to_string()));
+        }
+        rows
+    }
+}
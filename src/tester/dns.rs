@@ -0,0 +1,213 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! A minimal DNS-over-UDP client for `--dns-server`: A and AAAA lookups only.
+
+use anyhow::{anyhow, Context, Result};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::net::UdpSocket;
+
+/// Give up on a DNS server that has not answered within this long
+const QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+const TYPE_A: u16 = 1;
+const TYPE_AAAA: u16 = 28;
+const CLASS_IN: u16 = 1;
+
+/// Parse `--dns-server`, which defaults to port 53
+pub fn parse_dns_server(server: &str) -> Result<SocketAddr> {
+    if let Ok(addr) = server.parse::<SocketAddr>() {
+        return Ok(addr);
+    }
+    let ip = server
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+        .map_err(|_| anyhow!("Invalid DNS server: '{server}'. Expected an IP address"))?;
+    Ok(SocketAddr::new(ip, 53))
+}
+
+/// Resolutions kept for `--dns-cache-ttl`, shared by all workers
+pub struct DnsCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Vec<SocketAddr>)>>,
+}
+
+impl DnsCache {
+    pub fn new(ttl: Duration) -> Self {
+        DnsCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Addresses for `host` if they were looked up within the TTL
+    pub fn get(&self, host: &str) -> Option<Vec<SocketAddr>> {
+        let entries = self.entries.lock();
+        let (resolved_at, addrs) = entries.get(host)?;
+        (resolved_at.elapsed() < self.ttl).then(|| addrs.clone())
+    }
+
+    pub fn insert(&self, host: &str, addrs: &[SocketAddr]) {
+        self.entries
+            .lock()
+            .insert(host.to_string(), (Instant::now(), addrs.to_vec()));
+    }
+}
+
+/// Resolve `host` with the system resolver, or with `server` when one is given
+pub async fn lookup(server: Option<SocketAddr>, host: &str) -> Result<Vec<SocketAddr>> {
+    let Some(server) = server else {
+        return Ok(tokio::net::lookup_host((host, 0)).await?.collect());
+    };
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(vec![SocketAddr::new(ip, 0)]);
+    }
+
+    let (v4, v6) = tokio::join!(query(server, host, TYPE_A), query(server, host, TYPE_AAAA));
+    let addrs: Vec<_> = v4
+        .unwrap_or_default()
+        .into_iter()
+        .chain(v6.unwrap_or_default())
+        .map(|(ip, _ttl)| SocketAddr::new(ip, 0))
+        .collect();
+    if addrs.is_empty() {
+        return Err(anyhow!("{server} has no addresses for {host}"));
+    }
+    Ok(addrs)
+}
+
+/// Ask `server` for one record type, returning each address with its TTL in seconds
+pub async fn query(server: SocketAddr, host: &str, record_type: u16) -> Result<Vec<(IpAddr, u32)>> {
+    let id = next_id();
+    let local: SocketAddr = if server.is_ipv4() {
+        (Ipv4Addr::UNSPECIFIED, 0).into()
+    } else {
+        (Ipv6Addr::UNSPECIFIED, 0).into()
+    };
+    let socket = UdpSocket::bind(local).await?;
+    socket.connect(server).await?;
+    socket.send(&encode_query(id, host, record_type)?).await?;
+
+    let mut buffer = [0; 1232];
+    let exchange = async {
+        // Skip stray datagrams until the answer to our query arrives
+        loop {
+            let len = socket.recv(&mut buffer).await?;
+            if buffer[..len].starts_with(&id.to_be_bytes()) {
+                return Ok::<_, std::io::Error>(len);
+            }
+        }
+    };
+    let len = tokio::time::timeout(QUERY_TIMEOUT, exchange)
+        .await
+        .with_context(|| format!("DNS server {server} did not answer"))??;
+    parse_response(&buffer[..len])
+}
+
+fn next_id() -> u16 {
+    static NEXT: AtomicU16 = AtomicU16::new(0);
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos() as u16);
+    NEXT.fetch_add(1, Ordering::Relaxed) ^ seed
+}
+
+/// Build a recursive query for one name and record type
+pub fn encode_query(id: u16, host: &str, record_type: u16) -> Result<Vec<u8>> {
+    let mut packet = Vec::with_capacity(18 + host.len());
+    packet.extend_from_slice(&id.to_be_bytes());
+    // Recursion desired; one question
+    packet.extend_from_slice(&[0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in host.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(anyhow!("Invalid host name for DNS: '{host}'"));
+        }
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&record_type.to_be_bytes());
+    packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+    Ok(packet)
+}
+
+/// Pull the A and AAAA records out of a response, following nothing
+pub fn parse_response(packet: &[u8]) -> Result<Vec<(IpAddr, u32)>> {
+    let header = packet
+        .get(..12)
+        .ok_or_else(|| anyhow!("Truncated DNS response"))?;
+    let rcode = header[3] & 0x0f;
+    if rcode != 0 {
+        return Err(anyhow!("DNS server answered with rcode {rcode}"));
+    }
+    let questions = u16::from_be_bytes([header[4], header[5]]);
+    let answers = u16::from_be_bytes([header[6], header[7]]);
+
+    let mut pos = 12;
+    for _ in 0..questions {
+        pos = skip_name(packet, pos)? + 4;
+    }
+
+    let mut addrs = Vec::new();
+    for _ in 0..answers {
+        pos = skip_name(packet, pos)?;
+        let fixed = packet
+            .get(pos..pos + 10)
+            .ok_or_else(|| anyhow!("Truncated DNS record"))?;
+        let record_type = u16::from_be_bytes([fixed[0], fixed[1]]);
+        let ttl = u32::from_be_bytes([fixed[4], fixed[5], fixed[6], fixed[7]]);
+        let len = u16::from_be_bytes([fixed[8], fixed[9]]) as usize;
+        pos += 10;
+        let data = packet
+            .get(pos..pos + len)
+            .ok_or_else(|| anyhow!("Truncated DNS record"))?;
+        pos += len;
+
+        match (record_type, data.len()) {
+            (TYPE_A, 4) => addrs.push((IpAddr::from(<[u8; 4]>::try_from(data)?), ttl)),
+            (TYPE_AAAA, 16) => addrs.push((IpAddr::from(<[u8; 16]>::try_from(data)?), ttl)),
+            // CNAMEs and the like; the server already followed them for us
+            _ => {}
+        }
+    }
+    Ok(addrs)
+}
+
+/// Step over a possibly compressed name, returning the position after it
+fn skip_name(packet: &[u8], mut pos: usize) -> Result<usize> {
+    loop {
+        let len = *packet
+            .get(pos)
+            .ok_or_else(|| anyhow!("Truncated DNS name"))?;
+        match len {
+            0 => return Ok(pos + 1),
+            // A compression pointer ends the name
+            len if len & 0xc0 == 0xc0 => return Ok(pos + 2),
+            len => pos += 1 + len as usize,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Points a `SeriesHistory` keeps before it compacts itself, about an hour
+/// at one point per second
+pub const HISTORY_LEN: usize = 4096;
+
+/// Points of each series written to an exported report
+pub const REPORT_SERIES_LEN: usize = 1000;
+
+/// A chart series covering the whole test
+///
+/// Points are kept as recorded until there are `HISTORY_LEN` of them, then
+/// the series is downsampled to half that, so a soak test of any length
+/// keeps its overall shape in bounded memory.
+#[derive(Debug, Clone, Default)]
+pub struct SeriesHistory {
+    points: Vec<(f64, f64)>,
+}
+
+impl SeriesHistory {
+    /// Append a (seconds since start, value) point
+    pub fn push(&mut self, point: (f64, f64)) {
+        self.points.push(point);
+        if self.points.len() > HISTORY_LEN {
+            self.points = lttb(&self.points, HISTORY_LEN / 2);
+        }
+    }
+
+    /// All the points kept, oldest first
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// At most `threshold` points that keep the series' shape
+    pub fn downsampled(&self, threshold: usize) -> Vec<(f64, f64)> {
+        lttb(&self.points, threshold)
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
+
+/// Downsample `points` to at most `threshold` of them with
+/// Largest-Triangle-Three-Buckets
+///
+/// The first and last points are always kept. The rest are split into equal
+/// buckets, and from each the point forming the largest triangle with the
+/// previous pick and the next bucket's average is kept, which preserves
+/// spikes that plain averaging would flatten.
+pub fn lttb(points: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
+    if threshold >= points.len() || threshold < 3 {
+        return points.to_vec();
+    }
+
+    let mut sampled = Vec::with_capacity(threshold);
+    sampled.push(points[0]);
+
+    // Buckets cover everything between the first and last points
+    let every = (points.len() - 2) as f64 / (threshold - 2) as f64;
+    let mut a = 0;
+
+    for i in 0..threshold - 2 {
+        let start = (i as f64 * every) as usize + 1;
+        let end = (((i + 1) as f64 * every) as usize + 1).min(points.len() - 1);
+
+        // Average of the next bucket, or the last point for the final bucket
+        let next_end = (((i + 2) as f64 * every) as usize + 1).min(points.len());
+        let next = &points[end..next_end.max(end + 1)];
+        let avg_x = next.iter().map(|p| p.0).sum::<f64>() / next.len() as f64;
+        let avg_y = next.iter().map(|p| p.1).sum::<f64>() / next.len() as f64;
+
+        let (ax, ay) = points[a];
+        let mut best = start;
+        let mut best_area = -1.0;
+        for (j, &(x, y)) in points.iter().enumerate().take(end).skip(start) {
+            let area = ((ax - avg_x) * (y - ay) - (ax - x) * (avg_y - ay)).abs();
+            if area > best_area {
+                best_area = area;
+                best = j;
+            }
+        }
+
+        sampled.push(points[best]);
+        a = best;
+    }
+
+    sampled.push(points[points.len() - 1]);
+    sampled
+}
+
+/// Several series recorded at the same times, such as the parts of a stacked
+/// chart
+///
+/// Compaction and downsampling average neighbouring points instead of
+/// picking some, so the series stay aligned and their totals keep adding up.
+#[derive(Debug, Clone)]
+pub struct StackedHistory<const N: usize> {
+    points: Vec<(f64, [f64; N])>,
+}
+
+impl<const N: usize> Default for StackedHistory<N> {
+    fn default() -> Self {
+        StackedHistory { points: Vec::new() }
+    }
+}
+
+impl<const N: usize> StackedHistory<N> {
+    /// Append the values of every series at `time` seconds since start
+    pub fn push(&mut self, time: f64, values: [f64; N]) {
+        self.points.push((time, values));
+        if self.points.len() > HISTORY_LEN {
+            self.points = average_chunks(&self.points, 2);
+        }
+    }
+
+    /// All the points kept, oldest first
+    pub fn points(&self) -> &[(f64, [f64; N])] {
+        &self.points
+    }
+
+    /// Time of the latest point, if any
+    pub fn last_time(&self) -> Option<f64> {
+        self.points.last().map(|&(time, _)| time)
+    }
+
+    /// Each series as the running total of it and the ones before it, with
+    /// at most `threshold` points, ready to draw as stacked bands
+    pub fn stacked(&self, threshold: usize) -> [Vec<(f64, f64)>; N] {
+        let chunk = self.points.len().div_ceil(threshold.max(1)).max(1);
+        let points = average_chunks(&self.points, chunk);
+        std::array::from_fn(|series| {
+            points
+                .iter()
+                .map(|(time, values)| (*time, values[..=series].iter().sum()))
+                .collect()
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
+
+/// Replace each run of `size` points with their average
+fn average_chunks<const N: usize>(points: &[(f64, [f64; N])], size: usize) -> Vec<(f64, [f64; N])> {
+    points
+        .chunks(size)
+        .map(|chunk| {
+            let count = chunk.len() as f64;
+            let time = chunk.iter().map(|(time, _)| time).sum::<f64>() / count;
+            let values = std::array::from_fn(|i| {
+                chunk.iter().map(|(_, values)| values[i]).sum::<f64>() / count
+            });
+            (time, values)
+        })
+        .collect()
+}
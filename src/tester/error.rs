@@ -0,0 +1,95 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::fmt;
+use url::Url;
+
+/// URL schemes the runner can send requests to
+pub const SUPPORTED_SCHEMES: [&str; 2] = ["http", "https"];
+
+/// Configuration problems detected before a test starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The URL could not be parsed at all
+    InvalidUrl { url: String, reason: String },
+    /// The URL parsed but uses a scheme the runner can't speak
+    UnsupportedScheme { url: String, scheme: String },
+}
+
+impl ConfigError {
+    /// A hint for fixing the problem, if there is an obvious one
+    pub fn suggestion(&self) -> Option<String> {
+        match self {
+            ConfigError::InvalidUrl { url, .. } if !url.contains("://") => {
+                Some(format!("did you mean http://{url} ?"))
+            }
+            ConfigError::InvalidUrl { .. } => None,
+            ConfigError::UnsupportedScheme { url, scheme } => match scheme.as_str() {
+                "ws" | "wss" => Some(
+                    "WebSocket endpoints are not supported yet; test the HTTP upgrade URL instead"
+                        .to_string(),
+                ),
+                "h2" | "h2c" | "http2" => Some(format!(
+                    "use http:// or https://; HTTP/2 is negotiated automatically for {url}"
+                )),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidUrl { url, reason } => write!(f, "Invalid URL '{url}': {reason}")?,
+            ConfigError::UnsupportedScheme { url, scheme } => write!(
+                f,
+                "Unsupported URL scheme '{scheme}' in '{url}'. Supported schemes: {}",
+                SUPPORTED_SCHEMES.join(", ")
+            )?,
+        }
+
+        match self.suggestion() {
+            Some(suggestion) => write!(f, " ({suggestion})"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Parse a target URL, rejecting schemes the runner doesn't support
+pub fn parse_target_url(url: &str) -> Result<Url, ConfigError> {
+    let parsed = Url::parse(url).map_err(|e| ConfigError::InvalidUrl {
+        url: url.to_string(),
+        reason: e.to_string(),
+    })?;
+
+    if !SUPPORTED_SCHEMES.contains(&parsed.scheme()) {
+        return Err(ConfigError::UnsupportedScheme {
+            url: url.to_string(),
+            scheme: parsed.scheme().to_string(),
+        });
+    }
+
+    Ok(parsed)
+}
@@ -0,0 +1,104 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::Result;
+use std::io;
+use std::path::PathBuf;
+
+use super::types::{RequestMetric, TestState};
+
+/// A file the results of a run are written to, alongside the UI or text report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputSink {
+    /// Summary of the whole run as JSON, written when the run finishes
+    Json(PathBuf),
+    /// One CSV row per completed request
+    Csv(PathBuf),
+    /// One JSON line per failed request, saying why it failed
+    ErrorLog(PathBuf),
+}
+
+/// Receives the metrics stream of a run
+pub trait MetricExporter: Send {
+    /// Called for every completed request
+    fn record(&mut self, metric: &RequestMetric) -> io::Result<()>;
+
+    /// Called once when the run has finished
+    fn finish(&mut self, state: &TestState) -> io::Result<()>;
+}
+
+/// Fans the metrics stream out to every configured exporter
+#[derive(Default)]
+pub struct FanOut {
+    exporters: Vec<Box<dyn MetricExporter>>,
+    finished: bool,
+}
+
+impl FanOut {
+    /// Open an exporter for each output sink
+    pub fn open(sinks: &[OutputSink]) -> Result<Self> {
+        #[cfg(feature = "export")]
+        let exporters = sinks
+            .iter()
+            .map(super::exporters::open)
+            .collect::<Result<Vec<_>>>()?;
+
+        #[cfg(not(feature = "export"))]
+        let exporters = match sinks.first() {
+            Some(sink) => {
+                anyhow::bail!("Cannot write {sink:?}: whambam was built without the export feature")
+            }
+            None => Vec::new(),
+        };
+
+        Ok(FanOut {
+            exporters,
+            finished: false,
+        })
+    }
+
+    /// Whether there is anything to export to
+    pub fn is_empty(&self) -> bool {
+        self.exporters.is_empty()
+    }
+
+    /// Pass a completed request to every exporter
+    pub fn record(&mut self, metric: &RequestMetric) {
+        for exporter in &mut self.exporters {
+            if let Err(e) = exporter.record(metric) {
+                eprintln!("Warning: Failed to export metric: {e}");
+            }
+        }
+    }
+
+    /// Finish every exporter; later calls do nothing
+    pub fn finish(&mut self, state: &TestState) {
+        if std::mem::replace(&mut self.finished, true) {
+            return;
+        }
+        for exporter in &mut self.exporters {
+            if let Err(e) = exporter.finish(state) {
+                eprintln!("Warning: Failed to write results: {e}");
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use super::export::{MetricExporter, OutputSink};
+use super::test_report::TestReport;
+use super::types::{RequestMetric, TestState};
+
+/// Open the file behind an output sink and wrap it in the matching exporter
+pub fn open(sink: &OutputSink) -> Result<Box<dyn MetricExporter>> {
+    Ok(match sink {
+        OutputSink::Json(path) => Box::new(JsonExporter {
+            file: create(path)?,
+        }),
+        OutputSink::Csv(path) => Box::new(CsvExporter::new(create(path)?)?),
+        OutputSink::ErrorLog(path) => Box::new(ErrorLogExporter {
+            writer: create(path)?,
+        }),
+    })
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>> {
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create output file '{}'", path.display()))?;
+    Ok(BufWriter::new(file))
+}
+
+/// Writes one CSV row per request
+struct CsvExporter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> CsvExporter<W> {
+    fn new(mut writer: W) -> io::Result<Self> {
+        writeln!(
+            writer,
+            "timestamp,latency_ms,status_code,is_error,bytes_sent,bytes_received,target,protocol,dns_lookup_ms,connect_ms,tls_handshake_ms,ttfb_ms,body_read_ms"
+        )?;
+        Ok(CsvExporter { writer })
+    }
+}
+
+impl<W: Write + Send> MetricExporter for CsvExporter<W> {
+    fn record(&mut self, metric: &RequestMetric) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            "{:.6},{:.3},{},{},{},{},{},{},{},{},{},{},{}",
+            metric.timestamp,
+            metric.latency_ms,
+            metric.status_code,
+            metric.is_error,
+            metric.bytes_sent,
+            metric.bytes_received,
+            metric.target,
+            metric.protocol.map(|p| p.to_string()).unwrap_or_default(),
+            optional_ms(metric.dns_lookup_ms),
+            optional_ms(metric.connect_ms),
+            optional_ms(metric.tls_handshake_ms),
+            optional_ms(metric.ttfb_ms),
+            optional_ms(metric.body_read_ms)
+        )
+    }
+
+    fn finish(&mut self, _state: &TestState) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn optional_ms(ms: Option<f64>) -> String {
+    ms.map(|ms| format!("{ms:.3}")).unwrap_or_default()
+}
+
+/// Writes one JSON line for every failed request, saying why it failed
+struct ErrorLogExporter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write + Send> MetricExporter for ErrorLogExporter<W> {
+    fn record(&mut self, metric: &RequestMetric) -> io::Result<()> {
+        if !metric.is_error {
+            return Ok(());
+        }
+        serde_json::to_writer(&mut self.writer, &error_entry(metric))?;
+        writeln!(self.writer)
+    }
+
+    fn finish(&mut self, _state: &TestState) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// The `--log-errors` line for a failed request; protocols that don't say why
+/// they failed fall back to the status code
+pub fn error_entry(metric: &RequestMetric) -> Value {
+    let (url, kind, message) = match &metric.error {
+        Some(error) => (Some(error.url.as_str()), error.kind, error.message.clone()),
+        None if metric.status_code == 0 => (None, "error", "request failed".to_string()),
+        None => (None, "status", format!("HTTP {}", metric.status_code)),
+    };
+    json!({
+        "timestamp": metric.timestamp,
+        "url": url,
+        "target": metric.target,
+        "kind": kind,
+        "error": message,
+        "status_code": metric.status_code,
+        "latency_ms": metric.latency_ms,
+    })
+}
+
+/// Writes a JSON summary once the run has finished
+struct JsonExporter<W: Write> {
+    file: W,
+}
+
+impl<W: Write + Send> MetricExporter for JsonExporter<W> {
+    fn record(&mut self, _metric: &RequestMetric) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn finish(&mut self, state: &TestState) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut self.file, &TestReport::from_state(state))?;
+        writeln!(self.file)?;
+        self.file.flush()
+    }
+}
+
+/// The run summary written by `--json`: the same report the UI's snapshot saves
+pub fn json_summary(state: &TestState) -> Value {
+    // Map keys are numbers or strings, which JSON can always hold
+    serde_json::to_value(TestReport::from_state(state)).unwrap_or_default()
+}
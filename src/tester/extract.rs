@@ -0,0 +1,142 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::assertions::JsonPath;
+use super::scenario::ScenarioStep;
+
+/// Where in a response an extracted value comes from
+#[derive(Debug, Clone)]
+pub enum ExtractSource {
+    /// A value in a JSON body, e.g. `$.token`
+    Json(JsonPath),
+    /// A response header, e.g. `header:Location`
+    Header(String),
+    /// The first capture group of a pattern matched against the body, or the
+    /// whole match if it has none, e.g. `regex:id=(\d+)`
+    Regex(Regex),
+}
+
+/// Saves a value from one scenario step's responses into a variable that later
+/// requests use as `{{name}}`
+#[derive(Debug, Clone)]
+pub struct Extraction {
+    pub step: String,
+    pub variable: String,
+    pub source: ExtractSource,
+}
+
+impl Extraction {
+    /// Parse `STEP:VAR=SOURCE`, where SOURCE is a JSONPath, `header:NAME` or `regex:PATTERN`
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = || {
+            anyhow!("Invalid extraction: '{spec}'. Expected STEP:VAR=SOURCE, e.g. 'login:token=$.token'")
+        };
+        let (step, rest) = spec.trim().split_once(':').ok_or_else(invalid)?;
+        let (variable, source) = rest.split_once('=').ok_or_else(invalid)?;
+        let (step, variable) = (step.trim(), variable.trim());
+        if step.is_empty() || variable.is_empty() {
+            return Err(invalid());
+        }
+
+        let source = source.trim();
+        let source = if let Some(name) = source.strip_prefix("header:") {
+            ExtractSource::Header(name.trim().to_string())
+        } else if let Some(pattern) = source.strip_prefix("regex:") {
+            ExtractSource::Regex(
+                Regex::new(pattern).map_err(|err| anyhow!("Invalid regex '{pattern}': {err}"))?,
+            )
+        } else if source.starts_with('$') {
+            ExtractSource::Json(JsonPath::parse(source)?)
+        } else {
+            return Err(invalid());
+        };
+
+        Ok(Extraction {
+            step: step.to_string(),
+            variable: variable.to_string(),
+            source,
+        })
+    }
+
+    /// The value this extraction finds in a response, if any
+    pub fn extract(&self, headers: &HeaderMap, body: &[u8]) -> Option<String> {
+        match &self.source {
+            ExtractSource::Json(path) => {
+                let document = serde_json::from_slice::<Value>(body).ok()?;
+                match path.lookup(&document)? {
+                    Value::String(text) => Some(text.clone()),
+                    value => Some(value.to_string()),
+                }
+            }
+            ExtractSource::Header(name) => headers
+                .get(name.as_str())
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            ExtractSource::Regex(pattern) => {
+                let body = String::from_utf8_lossy(body);
+                let captures = pattern.captures(&body)?;
+                let found = captures.get(1).or_else(|| captures.get(0))?;
+                Some(found.as_str().to_string())
+            }
+        }
+    }
+}
+
+/// Check that every extraction names a step of the scenario
+pub fn check_extractions(steps: &[ScenarioStep], extractions: &[Extraction]) -> Result<()> {
+    for extraction in extractions {
+        if !steps.iter().any(|step| step.name == extraction.step) {
+            return Err(anyhow!(
+                "Extraction of '{}' names unknown step '{}'",
+                extraction.variable,
+                extraction.step
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Replace each `{{name}}` with its variable's value; unknown names are left as written
+pub fn substitute(template: &str, variables: &HashMap<String, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + end].trim();
+        output.push_str(&rest[..start]);
+        match variables.get(name) {
+            Some(value) => output.push_str(value),
+            None => output.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &rest[start + end + 2..];
+    }
+    output.push_str(rest);
+    output
+}
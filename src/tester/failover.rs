@@ -0,0 +1,92 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use url::Url;
+
+use super::types::{RequestMetric, TestConfig};
+
+/// Index of the primary target in `TestConfig::targets`
+pub const PRIMARY_TARGET: usize = 0;
+
+/// Index of the fallback target in `TestConfig::targets`
+pub const FALLBACK_TARGET: usize = 1;
+
+/// Reject a fallback URL alongside a URL mix, whose URLs take the target
+/// indexes failover reports under
+pub fn check_fallback(config: &TestConfig) -> Result<()> {
+    if config.fallback_url.is_some() && config.url_mix.is_some() {
+        return Err(anyhow!("A fallback URL can't be combined with a URL mix"));
+    }
+    Ok(())
+}
+
+/// Switches traffic to a fallback URL after sustained connection errors on the primary
+pub struct Failover {
+    fallback: Url,
+    threshold: usize,
+    consecutive_errors: AtomicUsize,
+    active: AtomicBool,
+}
+
+impl Failover {
+    /// Create a failover that triggers after `threshold` consecutive connection errors
+    pub fn new(fallback: Url, threshold: usize) -> Self {
+        Failover {
+            fallback,
+            threshold: threshold.max(1),
+            consecutive_errors: AtomicUsize::new(0),
+            active: AtomicBool::new(false),
+        }
+    }
+
+    /// The URL to request and its target index: the fallback once failover has happened
+    pub fn target<'a>(&'a self, primary: &'a Url) -> (&'a Url, usize) {
+        if self.is_active() {
+            (&self.fallback, FALLBACK_TARGET)
+        } else {
+            (primary, PRIMARY_TARGET)
+        }
+    }
+
+    /// Whether traffic has been switched to the fallback
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    /// Observe a completed request, returning true when it triggers the switch
+    pub fn observe(&self, metric: &RequestMetric) -> bool {
+        if metric.target != PRIMARY_TARGET || self.is_active() {
+            return false;
+        }
+
+        // A status code of 0 means no response was received at all
+        if metric.status_code != 0 {
+            self.consecutive_errors.store(0, Ordering::SeqCst);
+            return false;
+        }
+
+        let errors = self.consecutive_errors.fetch_add(1, Ordering::SeqCst) + 1;
+        errors >= self.threshold && !self.active.swap(true, Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,187 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::stagger::XorShift;
+use super::template::expand;
+
+/// The order rows are handed out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedOrder {
+    /// Row after row, starting over after the last
+    Cycle,
+    /// A random row for each request
+    Random,
+}
+
+impl FeedOrder {
+    /// Parse `cycle` or `random`
+    pub fn parse(order: &str) -> Result<Self> {
+        match order.trim().to_ascii_lowercase().as_str() {
+            "cycle" => Ok(FeedOrder::Cycle),
+            "random" => Ok(FeedOrder::Random),
+            _ => Err(anyhow!(
+                "Invalid data order: '{order}'. Expected 'cycle' or 'random'"
+            )),
+        }
+    }
+}
+
+/// Rows of a CSV file whose columns fill `${column}` placeholders in each request
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataFeed {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+    order: FeedOrder,
+}
+
+impl DataFeed {
+    /// Read a CSV file whose first line names the columns
+    pub fn load(path: &Path, order: FeedOrder) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read data file '{}'", path.display()))?;
+        Self::parse(&text, order).with_context(|| format!("Invalid data file '{}'", path.display()))
+    }
+
+    /// Parse CSV text whose first line names the columns
+    pub fn parse(text: &str, order: FeedOrder) -> Result<Self> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let (_, header) = lines.next().ok_or_else(|| anyhow!("No header row"))?;
+        let columns: Vec<String> = split_record(header)
+            .into_iter()
+            .map(|column| column.trim().to_string())
+            .collect();
+
+        let mut rows = Vec::new();
+        for (index, line) in lines {
+            let row = split_record(line);
+            if row.len() != columns.len() {
+                return Err(anyhow!(
+                    "Line {} has {} fields, expected {}",
+                    index + 1,
+                    row.len(),
+                    columns.len()
+                ));
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(anyhow!("No data rows"));
+        }
+
+        Ok(DataFeed {
+            columns,
+            rows,
+            order,
+        })
+    }
+
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+/// Split one CSV line into fields; quoted fields may hold commas and `""` quotes
+fn split_record(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Hands out the feed's rows in its order, one per request
+pub struct FeedCursor {
+    feed: Arc<DataFeed>,
+    next: usize,
+    rng: XorShift,
+}
+
+impl FeedCursor {
+    pub fn new(feed: Arc<DataFeed>) -> Self {
+        FeedCursor {
+            feed,
+            next: 0,
+            rng: XorShift::new(0),
+        }
+    }
+
+    /// The row for the next request
+    pub fn next_row(&mut self) -> Row<'_> {
+        let index = match self.feed.order {
+            FeedOrder::Cycle => {
+                let index = self.next;
+                self.next = (self.next + 1) % self.feed.rows.len();
+                index
+            }
+            FeedOrder::Random => (self.rng.next() % self.feed.rows.len() as u64) as usize,
+        };
+        Row {
+            columns: &self.feed.columns,
+            values: &self.feed.rows[index],
+        }
+    }
+}
+
+/// One row of the feed, filling in its columns by name
+pub struct Row<'a> {
+    columns: &'a [String],
+    values: &'a [String],
+}
+
+impl Row<'_> {
+    pub fn get(&self, column: &str) -> Option<&str> {
+        let index = self.columns.iter().position(|name| name == column)?;
+        Some(&self.values[index])
+    }
+
+    /// Replace `${column}` placeholders with this row's values
+    pub fn fill(&self, template: &str) -> String {
+        expand(template, |name| self.get(name).map(str::to_string))
+    }
+}
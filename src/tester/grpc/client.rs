@@ -0,0 +1,221 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use floating_duration::TimeAsFloat;
+use hyper::body::HttpBody;
+use hyper::client::HttpConnector;
+use hyper::header::{HeaderMap, CONTENT_TYPE, TE, USER_AGENT};
+use hyper::{Body, Client, Request};
+use hyper_rustls::HttpsConnector;
+use std::time::{Duration, Instant};
+use url::Url;
+
+use super::status::status_from_http;
+use crate::tester::{Protocol, RequestMetric, DEFAULT_USER_AGENT, PRIMARY_TARGET};
+
+/// A unary call to send: the method and its encoded request message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrpcCall {
+    /// `package.Service/Method`
+    pub method: String,
+    /// Protobuf-encoded request message
+    pub message: Vec<u8>,
+}
+
+/// What came back from a call
+#[derive(Debug, Clone)]
+pub struct GrpcReply {
+    pub http_status: u16,
+    /// gRPC status code, 0 for OK
+    pub status: u16,
+    /// The `grpc-message` sent with a failing status
+    pub message: Option<String>,
+    /// The length-prefixed response messages, still framed
+    pub body: Vec<u8>,
+}
+
+impl GrpcReply {
+    /// The response messages with their length prefixes removed
+    pub fn messages(&self) -> Result<Vec<&[u8]>> {
+        let mut messages = Vec::new();
+        let mut rest = self.body.as_slice();
+        while rest.len() >= 5 {
+            let len = u32::from_be_bytes([rest[1], rest[2], rest[3], rest[4]]) as usize;
+            let end = 5 + len;
+            if rest.len() < end {
+                return Err(anyhow!("Truncated gRPC response message"));
+            }
+            messages.push(&rest[5..end]);
+            rest = &rest[end..];
+        }
+        Ok(messages)
+    }
+}
+
+/// Prefix a message with the uncompressed-flag byte and its length
+pub fn frame(message: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(message.len() + 5);
+    framed.push(0);
+    framed.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    framed.extend_from_slice(message);
+    framed
+}
+
+/// An HTTP/2 client speaking gRPC over cleartext (h2c) or TLS
+#[derive(Clone)]
+pub struct GrpcClient {
+    client: Client<HttpsConnector<HttpConnector>>,
+}
+
+impl Default for GrpcClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GrpcClient {
+    pub fn new() -> Self {
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            rustls::OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        }));
+        let tls = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_tls_config(tls)
+            .https_or_http()
+            .enable_http2()
+            .build();
+
+        GrpcClient {
+            client: Client::builder().http2_only(true).build(connector),
+        }
+    }
+
+    /// Make one unary call against the server at `url`
+    pub async fn call(
+        &self,
+        url: &Url,
+        call: &GrpcCall,
+        headers: &[(String, String)],
+        timeout: u64,
+    ) -> Result<GrpcReply> {
+        let uri = format!("{}/{}", url.as_str().trim_end_matches('/'), call.method);
+        let mut request = Request::post(uri)
+            .header(CONTENT_TYPE, "application/grpc")
+            .header(TE, "trailers");
+        if !headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("user-agent"))
+        {
+            request = request.header(USER_AGENT, DEFAULT_USER_AGENT);
+        }
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let request = request.body(Body::from(frame(&call.message)))?;
+
+        let exchange = async {
+            let response = self.client.request(request).await?;
+            let http_status = response.status().as_u16();
+            let mut metadata = response.headers().clone();
+            let mut body = response.into_body();
+            let mut received = Vec::new();
+            while let Some(chunk) = body.data().await {
+                received.extend_from_slice(&chunk?);
+            }
+            // A trailers-only reply carries the status in the headers instead
+            if let Some(trailers) = body.trailers().await? {
+                metadata.extend(trailers);
+            }
+            Ok::<_, anyhow::Error>((http_status, metadata, received))
+        };
+        let (http_status, metadata, body) = if timeout > 0 {
+            tokio::time::timeout(Duration::from_secs(timeout), exchange)
+                .await
+                .map_err(|_| anyhow!("gRPC call timed out"))??
+        } else {
+            exchange.await?
+        };
+
+        Ok(GrpcReply {
+            http_status,
+            status: grpc_status(&metadata).unwrap_or_else(|| status_from_http(http_status)),
+            message: metadata
+                .get("grpc-message")
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            body,
+        })
+    }
+
+    /// Make one call and measure it like any other load test request
+    pub async fn execute(
+        &self,
+        url: &Url,
+        call: &GrpcCall,
+        headers: &[(String, String)],
+        timeout: u64,
+        start_time: Instant,
+    ) -> RequestMetric {
+        let bytes_sent = (call.message.len() + call.method.len() + 50) as u64
+            + headers
+                .iter()
+                .map(|(name, value)| (name.len() + value.len() + 4) as u64)
+                .sum::<u64>();
+
+        let request_start = Instant::now();
+        let result = self.call(url, call, headers, timeout).await;
+        let duration = request_start.elapsed();
+
+        let mut metric = RequestMetric {
+            timestamp: start_time.elapsed().as_fractional_secs(),
+            latency_ms: duration.as_fractional_millis(),
+            is_error: true,
+            bytes_sent,
+            target: PRIMARY_TARGET,
+            ..Default::default()
+        };
+        if let Ok(reply) = result {
+            metric.status_code = reply.http_status;
+            metric.is_error = reply.status != 0;
+            metric.bytes_received = reply.body.len() as u64;
+            metric.protocol = Some(Protocol::Http2);
+            metric.grpc_status = Some(reply.status);
+        }
+        metric
+    }
+}
+
+fn grpc_status(metadata: &HeaderMap) -> Option<u16> {
+    metadata
+        .get("grpc-status")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
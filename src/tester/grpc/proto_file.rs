@@ -0,0 +1,368 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+use super::lexer::{is_symbol, tokenize};
+use super::schema::{qualify, FieldDef, FieldType, MessageDef, Schema};
+
+/// Read the messages, enums and services out of .proto source.
+/// Imported files are not followed, so request types must be defined in this one.
+pub fn parse_proto(source: &str) -> Result<Schema> {
+    let mut parser = Parser {
+        tokens: tokenize(source),
+        pos: 0,
+        schema: Schema::default(),
+        unresolved: Vec::new(),
+    };
+    parser.parse_file()?;
+    parser.resolve()?;
+    Ok(parser.schema)
+}
+
+struct Parser {
+    tokens: Vec<String>,
+    pos: usize,
+    schema: Schema,
+    /// Type references still to be qualified: (message, field index, scope)
+    unresolved: Vec<(String, usize, String)>,
+}
+
+impl Parser {
+    fn parse_file(&mut self) -> Result<()> {
+        let mut package = String::new();
+        while let Some(token) = self.next() {
+            match token.as_str() {
+                "package" => {
+                    package = self.expect_word()?;
+                    self.expect(";")?;
+                }
+                "message" => self.parse_message(&package)?,
+                "enum" => self.parse_enum(&package)?,
+                "service" => self.parse_service(&package)?,
+                "extend" => self.skip_block()?,
+                "syntax" | "edition" | "import" | "option" => self.skip_statement()?,
+                ";" => {}
+                other => return Err(self.error(&format!("unexpected '{other}'"))),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_message(&mut self, scope: &str) -> Result<()> {
+        let name = qualify(scope, &self.expect_word()?);
+        self.schema
+            .messages
+            .insert(name.clone(), MessageDef::default());
+        self.expect("{")?;
+        self.parse_message_body(&name)
+    }
+
+    fn parse_message_body(&mut self, name: &str) -> Result<()> {
+        loop {
+            let token = self.next().ok_or_else(|| self.error("unclosed message"))?;
+            match token.as_str() {
+                "}" => return Ok(()),
+                "message" => self.parse_message(name)?,
+                "enum" => self.parse_enum(name)?,
+                "extend" => self.skip_block()?,
+                "option" | "reserved" | "extensions" => self.skip_statement()?,
+                // Fields inside a oneof are ordinary optional fields on the wire
+                "oneof" => {
+                    self.expect_word()?;
+                    self.expect("{")?;
+                    self.parse_message_body(name)?;
+                }
+                "map" => self.parse_map_field(name)?,
+                ";" => {}
+                _ => {
+                    self.pos -= 1;
+                    self.parse_field(name)?;
+                }
+            }
+        }
+    }
+
+    fn parse_field(&mut self, message: &str) -> Result<()> {
+        let mut type_name = self.expect_word()?;
+        let repeated = type_name == "repeated";
+        if matches!(type_name.as_str(), "repeated" | "optional" | "required") {
+            type_name = self.expect_word()?;
+        }
+        if type_name == "group" {
+            return Err(self.error("groups are not supported"));
+        }
+        let name = self.expect_word()?;
+        let number = self.parse_field_number()?;
+        self.add_field(message, name, number, &type_name, repeated);
+        Ok(())
+    }
+
+    // map<K, V> name = N; is sugar for a repeated NameEntry { K key = 1; V value = 2; }
+    fn parse_map_field(&mut self, message: &str) -> Result<()> {
+        self.expect("<")?;
+        let key_type = self.expect_word()?;
+        self.expect(",")?;
+        let value_type = self.expect_word()?;
+        self.expect(">")?;
+        let name = self.expect_word()?;
+        let number = self.parse_field_number()?;
+
+        let entry = qualify(message, &format!("{}Entry", upper_camel(&name)));
+        let mut entry_def = MessageDef {
+            map_entry: true,
+            ..MessageDef::default()
+        };
+        entry_def.fields.push(FieldDef {
+            name: "key".to_string(),
+            number: 1,
+            field_type: FieldType::scalar(&key_type)
+                .ok_or_else(|| self.error("map keys must be scalar"))?,
+            repeated: false,
+        });
+        self.schema.messages.insert(entry.clone(), entry_def);
+        self.add_field(&entry, "value".to_string(), 2, &value_type, false);
+        self.add_field(message, name, number, &format!(".{entry}"), true);
+        Ok(())
+    }
+
+    fn parse_field_number(&mut self) -> Result<u32> {
+        self.expect("=")?;
+        let number = self.expect_word()?;
+        let number = number
+            .parse()
+            .map_err(|_| self.error(&format!("invalid field number '{number}'")))?;
+        if self.peek() == Some("[") {
+            self.skip_until("]")?;
+        }
+        self.expect(";")?;
+        Ok(number)
+    }
+
+    fn add_field(
+        &mut self,
+        message: &str,
+        name: String,
+        number: u32,
+        type_name: &str,
+        repeated: bool,
+    ) {
+        let field_type =
+            FieldType::scalar(type_name).unwrap_or_else(|| FieldType::Named(type_name.to_string()));
+        let fields = &mut self
+            .schema
+            .messages
+            .get_mut(message)
+            .expect("message registered before its fields")
+            .fields;
+        if matches!(field_type, FieldType::Named(_)) {
+            self.unresolved
+                .push((message.to_string(), fields.len(), message.to_string()));
+        }
+        fields.push(FieldDef {
+            name,
+            number,
+            field_type,
+            repeated,
+        });
+    }
+
+    fn parse_enum(&mut self, scope: &str) -> Result<()> {
+        let name = qualify(scope, &self.expect_word()?);
+        self.expect("{")?;
+        let mut values = HashMap::new();
+        loop {
+            let token = self.next().ok_or_else(|| self.error("unclosed enum"))?;
+            match token.as_str() {
+                "}" => break,
+                "option" | "reserved" => self.skip_statement()?,
+                ";" => {}
+                value => {
+                    self.expect("=")?;
+                    let number = self.expect_word()?;
+                    let number = number
+                        .parse()
+                        .map_err(|_| self.error(&format!("invalid enum value '{number}'")))?;
+                    values.insert(value.to_string(), number);
+                    if self.peek() == Some("[") {
+                        self.skip_until("]")?;
+                    }
+                    self.expect(";")?;
+                }
+            }
+        }
+        self.schema.enums.insert(name, values);
+        Ok(())
+    }
+
+    fn parse_service(&mut self, package: &str) -> Result<()> {
+        let service = qualify(package, &self.expect_word()?);
+        self.expect("{")?;
+        loop {
+            let token = self.next().ok_or_else(|| self.error("unclosed service"))?;
+            match token.as_str() {
+                "}" => return Ok(()),
+                "option" => self.skip_statement()?,
+                ";" => {}
+                "rpc" => {
+                    let method = self.expect_word()?;
+                    self.expect("(")?;
+                    let mut input = self.expect_word()?;
+                    if input == "stream" {
+                        input = self.expect_word()?;
+                    }
+                    self.skip_until(")")?;
+                    self.expect("returns")?;
+                    self.expect("(")?;
+                    self.skip_until(")")?;
+                    match self.next().as_deref() {
+                        Some(";") => {}
+                        Some("{") => {
+                            self.pos -= 1;
+                            self.skip_block()?;
+                        }
+                        _ => return Err(self.error("expected ';' or '{' after rpc")),
+                    }
+                    let input = resolve(&self.schema, package, &input)
+                        .ok_or_else(|| self.error(&format!("unknown request type '{input}'")))?;
+                    self.schema
+                        .methods
+                        .insert(format!("{service}/{method}"), input);
+                }
+                other => return Err(self.error(&format!("unexpected '{other}' in service"))),
+            }
+        }
+    }
+
+    /// Qualify every message and enum reference now that all types are known
+    fn resolve(&mut self) -> Result<()> {
+        for (message, index, scope) in std::mem::take(&mut self.unresolved) {
+            let FieldType::Named(name) = self.schema.messages[&message].fields[index]
+                .field_type
+                .clone()
+            else {
+                continue;
+            };
+            let resolved = resolve(&self.schema, &scope, &name)
+                .ok_or_else(|| anyhow!("Unknown type '{name}' in {message}"))?;
+            self.schema.messages.get_mut(&message).unwrap().fields[index].field_type =
+                FieldType::Named(resolved);
+        }
+        Ok(())
+    }
+
+    fn next(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<()> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(self.error(&format!("expected '{expected}'"))),
+        }
+    }
+
+    fn expect_word(&mut self) -> Result<String> {
+        match self.next() {
+            Some(token) if !is_symbol(&token) => Ok(token),
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    /// Skip to the end of a statement, stepping over any braced option values
+    fn skip_statement(&mut self) -> Result<()> {
+        let mut depth = 0;
+        loop {
+            match self.next().as_deref() {
+                Some(";") if depth == 0 => return Ok(()),
+                Some("{") => depth += 1,
+                Some("}") => depth -= 1,
+                Some(_) => {}
+                None => return Err(self.error("unterminated statement")),
+            }
+        }
+    }
+
+    fn skip_block(&mut self) -> Result<()> {
+        self.skip_until("{")?;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.next().as_deref() {
+                Some("{") => depth += 1,
+                Some("}") => depth -= 1,
+                Some(_) => {}
+                None => return Err(self.error("unclosed block")),
+            }
+        }
+        Ok(())
+    }
+
+    fn skip_until(&mut self, end: &str) -> Result<()> {
+        while let Some(token) = self.next() {
+            if token == end {
+                return Ok(());
+            }
+        }
+        Err(self.error(&format!("expected '{end}'")))
+    }
+
+    fn error(&self, message: &str) -> anyhow::Error {
+        anyhow!("Invalid .proto file near token {}: {message}", self.pos)
+    }
+}
+
+/// Find the fully qualified name `name` refers to from inside `scope`
+fn resolve(schema: &Schema, scope: &str, name: &str) -> Option<String> {
+    if let Some(absolute) = name.strip_prefix('.') {
+        return Some(absolute.to_string());
+    }
+    let mut scope = scope;
+    loop {
+        let candidate = qualify(scope, name);
+        if schema.messages.contains_key(&candidate) || schema.enums.contains_key(&candidate) {
+            return Some(candidate);
+        }
+        if scope.is_empty() {
+            return None;
+        }
+        scope = scope.rsplit_once('.').map_or("", |(parent, _)| parent);
+    }
+}
+
+fn upper_camel(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -0,0 +1,221 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use url::Url;
+
+use super::client::{GrpcCall, GrpcClient};
+use super::schema::{qualify, FieldDef, FieldType, MessageDef, Schema};
+use super::wire::{decode, put_bytes, put_tag, LEN};
+
+/// Reflection service methods, newest first
+const REFLECTION_METHODS: [&str; 2] = [
+    "grpc.reflection.v1.ServerReflection/ServerReflectionInfo",
+    "grpc.reflection.v1alpha.ServerReflection/ServerReflectionInfo",
+];
+
+/// Status a server returns for a service it doesn't implement
+const UNIMPLEMENTED: u16 = 12;
+
+/// Ask the server's reflection service for the definitions behind `service`
+pub async fn fetch_schema(
+    client: &GrpcClient,
+    url: &Url,
+    service: &str,
+    headers: &[(String, String)],
+    timeout: u64,
+) -> Result<Schema> {
+    // ServerReflectionRequest { file_containing_symbol = 7 }
+    let mut message = Vec::new();
+    put_tag(&mut message, 7, LEN);
+    put_bytes(&mut message, service.as_bytes());
+
+    for method in REFLECTION_METHODS {
+        let call = GrpcCall {
+            method: method.to_string(),
+            message: message.clone(),
+        };
+        let reply = client.call(url, &call, headers, timeout).await?;
+        if reply.status == UNIMPLEMENTED {
+            continue;
+        }
+        if reply.status != 0 {
+            return Err(anyhow!(
+                "gRPC reflection failed with status {}: {}",
+                reply.status,
+                reply.message.unwrap_or_default()
+            ));
+        }
+
+        let mut schema = Schema::default();
+        for response in reply.messages()? {
+            for (number, value) in decode(response)? {
+                match number {
+                    // file_descriptor_response { repeated bytes file_descriptor_proto = 1 }
+                    4 => {
+                        for (number, file) in decode(value.as_bytes())? {
+                            if number == 1 {
+                                add_file(&mut schema, file.as_bytes())?;
+                            }
+                        }
+                    }
+                    // error_response { error_message = 2 }
+                    7 => {
+                        let reason = decode(value.as_bytes())?
+                            .into_iter()
+                            .find(|(number, _)| *number == 2)
+                            .map(|(_, message)| message.as_string())
+                            .unwrap_or_default();
+                        return Err(anyhow!("gRPC reflection failed for {service}: {reason}"));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        return Ok(schema);
+    }
+
+    Err(anyhow!(
+        "The server does not support gRPC reflection; pass --proto instead"
+    ))
+}
+
+// FileDescriptorProto
+fn add_file(schema: &mut Schema, bytes: &[u8]) -> Result<()> {
+    let fields = decode(bytes)?;
+    let package = fields
+        .iter()
+        .find(|(number, _)| *number == 2)
+        .map(|(_, value)| value.as_string())
+        .unwrap_or_default();
+    for (number, value) in fields {
+        match number {
+            4 => add_message(schema, &package, value.as_bytes())?,
+            5 => add_enum(schema, &package, value.as_bytes())?,
+            6 => add_service(schema, &package, value.as_bytes())?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// DescriptorProto
+fn add_message(schema: &mut Schema, scope: &str, bytes: &[u8]) -> Result<()> {
+    let fields = decode(bytes)?;
+    let name = qualify(scope, &name_of(&fields));
+    let mut def = MessageDef::default();
+    for (number, value) in fields {
+        match number {
+            2 => def.fields.push(field_def(value.as_bytes())?),
+            3 => add_message(schema, &name, value.as_bytes())?,
+            4 => add_enum(schema, &name, value.as_bytes())?,
+            // MessageOptions { bool map_entry = 7 }
+            7 => {
+                def.map_entry = decode(value.as_bytes())?
+                    .iter()
+                    .any(|(number, value)| *number == 7 && value.as_u64() != 0)
+            }
+            _ => {}
+        }
+    }
+    schema.messages.insert(name, def);
+    Ok(())
+}
+
+// FieldDescriptorProto
+fn field_def(bytes: &[u8]) -> Result<FieldDef> {
+    let fields = decode(bytes)?;
+    let get = |wanted: u32| fields.iter().find(|(number, _)| *number == wanted);
+    let type_name = get(6)
+        .map(|(_, value)| value.as_string().trim_start_matches('.').to_string())
+        .unwrap_or_default();
+    let field_type = match get(5).map_or(0, |(_, value)| value.as_u64()) {
+        1 => FieldType::Double,
+        2 => FieldType::Float,
+        3 => FieldType::Int64,
+        4 => FieldType::Uint64,
+        5 => FieldType::Int32,
+        6 => FieldType::Fixed64,
+        7 => FieldType::Fixed32,
+        8 => FieldType::Bool,
+        9 => FieldType::String,
+        11 | 14 => FieldType::Named(type_name),
+        12 => FieldType::Bytes,
+        13 => FieldType::Uint32,
+        15 => FieldType::Sfixed32,
+        16 => FieldType::Sfixed64,
+        17 => FieldType::Sint32,
+        18 => FieldType::Sint64,
+        other => return Err(anyhow!("Unsupported protobuf field type {other}")),
+    };
+    Ok(FieldDef {
+        name: name_of(&fields),
+        number: get(3).map_or(0, |(_, value)| value.as_u64() as u32),
+        field_type,
+        repeated: get(4).is_some_and(|(_, value)| value.as_u64() == 3),
+    })
+}
+
+// EnumDescriptorProto
+fn add_enum(schema: &mut Schema, scope: &str, bytes: &[u8]) -> Result<()> {
+    let fields = decode(bytes)?;
+    let mut values = std::collections::HashMap::new();
+    for (_, value) in fields.iter().filter(|(number, _)| *number == 2) {
+        let value = decode(value.as_bytes())?;
+        let number = value
+            .iter()
+            .find(|(number, _)| *number == 2)
+            .map_or(0, |(_, number)| number.as_u64() as i32);
+        values.insert(name_of(&value), number);
+    }
+    schema
+        .enums
+        .insert(qualify(scope, &name_of(&fields)), values);
+    Ok(())
+}
+
+// ServiceDescriptorProto
+fn add_service(schema: &mut Schema, package: &str, bytes: &[u8]) -> Result<()> {
+    let fields = decode(bytes)?;
+    let service = qualify(package, &name_of(&fields));
+    for (_, method) in fields.iter().filter(|(number, _)| *number == 2) {
+        let method = decode(method.as_bytes())?;
+        let input = method
+            .iter()
+            .find(|(number, _)| *number == 2)
+            .map(|(_, value)| value.as_string().trim_start_matches('.').to_string())
+            .unwrap_or_default();
+        schema
+            .methods
+            .insert(format!("{service}/{}", name_of(&method)), input);
+    }
+    Ok(())
+}
+
+/// Every descriptor keeps its name in field 1
+fn name_of(fields: &[(u32, super::wire::WireValue<'_>)]) -> String {
+    fields
+        .iter()
+        .find(|(number, _)| *number == 1)
+        .map(|(_, value)| value.as_string())
+        .unwrap_or_default()
+}
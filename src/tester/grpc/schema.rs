@@ -0,0 +1,299 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::wire::{put_bytes, put_tag, put_varint, FIXED32, FIXED64, LEN, VARINT};
+
+/// The type of a message field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    Double,
+    Float,
+    Int64,
+    Uint64,
+    Int32,
+    Fixed64,
+    Fixed32,
+    Bool,
+    String,
+    Bytes,
+    Uint32,
+    Sfixed32,
+    Sfixed64,
+    Sint32,
+    Sint64,
+    /// A message or enum, by fully qualified name without the leading dot
+    Named(String),
+}
+
+impl FieldType {
+    /// The scalar type spelled `name` in a .proto file
+    pub fn scalar(name: &str) -> Option<FieldType> {
+        Some(match name {
+            "double" => FieldType::Double,
+            "float" => FieldType::Float,
+            "int64" => FieldType::Int64,
+            "uint64" => FieldType::Uint64,
+            "int32" => FieldType::Int32,
+            "fixed64" => FieldType::Fixed64,
+            "fixed32" => FieldType::Fixed32,
+            "bool" => FieldType::Bool,
+            "string" => FieldType::String,
+            "bytes" => FieldType::Bytes,
+            "uint32" => FieldType::Uint32,
+            "sfixed32" => FieldType::Sfixed32,
+            "sfixed64" => FieldType::Sfixed64,
+            "sint32" => FieldType::Sint32,
+            "sint64" => FieldType::Sint64,
+            _ => return None,
+        })
+    }
+}
+
+/// A single message field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDef {
+    pub name: String,
+    pub number: u32,
+    pub field_type: FieldType,
+    pub repeated: bool,
+}
+
+/// A message type
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageDef {
+    pub fields: Vec<FieldDef>,
+    /// Synthesized `key`/`value` entry of a `map<K, V>` field
+    pub map_entry: bool,
+}
+
+/// The message, enum and method definitions needed to encode requests
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    /// Messages by fully qualified name
+    pub messages: HashMap<String, MessageDef>,
+    /// Enum value numbers by fully qualified enum name, then value name
+    pub enums: HashMap<String, HashMap<String, i32>>,
+    /// Request message type by `package.Service/Method`
+    pub methods: HashMap<String, String>,
+}
+
+impl Schema {
+    /// The request message type of `package.Service/Method`
+    pub fn input_type(&self, method: &str) -> Result<&str> {
+        self.methods.get(method).map(String::as_str).ok_or_else(|| {
+            let mut known: Vec<_> = self.methods.keys().map(String::as_str).collect();
+            known.sort();
+            anyhow!(
+                "Unknown gRPC method '{method}'. Known methods: {}",
+                known.join(", ")
+            )
+        })
+    }
+
+    /// Encode a JSON value (proto3 JSON mapping) as the named message
+    pub fn encode(&self, message: &str, value: &Value) -> Result<Vec<u8>> {
+        let def = self
+            .messages
+            .get(message)
+            .ok_or_else(|| anyhow!("Unknown message type '{message}'"))?;
+        let object = value
+            .as_object()
+            .ok_or_else(|| anyhow!("Expected a JSON object for {message}"))?;
+
+        let mut buf = Vec::new();
+        for (key, value) in object {
+            let field = def
+                .fields
+                .iter()
+                .find(|field| field.name == *key || json_name(&field.name) == *key)
+                .ok_or_else(|| anyhow!("{message} has no field '{key}'"))?;
+            self.encode_field(&mut buf, field, value)
+                .with_context(|| format!("Invalid value for {message}.{}", field.name))?;
+        }
+        Ok(buf)
+    }
+
+    fn encode_field(&self, buf: &mut Vec<u8>, field: &FieldDef, value: &Value) -> Result<()> {
+        if value.is_null() {
+            return Ok(());
+        }
+        if !field.repeated {
+            return self.encode_value(buf, field.number, &field.field_type, value);
+        }
+
+        // Maps arrive as JSON objects and go out as repeated key/value entries
+        if let (FieldType::Named(entry), Value::Object(map)) = (&field.field_type, value) {
+            if self.messages.get(entry).is_some_and(|def| def.map_entry) {
+                for (key, value) in map {
+                    let pair = serde_json::json!({ "key": key, "value": value });
+                    put_tag(buf, field.number, LEN);
+                    put_bytes(buf, &self.encode(entry, &pair)?);
+                }
+                return Ok(());
+            }
+        }
+
+        let items = value
+            .as_array()
+            .ok_or_else(|| anyhow!("expected a JSON array"))?;
+        for item in items {
+            self.encode_value(buf, field.number, &field.field_type, item)?;
+        }
+        Ok(())
+    }
+
+    fn encode_value(
+        &self,
+        buf: &mut Vec<u8>,
+        number: u32,
+        field_type: &FieldType,
+        value: &Value,
+    ) -> Result<()> {
+        match field_type {
+            FieldType::Double => {
+                put_tag(buf, number, FIXED64);
+                buf.extend_from_slice(&as_f64(value)?.to_le_bytes());
+            }
+            FieldType::Float => {
+                put_tag(buf, number, FIXED32);
+                buf.extend_from_slice(&(as_f64(value)? as f32).to_le_bytes());
+            }
+            FieldType::Int32 | FieldType::Int64 => {
+                put_tag(buf, number, VARINT);
+                put_varint(buf, as_i64(value)? as u64);
+            }
+            FieldType::Uint32 | FieldType::Uint64 => {
+                put_tag(buf, number, VARINT);
+                put_varint(buf, as_u64(value)?);
+            }
+            FieldType::Sint32 | FieldType::Sint64 => {
+                let n = as_i64(value)?;
+                put_tag(buf, number, VARINT);
+                put_varint(buf, ((n << 1) ^ (n >> 63)) as u64);
+            }
+            FieldType::Fixed32 | FieldType::Sfixed32 => {
+                put_tag(buf, number, FIXED32);
+                buf.extend_from_slice(&(as_i64(value)? as u32).to_le_bytes());
+            }
+            FieldType::Fixed64 | FieldType::Sfixed64 => {
+                put_tag(buf, number, FIXED64);
+                buf.extend_from_slice(&(as_i64(value)? as u64).to_le_bytes());
+            }
+            FieldType::Bool => {
+                let flag = match value {
+                    Value::Bool(flag) => *flag,
+                    Value::String(text) => text.parse()?,
+                    _ => return Err(anyhow!("expected true or false")),
+                };
+                put_tag(buf, number, VARINT);
+                put_varint(buf, u64::from(flag));
+            }
+            FieldType::String => {
+                let text = value.as_str().ok_or_else(|| anyhow!("expected a string"))?;
+                put_tag(buf, number, LEN);
+                put_bytes(buf, text.as_bytes());
+            }
+            FieldType::Bytes => {
+                let text = value
+                    .as_str()
+                    .ok_or_else(|| anyhow!("expected a base64 string"))?;
+                let bytes = base64::engine::general_purpose::STANDARD.decode(text)?;
+                put_tag(buf, number, LEN);
+                put_bytes(buf, &bytes);
+            }
+            FieldType::Named(name) => {
+                if let Some(values) = self.enums.get(name) {
+                    let n = match value {
+                        Value::String(text) => *values
+                            .get(text)
+                            .ok_or_else(|| anyhow!("{name} has no value '{text}'"))?,
+                        _ => as_i64(value)? as i32,
+                    };
+                    put_tag(buf, number, VARINT);
+                    put_varint(buf, i64::from(n) as u64);
+                } else {
+                    put_tag(buf, number, LEN);
+                    put_bytes(buf, &self.encode(name, value)?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `scope.name`, or just `name` at the top level
+pub fn qualify(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{scope}.{name}")
+    }
+}
+
+/// The lowerCamelCase name the JSON mapping uses for a field
+fn json_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+// 64-bit integers are usually written as strings in proto3 JSON
+fn as_i64(value: &Value) -> Result<i64> {
+    match value {
+        Value::Number(n) => n.as_i64().ok_or_else(|| anyhow!("expected an integer")),
+        Value::String(text) => Ok(text.parse()?),
+        _ => Err(anyhow!("expected an integer")),
+    }
+}
+
+fn as_u64(value: &Value) -> Result<u64> {
+    match value {
+        Value::Number(n) => n
+            .as_u64()
+            .ok_or_else(|| anyhow!("expected an unsigned integer")),
+        Value::String(text) => Ok(text.parse()?),
+        _ => Err(anyhow!("expected an unsigned integer")),
+    }
+}
+
+fn as_f64(value: &Value) -> Result<f64> {
+    match value {
+        Value::Number(n) => n.as_f64().ok_or_else(|| anyhow!("expected a number")),
+        Value::String(text) => Ok(text.parse()?),
+        _ => Err(anyhow!("expected a number")),
+    }
+}
@@ -0,0 +1,127 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+
+/// Varint: int32, int64, uint32, uint64, sint32, sint64, bool, enum
+pub const VARINT: u8 = 0;
+/// Eight bytes: fixed64, sfixed64, double
+pub const FIXED64: u8 = 1;
+/// Length-delimited: string, bytes, embedded messages
+pub const LEN: u8 = 2;
+/// Four bytes: fixed32, sfixed32, float
+pub const FIXED32: u8 = 5;
+
+/// Append a field tag
+pub fn put_tag(buf: &mut Vec<u8>, number: u32, wire_type: u8) {
+    put_varint(buf, (u64::from(number) << 3) | u64::from(wire_type));
+}
+
+/// Append a base-128 varint
+pub fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Append a length-delimited field body
+pub fn put_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    put_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+/// A decoded field value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl<'a> WireValue<'a> {
+    /// The integer value of a varint field, or 0 for any other wire type
+    pub fn as_u64(&self) -> u64 {
+        match self {
+            WireValue::Varint(value) | WireValue::Fixed64(value) => *value,
+            WireValue::Fixed32(value) => u64::from(*value),
+            WireValue::Bytes(_) => 0,
+        }
+    }
+
+    /// The payload of a length-delimited field, or nothing for any other wire type
+    pub fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            WireValue::Bytes(bytes) => bytes,
+            _ => &[],
+        }
+    }
+
+    /// The payload of a length-delimited field as text
+    pub fn as_string(&self) -> String {
+        String::from_utf8_lossy(self.as_bytes()).into_owned()
+    }
+}
+
+/// Split an encoded message into its `(field number, value)` pairs
+pub fn decode(mut buf: &[u8]) -> Result<Vec<(u32, WireValue<'_>)>> {
+    let mut fields = Vec::new();
+    while !buf.is_empty() {
+        let tag = take_varint(&mut buf)?;
+        let number = (tag >> 3) as u32;
+        let value = match (tag & 7) as u8 {
+            VARINT => WireValue::Varint(take_varint(&mut buf)?),
+            FIXED64 => WireValue::Fixed64(u64::from_le_bytes(take(&mut buf, 8)?.try_into()?)),
+            LEN => {
+                let len = take_varint(&mut buf)? as usize;
+                WireValue::Bytes(take(&mut buf, len)?)
+            }
+            FIXED32 => WireValue::Fixed32(u32::from_le_bytes(take(&mut buf, 4)?.try_into()?)),
+            other => return Err(anyhow!("Unsupported protobuf wire type {other}")),
+        };
+        fields.push((number, value));
+    }
+    Ok(fields)
+}
+
+fn take_varint(buf: &mut &[u8]) -> Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *take(buf, 1)?.first().unwrap_or(&0);
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return Ok(value);
+        }
+    }
+    Err(anyhow!("Malformed protobuf varint"))
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(anyhow!("Truncated protobuf message"));
+    }
+    let (head, tail) = buf.split_at(len);
+    *buf = tail;
+    Ok(head)
+}
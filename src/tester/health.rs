@@ -0,0 +1,123 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use hdrhistogram::Histogram;
+use std::collections::VecDeque;
+
+/// Seconds of history shown per target
+pub const HEALTH_WINDOW_SECS: u64 = 10;
+
+/// Error rates at or above these mark a second as degraded or failing
+pub const DEGRADED_ERROR_RATE: f64 = 0.01;
+pub const FAILING_ERROR_RATE: f64 = 0.05;
+
+/// p99 latencies at or above these mark a second as degraded or failing
+pub const DEGRADED_P99_MS: f64 = 500.0;
+pub const FAILING_P99_MS: f64 = 1000.0;
+
+/// How one target fared during one second
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Health {
+    /// No requests finished in that second
+    Idle,
+    Healthy,
+    Degraded,
+    Failing,
+}
+
+/// Requests finished by one target during one second of the run
+#[derive(Debug, Clone, PartialEq)]
+struct HealthBucket {
+    second: u64,
+    requests: usize,
+    errors: usize,
+    latency: Histogram<u64>,
+}
+
+impl HealthBucket {
+    fn health(&self) -> Health {
+        let error_rate = self.errors as f64 / self.requests as f64;
+        let p99_ms = self.latency.value_at_quantile(0.99) as f64 / 1000.0;
+        if error_rate >= FAILING_ERROR_RATE || p99_ms >= FAILING_P99_MS {
+            Health::Failing
+        } else if error_rate >= DEGRADED_ERROR_RATE || p99_ms >= DEGRADED_P99_MS {
+            Health::Degraded
+        } else {
+            Health::Healthy
+        }
+    }
+}
+
+/// Per-second health of one target over the last `HEALTH_WINDOW_SECS`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HealthWindow {
+    buckets: VecDeque<HealthBucket>,
+}
+
+impl HealthWindow {
+    /// Count a request that finished `second` whole seconds into the run
+    pub fn record(&mut self, second: u64, latency_ms: f64, is_error: bool) {
+        let index = match self.buckets.iter().rposition(|b| b.second == second) {
+            Some(index) => index,
+            None => {
+                // Late results can land behind newer seconds; keep buckets in order
+                let index = self.buckets.partition_point(|b| b.second < second);
+                self.buckets.insert(
+                    index,
+                    HealthBucket {
+                        second,
+                        requests: 0,
+                        errors: 0,
+                        latency: Histogram::new(2).unwrap(),
+                    },
+                );
+                index
+            }
+        };
+
+        let bucket = &mut self.buckets[index];
+        bucket.requests += 1;
+        bucket.errors += usize::from(is_error);
+        let _ = bucket.latency.record((latency_ms * 1000.0) as u64);
+
+        let newest = self.buckets.back().map_or(second, |b| b.second);
+        while self
+            .buckets
+            .front()
+            .is_some_and(|b| b.second + HEALTH_WINDOW_SECS <= newest)
+        {
+            self.buckets.pop_front();
+        }
+    }
+
+    /// Health for each of the `HEALTH_WINDOW_SECS` seconds ending at `now`, oldest first
+    pub fn row(&self, now: u64) -> Vec<Health> {
+        (0..HEALTH_WINDOW_SECS)
+            .rev()
+            .map(|ago| {
+                now.checked_sub(ago)
+                    .and_then(|second| self.buckets.iter().find(|b| b.second == second))
+                    .map_or(Health::Idle, HealthBucket::health)
+            })
+            .collect()
+    }
+}
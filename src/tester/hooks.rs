@@ -0,0 +1,98 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::time::Duration;
+
+use super::metrics::LockFreeMetrics;
+use super::types::RequestMetric;
+
+/// How often `RunnerHooks::on_tick` is called while a test runs
+pub const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Callbacks for embedders that want to stream a run's results into their own
+/// systems; every method does nothing unless overridden
+pub trait RunnerHooks: Send {
+    /// Called for every completed request, in the order results arrive
+    fn on_request_complete(&mut self, _metric: &RequestMetric) {}
+
+    /// Called every `TICK_INTERVAL` with the running totals
+    fn on_tick(&mut self, _metrics: &LockFreeMetrics) {}
+
+    /// Called once, after the last request has been recorded
+    fn on_complete(&mut self, _metrics: &LockFreeMetrics) {}
+}
+
+type MetricFn = dyn FnMut(&RequestMetric) + Send;
+type TotalsFn = dyn FnMut(&LockFreeMetrics) + Send;
+
+/// Hooks built from closures, for when a trait impl is more than needed
+///
+/// ```
+/// use whambam::tester::FnHooks;
+///
+/// let hooks = FnHooks::default()
+///     .on_request_complete(|metric| println!("{} in {:.1} ms", metric.status_code, metric.latency_ms))
+///     .on_complete(|metrics| println!("{} requests", metrics.completed_requests()));
+/// ```
+#[derive(Default)]
+pub struct FnHooks {
+    request_complete: Option<Box<MetricFn>>,
+    tick: Option<Box<TotalsFn>>,
+    complete: Option<Box<TotalsFn>>,
+}
+
+impl FnHooks {
+    pub fn on_request_complete(mut self, f: impl FnMut(&RequestMetric) + Send + 'static) -> Self {
+        self.request_complete = Some(Box::new(f));
+        self
+    }
+
+    pub fn on_tick(mut self, f: impl FnMut(&LockFreeMetrics) + Send + 'static) -> Self {
+        self.tick = Some(Box::new(f));
+        self
+    }
+
+    pub fn on_complete(mut self, f: impl FnMut(&LockFreeMetrics) + Send + 'static) -> Self {
+        self.complete = Some(Box::new(f));
+        self
+    }
+}
+
+impl RunnerHooks for FnHooks {
+    fn on_request_complete(&mut self, metric: &RequestMetric) {
+        if let Some(f) = &mut self.request_complete {
+            f(metric);
+        }
+    }
+
+    fn on_tick(&mut self, metrics: &LockFreeMetrics) {
+        if let Some(f) = &mut self.tick {
+            f(metrics);
+        }
+    }
+
+    fn on_complete(&mut self, metrics: &LockFreeMetrics) {
+        if let Some(f) = &mut self.complete {
+            f(metrics);
+        }
+    }
+}
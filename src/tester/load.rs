@@ -0,0 +1,123 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use reqwest::Client;
+use std::sync::{atomic::AtomicBool, Arc};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use url::Url;
+
+use super::submitter::JobSubmitter;
+use super::types::{EndReason, RequestMetric, SharedState, TestConfig};
+use super::worker_options::WorkerOptions;
+use super::worker_pool::WorkerPool;
+
+/// Most jobs a run queues, even when it has no request limit
+const JOB_CAPACITY: usize = 1_000_000;
+
+/// Send a test's load: queue jobs for the workers until every job has been handed
+/// out, the duration is up or the test is stopped, then give requests in flight
+/// the drain timeout to finish before cancelling the workers
+pub(super) async fn run_load(
+    config: TestConfig,
+    clients: Vec<Client>,
+    job_urls: Vec<Url>,
+    options: Arc<WorkerOptions>,
+    metric_sender: mpsc::Sender<RequestMetric>,
+    is_running: Arc<AtomicBool>,
+    state: Option<SharedState>,
+) {
+    let start_time = Instant::now();
+
+    // Calculate test limits
+    let max_requests = if config.requests > 0 {
+        config.requests
+    } else {
+        usize::MAX
+    };
+
+    let max_duration = if config.duration > 0 {
+        Some(Duration::from_secs(config.duration))
+    } else {
+        None
+    };
+
+    // Create a worker pool with shared ownership
+    let worker_pool = Arc::new(WorkerPool::new(
+        clients,
+        config.concurrent,
+        metric_sender,
+        Arc::clone(&is_running),
+        Arc::clone(&options),
+    ));
+
+    // Create a separate task for job submission to avoid blocking
+    let submitter = JobSubmitter::new(&config, job_urls, state.clone());
+    let mut job_submitter = tokio::spawn(submitter.run(
+        Arc::clone(&worker_pool),
+        max_requests.min(JOB_CAPACITY),
+        start_time,
+        Arc::clone(&is_running),
+    ));
+
+    // Run until every job has been handed to a worker, the duration is up or
+    // the test is stopped, whichever comes first
+    let all_dispatched = async {
+        let _ = (&mut job_submitter).await;
+        worker_pool.wait_dispatched().await;
+    };
+    let duration_limit = async {
+        match max_duration {
+            Some(max_dur) => tokio::time::sleep(max_dur).await,
+            None => std::future::pending().await,
+        }
+    };
+    tokio::select! {
+        _ = all_dispatched => {}
+        _ = duration_limit => {
+            worker_pool.stop();
+            if let Some(state) = &state {
+                let mut guard = state.state.lock().unwrap();
+                if guard.stop_reason.is_none() {
+                    guard.end_reason.get_or_insert(EndReason::DurationLimit(config.duration));
+                }
+            }
+        }
+    }
+    job_submitter.abort();
+
+    // Let requests already in flight finish so they make it into the report
+    let abandoned = worker_pool.drain(config.drain_timeout).await;
+    if let (true, Some(state)) = (abandoned > 0, &state) {
+        state.state.lock().unwrap().record_event(format!(
+            "{abandoned} requests still in flight after the {}s drain timeout were abandoned",
+            config.drain_timeout.as_secs_f64()
+        ));
+    }
+    worker_pool.stop();
+    worker_pool.abort();
+
+    // Nothing needs the access token once the workers are gone
+    if let Some(bearer) = &options.bearer {
+        bearer.stop();
+    }
+}
@@ -0,0 +1,172 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Notify};
+
+use super::breaker::{ErrorBreaker, LatencyBreaker};
+use super::export::FanOut;
+use super::failover::{Failover, FALLBACK_TARGET, PRIMARY_TARGET};
+use super::hooks::{RunnerHooks, TICK_INTERVAL};
+use super::metrics::{LockFreeMetrics, SharedMetrics};
+use super::types::{Message, RequestMetric, SharedState};
+
+/// Hooks registered on a runner, shared with the tasks that call them
+pub(super) type SharedHooks = Arc<parking_lot::Mutex<Vec<Box<dyn RunnerHooks>>>>;
+
+/// Takes each result from the workers and folds it into the run's metrics, shared
+/// state and output files, tripping circuit breakers and failover along the way
+pub(super) struct MetricsTask {
+    pub metrics: SharedMetrics,
+    pub state: Option<SharedState>,
+    pub hooks: SharedHooks,
+    pub is_running: Arc<AtomicBool>,
+    pub breaker: Option<LatencyBreaker>,
+    pub error_breaker: Option<ErrorBreaker>,
+    pub failover: Option<Arc<Failover>>,
+    pub exporters: FanOut,
+    /// Told about every result, for listeners such as the UI
+    pub listener: mpsc::Sender<Message>,
+    /// Notified to finish the run without waiting for requests in flight
+    pub finish_now: Arc<Notify>,
+}
+
+impl MetricsTask {
+    /// Process results until every worker has exited or `finish_now` is notified,
+    /// then mark the run complete and finish its output files
+    pub async fn run(mut self, mut job_rx: mpsc::Receiver<RequestMetric>) {
+        let hooked = !self.hooks.lock().is_empty();
+        let exporting = !self.exporters.is_empty();
+
+        // Efficiently process batched metrics from job channel
+        while let Some(metric) = tokio::select! {
+            metric = job_rx.recv() => metric,
+            // Turn away requests still in flight but keep the results already queued
+            () = self.finish_now.notified() => {
+                job_rx.close();
+                job_rx.recv().await
+            }
+        } {
+            // Record the metric in the lock-free collector
+            self.metrics.record(&metric);
+            if hooked {
+                for hook in self.hooks.lock().iter_mut() {
+                    hook.on_request_complete(&metric);
+                }
+            }
+
+            // Trip a circuit breaker if latency has stayed too high or too much failed
+            let slow = self.breaker.as_mut().and_then(|b| b.observe(&metric));
+            let failing = self.error_breaker.as_mut().and_then(|b| b.observe(&metric));
+            let stop_reason = slow.or(failing);
+            if stop_reason.is_some() && self.is_running.swap(false, Ordering::SeqCst) {
+                self.metrics.mark_complete();
+            }
+
+            // Switch to the fallback target after sustained connection errors
+            let failed_over = self.failover.as_ref().is_some_and(|f| f.observe(&metric));
+
+            // If we have a shared state, update it as well for UI compatibility
+            if let Some(state) = &self.state {
+                let mut guard = state.state.lock().unwrap();
+                guard.update(metric.clone());
+                if failed_over {
+                    let message = format!(
+                        "Failed over from {} to {} after sustained connection errors",
+                        guard.targets[PRIMARY_TARGET].url, guard.targets[FALLBACK_TARGET].url
+                    );
+                    guard.record_event(message);
+                }
+                if let Some(reason) = stop_reason {
+                    if guard.stop_reason.is_none() {
+                        guard.stop_reason = Some(reason);
+                    }
+                    guard.mark_complete();
+                }
+                if exporting {
+                    self.exporters.record(&metric);
+                    if guard.is_complete {
+                        self.exporters.finish(&guard);
+                    }
+                }
+            } else if exporting {
+                self.exporters.record(&metric);
+            }
+
+            // Notify any listeners without letting an unread channel stall the pipeline
+            let _ = self
+                .listener
+                .try_send(Message::RequestComplete(Box::new(metric)));
+        }
+
+        // Every worker has exited, or the run was finished early, so every result
+        // there will be is in
+        self.metrics.process_metrics();
+        if let Some(state) = &self.state {
+            let mut guard = state.state.lock().unwrap();
+            guard.mark_complete();
+            if exporting {
+                self.exporters.finish(&guard);
+            }
+        }
+        // Holding the hooks lock keeps a last tick from landing after completion
+        let mut hooks = self.hooks.lock();
+        self.metrics.mark_complete();
+        for hook in hooks.iter_mut() {
+            hook.on_complete(&self.metrics.metrics);
+        }
+        drop(hooks);
+        let _ = self.listener.try_send(Message::TestComplete);
+    }
+}
+
+/// Keep the statistics of `metrics` current and tick any hooks until the run completes
+pub(super) async fn process_metrics(metrics: Arc<LockFreeMetrics>, hooks: SharedHooks) {
+    let hooked = !hooks.lock().is_empty();
+    let mut last_tick = Instant::now();
+    while !metrics.is_complete() {
+        // Process queued metrics periodically
+        metrics.process_queued_metrics();
+        metrics.update_statistics();
+
+        if hooked && last_tick.elapsed() >= TICK_INTERVAL {
+            last_tick = Instant::now();
+            let mut hooks = hooks.lock();
+            if !metrics.is_complete() {
+                for hook in hooks.iter_mut() {
+                    hook.on_tick(&metrics);
+                }
+            }
+        }
+
+        // Sleep a bit to reduce CPU usage
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    // Final processing
+    metrics.process_queued_metrics();
+    metrics.update_statistics();
+}
@@ -20,14 +20,175 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod assertions;
+mod breaker;
+mod builder;
+mod capture;
+mod certificate;
+mod client;
+mod compare;
+mod compress;
+mod connection;
+mod cookies;
+mod describe;
+mod dns;
+mod downsample;
+mod error;
+mod export;
+#[cfg(feature = "export")]
+mod exporters;
+mod extract;
+mod failover;
+mod feeder;
+pub mod grpc;
+mod health;
+mod hooks;
+mod load;
 mod metrics;
+mod metrics_task;
+mod oauth;
+mod pacer;
+mod probe;
+mod proxy;
+mod random_body;
+mod report;
+mod report_sections;
+mod request;
+mod resolve;
+mod scenario;
+mod schedule;
+mod sitemap;
+mod skew;
+mod sse;
+mod stagger;
+mod submitter;
+mod summary;
+mod template;
+mod test_report;
+mod theme;
+mod tls;
 mod types;
 mod unified_runner;
+mod url_mix;
+mod user_agents;
+mod worker;
+mod worker_options;
+mod worker_pool;
 
 // Export all common types
 pub use types::*;
 
+// Export response body checks
+pub use assertions::{BodyChecksum, JsonAssertion, JsonPath};
+
+// Export the validating config builder
+pub use builder::{parse_duration, TestConfigBuilder};
+
+// Export typed configuration errors
+pub use error::{parse_target_url, ConfigError, SUPPORTED_SCHEMES};
+
+// Export result file writers
+pub use export::{FanOut, MetricExporter, OutputSink};
+#[cfg(feature = "export")]
+pub use exporters::{error_entry, json_summary};
+
+// Export failed and sampled response capture
+pub use capture::{format_response, FailureDump, ResponseHead, ResponseSample};
+
+// Export one-off verbose requests for `whambam debug`
+pub use probe::{probe, Probe};
+
+// Export the latency and error circuit breakers
+pub use breaker::{CircuitBreaker, ErrorBreaker, LatencyBreaker};
+
+// Export fallback target switching
+pub use failover::{Failover, FALLBACK_TARGET, PRIMARY_TARGET};
+
+// Export server clock skew estimation
+pub use skew::{estimate_skew_ms, ClockSkew};
+
+// Export Server-Sent Events streaming
+pub use sse::{execute_sse, EventCounter, SseOptions, SseStats};
+
+// Export per-second target health
+pub use health::{
+    Health, HealthWindow, DEGRADED_ERROR_RATE, DEGRADED_P99_MS, FAILING_ERROR_RATE, FAILING_P99_MS,
+    HEALTH_WINDOW_SECS,
+};
+
+// Export TLS settings
+pub use tls::{client_config, parse_sni, pin_sni, TlsVersion};
+
+// Export server certificate details
+pub use certificate::{fetch_chain, CertificateInfo, EXPIRY_WARNING_DAYS};
+
+// Export DNS overrides and lookups
+pub use dns::{encode_query, lookup, parse_dns_server, parse_response, DnsCache};
+pub use resolve::{pinned_hosts, HostOverride};
+
+// Export per-user cookie sessions
+pub use cookies::CookieJar;
+
+// Export OAuth2 client-credentials tokens
+pub use oauth::{refresh_after, BearerToken, OAuthClient};
+
+// Export proxy settings
+pub use proxy::{build_proxy, forwarded_proxy_headers, parse_proxy_url};
+
+// Export connection setup timing
+pub use connection::{timed, ConnectionTiming, PhaseStats};
+
+// Export worker jitter and think time
+pub use stagger::{Jitter, Stagger, ThinkDistribution, ThinkTime, Thinker};
+
+// Export CSV data feeds and request templates
+pub use feeder::{DataFeed, FeedCursor, FeedOrder, Row};
+pub use template::{expand, Builtins};
+
+// Export request body compression and generation
+pub use compress::BodyEncoding;
+pub use random_body::{BodyGenerator, RandomBody};
+
+// Export weighted URL mixes and the sitemaps they can come from
+pub use sitemap::{fetch_sitemap, parse_sitemap, Sitemap};
+pub use url_mix::{UrlMix, UrlPicker, UrlSelection, WeightedUrl};
+
+// Export rotating User-Agent lists
+pub use user_agents::{load_user_agents, parse_user_agents};
+
+// Export multi-step scenarios
+pub use extract::{check_extractions, substitute, ExtractSource, Extraction};
+pub use scenario::{check_steps, group_steps, ScenarioStep, ScenarioUser, StepRequest, StepStats};
+
+// Export request pacing
+pub use pacer::{LiveRate, Pacer, RATE_STEP};
+
+// Export the UI colour schemes
+pub use theme::Theme;
+
+// Export full-length chart series and their downsampling
+pub use downsample::{lttb, SeriesHistory, StackedHistory, HISTORY_LEN, REPORT_SERIES_LEN};
+pub use schedule::{
+    stages_duration, ArrivalSchedule, Burst, BurstSchedule, LoadPattern, LoadStage,
+    PatternSchedule, StageSchedule,
+};
+
+// Export the finished test summary and text report writers
+pub use report::{print_hey_format_report, print_partial_summary};
+pub use summary::{
+    LatencySummary, PhaseSummary, SkewSummary, SseSummary, StepSummary, TargetSummary,
+};
+pub use test_report::{save_snapshot, TestReport};
+
+// Export comparison of saved reports
+pub use compare::{compare, Delta, RunSummary};
+
+// Export the runner callbacks for embedders
+pub use hooks::{FnHooks, RunnerHooks, TICK_INTERVAL};
+
 // Export the unified runner implementation
+pub use submitter::{cache_busted_url, CACHE_BUST_PARAM};
 pub use unified_runner::UnifiedRunner;
 
 // Export metrics collector
+pub use metrics::{LockFreeMetrics, SharedMetrics};
@@ -0,0 +1,135 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use parking_lot::RwLock;
+use reqwest::Client;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::Duration;
+use tokio::task::AbortHandle;
+
+/// Wait between attempts when a token refresh fails
+const REFRESH_RETRY: Duration = Duration::from_secs(5);
+
+/// Where and as whom to get an access token with the OAuth2 client-credentials grant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthClient {
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scopes: Vec<String>,
+}
+
+impl OAuthClient {
+    /// Request a token, returning it with its lifetime when the server gives one
+    pub async fn fetch_token(&self, client: &Client) -> Result<(String, Option<Duration>)> {
+        let mut form = vec![("grant_type", "client_credentials".to_string())];
+        if !self.scopes.is_empty() {
+            form.push(("scope", self.scopes.join(" ")));
+        }
+        let response = client
+            .post(&self.token_url)
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .form(&form)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Failed to fetch an OAuth2 token from {}", self.token_url))?;
+        let body = response
+            .bytes()
+            .await
+            .with_context(|| format!("Failed to read the token from {}", self.token_url))?;
+        let reply: serde_json::Value = serde_json::from_slice(&body)
+            .with_context(|| format!("Token endpoint {} did not send JSON", self.token_url))?;
+
+        let token = reply["access_token"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Token endpoint {} sent no access_token", self.token_url))?;
+        let lifetime = reply["expires_in"].as_u64().map(Duration::from_secs);
+        Ok((token.to_string(), lifetime))
+    }
+}
+
+/// The current access token, swapped for a new one shortly before it expires
+#[derive(Debug, Clone)]
+pub struct BearerToken {
+    token: Arc<RwLock<String>>,
+    refresh: Option<AbortHandle>,
+}
+
+impl BearerToken {
+    /// Fetch the first token, failing the test before any load is sent if that fails,
+    /// then keep it fresh until `is_running` is cleared or `stop` is called
+    pub async fn start(
+        oauth: OAuthClient,
+        client: Client,
+        is_running: Arc<AtomicBool>,
+    ) -> Result<Self> {
+        let (token, lifetime) = oauth.fetch_token(&client).await?;
+        let token = Arc::new(RwLock::new(token));
+        let refresh = lifetime.map(|lifetime| {
+            let shared = Arc::clone(&token);
+            tokio::spawn(async move {
+                let mut wait = refresh_after(lifetime);
+                loop {
+                    tokio::time::sleep(wait).await;
+                    if !is_running.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    // Keep sending the old token until a new one arrives
+                    wait = match oauth.fetch_token(&client).await {
+                        Ok((token, lifetime)) => {
+                            *shared.write() = token;
+                            match lifetime {
+                                Some(lifetime) => refresh_after(lifetime),
+                                None => break,
+                            }
+                        }
+                        Err(_) => REFRESH_RETRY,
+                    };
+                }
+            })
+            .abort_handle()
+        });
+        Ok(BearerToken { token, refresh })
+    }
+
+    /// Stop refreshing the token, so nothing outlives the run that needed it
+    pub fn stop(&self) {
+        if let Some(refresh) = &self.refresh {
+            refresh.abort();
+        }
+    }
+
+    /// The Authorization header value for the current token
+    pub fn header_value(&self) -> String {
+        format!("Bearer {}", self.token.read())
+    }
+}
+
+/// Refresh once 90% of a token's lifetime has passed, leaving time to get the next
+pub fn refresh_after(lifetime: Duration) -> Duration {
+    (lifetime - lifetime / 10).max(Duration::from_secs(1))
+}
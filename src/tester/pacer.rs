@@ -0,0 +1,102 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+
+/// How far a worker may fall behind its schedule and still catch up. Beyond
+/// this, missed slots are dropped instead of being sent as a burst.
+const MAX_LAG: Duration = Duration::from_millis(10);
+
+/// Factor each step of `LiveRate::step` speeds up or slows down by
+pub const RATE_STEP: f64 = 1.25;
+
+/// Per-worker schedule that spaces requests `1 / rate` seconds apart
+///
+/// Send times are kept as absolute deadlines, so timer granularity and late
+/// wake-ups are made up on the next request instead of accumulating. That
+/// keeps rates above 1000/s and fractional intervals accurate.
+pub struct Pacer {
+    interval: Duration,
+    next: Option<Instant>,
+}
+
+impl Pacer {
+    /// Create a pacer for `rate` requests per second, which must be positive
+    pub fn new(rate: f64) -> Self {
+        Pacer {
+            interval: Duration::from_secs_f64(1.0 / rate),
+            next: None,
+        }
+    }
+
+    /// Space requests for `rate` requests per second from here on
+    pub fn set_rate(&mut self, rate: f64) {
+        self.interval = Duration::from_secs_f64(1.0 / rate);
+    }
+
+    /// How long to wait from `now` before sending the next request
+    pub fn next_delay(&mut self, now: Instant) -> Duration {
+        let slot = match self.next {
+            Some(next) if now <= next + MAX_LAG => next,
+            _ => now,
+        };
+        self.next = Some(slot + self.interval);
+        slot.saturating_duration_since(now)
+    }
+}
+
+/// Per-worker rate limit in requests per second (0 for no limit) that can be
+/// changed while a test runs; clones share the same value
+#[derive(Debug, Clone, Default)]
+pub struct LiveRate(Arc<AtomicU64>);
+
+impl LiveRate {
+    pub fn new(rate: f64) -> Self {
+        LiveRate(Arc::new(AtomicU64::new(rate.to_bits())))
+    }
+
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, rate: f64) {
+        self.0.store(rate.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Raise or lower the rate by one `RATE_STEP`, returning the new rate. An
+    /// unlimited run can only slow down, starting from `measured`, the rate
+    /// each worker is achieving now.
+    pub fn step(&self, faster: bool, measured: f64) -> Option<f64> {
+        let rate = match (self.get(), faster) {
+            (current, true) if current > 0.0 => current * RATE_STEP,
+            (current, false) if current > 0.0 => current / RATE_STEP,
+            (_, false) if measured > 0.0 => measured / RATE_STEP,
+            _ => return None,
+        };
+        self.set(rate);
+        Some(rate)
+    }
+}
@@ -0,0 +1,93 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::Result;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use super::capture::ResponseHead;
+use super::client::create_http_clients;
+use super::connection::{request_error, timed, ConnectionTiming};
+use super::error::parse_target_url;
+use super::resolve::pinned_hosts;
+use super::types::{RequestError, TestConfig};
+
+/// What one request saw, from opening its connection to the last byte of the body
+#[derive(Debug, Clone)]
+pub struct Probe {
+    /// Address the request was sent to, once DNS and any `--resolve` were applied
+    pub remote_addr: Option<SocketAddr>,
+    pub head: ResponseHead,
+    /// Connection setup; empty when the connection of an earlier probe was reused
+    pub timing: ConnectionTiming,
+    /// From sending the request until the response headers arrived
+    pub first_byte_ms: f64,
+    /// From sending the request until the body was read
+    pub total_ms: f64,
+    pub body_bytes: usize,
+}
+
+/// Send `count` requests one after another on one client built the way a load
+/// test builds them, so each can be inspected before a full run
+pub async fn probe(config: &TestConfig, count: usize) -> Result<Vec<Result<Probe, RequestError>>> {
+    let url = parse_target_url(&config.url)?;
+    let pinned = pinned_hosts(&config.resolve, &[&url]);
+    let client = create_http_clients(config, &pinned)?.swap_remove(0);
+
+    let mut probes = Vec::with_capacity(count);
+    for _ in 0..count {
+        let mut request = client.request(config.method.to_reqwest(), url.clone());
+        if config.timeout > 0 {
+            request = request.timeout(Duration::from_secs(config.timeout));
+        }
+        for (name, value) in &config.headers {
+            request = request.header(name, value);
+        }
+        if let Some((username, password)) = &config.basic_auth {
+            request = request.basic_auth(username, Some(password));
+        }
+        if let Some(body) = &config.body {
+            request = request.body(body.clone());
+        }
+
+        let started = Instant::now();
+        let (result, timing) = timed(request.send()).await;
+        let first_byte_ms = started.elapsed().as_secs_f64() * 1000.0;
+        probes.push(match result {
+            Ok(response) => {
+                let remote_addr = response.remote_addr();
+                let head = ResponseHead::of(&response);
+                let body_bytes = response.bytes().await.map_or(0, |body| body.len());
+                Ok(Probe {
+                    remote_addr,
+                    head,
+                    timing,
+                    first_byte_ms,
+                    total_ms: started.elapsed().as_secs_f64() * 1000.0,
+                    body_bytes,
+                })
+            }
+            Err(err) => Err(request_error(&url, &err)),
+        });
+    }
+    Ok(probes)
+}
@@ -0,0 +1,58 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use super::stagger::XorShift;
+
+/// Random bytes to send as the request body, in place of a fixed one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomBody {
+    /// Body length in bytes
+    pub size: usize,
+    /// Seed for a repeatable payload; a fresh one each run otherwise
+    pub seed: Option<u64>,
+    /// New bytes for every request rather than one payload for the whole test
+    pub per_request: bool,
+}
+
+/// Produces the random bodies a `RandomBody` describes
+pub struct BodyGenerator {
+    size: usize,
+    rng: XorShift,
+}
+
+impl BodyGenerator {
+    pub fn new(body: RandomBody) -> Self {
+        BodyGenerator {
+            size: body.size,
+            rng: body.seed.map_or_else(|| XorShift::new(0), XorShift::seeded),
+        }
+    }
+
+    /// The next body, `size` bytes long
+    pub fn next_body(&mut self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(self.size + 8);
+        while body.len() < self.size {
+            body.extend_from_slice(&self.rng.next().to_le_bytes());
+        }
+        body.truncate(self.size);
+        body
+    }
+}
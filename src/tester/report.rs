@@ -0,0 +1,222 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::io::{self, Write};
+
+use super::grpc::grpc_status_name;
+use super::report_sections::{
+    print_certificates, print_clock_skew, print_sse, print_steps, print_targets_and_events,
+};
+use super::summary::PhaseSummary;
+use super::test_report::TestReport;
+use super::types::{Protocol, TestState};
+
+/// Write a compact summary of a run that was stopped before completion
+pub fn print_partial_summary<W: Write>(w: &mut W, state: &TestState) -> io::Result<()> {
+    let report = TestReport::from_state(state);
+    let completed = report.completed_requests;
+    let error_rate = if completed > 0 {
+        100.0 * report.error_count as f64 / completed as f64
+    } else {
+        0.0
+    };
+    let target = if report.target_requests > 0 {
+        format!(" of {}", report.target_requests)
+    } else {
+        String::new()
+    };
+
+    writeln!(
+        w,
+        "Test stopped before completion after {:.2} secs.",
+        report.elapsed_secs
+    )?;
+    if let Some(reason) = &report.stop_reason {
+        writeln!(w, "  Reason:\t{reason}")?;
+    }
+    writeln!(w, "  Completed:\t{completed}{target} requests")?;
+    writeln!(w, "  Requests/sec:\t{:.2}", report.requests_per_sec)?;
+    writeln!(w, "  Errors:\t{} ({error_rate:.1}%)", report.error_count)?;
+    writeln!(
+        w,
+        "  Latency:\tp50 {:.3} ms, p99 {:.3} ms",
+        report.latency_ms.p50, report.latency_ms.p99
+    )?;
+
+    Ok(())
+}
+
+/// Write the final report in the same layout `hey` prints
+pub fn print_hey_format_report<W: Write>(w: &mut W, state: &TestState) -> io::Result<()> {
+    let report = TestReport::from_state(state);
+    let completed = report.completed_requests;
+    let latency = report.latency_ms;
+    let size_per_request = if completed > 0 {
+        report.bytes_received / completed as u64
+    } else {
+        0
+    };
+
+    writeln!(w)?;
+    if let Some(reason) = &report.stop_reason {
+        writeln!(w, "Test aborted: {reason}")?;
+        writeln!(w)?;
+    }
+    // Only worth saying when -n and -z were both set and either could have ended it
+    if let (Some(reason), true) = (
+        &report.end_reason,
+        report.target_requests > 0 && report.target_duration_secs > 0,
+    ) {
+        writeln!(w, "Test ended: {reason}")?;
+        writeln!(w)?;
+    }
+    writeln!(w, "Summary:")?;
+    writeln!(w, "  Total:\t{:.4} secs", report.elapsed_secs)?;
+    writeln!(w, "  Slowest:\t{:.4} secs", latency.max / 1000.0)?;
+    writeln!(w, "  Fastest:\t{:.4} secs", latency.min / 1000.0)?;
+    writeln!(w, "  Average:\t{:.4} secs", latency.average / 1000.0)?;
+    writeln!(w, "  Requests/sec:\t{:.4}", report.requests_per_sec)?;
+    writeln!(w)?;
+    writeln!(w, "  Total data:\t{} bytes", report.bytes_received)?;
+    writeln!(w, "  Size/request:\t{size_per_request} bytes")?;
+    writeln!(w)?;
+
+    writeln!(w, "Latency distribution:")?;
+    for (percentile, latency) in latency.distribution() {
+        writeln!(w, "  {percentile}% in {:.4} secs", latency / 1000.0)?;
+    }
+    writeln!(w)?;
+    print_phase_details(w, &report)?;
+    print_status_codes(w, &report)?;
+    print_errors(w, &report)?;
+    print_certificates(w, &report)?;
+    print_clock_skew(w, &report)?;
+    print_sse(w, &report)?;
+    print_steps(w, &report)?;
+    print_targets_and_events(w, &report)
+}
+
+/// Print the status code and protocol distributions
+fn print_status_codes<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    // gRPC calls all come back as HTTP 200, so their own status is the interesting one
+    if report.grpc_status_counts.is_empty() {
+        writeln!(w, "Status code distribution:")?;
+        let mut status_codes: Vec<_> = report.status_counts.iter().collect();
+        status_codes.sort();
+        for (status, count) in status_codes {
+            writeln!(w, "  [{status}]\t{count} responses")?;
+        }
+    } else {
+        writeln!(w, "gRPC status distribution:")?;
+        let mut status_codes: Vec<_> = report.grpc_status_counts.iter().collect();
+        status_codes.sort();
+        for (status, count) in status_codes {
+            let name = grpc_status_name(*status);
+            writeln!(w, "  [{status} {name}]\t{count} responses")?;
+        }
+    }
+
+    // Mixed or non-HTTP/1.1 traffic is worth calling out
+    let http11 = Protocol::Http11.to_string();
+    if report.protocol_counts.keys().any(|p| *p != http11) {
+        writeln!(w)?;
+        writeln!(w, "Protocol distribution:")?;
+        for (protocol, count) in &report.protocol_counts {
+            writeln!(w, "  [{protocol}]\t{count} responses")?;
+        }
+    }
+    Ok(())
+}
+
+/// Print requests that got no response, failed assertions and checksum mismatches
+fn print_errors<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    let answered = report.status_counts.values().sum::<usize>();
+    let unanswered = report.completed_requests.saturating_sub(answered);
+    if unanswered > 0 {
+        writeln!(w)?;
+        writeln!(w, "Error distribution:")?;
+        if report.connect_timeouts > 0 {
+            writeln!(w, "  [{}]\tconnect timeouts", report.connect_timeouts)?;
+        }
+        let connection_errors = unanswered.saturating_sub(report.connect_timeouts);
+        if connection_errors > 0 {
+            writeln!(w, "  [{connection_errors}]\tconnection errors")?;
+        }
+    }
+
+    if report.assertion_failures > 0 {
+        writeln!(w)?;
+        writeln!(w, "Assertion failures:")?;
+        writeln!(
+            w,
+            "  [{}]\tresponses failed JSON assertions",
+            report.assertion_failures
+        )?;
+    }
+
+    if report.checksum_mismatches > 0 {
+        writeln!(w)?;
+        writeln!(w, "Checksum mismatches:")?;
+        writeln!(
+            w,
+            "  [{}]\tresponses did not match the expected SHA-256",
+            report.checksum_mismatches
+        )?;
+    }
+    Ok(())
+}
+
+/// Print where requests spent their time, phase by phase
+fn print_phase_details<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    let phases: Vec<(&str, &PhaseSummary)> = report
+        .phases()
+        .into_iter()
+        .filter_map(|(name, phase)| Some((name, phase?)))
+        .collect();
+    if phases.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w, "Details (average, fastest, slowest):")?;
+    for (name, phase) in &phases {
+        writeln!(
+            w,
+            "  {name}:\t{:.4} secs, {:.4} secs, {:.4} secs",
+            phase.average / 1000.0,
+            phase.min / 1000.0,
+            phase.max / 1000.0
+        )?;
+    }
+    writeln!(w)?;
+
+    writeln!(w, "Details (50%, 90%, 99%):")?;
+    for (name, phase) in &phases {
+        writeln!(
+            w,
+            "  {name}:\t{:.4} secs, {:.4} secs, {:.4} secs",
+            phase.p50 / 1000.0,
+            phase.p90 / 1000.0,
+            phase.p99 / 1000.0
+        )?;
+    }
+    writeln!(w)
+}
@@ -0,0 +1,164 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! The optional sections at the end of the text report, each printed only when
+//! the run has something to say there
+
+use chrono::Utc;
+use std::io::{self, Write};
+
+use super::certificate::EXPIRY_WARNING_DAYS;
+use super::test_report::TestReport;
+
+/// Print the server certificate chain, warning about any that expire soon
+pub(super) fn print_certificates<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    if report.certificates.is_empty() {
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    writeln!(w)?;
+    writeln!(w, "Certificate chain:")?;
+    for (depth, cert) in report.certificates.iter().enumerate() {
+        writeln!(w, "  [{depth}] {}", cert.subject)?;
+        writeln!(w, "      Issuer:\t{}", cert.issuer)?;
+        writeln!(
+            w,
+            "      Expires:\t{} ({} days)",
+            cert.not_after.format("%Y-%m-%d %H:%M:%S UTC"),
+            cert.days_left(now)
+        )?;
+    }
+    for cert in &report.certificates {
+        let days = cert.days_left(now);
+        if days < 0 {
+            writeln!(w, "  Warning: {} has expired", cert.subject)?;
+        } else if days < EXPIRY_WARNING_DAYS {
+            writeln!(w, "  Warning: {} expires in {days} days", cert.subject)?;
+        }
+    }
+    Ok(())
+}
+
+/// Print the server clock skew estimated from `Date` headers, if any were seen
+pub(super) fn print_clock_skew<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    let Some(skew) = &report.clock_skew_ms else {
+        return Ok(());
+    };
+
+    writeln!(w)?;
+    writeln!(w, "Clock skew (server - client, from Date headers):")?;
+    writeln!(w, "  Initial:\t{:+.1} secs", skew.initial / 1000.0)?;
+    writeln!(w, "  Final:\t{:+.1} secs", skew.last / 1000.0)?;
+    writeln!(
+        w,
+        "  Range:\t{:+.1} to {:+.1} secs",
+        skew.min / 1000.0,
+        skew.max / 1000.0
+    )?;
+    writeln!(w, "  Drift:\t{:+.1} secs", skew.drift / 1000.0)
+}
+
+/// Print event counts and time to first event for SSE runs
+pub(super) fn print_sse<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    let Some(sse) = &report.sse else {
+        return Ok(());
+    };
+
+    writeln!(w)?;
+    writeln!(w, "Server-sent events:")?;
+    writeln!(w, "  Streams:\t{}", sse.streams)?;
+    writeln!(w, "  Events:\t{}", sse.events)?;
+    writeln!(
+        w,
+        "  Per stream:\t{:.1} average, {} min, {} max",
+        sse.events_per_stream, sse.min_events, sse.max_events
+    )?;
+    let Some(first_event) = &sse.first_event_ms else {
+        return writeln!(w, "  First event:\tnone received");
+    };
+    for (percentile, ms) in [
+        (50, first_event.p50),
+        (90, first_event.p90),
+        (99, first_event.p99),
+    ] {
+        writeln!(
+            w,
+            "  First event:\t{percentile}% in {:.4} secs",
+            ms / 1000.0
+        )?;
+    }
+    Ok(())
+}
+
+/// Print per-step counts and latency for scenario runs
+pub(super) fn print_steps<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    if report.steps.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    writeln!(w, "Scenario steps:")?;
+    for step in &report.steps {
+        let (average, p99) = step
+            .latency_ms
+            .map_or((0.0, 0.0), |latency| (latency.average, latency.p99));
+        writeln!(
+            w,
+            "  [{}]\t{} requests, {} errors, {:.4} secs average, {:.4} secs p99",
+            step.name,
+            step.requests(),
+            step.errors,
+            average / 1000.0,
+            p99 / 1000.0
+        )?;
+    }
+    Ok(())
+}
+
+/// Print per-target stats when there are several targets, and any run events
+pub(super) fn print_targets_and_events<W: Write>(w: &mut W, report: &TestReport) -> io::Result<()> {
+    if report.targets.len() > 1 {
+        writeln!(w)?;
+        writeln!(w, "Targets:")?;
+        for target in &report.targets {
+            writeln!(
+                w,
+                "  {}\t{} requests, {} errors, {:.4} secs average",
+                target.url,
+                target.completed_requests,
+                target.error_count,
+                target.average_latency_ms / 1000.0
+            )?;
+        }
+    }
+
+    if !report.events.is_empty() {
+        writeln!(w)?;
+        writeln!(w, "Events:")?;
+        for event in &report.events {
+            writeln!(w, "  [{:.2}s]\t{}", event.elapsed, event.message)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,248 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use floating_duration::TimeAsFloat;
+use reqwest::Client;
+use std::time::{Duration, Instant};
+use url::Url;
+
+use super::capture::ResponseHead;
+use super::connection::{is_connect_timeout, request_error};
+use super::cookies::CookieJar;
+use super::failover::PRIMARY_TARGET;
+use super::scenario::ScenarioUser;
+use super::skew::estimate_skew_ms;
+use super::types::{HttpMethod, Protocol, RequestError, RequestMetric};
+use super::worker_options::WorkerOptions;
+
+/// Execute an HTTP request and return metrics
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn execute_request(
+    client: &Client,
+    url: Url,
+    method: HttpMethod,
+    headers: &[(String, String)],
+    body: Option<Vec<u8>>,
+    basic_auth: Option<(String, String)>,
+    timeout: u64,
+    start_time: Instant,
+    options: &WorkerOptions,
+    scenario: Option<(&mut ScenarioUser, usize)>,
+    cookies: Option<&mut CookieJar>,
+) -> RequestMetric {
+    // Calculate approximate bytes sent
+    let bytes_sent = {
+        let mut total = 0u64;
+
+        // Method and path
+        total += method.to_string().len() as u64;
+        total += url.path().len() as u64;
+        if let Some(query) = url.query() {
+            total += query.len() as u64;
+        }
+
+        // Headers
+        for (name, value) in headers {
+            total += name.len() as u64 + value.len() as u64 + 4;
+        }
+
+        // Body
+        if let Some(body) = &body {
+            total += body.len() as u64;
+        }
+
+        // Basic overhead
+        total += 50;
+
+        total
+    };
+
+    // Start request timing; wall-clock time is only needed for clock skew
+    let request_start = Instant::now();
+    let sent_at = chrono::Utc::now();
+
+    // Create the request builder based on method
+    let plain_http = url.scheme() == "http";
+    let logged_url = options.log_errors.then(|| url.clone());
+    let mut request_builder = client.request(method.to_reqwest(), url);
+
+    // Set timeout
+    if timeout > 0 {
+        request_builder = request_builder.timeout(Duration::from_secs(timeout));
+    }
+
+    // Add headers; proxy headers would leak to the server through an https tunnel
+    for (name, value) in headers {
+        request_builder = request_builder.header(name, value);
+    }
+    if plain_http {
+        for (name, value) in &options.proxy_headers {
+            request_builder = request_builder.header(name, value);
+        }
+    }
+
+    // Add basic auth
+    if let Some((username, password)) = &basic_auth {
+        request_builder = request_builder.basic_auth(username, Some(password));
+    }
+
+    // Add body
+    if let Some(body_content) = &body {
+        request_builder = request_builder.body(body_content.clone());
+    }
+
+    // Send request and process response
+    let result = request_builder.send().await;
+    let duration = request_start.elapsed();
+
+    match result {
+        Ok(resp) => {
+            let status = resp.status().as_u16();
+            let protocol = Protocol::from_version(resp.version());
+            let clock_skew_ms = resp
+                .headers()
+                .get(reqwest::header::DATE)
+                .and_then(|date| date.to_str().ok())
+                .and_then(|date| estimate_skew_ms(date, sent_at, duration));
+            if let Some(jar) = cookies {
+                jar.store(resp.url(), resp.headers());
+            }
+            let final_url = logged_url.map(|_| resp.url().to_string());
+            let sample = options.sample.as_ref().and_then(|sample| {
+                let request = format!("{method} {}", resp.url());
+                sample.claim().map(|number| (sample, number, request))
+            });
+            let head = (sample.is_some() || options.failure_dump.is_some())
+                .then(|| ResponseHead::of(&resp));
+            // Keep the headers only when a scenario step extracts values from them
+            let extract_headers = scenario
+                .as_ref()
+                .filter(|(user, step)| user.extracts_from(*step))
+                .map(|_| resp.headers().clone());
+            let body = match options.max_body_bytes {
+                Some(limit) => read_capped(resp, limit).await.into(),
+                None => resp.bytes().await.unwrap_or_default(),
+            };
+            let body_read_ms =
+                request_start.elapsed().as_fractional_millis() - duration.as_fractional_millis();
+            let bytes_received = body.len() as u64;
+            if let (Some((user, step)), Some(headers)) = (scenario, extract_headers) {
+                user.capture(step, &headers, &body);
+            }
+
+            // Classify against the same latency that gets reported
+            let is_error =
+                !options
+                    .classifier
+                    .is_success(status, duration.as_fractional_millis(), &body);
+            let assertion_failed = !options
+                .json_assertions
+                .iter()
+                .all(|assertion| assertion.check(&body));
+            let checksum_mismatch = options
+                .expected_body_sha256
+                .is_some_and(|checksum| !checksum.matches(&body));
+            if let Some(head) = &head {
+                if let Some((sample, number, request)) = sample {
+                    sample.write(number, &request, head, &body);
+                }
+                if let Some(dump) = options.failure_dump.as_ref().filter(|_| is_error) {
+                    dump.save(head, &body).await;
+                }
+            }
+            let error = final_url.filter(|_| is_error).map(|url| {
+                Box::new(RequestError {
+                    url,
+                    kind: if options.classifier.is_success_status(status) {
+                        "classified"
+                    } else {
+                        "status"
+                    },
+                    message: format!("HTTP {status}"),
+                })
+            });
+
+            RequestMetric {
+                timestamp: start_time.elapsed().as_fractional_secs(),
+                latency_ms: duration.as_fractional_millis(),
+                status_code: status,
+                is_error,
+                bytes_sent,
+                bytes_received,
+                target: PRIMARY_TARGET,
+                assertion_failed,
+                checksum_mismatch,
+                protocol,
+                clock_skew_ms,
+                grpc_status: None,
+                sse_events: None,
+                first_event_ms: None,
+                dns_lookup_ms: None,
+                tls_handshake_ms: None,
+                connect_ms: None,
+                ttfb_ms: None,
+                body_read_ms: Some(body_read_ms),
+                connect_timeout: false,
+                step: None,
+                error,
+            }
+        }
+        Err(err) => RequestMetric {
+            timestamp: start_time.elapsed().as_fractional_secs(),
+            latency_ms: duration.as_fractional_millis(),
+            status_code: 0,
+            is_error: true,
+            bytes_sent,
+            bytes_received: 0,
+            target: PRIMARY_TARGET,
+            assertion_failed: false,
+            checksum_mismatch: false,
+            protocol: None,
+            clock_skew_ms: None,
+            grpc_status: None,
+            sse_events: None,
+            first_event_ms: None,
+            dns_lookup_ms: None,
+            tls_handshake_ms: None,
+            connect_ms: None,
+            ttfb_ms: None,
+            body_read_ms: None,
+            connect_timeout: is_connect_timeout(&err),
+            step: None,
+            error: logged_url.map(|url| Box::new(request_error(&url, &err))),
+        },
+    }
+}
+
+/// Read at most `limit` bytes of a response body, dropping the connection
+/// rather than downloading the rest
+async fn read_capped(mut resp: reqwest::Response, limit: usize) -> Vec<u8> {
+    let mut body = Vec::new();
+    while body.len() < limit {
+        match resp.chunk().await {
+            Ok(Some(chunk)) => body.extend_from_slice(&chunk),
+            _ => break,
+        }
+    }
+    body.truncate(limit);
+    body
+}
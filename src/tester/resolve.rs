@@ -0,0 +1,109 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use url::Url;
+
+/// A curl-style `--resolve host:port:addr[,addr...]` entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostOverride {
+    pub host: String,
+    pub port: u16,
+    pub addrs: Vec<IpAddr>,
+}
+
+impl HostOverride {
+    /// Parse `host:port:addr`, where IPv6 addresses may be bracketed
+    pub fn parse(entry: &str) -> Result<Self> {
+        let invalid = || anyhow!("Invalid --resolve entry: '{entry}'. Expected host:port:addr");
+        let mut parts = entry.splitn(3, ':');
+        let (Some(host), Some(port), Some(addrs)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+        let port = port.parse().map_err(|_| invalid())?;
+        let addrs = addrs
+            .split(',')
+            .map(|addr| {
+                addr.trim()
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .parse()
+                    .map_err(|_| invalid())
+            })
+            .collect::<Result<Vec<IpAddr>>>()?;
+
+        Ok(HostOverride {
+            host: host.to_ascii_lowercase(),
+            port,
+            addrs,
+        })
+    }
+
+    /// Whether requests to `url` should use these addresses
+    pub fn matches(&self, url: &Url) -> bool {
+        url.host_str() == Some(self.host.as_str()) && url.port_or_known_default() == Some(self.port)
+    }
+
+    pub fn socket_addrs(&self) -> Vec<SocketAddr> {
+        self.addrs
+            .iter()
+            .map(|ip| SocketAddr::new(*ip, self.port))
+            .collect()
+    }
+}
+
+impl fmt::Display for HostOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let addrs: Vec<_> = self
+            .addrs
+            .iter()
+            .map(|addr| match addr {
+                IpAddr::V4(v4) => v4.to_string(),
+                IpAddr::V6(v6) => format!("[{v6}]"),
+            })
+            .collect();
+        write!(f, "{}:{}:{}", self.host, self.port, addrs.join(","))
+    }
+}
+
+/// The overrides that apply to any of `urls`, keyed by host as reqwest wants them
+pub fn pinned_hosts(overrides: &[HostOverride], urls: &[&Url]) -> Vec<(String, Vec<SocketAddr>)> {
+    overrides
+        .iter()
+        .filter(|entry| urls.iter().any(|url| entry.matches(url)))
+        .map(|entry| (entry.host.clone(), entry.socket_addrs()))
+        .collect()
+}
+
+/// The addresses `pinned` gives for the host of `url`, if any
+pub fn pinned_addrs(pinned: &[(String, Vec<SocketAddr>)], url: &Url) -> Option<Vec<SocketAddr>> {
+    pinned
+        .iter()
+        .find(|(host, _)| Some(host.as_str()) == url.host_str())
+        .map(|(_, addrs)| addrs.clone())
+}
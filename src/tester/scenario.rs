@@ -0,0 +1,190 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{Context, Result};
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use url::Url;
+
+use super::connection::PhaseStats;
+use super::extract::{substitute, Extraction};
+use super::types::{HttpMethod, RequestMetric};
+
+/// One request in the ordered sequence each virtual user repeats
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioStep {
+    pub name: String,
+    pub method: HttpMethod,
+    /// Absolute URL, or a path resolved against the test URL
+    pub url: String,
+    pub body: Option<String>,
+    /// Sent in addition to the test's own headers
+    pub headers: Vec<(String, String)>,
+}
+
+/// Check that every step's URL resolves against the test URL
+pub fn check_steps(base: &Url, steps: &[ScenarioStep]) -> Result<()> {
+    for step in steps {
+        base.join(&step.url)
+            .with_context(|| format!("Invalid URL for step '{}': {}", step.name, step.url))?;
+    }
+    Ok(())
+}
+
+/// A virtual user's place in the scenario and the values it has extracted so
+/// far; each worker runs one
+pub struct ScenarioUser {
+    base: Url,
+    steps: Arc<[ScenarioStep]>,
+    extractions: Arc<[Extraction]>,
+    variables: HashMap<String, String>,
+    next: usize,
+}
+
+/// The request a virtual user sends for one scenario step
+pub struct StepRequest {
+    pub step: usize,
+    pub method: HttpMethod,
+    pub url: Url,
+    pub body: Option<String>,
+    pub headers: Vec<(String, String)>,
+}
+
+impl ScenarioUser {
+    pub fn new(base: Url, steps: Arc<[ScenarioStep]>) -> Self {
+        ScenarioUser {
+            base,
+            steps,
+            extractions: Arc::from([]),
+            variables: HashMap::new(),
+            next: 0,
+        }
+    }
+
+    /// Save values from step responses into variables for later requests
+    pub fn with_extractions(mut self, extractions: Arc<[Extraction]>) -> Self {
+        self.extractions = extractions;
+        self
+    }
+
+    /// The variables this user has extracted so far
+    pub fn variables(&self) -> &HashMap<String, String> {
+        &self.variables
+    }
+
+    /// Fill this user's variables into a header value or other text
+    pub fn substitute(&self, text: &str) -> String {
+        substitute(text, &self.variables)
+    }
+
+    /// Whether responses to `step` have values to extract
+    pub fn extracts_from(&self, step: usize) -> bool {
+        let name = &self.steps[step].name;
+        self.extractions
+            .iter()
+            .any(|extraction| &extraction.step == name)
+    }
+
+    /// Save the values a response to `step` holds; a value that isn't found
+    /// keeps whatever the variable held before
+    pub fn capture(&mut self, step: usize, headers: &HeaderMap, body: &[u8]) {
+        let name = &self.steps[step].name;
+        for extraction in self.extractions.iter() {
+            if &extraction.step != name {
+                continue;
+            }
+            if let Some(value) = extraction.extract(headers, body) {
+                self.variables.insert(extraction.variable.clone(), value);
+            }
+        }
+    }
+
+    /// The next step's request with variables filled in, starting over after the last step
+    pub fn next_request(&mut self) -> StepRequest {
+        let step = self.next;
+        self.next = (self.next + 1) % self.steps.len();
+
+        let ScenarioStep {
+            method,
+            url,
+            body,
+            headers,
+            ..
+        } = &self.steps[step];
+        let url = self.substitute(url);
+        StepRequest {
+            step,
+            method: method.clone(),
+            // `check_steps` has already rejected URLs that don't resolve
+            url: self.base.join(&url).unwrap_or_else(|_| self.base.clone()),
+            body: body.as_deref().map(|body| self.substitute(body)),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.clone(), self.substitute(value)))
+                .collect(),
+        }
+    }
+}
+
+/// Per-step counters, kept separately for each step of a scenario
+#[derive(Debug, Clone)]
+pub struct StepStats {
+    pub name: String,
+    pub error_count: usize,
+    pub latency: PhaseStats,
+}
+
+impl StepStats {
+    pub fn new(name: String) -> Self {
+        StepStats {
+            name,
+            error_count: 0,
+            latency: PhaseStats::default(),
+        }
+    }
+
+    pub fn record(&mut self, metric: &RequestMetric) {
+        self.latency.record(metric.latency_ms);
+        if metric.is_error {
+            self.error_count += 1;
+        }
+    }
+}
+
+/// Counters for each distinct step name, and the index into them of each step
+pub fn group_steps(steps: &[ScenarioStep]) -> (Vec<StepStats>, Vec<usize>) {
+    let mut stats: Vec<StepStats> = Vec::new();
+    let groups = steps
+        .iter()
+        .map(
+            |step| match stats.iter().position(|group| group.name == step.name) {
+                Some(group) => group,
+                None => {
+                    stats.push(StepStats::new(step.name.clone()));
+                    stats.len() - 1
+                }
+            },
+        )
+        .collect();
+    (stats, groups)
+}
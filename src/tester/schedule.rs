@@ -0,0 +1,235 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::time::Duration;
+
+use super::builder::parse_duration;
+
+/// Longest step taken when integrating a pattern's rate over time
+const PATTERN_STEP_SECS: f64 = 0.01;
+
+/// One step of a staged load profile: hold `rate` requests per second for `duration`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadStage {
+    pub duration: Duration,
+    pub rate: f64,
+}
+
+impl LoadStage {
+    /// Parse `<duration>:<rate>`, e.g. `60s:100rps`, `2m:500` or `30s:0rps` for a pause
+    pub fn parse(stage: &str) -> Result<Self> {
+        let invalid = || anyhow!("Invalid stage: '{stage}'. Expected e.g. 60s:100rps");
+        let (duration, rate) = stage.trim().split_once(':').ok_or_else(invalid)?;
+        let duration = parse_stage_duration(duration).ok_or_else(invalid)?;
+        let rate: f64 = rate
+            .trim_end_matches("rps")
+            .parse()
+            .map_err(|_| invalid())?;
+        if duration.is_zero() || !rate.is_finite() || rate < 0.0 {
+            return Err(invalid());
+        }
+        Ok(LoadStage { duration, rate })
+    }
+}
+
+/// Parse `<n>s`, `<n>m` or `<n>h`; unlike `--duration`, a stage always names its unit
+fn parse_stage_duration(duration: &str) -> Option<Duration> {
+    if !duration.ends_with(['s', 'm', 'h']) {
+        return None;
+    }
+    parse_duration(duration).ok().map(Duration::from_secs)
+}
+
+/// Total length of a staged run
+pub fn stages_duration(stages: &[LoadStage]) -> Duration {
+    stages.iter().map(|stage| stage.duration).sum()
+}
+
+/// Decides when the runner releases each request, for open-model tests where
+/// the arrival rate is set up front rather than by how fast responses come back
+pub trait ArrivalSchedule: Send {
+    /// Offset from the start of the test to send the next request at, or `None`
+    /// when the schedule has nothing more to send
+    fn next_send(&mut self) -> Option<Duration>;
+}
+
+/// Send times for a staged run, as offsets from the start of the test
+///
+/// Each request is spaced by the rate of the stage it falls in, so the
+/// arrival rate follows the profile no matter how fast responses come back.
+pub struct StageSchedule {
+    stages: Vec<LoadStage>,
+    next: Duration,
+}
+
+impl StageSchedule {
+    pub fn new(stages: Vec<LoadStage>) -> Self {
+        StageSchedule {
+            stages,
+            next: Duration::ZERO,
+        }
+    }
+}
+
+impl ArrivalSchedule for StageSchedule {
+    /// When to send the next request, or `None` once the last stage is over
+    fn next_send(&mut self) -> Option<Duration> {
+        loop {
+            let mut stage_end = Duration::ZERO;
+            let stage = self.stages.iter().find(|stage| {
+                stage_end += stage.duration;
+                self.next < stage_end
+            })?;
+            if stage.rate == 0.0 {
+                // Nothing to send during a pause; resume where the next stage starts
+                self.next = stage_end;
+                continue;
+            }
+            let at = self.next;
+            self.next += Duration::from_secs_f64(1.0 / stage.rate);
+            return Some(at);
+        }
+    }
+}
+
+/// `size` requests fired together every `interval`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Burst {
+    pub size: usize,
+    pub interval: Duration,
+}
+
+/// Send times for burst mode: each group of `size` requests shares one send time
+pub struct BurstSchedule {
+    burst: Burst,
+    sent: u32,
+}
+
+impl BurstSchedule {
+    pub fn new(burst: Burst) -> Self {
+        BurstSchedule { burst, sent: 0 }
+    }
+}
+
+impl ArrivalSchedule for BurstSchedule {
+    fn next_send(&mut self) -> Option<Duration> {
+        let at = self.burst.interval * (self.sent / self.burst.size as u32);
+        self.sent = self.sent.checked_add(1)?;
+        Some(at)
+    }
+}
+
+/// A target rate that varies over time, e.g. `sine:period=60s,min=50,max=500`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadPattern {
+    /// Swings between `min` and `max` requests per second once per `period`,
+    /// starting from `min`
+    Sine {
+        period: Duration,
+        min: f64,
+        max: f64,
+    },
+}
+
+impl LoadPattern {
+    /// Parse `<shape>:key=value,...`; `sine` takes `period`, `min` and `max`
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let invalid = || {
+            anyhow!("Invalid pattern: '{pattern}'. Expected e.g. sine:period=60s,min=50,max=500")
+        };
+        let (shape, params) = pattern.trim().split_once(':').ok_or_else(invalid)?;
+        if shape != "sine" {
+            return Err(anyhow!("Unknown load pattern '{shape}'. Supported: sine"));
+        }
+
+        let (mut period, mut min, mut max) = (None, None, None);
+        for param in params.split(',') {
+            match param.trim().split_once('=').ok_or_else(invalid)? {
+                ("period", value) => period = parse_stage_duration(value),
+                ("min", value) => min = value.trim_end_matches("rps").parse::<f64>().ok(),
+                ("max", value) => max = value.trim_end_matches("rps").parse::<f64>().ok(),
+                _ => return Err(invalid()),
+            }
+        }
+        let (Some(period), Some(min), Some(max)) = (period, min, max) else {
+            return Err(invalid());
+        };
+        if period.is_zero() || !(0.0..=max).contains(&min) || max <= 0.0 || !max.is_finite() {
+            return Err(invalid());
+        }
+        Ok(LoadPattern::Sine { period, min, max })
+    }
+
+    /// Target requests per second at `elapsed` into the test
+    pub fn rate_at(&self, elapsed: Duration) -> f64 {
+        match *self {
+            LoadPattern::Sine { period, min, max } => {
+                let phase = elapsed.as_secs_f64() / period.as_secs_f64();
+                let wave = (1.0 - (std::f64::consts::TAU * phase).cos()) / 2.0;
+                min + (max - min) * wave
+            }
+        }
+    }
+}
+
+/// Send times that follow a `LoadPattern` for as long as the test runs
+pub struct PatternSchedule {
+    pattern: LoadPattern,
+    next: Duration,
+}
+
+impl PatternSchedule {
+    pub fn new(pattern: LoadPattern) -> Self {
+        PatternSchedule {
+            pattern,
+            next: Duration::ZERO,
+        }
+    }
+}
+
+impl ArrivalSchedule for PatternSchedule {
+    fn next_send(&mut self) -> Option<Duration> {
+        let at = self.next;
+
+        // The following request is due once the rate integrates to one more
+        // request, which copes with rates changing faster than requests are sent
+        let mut t = at.as_secs_f64();
+        let mut owed = 1.0;
+        loop {
+            let rate = self.pattern.rate_at(Duration::from_secs_f64(t));
+            let step = if rate > 0.0 {
+                PATTERN_STEP_SECS.min(0.1 / rate)
+            } else {
+                PATTERN_STEP_SECS
+            };
+            if rate * step >= owed {
+                t += owed / rate;
+                break;
+            }
+            owed -= rate * step;
+            t += step;
+        }
+        self.next = Duration::from_secs_f64(t);
+        Some(at)
+    }
+}
@@ -0,0 +1,127 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::Client;
+use std::time::Duration;
+
+use super::error::parse_target_url;
+use super::url_mix::{UrlMix, WeightedUrl};
+
+/// Nested sitemaps fetched from a sitemap index, at most
+const MAX_NESTED_SITEMAPS: usize = 50;
+
+/// What a sitemap file lists: pages with their priority, or further sitemaps
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Sitemap {
+    pub pages: Vec<WeightedUrl>,
+    pub sitemaps: Vec<String>,
+}
+
+/// Parse a sitemap or sitemap index. A page's `<priority>` (0.0 to 1.0, 0.5 when
+/// absent) becomes its weight, so a page at 1.0 gets ten times the requests of one at 0.1.
+pub fn parse_sitemap(xml: &str) -> Sitemap {
+    let mut sitemap = Sitemap::default();
+    for entry in elements(xml, "url") {
+        let Some(loc) = elements(entry, "loc").next() else {
+            continue;
+        };
+        let priority = elements(entry, "priority")
+            .next()
+            .and_then(|priority| priority.trim().parse::<f64>().ok())
+            .unwrap_or(0.5)
+            .clamp(0.0, 1.0);
+        sitemap.pages.push(WeightedUrl {
+            url: unescape(loc.trim()),
+            weight: ((priority * 10.0).round() as u32).max(1),
+        });
+    }
+    for entry in elements(xml, "sitemap") {
+        if let Some(loc) = elements(entry, "loc").next() {
+            sitemap.sitemaps.push(unescape(loc.trim()));
+        }
+    }
+    sitemap
+}
+
+/// Fetch a sitemap, following a sitemap index one level down, and weight its pages
+/// for the test; pages that aren't HTTP(S) URLs are left out
+pub async fn fetch_sitemap(url: &str, timeout: u64, user_agent: &str) -> Result<UrlMix> {
+    let mut builder = Client::builder().user_agent(user_agent);
+    if timeout > 0 {
+        builder = builder.timeout(Duration::from_secs(timeout));
+    }
+    let client = builder.build()?;
+
+    let sitemap = fetch(&client, url).await?;
+    let mut pages = sitemap.pages;
+    for nested in sitemap.sitemaps.iter().take(MAX_NESTED_SITEMAPS) {
+        pages.extend(fetch(&client, nested).await?.pages);
+    }
+
+    pages.retain(|page| parse_target_url(&page.url).is_ok());
+    if pages.is_empty() {
+        return Err(anyhow!("Sitemap {url} lists no pages to test"));
+    }
+    Ok(UrlMix { urls: pages })
+}
+
+async fn fetch(client: &Client, url: &str) -> Result<Sitemap> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to fetch sitemap {url}"))?;
+    let xml = response
+        .text()
+        .await
+        .with_context(|| format!("Failed to read sitemap {url}"))?;
+    Ok(parse_sitemap(&xml))
+}
+
+/// The contents of each `<tag>...</tag>` element, outermost first
+fn elements<'a>(xml: &'a str, tag: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let mut rest = xml;
+    std::iter::from_fn(move || {
+        let start = rest.find(&open)? + open.len();
+        let end = start + rest[start..].find(&close)?;
+        let content = &rest[start..end];
+        rest = &rest[end + close.len()..];
+        Some(content)
+    })
+}
+
+/// Decode the XML entities a URL may contain
+fn unescape(text: &str) -> String {
+    let text = text
+        .strip_prefix("<![CDATA[")
+        .and_then(|text| text.strip_suffix("]]>"))
+        .unwrap_or(text);
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,71 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::time::Duration;
+
+/// Estimate how far the server clock is ahead of ours, in milliseconds, from a `Date` header.
+/// The header has one-second resolution, so single estimates are only good to about ±0.5s.
+pub fn estimate_skew_ms(
+    date_header: &str,
+    sent_at: DateTime<Utc>,
+    latency: Duration,
+) -> Option<f64> {
+    let server_time = DateTime::parse_from_rfc2822(date_header).ok()?;
+
+    // The server stamped the response somewhere inside its second, and roughly halfway
+    // through the round trip as we saw it
+    let server_mid = server_time.with_timezone(&Utc) + ChronoDuration::milliseconds(500);
+    let client_mid = sent_at + ChronoDuration::from_std(latency / 2).ok()?;
+
+    Some((server_mid - client_mid).num_milliseconds() as f64)
+}
+
+/// Clock skew estimates gathered over a run
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClockSkew {
+    pub samples: usize,
+    pub first_ms: f64,
+    pub last_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+}
+
+impl ClockSkew {
+    /// Add one estimate
+    pub fn observe(&mut self, skew_ms: f64) {
+        if self.samples == 0 {
+            self.first_ms = skew_ms;
+            self.min_ms = skew_ms;
+            self.max_ms = skew_ms;
+        }
+        self.samples += 1;
+        self.last_ms = skew_ms;
+        self.min_ms = self.min_ms.min(skew_ms);
+        self.max_ms = self.max_ms.max(skew_ms);
+    }
+
+    /// How much the skew moved between the first and last estimate
+    pub fn drift_ms(&self) -> f64 {
+        self.last_ms - self.first_ms
+    }
+}
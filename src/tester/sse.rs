@@ -0,0 +1,231 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use floating_duration::TimeAsFloat;
+use hdrhistogram::Histogram;
+use reqwest::Client;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use url::Url;
+
+use super::connection::is_connect_timeout;
+use super::types::{HttpMethod, Protocol, RequestMetric, ResponseClassifier};
+
+/// How often a held stream checks whether the test has stopped
+const STOP_POLL: Duration = Duration::from_millis(250);
+
+/// Hold each request open as a Server-Sent Events stream
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SseOptions {
+    /// Close each stream after this long; `None` holds it until the server or test ends it
+    pub hold: Option<Duration>,
+}
+
+/// Counts complete events in an `text/event-stream` body fed in arbitrary chunks
+#[derive(Debug, Clone, Default)]
+pub struct EventCounter {
+    line: Vec<u8>,
+    after_cr: bool,
+    has_field: bool,
+    events: u64,
+}
+
+impl EventCounter {
+    /// Consume the next chunk of the stream and return the total event count so far
+    pub fn feed(&mut self, chunk: &[u8]) -> u64 {
+        for &byte in chunk {
+            match byte {
+                // "\r\n" is one line ending, not two
+                b'\n' if self.after_cr => self.after_cr = false,
+                b'\n' | b'\r' => {
+                    self.after_cr = byte == b'\r';
+                    self.end_line();
+                }
+                _ => {
+                    self.after_cr = false;
+                    self.line.push(byte);
+                }
+            }
+        }
+        self.events
+    }
+
+    /// Events completed so far
+    pub fn events(&self) -> u64 {
+        self.events
+    }
+
+    // A blank line dispatches the event; comment lines starting with ':' don't count as content
+    fn end_line(&mut self) {
+        if self.line.is_empty() {
+            if self.has_field {
+                self.events += 1;
+            }
+            self.has_field = false;
+        } else if self.line[0] != b':' {
+            self.has_field = true;
+        }
+        self.line.clear();
+    }
+}
+
+/// Event counts and time to first event across all streams in a run
+#[derive(Debug, Clone)]
+pub struct SseStats {
+    pub connections: usize,
+    pub events: u64,
+    pub min_events: u64,
+    pub max_events: u64,
+    /// Milliseconds from sending the request to the first complete event, stored in µs
+    pub first_event: Histogram<u64>,
+}
+
+impl Default for SseStats {
+    fn default() -> Self {
+        SseStats {
+            connections: 0,
+            events: 0,
+            min_events: 0,
+            max_events: 0,
+            first_event: Histogram::new(3).unwrap(),
+        }
+    }
+}
+
+impl SseStats {
+    /// Add one finished stream
+    pub fn observe(&mut self, events: u64, first_event_ms: Option<f64>) {
+        if self.connections == 0 {
+            self.min_events = events;
+        }
+        self.connections += 1;
+        self.events += events;
+        self.min_events = self.min_events.min(events);
+        self.max_events = self.max_events.max(events);
+        if let Some(ms) = first_event_ms {
+            let _ = self.first_event.record((ms * 1000.0) as u64);
+        }
+    }
+
+    /// Average events per stream
+    pub fn events_per_connection(&self) -> f64 {
+        if self.connections == 0 {
+            0.0
+        } else {
+            self.events as f64 / self.connections as f64
+        }
+    }
+
+    /// Time to first event at a quantile, in milliseconds
+    pub fn first_event_at_quantile(&self, quantile: f64) -> f64 {
+        self.first_event.value_at_quantile(quantile) as f64 / 1000.0
+    }
+}
+
+/// Open one event stream and hold it, counting events until it ends
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_sse(
+    client: &Client,
+    url: Url,
+    method: HttpMethod,
+    headers: &[(String, String)],
+    basic_auth: Option<(String, String)>,
+    timeout: u64,
+    options: SseOptions,
+    classifier: &ResponseClassifier,
+    is_running: &AtomicBool,
+    start_time: Instant,
+) -> RequestMetric {
+    let request_start = Instant::now();
+    let first_event_deadline = (timeout > 0).then(|| Duration::from_secs(timeout));
+
+    let url_len = url.as_str().len();
+    let mut request = client
+        .request(method.to_reqwest(), url)
+        .header(reqwest::header::ACCEPT, "text/event-stream");
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    if let Some((username, password)) = &basic_auth {
+        request = request.basic_auth(username, Some(password));
+    }
+
+    let mut metric = RequestMetric {
+        is_error: true,
+        bytes_sent: (url_len + 50) as u64
+            + headers
+                .iter()
+                .map(|(name, value)| (name.len() + value.len() + 4) as u64)
+                .sum::<u64>(),
+        ..Default::default()
+    };
+
+    let connect = request.send();
+    let response = match first_event_deadline {
+        Some(deadline) => tokio::time::timeout(deadline, connect).await.ok(),
+        None => Some(connect.await),
+    };
+    if let Some(Ok(mut response)) = response {
+        let status = response.status().as_u16();
+        metric.status_code = status;
+        metric.protocol = Protocol::from_version(response.version());
+        metric.is_error = !classifier.is_success_status(status);
+
+        let mut counter = EventCounter::default();
+        let mut first_event_ms = None;
+        loop {
+            let elapsed = request_start.elapsed();
+            if !is_running.load(Ordering::SeqCst)
+                || options.hold.is_some_and(|hold| elapsed >= hold)
+            {
+                break;
+            }
+            // No first event within the request timeout counts as a failed stream
+            if first_event_ms.is_none() && first_event_deadline.is_some_and(|d| elapsed >= d) {
+                metric.is_error = true;
+                break;
+            }
+            match tokio::time::timeout(STOP_POLL, response.chunk()).await {
+                Ok(Ok(Some(chunk))) => {
+                    metric.bytes_received += chunk.len() as u64;
+                    if counter.feed(&chunk) > 0 && first_event_ms.is_none() {
+                        first_event_ms = Some(request_start.elapsed().as_fractional_millis());
+                    }
+                }
+                Ok(Ok(None)) => break,
+                Ok(Err(_)) => {
+                    metric.is_error = true;
+                    break;
+                }
+                Err(_) => {}
+            }
+        }
+        metric.sse_events = Some(counter.events());
+        metric.first_event_ms = first_event_ms;
+    } else if let Some(Err(err)) = &response {
+        metric.connect_timeout = is_connect_timeout(err);
+    }
+
+    metric.timestamp = start_time.elapsed().as_fractional_secs();
+    metric.latency_ms = request_start.elapsed().as_fractional_millis();
+    metric
+}
@@ -0,0 +1,193 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Range of random delays used to keep workers from firing in lockstep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stagger {
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stagger {
+    /// Parse a range such as `0-50ms`, `10ms-1s` or just `50ms` (meaning `0-50ms`)
+    pub fn parse(range: &str) -> Result<Self> {
+        let invalid = || anyhow!("Invalid stagger: '{range}'. Expected e.g. 0-50ms");
+        let (min, max) = range.trim().split_once('-').unwrap_or(("0", range.trim()));
+
+        // A bare lower bound takes the upper bound's unit, so "0-50ms" reads naturally
+        let unit = max.trim_start_matches(|c: char| c.is_ascii_digit());
+        let min = if min.chars().all(|c| c.is_ascii_digit()) {
+            format!("{min}{unit}")
+        } else {
+            min.to_string()
+        };
+
+        let min = parse_delay(&min).ok_or_else(invalid)?;
+        let max = parse_delay(max).ok_or_else(invalid)?;
+        if min > max {
+            return Err(invalid());
+        }
+        Ok(Stagger { min, max })
+    }
+}
+
+/// Parse `<n>ms` or `<n>s`
+fn parse_delay(delay: &str) -> Option<Duration> {
+    if let Some(ms) = delay.strip_suffix("ms") {
+        ms.parse().ok().map(Duration::from_millis)
+    } else if let Some(secs) = delay.strip_suffix('s') {
+        secs.parse().ok().map(Duration::from_secs)
+    } else {
+        None
+    }
+}
+
+/// Per-worker source of delays within a `Stagger` range
+pub struct Jitter {
+    stagger: Stagger,
+    rng: XorShift,
+}
+
+impl Jitter {
+    /// Create a jitter source; each worker passes its own index so workers don't share a sequence
+    pub fn new(stagger: Stagger, worker_id: usize) -> Self {
+        Jitter {
+            stagger,
+            rng: XorShift::new(worker_id),
+        }
+    }
+
+    /// The next random delay within the range
+    pub fn next_delay(&mut self) -> Duration {
+        let span = (self.stagger.max - self.stagger.min).as_micros() as u64;
+        let offset = if span == 0 {
+            0
+        } else {
+            self.rng.next() % (span + 1)
+        };
+        self.stagger.min + Duration::from_micros(offset)
+    }
+}
+
+/// How think times are spread around their mean
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThinkDistribution {
+    /// Always exactly the mean
+    Fixed,
+    /// Anywhere from zero to twice the mean
+    Uniform,
+    /// Memoryless pauses, mostly short with the occasional long one
+    Exponential,
+}
+
+/// Pause each virtual user takes between its requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThinkTime {
+    pub mean: Duration,
+    pub distribution: ThinkDistribution,
+}
+
+impl ThinkTime {
+    /// Parse `200ms`, `200ms:uniform` or `1s:exponential`
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = || {
+            anyhow!("Invalid think time: '{spec}'. Expected e.g. 200ms, 200ms:uniform or 1s:exponential")
+        };
+        let (mean, distribution) = spec
+            .trim()
+            .split_once(':')
+            .unwrap_or((spec.trim(), "fixed"));
+        let distribution = match distribution {
+            "fixed" => ThinkDistribution::Fixed,
+            "uniform" => ThinkDistribution::Uniform,
+            "exponential" | "exp" => ThinkDistribution::Exponential,
+            _ => return Err(invalid()),
+        };
+        let mean = parse_delay(mean).ok_or_else(invalid)?;
+        Ok(ThinkTime { mean, distribution })
+    }
+}
+
+/// Per-worker source of think times
+pub struct Thinker {
+    think: ThinkTime,
+    rng: XorShift,
+    started: bool,
+}
+
+impl Thinker {
+    /// Create a think time source; each worker passes its own index as with `Jitter`
+    pub fn new(think: ThinkTime, worker_id: usize) -> Self {
+        Thinker {
+            think,
+            rng: XorShift::new(worker_id),
+            started: false,
+        }
+    }
+
+    /// The pause before the next request; a user's first request goes out straight away
+    pub fn next_pause(&mut self) -> Duration {
+        if !std::mem::replace(&mut self.started, true) {
+            return Duration::ZERO;
+        }
+        let mean = self.think.mean.as_secs_f64();
+        let secs = match self.think.distribution {
+            ThinkDistribution::Fixed => mean,
+            ThinkDistribution::Uniform => 2.0 * mean * self.rng.next_fraction(),
+            ThinkDistribution::Exponential => -mean * (1.0 - self.rng.next_fraction()).ln(),
+        };
+        Duration::from_secs_f64(secs)
+    }
+}
+
+/// xorshift64: plenty for spreading out request timings
+pub(super) struct XorShift(u64);
+
+impl XorShift {
+    pub(super) fn new(worker_id: usize) -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        // xorshift needs a non-zero state
+        XorShift((nanos ^ (worker_id as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)) | 1)
+    }
+
+    /// The same sequence every time for the same seed
+    pub(super) fn seeded(seed: u64) -> Self {
+        XorShift((seed ^ 0x9E37_79B9_7F4A_7C15).wrapping_mul(0xBF58_476D_1CE4_E5B9) | 1)
+    }
+
+    pub(super) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A value in `[0, 1)`
+    fn next_fraction(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
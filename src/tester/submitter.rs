@@ -0,0 +1,289 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+use url::Url;
+
+use super::compress::BodyEncoding;
+use super::failover::PRIMARY_TARGET;
+use super::feeder::FeedCursor;
+use super::random_body::BodyGenerator;
+use super::schedule::{ArrivalSchedule, Burst, BurstSchedule, PatternSchedule, StageSchedule};
+use super::stagger::XorShift;
+use super::template::{expand, Builtins};
+use super::types::{HttpMethod, SharedState, TestConfig};
+use super::url_mix::UrlPicker;
+use super::worker_pool::{RequestJob, WorkerPool};
+
+/// Query parameter added to each request URL when cache busting
+pub const CACHE_BUST_PARAM: &str = "whambam_nonce";
+
+/// Jobs are queued in batches of this many, yielding in between
+const BATCH_SIZE: usize = 1000;
+
+/// Builds each request of a run from the config, filling in templates, rotating
+/// User-Agents and busting caches, and queues them at the schedule's pace
+pub(super) struct JobSubmitter {
+    job_urls: Vec<Url>,
+    url_templates: Vec<String>,
+    picker: Option<UrlPicker>,
+    headers: Vec<(String, String)>,
+    user_agents: Vec<String>,
+    body: Option<Vec<u8>>,
+    /// A random body is drawn once, or afresh for every request
+    generator: Option<BodyGenerator>,
+    per_request_body: bool,
+    compress_body: Option<BodyEncoding>,
+    /// Fixed bodies are compressed once; templated and per-request ones as built
+    compress_each: bool,
+    basic_auth: Option<(String, String)>,
+    method: HttpMethod,
+    timeout: u64,
+    /// Random per-run part of the cache-bust nonce, so no run repeats another's URLs
+    cache_bust: Option<u64>,
+    /// Only requests with placeholders need filling in
+    templated: bool,
+    feed: Option<FeedCursor>,
+    builtins: Builtins,
+    schedule: Option<Box<dyn ArrivalSchedule>>,
+    burst: Option<Burst>,
+    state: Option<SharedState>,
+}
+
+impl JobSubmitter {
+    /// Prepare to send jobs to `job_urls`, indexed like `TestConfig::targets`
+    pub fn new(config: &TestConfig, job_urls: Vec<Url>, state: Option<SharedState>) -> Self {
+        let mut headers = config.headers.clone();
+        let mut generator = config.random_body.map(BodyGenerator::new);
+        let per_request_body = config.random_body.is_some_and(|body| body.per_request);
+        let body = match &mut generator {
+            Some(generator) if !per_request_body => Some(generator.next_body()),
+            _ => config.body.clone(),
+        };
+        let compress_body = config
+            .compress_body
+            .filter(|_| body.is_some() || per_request_body);
+        let url_templates = config.targets();
+        let feed = config.data_feed.clone().map(FeedCursor::new);
+        let templated = feed.is_some()
+            || url_templates.iter().any(|url| url.contains("${"))
+            || config.headers.iter().any(|(_, value)| value.contains("${"))
+            || config
+                .body
+                .as_ref()
+                .is_some_and(|body| body.windows(2).any(|pair| pair == b"${"));
+        let compress_each = templated || per_request_body;
+        if let Some(encoding) = compress_body {
+            headers.push((
+                "Content-Encoding".to_string(),
+                encoding.header_value().to_string(),
+            ));
+        }
+        let body = match compress_body {
+            Some(encoding) if !compress_each => body.map(|body| encoding.compress(&body)),
+            _ => body,
+        };
+
+        JobSubmitter {
+            job_urls,
+            url_templates,
+            picker: config
+                .url_mix
+                .as_ref()
+                .map(|mix| UrlPicker::new(mix, config.url_selection)),
+            headers,
+            user_agents: config.user_agents.clone(),
+            body,
+            generator,
+            per_request_body,
+            compress_body,
+            compress_each,
+            basic_auth: config.basic_auth.clone(),
+            method: config.method.clone(),
+            timeout: config.timeout,
+            cache_bust: config.cache_bust.then(|| XorShift::new(0).next()),
+            templated,
+            feed,
+            builtins: Builtins::new(),
+            schedule: arrival_schedule(config),
+            burst: config.burst,
+            state,
+        }
+    }
+
+    /// Queue up to `jobs` requests on the pool until the test is stopped, returning
+    /// how many were queued
+    pub async fn run(
+        mut self,
+        pool: Arc<WorkerPool>,
+        jobs: usize,
+        start_time: Instant,
+        is_running: Arc<AtomicBool>,
+    ) -> usize {
+        let mut submitted = 0;
+
+        // Submit jobs in batches to avoid memory issues
+        'batches: for _ in 0..jobs.div_ceil(BATCH_SIZE) {
+            if !is_running.load(Ordering::SeqCst) {
+                break; // Stop if test is cancelled
+            }
+
+            for _ in 0..BATCH_SIZE.min(jobs - submitted) {
+                // Staged and burst runs release each job at its scheduled time
+                if let Some(schedule) = &mut self.schedule {
+                    let Some(at) = schedule.next_send() else {
+                        break 'batches;
+                    };
+                    if !wait_until(start_time + at, &is_running).await {
+                        break 'batches;
+                    }
+                }
+
+                self.mark_burst(submitted);
+                let job = self.next_job(submitted, start_time);
+
+                // Use async submission to properly backpressure
+                pool.submit_job(job).await;
+                submitted += 1;
+            }
+
+            // Let other tasks run
+            tokio::task::yield_now().await;
+        }
+
+        submitted
+    }
+
+    /// Note each burst as it starts so the charts can mark it
+    fn mark_burst(&self, submitted: usize) {
+        if let (Some(burst), Some(state)) = (self.burst, &self.state) {
+            if submitted % burst.size == 0 {
+                let mut state = state.state.lock().unwrap();
+                let elapsed = state.elapsed_secs();
+                state.bursts.push(elapsed);
+            }
+        }
+    }
+
+    /// Build the job with sequence number `submitted`
+    fn next_job(&mut self, submitted: usize, start_time: Instant) -> RequestJob {
+        let target = self
+            .picker
+            .as_mut()
+            .map_or(PRIMARY_TARGET, UrlPicker::next_index);
+        let mut url = self.job_urls[target].clone();
+        let mut headers = self.headers.clone();
+        if !self.user_agents.is_empty() {
+            let agent = &self.user_agents[submitted % self.user_agents.len()];
+            headers.push(("User-Agent".to_string(), agent.clone()));
+        }
+        let per_request_body = self.per_request_body;
+        let mut body = match &mut self.generator {
+            Some(generator) if per_request_body => Some(generator.next_body()),
+            _ => self.body.clone(),
+        };
+
+        // Fill the next data row and built-in values into the request; a URL they
+        // break is sent as is
+        if self.templated {
+            let row = self.feed.as_mut().map(FeedCursor::next_row);
+            let builtins = &mut self.builtins;
+            let mut fill = |text: &str| {
+                expand(text, |name| {
+                    row.as_ref()
+                        .and_then(|row| row.get(name))
+                        .map(str::to_string)
+                        .or_else(|| builtins.resolve(name, submitted))
+                })
+            };
+            if let Ok(filled) = Url::parse(&fill(&self.url_templates[target])) {
+                url = filled;
+            }
+            for (_, value) in &mut headers {
+                *value = fill(value);
+            }
+            // Binary bodies go out untouched
+            body = body.map(|body| match String::from_utf8(body) {
+                Ok(text) => fill(&text).into_bytes(),
+                Err(binary) => binary.into_bytes(),
+            });
+        }
+        if let (true, Some(encoding)) = (self.compress_each, self.compress_body) {
+            body = body.map(|body| encoding.compress(&body));
+        }
+        if let Some(run) = self.cache_bust {
+            url = cache_busted_url(&url, run, submitted);
+        }
+
+        RequestJob {
+            url,
+            target,
+            headers,
+            body,
+            basic_auth: self.basic_auth.clone(),
+            method: self.method.clone(),
+            timeout: self.timeout,
+            start_time,
+        }
+    }
+}
+
+/// A copy of `url` with a query parameter unique to the run and request, so no
+/// cache can serve it
+pub fn cache_busted_url(url: &Url, run: u64, key: usize) -> Url {
+    let mut busted = url.clone();
+    busted
+        .query_pairs_mut()
+        .append_pair(CACHE_BUST_PARAM, &format!("{run:x}-{key}"));
+    busted
+}
+
+/// Sleep until `deadline`, returning false early if the test is stopped meanwhile
+async fn wait_until(deadline: Instant, is_running: &AtomicBool) -> bool {
+    while is_running.load(Ordering::SeqCst) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return true;
+        }
+        tokio::time::sleep(remaining.min(Duration::from_millis(100))).await;
+    }
+    false
+}
+
+/// The schedule deciding when jobs are released, if the test has one; without
+/// one, jobs are queued as fast as workers take them
+fn arrival_schedule(config: &TestConfig) -> Option<Box<dyn ArrivalSchedule>> {
+    if let Some(burst) = config.burst {
+        return Some(Box::new(BurstSchedule::new(burst)));
+    }
+    if let Some(pattern) = config.pattern {
+        return Some(Box::new(PatternSchedule::new(pattern)));
+    }
+    if !config.stages.is_empty() {
+        return Some(Box::new(StageSchedule::new(config.stages.clone())));
+    }
+    None
+}
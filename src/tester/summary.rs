@@ -0,0 +1,207 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use hdrhistogram::Histogram;
+use serde::{Deserialize, Serialize};
+
+use super::scenario::StepStats;
+use super::skew::ClockSkew;
+use super::sse::SseStats;
+use super::types::{TargetStats, TestState};
+
+/// Latency percentiles of a finished test, in milliseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub min: f64,
+    pub average: f64,
+    pub p10: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p90: f64,
+    pub p95: f64,
+    pub p99: f64,
+    pub max: f64,
+}
+
+impl LatencySummary {
+    pub fn from_state(state: &TestState) -> Self {
+        let histogram = &state.latency_histogram;
+        LatencySummary {
+            min: if state.min_latency == f64::MAX {
+                0.0
+            } else {
+                state.min_latency
+            },
+            average: if histogram.is_empty() {
+                0.0
+            } else {
+                histogram.mean() / 1000.0
+            },
+            p10: state.latency_at_quantile(0.10),
+            p25: state.latency_at_quantile(0.25),
+            p50: state.latency_at_quantile(0.50),
+            p75: state.latency_at_quantile(0.75),
+            p90: state.latency_at_quantile(0.90),
+            p95: state.latency_at_quantile(0.95),
+            p99: state.latency_at_quantile(0.99),
+            max: state.max_latency,
+        }
+    }
+
+    /// The percentiles the text report lists, as (percentile, milliseconds)
+    pub fn distribution(&self) -> [(u32, f64); 7] {
+        [
+            (10, self.p10),
+            (25, self.p25),
+            (50, self.p50),
+            (75, self.p75),
+            (90, self.p90),
+            (95, self.p95),
+            (99, self.p99),
+        ]
+    }
+}
+
+/// Count, average, extremes and percentiles of a request phase, in milliseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PhaseSummary {
+    pub count: u64,
+    pub average: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+}
+
+impl PhaseSummary {
+    /// Summarize samples stored in µs; `None` when nothing was timed
+    pub fn from_histogram(samples: &Histogram<u64>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let ms = |micros: u64| micros as f64 / 1000.0;
+        Some(PhaseSummary {
+            count: samples.len(),
+            average: samples.mean() / 1000.0,
+            min: ms(samples.min()),
+            max: ms(samples.max()),
+            p50: ms(samples.value_at_quantile(0.50)),
+            p90: ms(samples.value_at_quantile(0.90)),
+            p99: ms(samples.value_at_quantile(0.99)),
+        })
+    }
+}
+
+/// Server clock skew estimated from `Date` headers, in milliseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SkewSummary {
+    pub initial: f64,
+    #[serde(rename = "final")]
+    pub last: f64,
+    pub min: f64,
+    pub max: f64,
+    pub drift: f64,
+}
+
+impl SkewSummary {
+    /// `None` when no response carried a `Date` header
+    pub fn from_skew(skew: &ClockSkew) -> Option<Self> {
+        (skew.samples > 0).then(|| SkewSummary {
+            initial: skew.first_ms,
+            last: skew.last_ms,
+            min: skew.min_ms,
+            max: skew.max_ms,
+            drift: skew.drift_ms(),
+        })
+    }
+}
+
+/// Streams and events of a Server-Sent Events run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SseSummary {
+    pub streams: usize,
+    pub events: u64,
+    pub events_per_stream: f64,
+    pub min_events: u64,
+    pub max_events: u64,
+    /// Time from sending the request to the first complete event
+    pub first_event_ms: Option<PhaseSummary>,
+}
+
+impl SseSummary {
+    /// `None` unless the run opened event streams
+    pub fn from_stats(sse: &SseStats) -> Option<Self> {
+        (sse.connections > 0).then(|| SseSummary {
+            streams: sse.connections,
+            events: sse.events,
+            events_per_stream: sse.events_per_connection(),
+            min_events: sse.min_events,
+            max_events: sse.max_events,
+            first_event_ms: PhaseSummary::from_histogram(&sse.first_event),
+        })
+    }
+}
+
+/// Requests, errors and latency of one scenario step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepSummary {
+    pub name: String,
+    pub errors: usize,
+    pub latency_ms: Option<PhaseSummary>,
+}
+
+impl StepSummary {
+    pub fn from_stats(step: &StepStats) -> Self {
+        StepSummary {
+            name: step.name.clone(),
+            errors: step.error_count,
+            latency_ms: PhaseSummary::from_histogram(&step.latency.samples),
+        }
+    }
+
+    /// Requests the step completed
+    pub fn requests(&self) -> u64 {
+        self.latency_ms.map_or(0, |latency| latency.count)
+    }
+}
+
+/// Requests, errors and average latency of one target URL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetSummary {
+    pub url: String,
+    pub completed_requests: usize,
+    pub error_count: usize,
+    pub average_latency_ms: f64,
+}
+
+impl TargetSummary {
+    pub fn from_stats(target: &TargetStats) -> Self {
+        TargetSummary {
+            url: target.url.clone(),
+            completed_requests: target.completed_requests,
+            error_count: target.error_count,
+            average_latency_ms: target.average_latency(),
+        }
+    }
+}
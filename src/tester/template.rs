@@ -0,0 +1,99 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use chrono::{SecondsFormat, Utc};
+
+use super::stagger::XorShift;
+
+/// Replace each `${name}` with what `resolve` returns for it; names it doesn't
+/// know are left as written
+pub fn expand(template: &str, mut resolve: impl FnMut(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        match resolve(&rest[start + 2..start + end]) {
+            Some(value) => output.push_str(&value),
+            None => output.push_str(&rest[start..start + end + 1]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Values for the built-in placeholders: `${UUID}`, `${SEQ}`, `${NOW_ISO}` and
+/// `${RAND_INT(min,max)}`, fresh for every request
+pub struct Builtins {
+    rng: XorShift,
+}
+
+impl Default for Builtins {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builtins {
+    pub fn new() -> Self {
+        Builtins {
+            rng: XorShift::new(0),
+        }
+    }
+
+    /// The value of a built-in placeholder for request number `seq`, counting from zero
+    pub fn resolve(&mut self, name: &str, seq: usize) -> Option<String> {
+        match name {
+            "UUID" => Some(self.uuid()),
+            "SEQ" => Some(seq.to_string()),
+            "NOW_ISO" => Some(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+            _ => {
+                let range = name.strip_prefix("RAND_INT(")?.strip_suffix(')')?;
+                let (min, max) = range.split_once(',')?;
+                let min: i64 = min.trim().parse().ok()?;
+                let max: i64 = max.trim().parse().ok()?;
+                if min > max {
+                    return None;
+                }
+                let span = (max as i128 - min as i128 + 1) as u128;
+                Some((min as i128 + (self.rng.next() as u128 % span) as i128).to_string())
+            }
+        }
+    }
+
+    /// A random version 4 UUID
+    fn uuid(&mut self) -> String {
+        let high = (self.rng.next() & !0xF000) | 0x4000;
+        let low = (self.rng.next() & !(0b11 << 62)) | (0b10 << 62);
+        format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xFFFF,
+            high & 0xFFFF,
+            low >> 48,
+            low & 0xFFFF_FFFF_FFFF
+        )
+    }
+}
@@ -0,0 +1,166 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use super::certificate::CertificateInfo;
+use super::downsample::REPORT_SERIES_LEN;
+use super::report::print_hey_format_report;
+use super::summary::{
+    LatencySummary, PhaseSummary, SkewSummary, SseSummary, StepSummary, TargetSummary,
+};
+use super::types::{EndReason, HttpMethod, StopReason, TestEvent, TestState};
+
+/// The results of a finished test: what every output format reports, and what
+/// programs embedding whambam as a library get back
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestReport {
+    pub url: String,
+    pub method: HttpMethod,
+    pub elapsed_secs: f64,
+    /// The `-n` limit the test ran with (0 for none)
+    pub target_requests: usize,
+    /// The `-z` limit the test ran with, in seconds (0 for none)
+    pub target_duration_secs: u64,
+    pub completed_requests: usize,
+    pub error_count: usize,
+    pub requests_per_sec: f64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub latency_ms: LatencySummary,
+    pub status_counts: HashMap<u16, usize>,
+    /// gRPC status codes, for gRPC runs
+    pub grpc_status_counts: HashMap<u16, usize>,
+    /// Responses by the HTTP version they came back over
+    pub protocol_counts: BTreeMap<String, usize>,
+    pub assertion_failures: usize,
+    pub checksum_mismatches: usize,
+    pub connect_timeouts: usize,
+    pub dns_lookup_ms: Option<PhaseSummary>,
+    pub connect_ms: Option<PhaseSummary>,
+    pub tls_handshake_ms: Option<PhaseSummary>,
+    pub ttfb_ms: Option<PhaseSummary>,
+    pub body_read_ms: Option<PhaseSummary>,
+    /// Server certificate chain of an HTTPS run, leaf first
+    pub certificates: Vec<CertificateInfo>,
+    pub clock_skew_ms: Option<SkewSummary>,
+    pub sse: Option<SseSummary>,
+    pub steps: Vec<StepSummary>,
+    pub targets: Vec<TargetSummary>,
+    pub events: Vec<TestEvent>,
+    /// Requests per second over the test, as (seconds since start, value) points,
+    /// downsampled to at most `REPORT_SERIES_LEN` of them
+    pub throughput_series: Vec<(f64, f64)>,
+    /// Latency in milliseconds over the test, as (seconds since start, value) points,
+    /// downsampled to at most `REPORT_SERIES_LEN` of them
+    pub latency_series: Vec<(f64, f64)>,
+    /// Why the test stopped early, if it did
+    pub stop_reason: Option<StopReason>,
+    /// Which limit ended a test that ran its course
+    pub end_reason: Option<EndReason>,
+}
+
+impl TestReport {
+    /// Summarize a finished test
+    pub fn from_state(state: &TestState) -> Self {
+        let elapsed = state.elapsed_secs();
+
+        TestReport {
+            url: state.url.clone(),
+            method: state.method.clone(),
+            elapsed_secs: elapsed,
+            target_requests: state.target_requests,
+            target_duration_secs: state.duration,
+            completed_requests: state.completed_requests,
+            error_count: state.error_count,
+            requests_per_sec: if elapsed > 0.0 {
+                state.completed_requests as f64 / elapsed
+            } else {
+                0.0
+            },
+            bytes_sent: state.total_bytes_sent,
+            bytes_received: state.total_bytes_received,
+            latency_ms: LatencySummary::from_state(state),
+            status_counts: state.status_counts.clone(),
+            grpc_status_counts: state.grpc_status_counts.clone(),
+            protocol_counts: state
+                .protocol_counts
+                .iter()
+                .map(|(protocol, count)| (protocol.to_string(), *count))
+                .collect(),
+            assertion_failures: state.assertion_failures,
+            checksum_mismatches: state.checksum_mismatches,
+            connect_timeouts: state.connect_timeouts,
+            dns_lookup_ms: PhaseSummary::from_histogram(&state.dns_lookup.samples),
+            connect_ms: PhaseSummary::from_histogram(&state.connect.samples),
+            tls_handshake_ms: PhaseSummary::from_histogram(&state.tls_handshake.samples),
+            ttfb_ms: PhaseSummary::from_histogram(&state.ttfb.samples),
+            body_read_ms: PhaseSummary::from_histogram(&state.body_read.samples),
+            certificates: state.certificates.clone(),
+            clock_skew_ms: SkewSummary::from_skew(&state.clock_skew),
+            sse: SseSummary::from_stats(&state.sse),
+            steps: state.steps.iter().map(StepSummary::from_stats).collect(),
+            targets: state
+                .targets
+                .iter()
+                .map(TargetSummary::from_stats)
+                .collect(),
+            events: state.events.clone(),
+            throughput_series: state.throughput_history.downsampled(REPORT_SERIES_LEN),
+            latency_series: state.latency_history.downsampled(REPORT_SERIES_LEN),
+            stop_reason: state.stop_reason.clone(),
+            end_reason: state.end_reason,
+        }
+    }
+
+    /// The timed request phases, named as the text report shows them
+    pub fn phases(&self) -> [(&'static str, Option<&PhaseSummary>); 5] {
+        [
+            ("DNS-lookup", self.dns_lookup_ms.as_ref()),
+            ("Connect", self.connect_ms.as_ref()),
+            ("TLS handshake", self.tls_handshake_ms.as_ref()),
+            ("Resp wait", self.ttfb_ms.as_ref()),
+            ("Resp read", self.body_read_ms.as_ref()),
+        ]
+    }
+}
+
+/// Save the results so far to `whambam-<timestamp>.json` and a matching `.txt`
+/// text report in `dir`, returning the path without its extension
+pub fn save_snapshot(state: &TestState, dir: &Path) -> io::Result<PathBuf> {
+    let stem = dir.join(format!("whambam-{}", Local::now().format("%Y%m%d-%H%M%S")));
+
+    let mut json = BufWriter::new(File::create(stem.with_extension("json"))?);
+    serde_json::to_writer_pretty(&mut json, &TestReport::from_state(state))?;
+    json.flush()?;
+
+    let mut text = BufWriter::new(File::create(stem.with_extension("txt"))?);
+    print_hey_format_report(&mut text, state)?;
+    text.flush()?;
+
+    Ok(stem)
+}
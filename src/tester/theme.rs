@@ -0,0 +1,74 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+
+/// Colour scheme of the interactive UI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Theme {
+    /// The terminal's standard colours
+    #[default]
+    Default,
+    /// Brighter colours for dark backgrounds
+    Dark,
+    /// Darker colours and black text for light backgrounds
+    Light,
+    /// No colour at all, only bold and reversed text
+    Monochrome,
+}
+
+impl Theme {
+    /// Parse "default", "dark", "light" or "monochrome"
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "default" => Ok(Theme::Default),
+            "dark" => Ok(Theme::Dark),
+            "light" => Ok(Theme::Light),
+            "monochrome" | "mono" | "none" => Ok(Theme::Monochrome),
+            _ => Err(anyhow!(
+                "Invalid theme: '{name}'. Expected default, dark, light or monochrome"
+            )),
+        }
+    }
+
+    /// The theme to draw with, which is monochrome whenever the `NO_COLOR`
+    /// environment variable is set (see https://no-color.org)
+    pub fn effective(self) -> Self {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        if no_color {
+            Theme::Monochrome
+        } else {
+            self
+        }
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Theme::Default => write!(f, "default"),
+            Theme::Dark => write!(f, "dark"),
+            Theme::Light => write!(f, "light"),
+            Theme::Monochrome => write!(f, "monochrome"),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+use rustls::client::{Resumption, ServerCertVerified, ServerCertVerifier};
+use rustls::{
+    Certificate, ClientConfig, DigitallySignedStruct, OwnedTrustAnchor, PrivateKey, RootCertStore,
+    ServerName, SupportedProtocolVersion,
+};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+use url::Url;
+
+use super::connection::{HandshakeKeyLog, TimedSessionStore};
+use super::types::TestConfig;
+
+/// A TLS protocol version the client can be pinned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TlsVersion {
+    Tls12,
+    Tls13,
+}
+
+impl TlsVersion {
+    /// Parse "1.2" or "1.3", optionally prefixed with "TLS"
+    pub fn parse(version: &str) -> Result<Self> {
+        let trimmed = version.trim();
+        let number = trimmed
+            .strip_prefix("TLS")
+            .or_else(|| trimmed.strip_prefix("tls"))
+            .unwrap_or(trimmed)
+            .trim_start_matches(['v', ' ']);
+        match number {
+            "1.2" => Ok(TlsVersion::Tls12),
+            "1.3" => Ok(TlsVersion::Tls13),
+            _ => Err(anyhow!(
+                "Invalid TLS version: '{version}'. Expected 1.2 or 1.3"
+            )),
+        }
+    }
+
+    /// The matching rustls version
+    pub fn to_rustls(self) -> &'static SupportedProtocolVersion {
+        match self {
+            TlsVersion::Tls12 => &rustls::version::TLS12,
+            TlsVersion::Tls13 => &rustls::version::TLS13,
+        }
+    }
+}
+
+impl std::fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlsVersion::Tls12 => write!(f, "TLS 1.2"),
+            TlsVersion::Tls13 => write!(f, "TLS 1.3"),
+        }
+    }
+}
+
+/// Parse a `--sni` value, which must be a DNS name rather than an IP address
+pub fn parse_sni(name: &str) -> Result<String> {
+    match ServerName::try_from(name) {
+        Ok(ServerName::DnsName(_)) => Ok(name.to_ascii_lowercase()),
+        _ => Err(anyhow!("Invalid SNI name: '{name}'. Expected a DNS name")),
+    }
+}
+
+/// Swap the URL's host for the `--sni` name, returning the addresses to keep connecting to
+///
+/// `resolved` comes from `--resolve` and saves looking the original host up.
+pub async fn pin_sni(
+    url: &Url,
+    name: &str,
+    resolved: Option<Vec<SocketAddr>>,
+) -> Result<(Url, Vec<SocketAddr>)> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("URL has no host: {url}"))?
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addrs = match resolved {
+        Some(addrs) => addrs,
+        None => tokio::net::lookup_host((host, port))
+            .await
+            .with_context(|| format!("Failed to resolve {host}"))?
+            .collect(),
+    };
+
+    let mut pinned = url.clone();
+    pinned
+        .set_host(Some(name))
+        .with_context(|| format!("Invalid SNI name: '{name}'"))?;
+    Ok((pinned, addrs))
+}
+
+/// Build the rustls configuration handed to reqwest
+///
+/// reqwest's own TLS options cannot be combined with the timing hooks, so the
+/// trust, version and client certificate settings are applied here instead.
+pub fn client_config(config: &TestConfig) -> Result<ClientConfig> {
+    let versions: Vec<_> = [TlsVersion::Tls12, TlsVersion::Tls13]
+        .into_iter()
+        .filter(|v| config.tls_min.is_none_or(|min| *v >= min))
+        .filter(|v| config.tls_max.is_none_or(|max| *v <= max))
+        .map(TlsVersion::to_rustls)
+        .collect();
+
+    let builder = ClientConfig::builder()
+        .with_safe_default_cipher_suites()
+        .with_safe_default_kx_groups()
+        .with_protocol_versions(&versions)
+        .context("Unsupported TLS version range")?
+        .with_root_certificates(root_store(config)?);
+
+    // Present a client certificate for mutual TLS
+    let mut tls = match &config.client_cert {
+        Some((cert_path, key_path)) => builder
+            .with_client_auth_cert(load_certs(cert_path)?, load_key(key_path)?)
+            .context("Invalid client certificate or key; both must be PEM")?,
+        None => builder.with_no_client_auth(),
+    };
+
+    if config.insecure {
+        tls.dangerous()
+            .set_certificate_verifier(Arc::new(AcceptAnyCertificate));
+    }
+
+    tls.alpn_protocols = if config.http3 {
+        vec![b"h3".to_vec()]
+    } else if config.http2_prior_knowledge {
+        vec![b"h2".to_vec()]
+    } else {
+        vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+    };
+    tls.resumption = Resumption::store(Arc::new(TimedSessionStore::new()));
+    tls.key_log = Arc::new(match &config.tls_keylog {
+        Some(path) => HandshakeKeyLog::to_file(path)?,
+        None => HandshakeKeyLog::default(),
+    });
+    Ok(tls)
+}
+
+/// The built-in web roots plus any CAs from `--cacert`
+fn root_store(config: &TestConfig) -> Result<RootCertStore> {
+    let mut roots = RootCertStore::empty();
+    roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+        OwnedTrustAnchor::from_subject_spki_name_constraints(
+            anchor.subject,
+            anchor.spki,
+            anchor.name_constraints,
+        )
+    }));
+
+    if let Some(path) = &config.ca_cert {
+        for cert in load_certs(path)? {
+            roots.add(&cert).with_context(|| {
+                format!(
+                    "Invalid certificate in CA certificate file {}",
+                    path.display()
+                )
+            })?;
+        }
+    }
+    Ok(roots)
+}
+
+fn load_certs(path: &Path) -> Result<Vec<Certificate>> {
+    let pem = std::fs::read(path)
+        .with_context(|| format!("Failed to read certificate file {}", path.display()))?;
+    let certs = rustls_pemfile::certs(&mut pem.as_slice())
+        .with_context(|| format!("Invalid PEM in certificate file {}", path.display()))?;
+    if certs.is_empty() {
+        return Err(anyhow!("No certificates found in {}", path.display()));
+    }
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn load_key(path: &Path) -> Result<PrivateKey> {
+    let pem = std::fs::read(path)
+        .with_context(|| format!("Failed to read client key {}", path.display()))?;
+    let mut reader = pem.as_slice();
+    while let Some(item) = rustls_pemfile::read_one(&mut reader)
+        .with_context(|| format!("Invalid PEM in client key {}", path.display()))?
+    {
+        match item {
+            rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::ECKey(key) => return Ok(PrivateKey(key)),
+            _ => {}
+        }
+    }
+    Err(anyhow!("No private key found in {}", path.display()))
+}
+
+/// Verifier behind `--insecure`: any certificate, any name, any signature
+struct AcceptAnyCertificate;
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &rustls::ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _message: &[u8],
+        _cert: &Certificate,
+        _dss: &DigitallySignedStruct,
+    ) -> Result<rustls::client::HandshakeSignatureValid, rustls::Error> {
+        Ok(rustls::client::HandshakeSignatureValid::assertion())
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        _message: &[u8],
+        _cert: &Certificate,
+        _dss: &DigitallySignedStruct,
+    ) -> Result<rustls::client::HandshakeSignatureValid, rustls::Error> {
+        Ok(rustls::client::HandshakeSignatureValid::assertion())
+    }
+}
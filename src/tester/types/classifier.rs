@@ -0,0 +1,61 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Whether a status code counts as success: any 2xx unless an explicit list is configured
+pub fn is_success_status(status: u16, success_statuses: &[u16]) -> bool {
+    if success_statuses.is_empty() {
+        status / 100 == 2
+    } else {
+        success_statuses.contains(&status)
+    }
+}
+
+/// Rules deciding whether a response counts as a success
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResponseClassifier {
+    /// Status codes that count as success (empty means any 2xx)
+    pub success_statuses: Vec<u16>,
+
+    /// Responses slower than this many milliseconds count as failures
+    pub max_latency_ms: Option<f64>,
+
+    /// Text the response body must contain
+    pub body_contains: Option<String>,
+}
+
+impl ResponseClassifier {
+    /// Whether the status code alone counts as success
+    pub fn is_success_status(&self, status: u16) -> bool {
+        is_success_status(status, &self.success_statuses)
+    }
+
+    /// Whether a response counts as a success under every configured rule
+    pub fn is_success(&self, status: u16, latency_ms: f64, body: &[u8]) -> bool {
+        let fast_enough = self.max_latency_ms.is_none_or(|limit| latency_ms <= limit);
+        let body_matches = self
+            .body_contains
+            .as_ref()
+            .is_none_or(|text| String::from_utf8_lossy(body).contains(text.as_str()));
+
+        self.is_success_status(status) && fast_enough && body_matches
+    }
+}
@@ -0,0 +1,274 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::classifier::ResponseClassifier;
+use super::http::HttpMethod;
+use crate::tester::assertions::{BodyChecksum, JsonAssertion};
+use crate::tester::breaker::CircuitBreaker;
+use crate::tester::compress::BodyEncoding;
+use crate::tester::export::OutputSink;
+use crate::tester::extract::Extraction;
+use crate::tester::feeder::DataFeed;
+use crate::tester::grpc::GrpcCall;
+use crate::tester::oauth::OAuthClient;
+use crate::tester::random_body::RandomBody;
+use crate::tester::resolve::HostOverride;
+use crate::tester::scenario::ScenarioStep;
+use crate::tester::schedule::{Burst, LoadPattern, LoadStage};
+use crate::tester::sse::SseOptions;
+use crate::tester::stagger::{Stagger, ThinkTime};
+use crate::tester::theme::Theme;
+use crate::tester::tls::TlsVersion;
+use crate::tester::url_mix::{UrlMix, UrlSelection};
+
+/// User-Agent sent unless another is configured, so server logs can pick out load-test traffic
+pub const DEFAULT_USER_AGENT: &str = concat!("whambam/", env!("CARGO_PKG_VERSION"));
+
+/// Configuration for the throughput test
+#[derive(Clone)]
+pub struct TestConfig {
+    /// URL to test
+    pub url: String,
+
+    /// HTTP method to use
+    pub method: HttpMethod,
+
+    /// Number of requests to send (0 for unlimited)
+    pub requests: usize,
+
+    /// Number of concurrent connections
+    pub concurrent: usize,
+
+    /// Duration of the test in seconds (0 for unlimited)
+    pub duration: u64,
+
+    /// Rate limit in queries per second (QPS) per worker (0 for no limit)
+    pub rate_limit: f64,
+
+    /// Arrival-rate stages run in order; empty for a closed-loop test
+    pub stages: Vec<LoadStage>,
+
+    /// Fire groups of requests together at a fixed interval
+    pub burst: Option<Burst>,
+
+    /// Arrival rate that varies over the run, such as a sine wave
+    pub pattern: Option<LoadPattern>,
+
+    /// How long requests still in flight at the end get to finish
+    pub drain_timeout: Duration,
+
+    /// How often text mode prints a progress line
+    pub progress_interval: Duration,
+
+    /// Custom HTTP headers to include with each request
+    pub headers: Vec<(String, String)>,
+
+    /// User-Agent header value; a User-Agent in `headers` takes precedence
+    pub user_agent: String,
+
+    /// User-Agent values to rotate through, one request after another, in place of `user_agent`
+    pub user_agents: Vec<String>,
+
+    /// Timeout for each request in seconds (0 for no timeout)
+    pub timeout: u64,
+
+    /// Request body bytes, sent as is
+    pub body: Option<Vec<u8>>,
+
+    /// Random bytes to send in place of `body`
+    pub random_body: Option<RandomBody>,
+
+    /// Compress the body this way and send it with a matching Content-Encoding
+    pub compress_body: Option<BodyEncoding>,
+
+    /// Content-Type header value
+    #[allow(dead_code)]
+    pub content_type: String,
+
+    /// Basic authentication in (username, password) format
+    pub basic_auth: Option<(String, String)>,
+
+    /// Fetch a token before the test and send it as a Bearer Authorization header
+    pub oauth: Option<OAuthClient>,
+
+    /// Proxy URL; a bare host:port means an HTTP proxy
+    pub proxy: Option<String>,
+
+    /// Headers for the proxy; only Proxy-Authorization reaches it for https:// targets
+    pub proxy_headers: Vec<(String, String)>,
+
+    /// Accept any TLS certificate, including self-signed and expired ones
+    pub insecure: bool,
+
+    /// PEM bundle of extra CA certificates to trust
+    pub ca_cert: Option<PathBuf>,
+
+    /// PEM client certificate and private key presented for mutual TLS
+    pub client_cert: Option<(PathBuf, PathBuf)>,
+
+    /// Oldest and newest TLS versions the client will negotiate
+    pub tls_min: Option<TlsVersion>,
+    pub tls_max: Option<TlsVersion>,
+
+    /// Server name presented instead of the URL's host, which is still where we connect
+    pub sni: Option<String>,
+
+    /// Fixed addresses for host and port pairs, bypassing DNS
+    pub resolve: Vec<HostOverride>,
+
+    /// DNS server to query instead of the system resolver
+    pub dns_server: Option<SocketAddr>,
+
+    /// Reuse each resolution for this long instead of looking up per connection
+    pub dns_cache_ttl: Option<Duration>,
+
+    /// Open a connection, and so do a lookup, for every request
+    pub no_dns_cache: bool,
+
+    /// Time allowed to open a connection, TLS handshake included, separate from `timeout`
+    pub connect_timeout: Option<Duration>,
+
+    /// Bytes of each response body to read before abandoning the download
+    pub max_body_bytes: Option<usize>,
+
+    /// Source IPs requests are bound to, shared round-robin between workers
+    pub local_address: Vec<IpAddr>,
+
+    /// SSLKEYLOGFILE-style file that TLS secrets are appended to
+    pub tls_keylog: Option<PathBuf>,
+
+    /// Whether to disable compression
+    pub disable_compression: bool,
+
+    /// Whether to disable keep-alive (prevent TCP connection reuse)
+    pub disable_keepalive: bool,
+
+    /// Whether to disable following redirects
+    pub disable_redirects: bool,
+
+    /// Keep the cookies servers set, in a separate jar for each worker
+    pub cookies: bool,
+
+    /// Give each worker its own connection pool rather than sharing one
+    pub isolate_connections: bool,
+
+    /// Speak HTTP/2 without negotiation, for cleartext (h2c) backends
+    pub http2_prior_knowledge: bool,
+
+    /// Speak HTTP/3 over QUIC (experimental, needs the `http3` feature)
+    pub http3: bool,
+
+    /// Send this unary gRPC call instead of plain HTTP requests
+    pub grpc: Option<GrpcCall>,
+
+    /// Hold each request open as a Server-Sent Events stream
+    pub sse: Option<SseOptions>,
+
+    /// Whether to use interactive UI
+    #[allow(dead_code)]
+    pub interactive: bool,
+
+    /// Colour scheme of the interactive UI
+    pub theme: Theme,
+
+    /// Deprecated output format field
+    #[deprecated]
+    #[allow(dead_code)]
+    pub output_format: String,
+
+    /// Abort the test when windowed p99 latency stays above a limit
+    pub circuit_breaker: Option<CircuitBreaker>,
+
+    /// Abort the test once this many requests have failed
+    pub abort_after_errors: Option<u64>,
+
+    /// Rules deciding whether a response counts as a success
+    pub classifier: ResponseClassifier,
+
+    /// URL to switch to after sustained connection errors on the primary URL
+    pub fallback_url: Option<String>,
+
+    /// Consecutive connection errors on the primary URL before failing over
+    pub failover_after: usize,
+
+    /// Add a unique query parameter to every request so caches always miss
+    pub cache_bust: bool,
+
+    /// Checks evaluated against every JSON response body
+    pub json_assertions: Vec<JsonAssertion>,
+
+    /// SHA-256 digest every response body must have
+    pub expected_body_sha256: Option<BodyChecksum>,
+
+    /// Files to write results to while the run is in progress
+    pub outputs: Vec<OutputSink>,
+
+    /// Directory the first failed responses are saved to, headers and body included
+    pub save_failures: Option<PathBuf>,
+
+    /// Failed responses to save before `save_failures` stops
+    pub save_failures_limit: usize,
+
+    /// Responses at the start of the run to write to `sample_file` (0 for none)
+    pub sample_responses: usize,
+
+    /// File the sampled responses are written to
+    pub sample_file: PathBuf,
+
+    /// Random worker start offset and per-request dispatch delay
+    pub stagger: Option<Stagger>,
+
+    /// Pause each worker takes between its requests, like a real user would
+    pub think_time: Option<ThinkTime>,
+
+    /// Requests each worker sends in order, over and over, instead of the single URL
+    pub scenario: Vec<ScenarioStep>,
+
+    /// Values each virtual user saves from step responses for its later requests
+    pub extractions: Vec<Extraction>,
+
+    /// CSV rows whose columns fill `${column}` in the URL, headers and body of each request
+    pub data_feed: Option<Arc<DataFeed>>,
+
+    /// URLs to spread requests across by weight; `url` is the first of them
+    pub url_mix: Option<UrlMix>,
+
+    /// How requests take turns across the URL mix
+    pub url_selection: UrlSelection,
+}
+
+impl TestConfig {
+    /// All URLs the test may send requests to, primary first
+    pub fn targets(&self) -> Vec<String> {
+        if let Some(mix) = &self.url_mix {
+            return mix.urls.iter().map(|url| url.url.clone()).collect();
+        }
+        std::iter::once(self.url.clone())
+            .chain(self.fallback_url.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,108 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use super::classifier::ResponseClassifier;
+use super::config::{TestConfig, DEFAULT_USER_AGENT};
+use super::http::HttpMethod;
+use crate::tester::theme::Theme;
+use crate::tester::url_mix::UrlSelection;
+
+impl Default for TestConfig {
+    /// Defaults matching the command-line defaults
+    #[allow(deprecated)]
+    fn default() -> Self {
+        TestConfig {
+            url: String::new(),
+            method: HttpMethod::GET,
+            requests: 200,
+            concurrent: 50,
+            duration: 0,
+            rate_limit: 0.0,
+            stages: Vec::new(),
+            burst: None,
+            pattern: None,
+            drain_timeout: Duration::from_secs(10),
+            progress_interval: Duration::from_secs(5),
+            headers: Vec::new(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            user_agents: Vec::new(),
+            timeout: 20,
+            body: None,
+            random_body: None,
+            compress_body: None,
+            content_type: "text/html".to_string(),
+            basic_auth: None,
+            oauth: None,
+            proxy: None,
+            proxy_headers: Vec::new(),
+            insecure: false,
+            ca_cert: None,
+            client_cert: None,
+            tls_min: None,
+            tls_max: None,
+            sni: None,
+            resolve: Vec::new(),
+            dns_server: None,
+            dns_cache_ttl: None,
+            no_dns_cache: false,
+            connect_timeout: None,
+            max_body_bytes: None,
+            local_address: Vec::new(),
+            tls_keylog: None,
+            disable_compression: false,
+            disable_keepalive: false,
+            disable_redirects: false,
+            cookies: false,
+            isolate_connections: false,
+            http2_prior_knowledge: false,
+            http3: false,
+            grpc: None,
+            sse: None,
+            interactive: true,
+            theme: Theme::Default,
+            output_format: String::new(),
+            circuit_breaker: None,
+            abort_after_errors: None,
+            classifier: ResponseClassifier::default(),
+            fallback_url: None,
+            failover_after: 10,
+            cache_bust: false,
+            json_assertions: Vec::new(),
+            expected_body_sha256: None,
+            outputs: Vec::new(),
+            save_failures: None,
+            save_failures_limit: 10,
+            sample_responses: 0,
+            sample_file: PathBuf::from("whambam-samples.txt"),
+            stagger: None,
+            think_time: None,
+            scenario: Vec::new(),
+            extractions: Vec::new(),
+            data_feed: None,
+            url_mix: None,
+            url_selection: UrlSelection::Random,
+        }
+    }
+}
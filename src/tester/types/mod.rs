@@ -0,0 +1,42 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! The configuration a test runs with, the state it builds up as results come
+//! in, and the per-request results themselves.
+
+mod classifier;
+mod config;
+mod defaults;
+mod http;
+mod reset;
+mod results;
+mod state;
+mod stats;
+mod update;
+
+pub use classifier::{is_success_status, ResponseClassifier};
+pub use config::{TestConfig, DEFAULT_USER_AGENT};
+pub use http::{HttpMethod, Protocol};
+pub use results::{
+    EndReason, Message, RequestError, RequestMetric, StopReason, TargetStats, TestEvent,
+};
+pub use state::{status_class, SharedState, TestState, REQUEST_LOG_LEN, STATUS_CLASSES};
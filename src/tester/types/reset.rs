@@ -0,0 +1,124 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use hdrhistogram::Histogram;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use super::results::TargetStats;
+use super::state::TestState;
+use crate::tester::connection::PhaseStats;
+use crate::tester::scenario::StepStats;
+use crate::tester::skew::ClockSkew;
+use crate::tester::sse::SseStats;
+
+impl TestState {
+    /// Clear the chart series so they start over, keeping cumulative stats
+    pub fn rebaseline_charts(&mut self) {
+        self.recent_latencies.clear();
+        self.recent_throughput.clear();
+        self.throughput_data.clear();
+        self.latency_data.clear();
+        self.throughput_history.clear();
+        self.latency_history.clear();
+        self.clear_interval_series();
+        self.current_throughput = 0.0;
+        self.record_event("Charts rebaselined");
+    }
+
+    /// Reset the state for a new test run
+    pub fn reset(&mut self) {
+        let now = Instant::now();
+        self.start_time = now;
+
+        // Reset counters
+        self.completed_requests = 0;
+        self.error_count = 0;
+        self.assertion_failures = 0;
+        self.checksum_mismatches = 0;
+        self.connect_timeouts = 0;
+        self.status_counts.clear();
+        self.grpc_status_counts.clear();
+        self.protocol_counts.clear();
+        self.clock_skew = ClockSkew::default();
+        self.sse = SseStats::default();
+        self.dns_lookup = PhaseStats::default();
+        self.connect = PhaseStats::default();
+        self.tls_handshake = PhaseStats::default();
+        self.ttfb = PhaseStats::default();
+        self.body_read = PhaseStats::default();
+        for target in &mut self.targets {
+            *target = TargetStats::new(target.url.clone());
+        }
+        for step in &mut self.steps {
+            *step = StepStats::new(step.name.clone());
+        }
+        self.events.clear();
+        self.bursts.clear();
+
+        // Reset data collections
+        self.request_log.clear();
+        self.recent_latencies.clear();
+        self.recent_throughput.clear();
+
+        // Reset histogram with higher precision (5 significant figures)
+        self.latency_histogram = Histogram::<u64>::new(5).unwrap();
+
+        // Reset chart data
+        self.throughput_data.clear();
+        self.latency_data.clear();
+        self.throughput_history.clear();
+        self.latency_history.clear();
+        self.clear_interval_series();
+
+        // Reset statistics
+        self.min_latency = f64::MAX;
+        self.max_latency = 0.0;
+        self.p50_latency = 0.0;
+        self.p90_latency = 0.0;
+        self.p95_latency = 0.0;
+        self.p99_latency = 0.0;
+        self.current_throughput = 0.0;
+
+        // Reset status
+        self.is_complete = false;
+        self.should_quit = false;
+        self.end_time = None;
+        self.paused.store(false, Ordering::SeqCst);
+
+        // Reset byte tracking
+        self.total_bytes_sent = 0;
+        self.total_bytes_received = 0;
+
+        self.stop_reason = None;
+        self.end_reason = None;
+    }
+
+    fn clear_interval_series(&mut self) {
+        self.interval_histogram.reset();
+        self.p50_history.clear();
+        self.p95_history.clear();
+        self.p99_history.clear();
+        self.status_classes.clear();
+        self.interval_status_classes = [0; 5];
+    }
+}
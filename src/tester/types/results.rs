@@ -0,0 +1,177 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::{Deserialize, Serialize};
+
+use super::http::Protocol;
+use crate::tester::health::HealthWindow;
+
+/// Why a test stopped before reaching its configured limits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopReason {
+    /// Windowed p99 latency stayed above the circuit breaker limit
+    LatencyBreaker { limit_ms: f64, seconds: u64 },
+    /// The number of failed requests reached `--abort-after-errors`
+    ErrorLimit { errors: u64 },
+    /// The user pressed Ctrl-C
+    Interrupted,
+    /// The process was sent SIGTERM
+    Terminated,
+}
+
+impl std::fmt::Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StopReason::LatencyBreaker { limit_ms, seconds } => write!(
+                f,
+                "p99 latency above {limit_ms} ms for {seconds} consecutive seconds"
+            ),
+            StopReason::ErrorLimit { errors } => {
+                write!(f, "error limit of {errors} failed requests reached")
+            }
+            StopReason::Interrupted => write!(f, "interrupted"),
+            StopReason::Terminated => write!(f, "terminated"),
+        }
+    }
+}
+
+/// Which configured limit ended a test that ran its course
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndReason {
+    /// All `-n` requests completed
+    RequestLimit(usize),
+    /// The `-z` duration ran out
+    DurationLimit(u64),
+}
+
+impl std::fmt::Display for EndReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EndReason::RequestLimit(requests) => write!(f, "all {requests} requests completed"),
+            EndReason::DurationLimit(secs) => write!(f, "{secs}s duration reached"),
+        }
+    }
+}
+
+/// Why a request failed, as `--log-errors` records it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestError {
+    /// URL that was requested, after any redirects
+    pub url: String,
+    /// Broad class of failure, such as "timeout", "connect" or "status"
+    pub kind: &'static str,
+    /// What went wrong, including every underlying cause
+    pub message: String,
+}
+
+/// Metrics for a single request
+#[derive(Debug, Clone, Default)]
+pub struct RequestMetric {
+    #[allow(dead_code)]
+    pub timestamp: f64,
+    pub latency_ms: f64,
+    pub status_code: u16,
+    pub is_error: bool,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    /// Index into `TestConfig::targets` of the URL that was requested
+    pub target: usize,
+    /// Whether the response failed a JSON assertion (independent of `is_error`)
+    pub assertion_failed: bool,
+    /// Whether the response body hash differed from the expected SHA-256
+    pub checksum_mismatch: bool,
+    /// Protocol the response arrived over; `None` when there was no response
+    pub protocol: Option<Protocol>,
+    /// Server clock minus client clock, estimated from the `Date` header
+    pub clock_skew_ms: Option<f64>,
+    /// gRPC status of a gRPC call that got a reply
+    pub grpc_status: Option<u16>,
+    /// Events received on an SSE stream that got a response
+    pub sse_events: Option<u64>,
+    /// Time from sending an SSE request to its first complete event
+    pub first_event_ms: Option<f64>,
+    /// Time spent resolving the host when this request opened a new connection
+    pub dns_lookup_ms: Option<f64>,
+    /// Time spent connecting when this request opened a new HTTPS connection
+    pub connect_ms: Option<f64>,
+    /// Time spent on the TLS handshake when this request opened a new connection
+    pub tls_handshake_ms: Option<f64>,
+    /// Time from a usable connection to the response headers
+    pub ttfb_ms: Option<f64>,
+    /// Time spent downloading the response body, which `latency_ms` leaves out
+    pub body_read_ms: Option<f64>,
+    /// Whether the request failed because its connection took too long to open
+    pub connect_timeout: bool,
+    /// Index into `TestConfig::scenario` of the step this request was for
+    pub step: Option<usize>,
+    /// Why a failed request failed, filled in when the run logs errors
+    pub error: Option<Box<RequestError>>,
+}
+
+/// Per-target counters, kept separately for each URL under test
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetStats {
+    pub url: String,
+    pub completed_requests: usize,
+    pub error_count: usize,
+    pub total_latency_ms: f64,
+    /// Per-second health over the last few seconds, for the health matrix
+    pub health: HealthWindow,
+}
+
+impl TargetStats {
+    pub fn new(url: String) -> Self {
+        TargetStats {
+            url,
+            completed_requests: 0,
+            error_count: 0,
+            total_latency_ms: 0.0,
+            health: HealthWindow::default(),
+        }
+    }
+
+    /// Average latency in milliseconds
+    pub fn average_latency(&self) -> f64 {
+        if self.completed_requests > 0 {
+            self.total_latency_ms / self.completed_requests as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Something notable that happened during a run, such as a failover
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestEvent {
+    /// Seconds since the start of the test
+    pub elapsed: f64,
+    pub message: String,
+}
+
+/// Messages sent between testing and UI threads
+#[allow(dead_code)]
+pub enum Message {
+    RequestComplete(Box<RequestMetric>),
+    TestComplete,
+}
@@ -0,0 +1,267 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use hdrhistogram::Histogram;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{atomic::AtomicBool, Arc, Mutex},
+    time::Instant,
+};
+
+use super::classifier::ResponseClassifier;
+use super::config::TestConfig;
+use super::http::{HttpMethod, Protocol};
+use super::results::{EndReason, RequestMetric, StopReason, TargetStats, TestEvent};
+use crate::tester::certificate::CertificateInfo;
+use crate::tester::connection::PhaseStats;
+use crate::tester::downsample::{SeriesHistory, StackedHistory};
+use crate::tester::grpc::GrpcCall;
+use crate::tester::pacer::LiveRate;
+use crate::tester::scenario::{group_steps, StepStats};
+use crate::tester::skew::ClockSkew;
+use crate::tester::sse::SseStats;
+
+/// How many of the latest requests `TestState::request_log` keeps
+pub const REQUEST_LOG_LEN: usize = 500;
+
+/// Names of the response classes `TestState::status_classes` splits throughput into
+pub const STATUS_CLASSES: [&str; 5] = ["2xx", "3xx", "4xx", "5xx", "Errors"];
+
+/// Index into `STATUS_CLASSES` of a status code, where 0 means no response
+pub fn status_class(status_code: u16) -> usize {
+    match status_code {
+        0 => 4,
+        1..=299 => 0,
+        300..=399 => 1,
+        400..=499 => 2,
+        _ => 3,
+    }
+}
+
+/// Test state and statistics
+#[derive(Debug)]
+pub struct TestState {
+    // Test configuration
+    pub url: String,
+    pub method: HttpMethod,
+    pub target_requests: usize,
+    pub concurrent_requests: usize,
+    pub duration: u64,
+    pub start_time: Instant,
+    pub headers: Vec<(String, String)>,
+    pub classifier: ResponseClassifier,
+    pub grpc: Option<GrpcCall>,
+
+    // Result counters
+    pub completed_requests: usize,
+    pub error_count: usize,
+    pub assertion_failures: usize,
+    pub checksum_mismatches: usize,
+    /// Requests that gave up opening a connection within `--connect-timeout`
+    pub connect_timeouts: usize,
+
+    // Status code counts
+    pub status_counts: HashMap<u16, usize>,
+    pub grpc_status_counts: HashMap<u16, usize>,
+    pub protocol_counts: HashMap<Protocol, usize>,
+    pub clock_skew: ClockSkew,
+    pub sse: SseStats,
+    pub dns_lookup: PhaseStats,
+    pub connect: PhaseStats,
+    pub tls_handshake: PhaseStats,
+    pub ttfb: PhaseStats,
+    pub body_read: PhaseStats,
+    /// Server certificate chain, leaf first, fetched once when an HTTPS run starts
+    pub certificates: Vec<CertificateInfo>,
+
+    // Per-target counters and notable events
+    pub targets: Vec<TargetStats>,
+    /// Per-step counters for a scenario run in step order; steps sharing a name share counters
+    pub steps: Vec<StepStats>,
+    /// Index into `steps` of each scenario step's counters
+    pub step_groups: Vec<usize>,
+    pub events: Vec<TestEvent>,
+    /// When each `--burst` was fired, in seconds since the start, for the charts
+    pub bursts: Vec<f64>,
+
+    // Recent metrics
+    pub request_log: VecDeque<RequestMetric>,
+    pub recent_latencies: VecDeque<f64>,
+    pub recent_throughput: VecDeque<(f64, f64)>, // (timestamp, requests/sec)
+
+    // Histograms
+    pub latency_histogram: Histogram<u64>,
+    /// Latencies since the last chart point, for the windowed percentiles
+    pub interval_histogram: Histogram<u64>,
+
+    // Chart data
+    pub throughput_data: VecDeque<(f64, f64)>, // Rolling throughput over time
+    pub latency_data: VecDeque<(f64, f64)>,    // Rolling latency over time
+    pub throughput_history: SeriesHistory,     // Throughput over the whole test
+    pub latency_history: SeriesHistory,        // Latency over the whole test
+    pub p50_history: SeriesHistory,            // Windowed p50 latency over the whole test
+    pub p95_history: SeriesHistory,            // Windowed p95 latency over the whole test
+    pub p99_history: SeriesHistory,            // Windowed p99 latency over the whole test
+    /// Requests per second of each of `STATUS_CLASSES` over the whole test
+    pub status_classes: StackedHistory<5>,
+    /// Requests of each status class since the last chart point
+    pub interval_status_classes: [u64; 5],
+
+    // Running statistics
+    pub min_latency: f64,
+    pub max_latency: f64,
+    pub p50_latency: f64,
+    pub p90_latency: f64,
+    pub p95_latency: f64,
+    pub p99_latency: f64,
+
+    // Current throughput
+    pub current_throughput: f64,
+
+    // Test completion
+    pub is_complete: bool,
+    pub should_quit: bool,
+    pub end_time: Option<Instant>,
+
+    // Set to hold load without ending the test; workers idle while it is
+    pub paused: Arc<AtomicBool>,
+
+    // Per-worker rate limit, adjustable while the test runs
+    pub rate_limit: LiveRate,
+
+    // The test's settings as `TestConfig::describe` lists them
+    pub settings: Vec<(&'static str, String)>,
+
+    // Byte tracking
+    pub total_bytes_sent: u64,
+    pub total_bytes_received: u64,
+
+    // Set when the test was stopped early
+    pub stop_reason: Option<StopReason>,
+
+    // Set when the test reached one of its limits
+    pub end_reason: Option<EndReason>,
+}
+
+impl TestState {
+    /// Record a notable event at the current point in the test
+    pub fn record_event(&mut self, message: impl Into<String>) {
+        self.events.push(TestEvent {
+            elapsed: self.elapsed_secs(),
+            message: message.into(),
+        });
+    }
+
+    /// Mark the test as complete, freezing the end time the first time it is called
+    pub fn mark_complete(&mut self) {
+        if !self.is_complete {
+            self.is_complete = true;
+            self.end_time = Some(Instant::now());
+        }
+    }
+
+    pub fn new(config: &TestConfig) -> Self {
+        let now = Instant::now();
+        let (steps, step_groups) = group_steps(&config.scenario);
+        TestState {
+            url: config.url.clone(),
+            method: config.method.clone(),
+            target_requests: config.requests,
+            concurrent_requests: config.concurrent,
+            duration: config.duration,
+            start_time: now,
+            headers: config.headers.clone(),
+            classifier: config.classifier.clone(),
+            grpc: config.grpc.clone(),
+
+            completed_requests: 0,
+            error_count: 0,
+            assertion_failures: 0,
+            checksum_mismatches: 0,
+            connect_timeouts: 0,
+
+            status_counts: HashMap::new(),
+            grpc_status_counts: HashMap::new(),
+            protocol_counts: HashMap::new(),
+            clock_skew: ClockSkew::default(),
+            sse: SseStats::default(),
+            dns_lookup: PhaseStats::default(),
+            connect: PhaseStats::default(),
+            tls_handshake: PhaseStats::default(),
+            ttfb: PhaseStats::default(),
+            body_read: PhaseStats::default(),
+            certificates: Vec::new(),
+
+            targets: config.targets().into_iter().map(TargetStats::new).collect(),
+            steps,
+            step_groups,
+            events: Vec::new(),
+            bursts: Vec::new(),
+
+            request_log: VecDeque::with_capacity(REQUEST_LOG_LEN),
+            recent_latencies: VecDeque::with_capacity(100),
+            recent_throughput: VecDeque::with_capacity(30),
+
+            // Higher precision for latency histogram (5 significant figures instead of 3)
+            latency_histogram: Histogram::<u64>::new(5).unwrap(),
+            interval_histogram: Histogram::<u64>::new(3).unwrap(),
+
+            throughput_data: VecDeque::with_capacity(60),
+            latency_data: VecDeque::with_capacity(60),
+            throughput_history: SeriesHistory::default(),
+            latency_history: SeriesHistory::default(),
+            p50_history: SeriesHistory::default(),
+            p95_history: SeriesHistory::default(),
+            p99_history: SeriesHistory::default(),
+            status_classes: StackedHistory::default(),
+            interval_status_classes: [0; 5],
+
+            min_latency: f64::MAX,
+            max_latency: 0.0,
+            p50_latency: 0.0,
+            p90_latency: 0.0,
+            p95_latency: 0.0,
+            p99_latency: 0.0,
+
+            current_throughput: 0.0,
+
+            is_complete: false,
+            should_quit: false,
+            end_time: None,
+            paused: Arc::default(),
+            rate_limit: LiveRate::new(config.rate_limit),
+            settings: config.describe(),
+
+            total_bytes_sent: 0,
+            total_bytes_received: 0,
+
+            stop_reason: None,
+            end_reason: None,
+        }
+    }
+}
+
+/// Shared state wrapper for thread communication
+#[derive(Clone)]
+pub struct SharedState {
+    pub state: Arc<Mutex<TestState>>,
+}
@@ -0,0 +1,111 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use super::state::TestState;
+use crate::tester::connection::PhaseStats;
+
+impl TestState {
+    /// Elapsed test time in seconds, frozen at the end time once complete
+    pub fn elapsed_secs(&self) -> f64 {
+        match self.end_time {
+            Some(end) if self.is_complete => end.duration_since(self.start_time).as_secs_f64(),
+            _ => self.start_time.elapsed().as_secs_f64(),
+        }
+    }
+
+    /// How far the test is toward whichever of its `-n` and `-z` limits it
+    /// reaches first, from 0 to 1, or `None` when it runs until stopped
+    pub fn progress(&self) -> Option<f64> {
+        let by_requests = (self.target_requests > 0)
+            .then(|| self.completed_requests as f64 / self.target_requests as f64);
+        let by_time = (self.duration > 0).then(|| self.elapsed_secs() / self.duration as f64);
+        let progress = match (by_requests, by_time) {
+            (Some(requests), Some(time)) => requests.max(time),
+            (progress, None) | (None, progress) => progress?,
+        };
+        Some(progress.min(1.0))
+    }
+
+    /// Estimated seconds until the test reaches one of its limits, from the
+    /// time left and the request rate so far, or `None` when there is no
+    /// estimate yet or nothing left to wait for
+    pub fn eta_secs(&self) -> Option<f64> {
+        if self.is_complete {
+            return None;
+        }
+        let elapsed = self.elapsed_secs();
+        let by_time = (self.duration > 0).then(|| (self.duration as f64 - elapsed).max(0.0));
+        let by_requests = (self.target_requests > 0 && self.completed_requests > 0).then(|| {
+            let remaining = self.target_requests.saturating_sub(self.completed_requests);
+            remaining as f64 * elapsed / self.completed_requests as f64
+        });
+        match (by_requests, by_time) {
+            (Some(requests), Some(time)) => Some(requests.min(time)),
+            (eta, None) | (None, eta) => eta,
+        }
+    }
+
+    /// Latency at the given quantile in milliseconds, read straight from the histogram
+    pub fn latency_at_quantile(&self, quantile: f64) -> f64 {
+        if self.latency_histogram.is_empty() {
+            return 0.0;
+        }
+        self.latency_histogram.value_at_quantile(quantile) as f64 / 1000.0
+    }
+
+    /// Request counts in up to `buckets` latency ranges, each wider than the last by
+    /// the same factor, from the fastest response to the slowest, as pairs of
+    /// (upper bound in milliseconds, count)
+    pub fn latency_buckets(&self, buckets: usize) -> Vec<(f64, u64)> {
+        let histogram = &self.latency_histogram;
+        if histogram.is_empty() || buckets == 0 {
+            return Vec::new();
+        }
+        let low = histogram.min().max(1) as f64;
+        let high = histogram.max().max(1) as f64;
+        let buckets = if high > low { buckets } else { 1 };
+        let step = (high / low).powf(1.0 / buckets as f64);
+
+        let mut counts = vec![0; buckets];
+        for value in histogram.iter_recorded() {
+            let micros = value.value_iterated_to().max(1) as f64;
+            // Rounding can put a value a hair outside the range; keep it at the ends
+            let index = ((micros / low).ln() / step.ln().max(f64::EPSILON)) as usize;
+            counts[index.min(buckets - 1)] += value.count_at_value();
+        }
+        (1..=buckets)
+            .zip(counts)
+            .map(|(i, count)| (low * step.powi(i as i32) / 1000.0, count))
+            .collect()
+    }
+
+    /// Request phases in the order they happen, named as the text report shows them
+    pub fn phases(&self) -> [(&'static str, &PhaseStats); 5] {
+        [
+            ("DNS-lookup", &self.dns_lookup),
+            ("Connect", &self.connect),
+            ("TLS handshake", &self.tls_handshake),
+            ("Resp wait", &self.ttfb),
+            ("Resp read", &self.body_read),
+        ]
+    }
+}
@@ -0,0 +1,225 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use super::results::{EndReason, RequestMetric};
+use super::state::{status_class, TestState, REQUEST_LOG_LEN};
+
+impl TestState {
+    pub fn update(&mut self, metric: RequestMetric) {
+        // Update counters
+        self.completed_requests += 1;
+
+        // Update byte counters
+        self.total_bytes_sent += metric.bytes_sent;
+        self.total_bytes_received += metric.bytes_received;
+
+        // Always update status counts with the status code
+        if metric.status_code > 0 {
+            // Only update if there is a valid status code
+            *self.status_counts.entry(metric.status_code).or_insert(0) += 1;
+        }
+        if let Some(events) = metric.sse_events {
+            self.sse.observe(events, metric.first_event_ms);
+        }
+        for (stats, ms) in [
+            (&mut self.dns_lookup, metric.dns_lookup_ms),
+            (&mut self.connect, metric.connect_ms),
+            (&mut self.tls_handshake, metric.tls_handshake_ms),
+            (&mut self.ttfb, metric.ttfb_ms),
+            (&mut self.body_read, metric.body_read_ms),
+        ] {
+            if let Some(ms) = ms {
+                stats.record(ms);
+            }
+        }
+        if let Some(status) = metric.grpc_status {
+            *self.grpc_status_counts.entry(status).or_insert(0) += 1;
+        }
+        if let Some(protocol) = metric.protocol {
+            *self.protocol_counts.entry(protocol).or_insert(0) += 1;
+        }
+        if let Some(skew_ms) = metric.clock_skew_ms {
+            self.clock_skew.observe(skew_ms);
+        }
+
+        // Update error count if it's an error (includes non-success status codes)
+        if metric.is_error {
+            self.error_count += 1;
+        }
+
+        if metric.assertion_failed {
+            self.assertion_failures += 1;
+        }
+        if metric.connect_timeout {
+            self.connect_timeouts += 1;
+        }
+        if metric.checksum_mismatch {
+            self.checksum_mismatches += 1;
+        }
+
+        let group = metric.step.and_then(|step| self.step_groups.get(step));
+        if let Some(step) = group.and_then(|&group| self.steps.get_mut(group)) {
+            step.record(&metric);
+        }
+
+        if let Some(target) = self.targets.get_mut(metric.target) {
+            target.completed_requests += 1;
+            target.total_latency_ms += metric.latency_ms;
+            if metric.is_error {
+                target.error_count += 1;
+            }
+            target
+                .health
+                .record(metric.timestamp as u64, metric.latency_ms, metric.is_error);
+        }
+
+        // Keep the latest requests for the request log
+        if self.request_log.len() == REQUEST_LOG_LEN {
+            self.request_log.pop_front();
+        }
+        self.request_log.push_back(metric.clone());
+
+        // Update latency stats
+        let latency = metric.latency_ms;
+        self.recent_latencies.push_back(latency);
+        if self.recent_latencies.len() > 100 {
+            self.recent_latencies.pop_front();
+        }
+
+        // Convert from f64 to u64 with higher resolution (microseconds = milliseconds * 1000)
+        // This gives us nanosecond-level precision for recording in the histogram
+        self.latency_histogram
+            .record((latency * 1000.0) as u64)
+            .unwrap();
+        self.interval_histogram
+            .record((latency * 1000.0) as u64)
+            .unwrap();
+        self.interval_status_classes[status_class(metric.status_code)] += 1;
+
+        // Update min/max
+        if latency < self.min_latency {
+            self.min_latency = latency;
+        }
+        if latency > self.max_latency {
+            self.max_latency = latency;
+        }
+
+        // Update percentiles
+        if self.completed_requests.is_multiple_of(10) {
+            // Divide by 1000 to convert back to milliseconds from the microsecond storage
+            self.p50_latency = self.latency_histogram.value_at_quantile(0.5) as f64 / 1000.0;
+            self.p90_latency = self.latency_histogram.value_at_quantile(0.9) as f64 / 1000.0;
+            self.p95_latency = self.latency_histogram.value_at_quantile(0.95) as f64 / 1000.0;
+            self.p99_latency = self.latency_histogram.value_at_quantile(0.99) as f64 / 1000.0;
+        }
+
+        // Update throughput calculations once per second
+        let elapsed = self.start_time.elapsed().as_secs_f64();
+        let last_throughput_time = self.throughput_data.back().map(|&(t, _)| t).unwrap_or(0.0);
+
+        if elapsed - last_throughput_time >= 1.0 || self.throughput_data.is_empty() {
+            // Calculate current throughput (requests per second)
+            if !self.recent_throughput.is_empty() {
+                let window_size = self.recent_throughput.len().min(10) as f64;
+                let sum: f64 = self.recent_throughput.iter().map(|&(_, tps)| tps).sum();
+                self.current_throughput = sum / window_size;
+            }
+
+            // Add data points for charts
+            self.throughput_data
+                .push_back((elapsed, self.current_throughput));
+            self.throughput_history
+                .push((elapsed, self.current_throughput));
+            if self.throughput_data.len() > 60 {
+                self.throughput_data.pop_front();
+            }
+
+            let avg_latency: f64 = if !self.recent_latencies.is_empty() {
+                self.recent_latencies.iter().sum::<f64>() / self.recent_latencies.len() as f64
+            } else {
+                0.0
+            };
+
+            self.latency_data.push_back((elapsed, avg_latency));
+            self.latency_history.push((elapsed, avg_latency));
+            self.record_interval_percentiles(elapsed);
+            self.record_interval_status_classes(elapsed);
+            if self.latency_data.len() > 60 {
+                self.latency_data.pop_front();
+            }
+        }
+
+        // Add throughput data point
+        let second_bucket = elapsed.floor();
+        let last_entry = self.recent_throughput.back().cloned();
+
+        match last_entry {
+            Some((bucket, count)) if bucket == second_bucket => {
+                // Update existing bucket
+                self.recent_throughput.pop_back();
+                self.recent_throughput.push_back((bucket, count + 1.0));
+            }
+            _ => {
+                // Create new bucket
+                self.recent_throughput.push_back((second_bucket, 1.0));
+                if self.recent_throughput.len() > 30 {
+                    self.recent_throughput.pop_front();
+                }
+            }
+        }
+
+        // Check if test is complete
+        if self.target_requests > 0 && self.completed_requests >= self.target_requests {
+            self.end_reason
+                .get_or_insert(EndReason::RequestLimit(self.target_requests));
+            self.mark_complete();
+        }
+    }
+
+    /// Add the p50/p95/p99 of the latencies since the last chart point to the
+    /// percentile series, then start the next interval
+    fn record_interval_percentiles(&mut self, elapsed: f64) {
+        if self.interval_histogram.is_empty() {
+            return;
+        }
+        let at = |quantile| self.interval_histogram.value_at_quantile(quantile) as f64 / 1000.0;
+        let (p50, p95, p99) = (at(0.50), at(0.95), at(0.99));
+        self.p50_history.push((elapsed, p50));
+        self.p95_history.push((elapsed, p95));
+        self.p99_history.push((elapsed, p99));
+        self.interval_histogram.reset();
+    }
+
+    /// Add the rate of each status class since the last chart point to
+    /// `status_classes`, then start the next interval
+    fn record_interval_status_classes(&mut self, elapsed: f64) {
+        let interval = self
+            .status_classes
+            .last_time()
+            .map_or(1.0, |last| (elapsed - last).max(1.0));
+        let rates = self
+            .interval_status_classes
+            .map(|count| count as f64 / interval);
+        self.status_classes.push(elapsed, rates);
+        self.interval_status_classes = [0; 5];
+    }
+}
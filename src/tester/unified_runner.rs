@@ -20,28 +20,46 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use anyhow::{Context, Result};
-use floating_duration::TimeAsFloat;
-use reqwest::Client;
-use std::{
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
-    },
-    time::{Duration, Instant},
+use anyhow::{anyhow, Result};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
 };
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Notify};
+use tokio::task::JoinHandle;
 use url::Url;
 
+use super::breaker::{ErrorBreaker, LatencyBreaker};
+use super::certificate::record_chain;
+use super::client::create_http_clients;
+use super::error::parse_target_url;
+use super::export::FanOut;
+use super::extract::check_extractions;
+use super::failover::{check_fallback, Failover};
+use super::hooks::RunnerHooks;
+use super::load::run_load;
 use super::metrics::SharedMetrics;
-use super::types::{HttpMethod, Message, RequestMetric, SharedState, TestConfig};
+use super::metrics_task::{process_metrics, MetricsTask, SharedHooks};
+use super::oauth::BearerToken;
+use super::pacer::LiveRate;
+use super::resolve::{pinned_addrs, pinned_hosts};
+use super::scenario::check_steps;
+use super::tls::{client_config, pin_sni};
+use super::types::{Message, RequestMetric, SharedState, TestConfig};
+use super::worker_options::WorkerOptions;
 
 /// Unified runner implementation that combines worker pool and lock-free metrics
 pub struct UnifiedRunner {
     config: TestConfig,
     metrics: SharedMetrics,
     shared_state: Option<SharedState>,
+    hooks: SharedHooks,
     is_running: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+    rate_limit: LiveRate,
+    finish_now: Arc<Notify>,
+    metrics_task: Option<JoinHandle<()>>,
+    bearer: Option<BearerToken>,
     tx: mpsc::Sender<Message>,
     #[allow(dead_code)]
     rx: mpsc::Receiver<Message>,
@@ -51,31 +69,42 @@ impl UnifiedRunner {
     /// Create a new unified runner with the given configuration
     #[allow(dead_code)]
     pub fn new(config: TestConfig) -> Self {
-        let (tx, rx) = mpsc::channel::<Message>(config.concurrent * 2);
-        let is_running = Arc::new(AtomicBool::new(true));
-        let metrics = SharedMetrics::new(config.url.clone(), config.method.to_string());
-
-        UnifiedRunner {
-            config,
-            metrics,
-            shared_state: None,
-            is_running,
-            tx,
-            rx,
-        }
+        let rate_limit = LiveRate::new(config.rate_limit);
+        Self::assemble(config, None, Arc::default(), rate_limit)
     }
 
     /// Create a new unified runner with the given configuration and shared state
     pub fn with_state(config: TestConfig, shared_state: SharedState) -> Self {
+        // Pausing or changing the rate through the shared state, as the UI
+        // does, applies to this run
+        let (paused, rate_limit) = {
+            let state = shared_state.state.lock().unwrap();
+            state.rate_limit.set(config.rate_limit);
+            (Arc::clone(&state.paused), state.rate_limit.clone())
+        };
+        Self::assemble(config, Some(shared_state), paused, rate_limit)
+    }
+
+    fn assemble(
+        config: TestConfig,
+        shared_state: Option<SharedState>,
+        paused: Arc<AtomicBool>,
+        rate_limit: LiveRate,
+    ) -> Self {
         let (tx, rx) = mpsc::channel::<Message>(config.concurrent * 2);
-        let is_running = Arc::new(AtomicBool::new(true));
         let metrics = SharedMetrics::new(config.url.clone(), config.method.to_string());
 
         UnifiedRunner {
             config,
             metrics,
-            shared_state: Some(shared_state),
-            is_running,
+            shared_state,
+            hooks: Default::default(),
+            is_running: Arc::new(AtomicBool::new(true)),
+            paused,
+            rate_limit,
+            finish_now: Arc::default(),
+            metrics_task: None,
+            bearer: None,
             tx,
             rx,
         }
@@ -85,525 +114,185 @@ impl UnifiedRunner {
     #[allow(dead_code)]
     pub fn stop(&self) {
         self.is_running.store(false, Ordering::SeqCst);
+        if let Some(bearer) = &self.bearer {
+            bearer.stop();
+        }
     }
 
-    /// Get a clone of the shared metrics
-    #[allow(dead_code)]
-    pub fn metrics(&self) -> SharedMetrics {
-        self.metrics.clone()
+    /// Stop the test and finish it with the results already in, without waiting for
+    /// requests still in flight; output files are complete once this returns
+    pub async fn finish(&mut self) {
+        self.stop();
+        self.finish_now.notify_one();
+        if let Some(metrics_task) = self.metrics_task.take() {
+            let _ = metrics_task.await;
+        }
     }
 
-    /// Set the shared metrics to use for this runner
-    #[allow(dead_code)]
-    pub fn set_metrics(&mut self, metrics: SharedMetrics) {
-        self.metrics = metrics;
+    /// Hold load without ending the test: workers finish their current request and
+    /// then idle, while the clock, including any `duration` limit, keeps running
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
     }
 
-    /// Start the test in a separate task
-    pub async fn start(&mut self) -> Result<()> {
-        // Validate URL
-        let url = Url::parse(&self.config.url).context("Invalid URL")?;
-
-        // Clone values for task
-        let load_tx = self.tx.clone();
-        let is_running = Arc::clone(&self.is_running);
-        let config = self.config.clone();
-        let metrics = self.metrics.clone();
-
-        // Create a channel for job completion with much larger capacity
-        let (job_tx, mut job_rx) = mpsc::channel::<RequestMetric>(config.concurrent * 50);
-
-        // Spawn load test task
-        let _load_test_handle = tokio::spawn(async move {
-            // Create HTTP client with pooling configuration
-            let client = create_http_client(&config);
-            let start_time = Instant::now();
-
-            // Calculate test limits
-            let max_requests = if config.requests > 0 {
-                config.requests
-            } else {
-                usize::MAX
-            };
-
-            let max_duration = if config.duration > 0 {
-                Some(Duration::from_secs(config.duration))
-            } else {
-                None
-            };
-
-            // Create a worker pool with shared ownership
-            let worker_pool = Arc::new(WorkerPool::new(
-                client,
-                config.concurrent,
-                job_tx,
-                Arc::clone(&is_running),
-                config.rate_limit,
-            ));
-
-            // A much simpler approach - submit a large number of jobs at once
-            let mut _submitted_jobs = 0;
-            let job_capacity = 1_000_000; // 1M job limit
-
-            // Calculate how many jobs to actually submit
-            // If limited by requests, use that, otherwise use our large capacity
-            let jobs_to_submit = if max_requests > 0 {
-                max_requests.min(job_capacity)
-            } else {
-                job_capacity
-            };
-
-            // Create a separate task for job submission to avoid blocking
-            let job_submitter = tokio::spawn({
-                let is_running_clone = Arc::clone(&is_running);
-                let url_clone = url.clone();
-                let headers_clone = config.headers.clone();
-                let body_clone = config.body.clone();
-                let auth_clone = config.basic_auth.clone();
-                let method_clone = config.method;
-                let timeout_clone = config.timeout;
-                let pool_clone = Arc::clone(&worker_pool);
-
-                async move {
-                    let mut submitted = 0;
-
-                    // Submit jobs in batches to avoid memory issues
-                    let batch_size = 1000;
-                    let num_batches = jobs_to_submit.div_ceil(batch_size);
-
-                    for _ in 0..num_batches {
-                        if !is_running_clone.load(Ordering::SeqCst) {
-                            break; // Stop if test is cancelled
-                        }
-
-                        // Calculate this batch size
-                        let current_batch = batch_size.min(jobs_to_submit - submitted);
-
-                        // Submit a batch of jobs
-                        for _ in 0..current_batch {
-                            let job = RequestJob {
-                                url: url_clone.clone(),
-                                headers: headers_clone.clone(),
-                                body: body_clone.clone(),
-                                basic_auth: auth_clone.clone(),
-                                method: method_clone,
-                                timeout: timeout_clone,
-                                start_time,
-                            };
-
-                            // Use async submission to properly backpressure
-                            pool_clone.submit_job(job).await;
-                            submitted += 1;
-                        }
-
-                        // Let other tasks run
-                        tokio::task::yield_now().await;
-                    }
-
-                    submitted
-                }
-            });
-
-            // Start a duration-based timer if needed
-            let duration_timer = if let Some(max_dur) = max_duration {
-                // This task will stop the worker pool when the max duration is reached
-                let pool_for_timer = Arc::clone(&worker_pool);
-                let timer_handle = tokio::spawn(async move {
-                    tokio::time::sleep(max_dur).await;
-                    pool_for_timer.stop();
-                });
-                Some(timer_handle)
-            } else {
-                None
-            };
-
-            // Wait for the job submitter to complete
-            if let Ok(count) = job_submitter.await {
-                _submitted_jobs = count;
-            }
-
-            // If we have a duration timer, wait for it
-            if let Some(timer) = duration_timer {
-                // We don't care about the result, just making sure it's done
-                let _ = timer.await;
-            }
-
-            // Job submitters are already awaited in the code above
-
-            // Wait a bit to allow metrics to be processed
-            tokio::time::sleep(Duration::from_millis(500)).await;
-
-            // Mark the metrics as complete
-            metrics.mark_complete();
-
-            // Send completion message
-            let _ = load_tx.send(Message::TestComplete).await;
-
-            // We can't use wait() with Arc since it requires ownership
-            // Just sleep a bit longer for workers to complete
-            tokio::time::sleep(Duration::from_secs(1)).await;
-        });
-
-        // Spawn metrics processing task
-        let metrics_clone = self.metrics.clone();
-        let metrics_tx = self.tx.clone();
-        let shared_state = self.shared_state.clone();
-
-        let _metrics_handle = tokio::spawn(async move {
-            // Efficiently process batched metrics from job channel
-            while let Some(metric) = job_rx.recv().await {
-                // Record the metric in the lock-free collector
-                metrics_clone.record(&metric);
-
-                // If we have a shared state, update it as well for UI compatibility
-                if let Some(state) = &shared_state {
-                    let mut guard = state.state.lock().unwrap();
-                    guard.update(metric.clone());
-                }
-
-                // Send the message for any listeners
-                let _ = metrics_tx.send(Message::RequestComplete(metric)).await;
-            }
-
-            // Do a final metrics processing
-            metrics_clone.process_metrics();
-        });
-
-        // Start metrics processor task
-        let metrics_ref = self.metrics.metrics.clone();
-        let _processor_handle = tokio::spawn(async move {
-            while !metrics_ref.is_complete() {
-                // Process queued metrics periodically
-                metrics_ref.process_queued_metrics();
-                metrics_ref.update_statistics();
-
-                // Sleep a bit to reduce CPU usage
-                tokio::time::sleep(Duration::from_millis(100)).await;
-            }
-
-            // Final processing
-            metrics_ref.process_queued_metrics();
-            metrics_ref.update_statistics();
-        });
-
-        Ok(())
+    /// Carry on sending after `pause`
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
     }
-}
-
-/// A request job to be processed by a worker
-pub struct RequestJob {
-    /// URL to send the request to
-    pub url: Url,
-    /// HTTP headers to include
-    pub headers: Vec<(String, String)>,
-    /// Request body data
-    pub body: Option<String>,
-    /// Basic authentication credentials
-    pub basic_auth: Option<(String, String)>,
-    /// HTTP method to use
-    pub method: HttpMethod,
-    /// Request timeout in seconds
-    pub timeout: u64,
-    /// The start time of the test (for timestamp calculation)
-    pub start_time: Instant,
-}
-
-/// A worker pool for efficiently processing HTTP requests
-pub struct WorkerPool {
-    #[allow(dead_code)]
-    client: Client,
-    job_sender: mpsc::Sender<RequestJob>,
-    #[allow(dead_code)]
-    worker_handles: Vec<tokio::task::JoinHandle<()>>,
-    is_running: Arc<AtomicBool>,
-}
 
-impl WorkerPool {
-    /// Create a new worker pool with the given configuration
-    pub fn new(
-        client: Client,
-        concurrency: usize,
-        metric_sender: mpsc::Sender<RequestMetric>,
-        is_running: Arc<AtomicBool>,
-        rate_limit: f64,
-    ) -> Self {
-        // Create a channel for distributing jobs with much larger buffer
-        let (job_sender, job_receiver) = mpsc::channel::<RequestJob>(concurrency * 100);
-
-        // Share the job receiver among workers
-        let job_receiver = Arc::new(tokio::sync::Mutex::new(job_receiver));
-
-        // Create worker tasks
-        let mut worker_handles = Vec::with_capacity(concurrency);
-
-        for _ in 0..concurrency {
-            let worker_client = client.clone();
-            let worker_job_receiver = job_receiver.clone();
-            let worker_metric_sender = metric_sender.clone();
-            let worker_is_running = Arc::clone(&is_running);
-            let worker_rate_limit = rate_limit;
-
-            // Create a semaphore for this worker to control its own concurrency
-            let worker_sem = Arc::new(tokio::sync::Semaphore::new(1));
-
-            // Spawn the worker task
-            let handle = tokio::spawn(async move {
-                Self::worker_loop(
-                    worker_client,
-                    worker_job_receiver,
-                    worker_metric_sender,
-                    worker_is_running,
-                    worker_sem,
-                    worker_rate_limit,
-                )
-                .await;
-            });
-
-            worker_handles.push(handle);
-        }
-
-        WorkerPool {
-            client,
-            job_sender,
-            worker_handles,
-            is_running,
-        }
+    /// Change the per-worker rate limit of a running test (0 for no limit)
+    pub fn set_rate_limit(&self, rate: f64) {
+        self.rate_limit.set(rate);
     }
 
-    /// Submit a job to the worker pool
-    pub async fn submit_job(&self, job: RequestJob) {
-        // Send the job to the worker pool
-        if self.is_running.load(Ordering::SeqCst) {
-            let _ = self.job_sender.send(job).await;
-        }
+    /// Flag the test runs under; clearing it stops the test just like `stop`
+    pub fn stop_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.is_running)
     }
 
-    /// Try to submit a job to the worker pool without awaiting
-    /// Returns true if the job was submitted, false otherwise
+    /// Get a clone of the shared metrics
     #[allow(dead_code)]
-    pub fn try_submit_job(&self, job: RequestJob) -> bool {
-        // Check if we're still running
-        if !self.is_running.load(Ordering::SeqCst) {
-            return false;
-        }
-
-        // Try to send the job to the worker pool
-        self.job_sender.try_send(job).is_ok()
-    }
-
-    /// Stop the worker pool
-    pub fn stop(&self) {
-        self.is_running.store(false, Ordering::SeqCst);
+    pub fn metrics(&self) -> SharedMetrics {
+        self.metrics.clone()
     }
 
-    /// Wait for all workers to complete
+    /// Set the shared metrics to use for this runner
     #[allow(dead_code)]
-    pub async fn wait(self) {
-        if !self.worker_handles.is_empty() {
-            let _ = futures::future::join_all(self.worker_handles).await;
-        }
+    pub fn set_metrics(&mut self, metrics: SharedMetrics) {
+        self.metrics = metrics;
     }
 
-    /// Main worker processing loop
-    async fn worker_loop(
-        client: Client,
-        job_receiver: Arc<tokio::sync::Mutex<mpsc::Receiver<RequestJob>>>,
-        metric_sender: mpsc::Sender<RequestMetric>,
-        is_running: Arc<AtomicBool>,
-        sem: Arc<tokio::sync::Semaphore>,
-        rate_limit: f64,
-    ) {
-        while is_running.load(Ordering::SeqCst) {
-            // Get the next job with timeout to check for stop condition
-            let job_result = {
-                let mut receiver = job_receiver.lock().await;
-                tokio::select! {
-                    job = receiver.recv() => job,
-                    _ = tokio::time::sleep(Duration::from_millis(100)) => {
-                        // Check if we should stop
-                        if !is_running.load(Ordering::SeqCst) {
-                            None
-                        } else {
-                            continue;
-                        }
-                    }
-                }
-            };
-
-            let job = match job_result {
-                Some(job) => job,
-                None => break, // No more jobs or stopping
-            };
-
-            // Apply rate limiting if configured
-            if rate_limit > 0.0 {
-                let delay_ms = (1000.0 / rate_limit) as u64;
-                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
-            }
-
-            // Acquire a permit from the semaphore
-            let _permit = sem.acquire().await.unwrap();
-
-            // Execute the request
-            let result = Self::execute_request(
-                &client,
-                job.url,
-                job.method,
-                &job.headers,
-                job.body,
-                job.basic_auth,
-                job.timeout,
-                job.start_time,
-            )
-            .await;
-
-            // Send the result metric
-            let _ = metric_sender.send(result).await;
-        }
+    /// Register callbacks that see every result as the test runs; call before `start`
+    pub fn add_hooks(&mut self, hooks: impl RunnerHooks + 'static) {
+        self.hooks.lock().push(Box::new(hooks));
     }
 
-    /// Execute an HTTP request and return metrics
-    #[allow(clippy::too_many_arguments)]
-    async fn execute_request(
-        client: &Client,
-        url: Url,
-        method: HttpMethod,
-        headers: &[(String, String)],
-        body: Option<String>,
-        basic_auth: Option<(String, String)>,
-        timeout: u64,
-        start_time: Instant,
-    ) -> RequestMetric {
-        // Calculate approximate bytes sent
-        let bytes_sent = {
-            let mut total = 0u64;
-
-            // Method and path
-            total += method.to_string().len() as u64;
-            total += url.path().len() as u64;
-            if let Some(query) = url.query() {
-                total += query.len() as u64;
-            }
-
-            // Headers
-            for (name, value) in headers {
-                total += name.len() as u64 + value.len() as u64 + 4;
-            }
-
-            // Body
-            if let Some(body) = &body {
-                total += body.len() as u64;
-            }
+    /// Start the test in a separate task
+    pub async fn start(&mut self) -> Result<()> {
+        // Validate URLs; a `ConfigError` can be downcast from the returned error
+        let url = parse_target_url(&self.config.url)?;
+        if self.config.http3 && !cfg!(feature = "http3") {
+            return Err(anyhow!(
+                "HTTP/3 support is not compiled in; rebuild with RUSTFLAGS='--cfg reqwest_unstable' and --features http3"
+            ));
+        }
 
-            // Basic overhead
-            total += 50;
+        // Catch a step URL that can't be resolved before any load is sent
+        check_steps(&url, &self.config.scenario)?;
+        check_extractions(&self.config.scenario, &self.config.extractions)?;
 
-            total
+        // Prepare failover to the fallback URL, if one is configured
+        check_fallback(&self.config)?;
+        let fallback_url = match &self.config.fallback_url {
+            Some(fallback) => Some(parse_target_url(fallback)?),
+            None => None,
         };
 
-        // Start request timing
-        let request_start = Instant::now();
-
-        // Create the request builder based on method
-        let mut request_builder = match method {
-            HttpMethod::GET => client.get(url),
-            HttpMethod::POST => client.post(url),
-            HttpMethod::PUT => client.put(url),
-            HttpMethod::DELETE => client.delete(url),
-            HttpMethod::HEAD => client.head(url),
-            HttpMethod::OPTIONS => client.request(reqwest::Method::OPTIONS, url),
+        // Parse the URLs of the mix, if requests are spread across several
+        let mix_urls = match &self.config.url_mix {
+            Some(mix) => mix
+                .urls
+                .iter()
+                .map(|weighted| parse_target_url(&weighted.url))
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
         };
 
-        // Set timeout
-        if timeout > 0 {
-            request_builder = request_builder.timeout(Duration::from_secs(timeout));
-        }
-
-        // Add headers
-        for (name, value) in headers {
-            request_builder = request_builder.header(name, value);
-        }
-
-        // Add basic auth
-        if let Some((username, password)) = &basic_auth {
-            request_builder = request_builder.basic_auth(username, Some(password));
-        }
+        // Apply `--resolve` to whichever targets it names
+        let targets: Vec<&Url> = std::iter::once(&url)
+            .chain(&fallback_url)
+            .chain(&mix_urls)
+            .collect();
+        let mut pinned_hosts = pinned_hosts(&self.config.resolve, &targets);
+        let failover = fallback_url
+            .map(|fallback| Arc::new(Failover::new(fallback, self.config.failover_after)));
+
+        // Open output files up front so a bad path fails before any load is sent
+        let exporters = FanOut::open(&self.config.outputs)?;
+
+        // Keep connecting to the URL's address while presenting the `--sni` name
+        let url = match &self.config.sni {
+            Some(name) => {
+                let resolved = pinned_addrs(&pinned_hosts, &url);
+                let (pinned, addrs) = pin_sni(&url, name, resolved).await?;
+                pinned_hosts.push((name.clone(), addrs));
+                pinned
+            }
+            None => url,
+        };
 
-        // Add body
-        if let Some(body_content) = &body {
-            request_builder = request_builder.body(body_content.clone());
-        }
+        // Create HTTP clients with pooling configuration; a bad CA file fails here
+        let clients = create_http_clients(&self.config, &pinned_hosts)?;
 
-        // Send request and process response
-        let result = request_builder.send().await;
-        let duration = request_start.elapsed();
-
-        match result {
-            Ok(resp) => {
-                let status = resp.status().as_u16();
-                let status_class = status / 100;
-                let is_error = status_class != 2;
-
-                let bytes_received = match resp.bytes().await {
-                    Ok(bytes) => bytes.len() as u64,
-                    Err(_) => 0,
-                };
-
-                RequestMetric {
-                    timestamp: start_time.elapsed().as_fractional_secs(),
-                    latency_ms: duration.as_fractional_millis(),
-                    status_code: status,
-                    is_error,
-                    bytes_sent,
-                    bytes_received,
-                }
+        // Get an access token before any load is sent, and keep it fresh
+        let bearer = match &self.config.oauth {
+            Some(oauth) => {
+                let client = clients[0].clone();
+                Some(BearerToken::start(oauth.clone(), client, Arc::clone(&self.is_running)).await?)
             }
-            Err(_) => RequestMetric {
-                timestamp: start_time.elapsed().as_fractional_secs(),
-                latency_ms: duration.as_fractional_millis(),
-                status_code: 0,
-                is_error: true,
-                bytes_sent,
-                bytes_received: 0,
-            },
+            None => None,
+        };
+        self.bearer = bearer.clone();
+
+        let worker_options = Arc::new(WorkerOptions::new(
+            &self.config,
+            &url,
+            self.rate_limit.clone(),
+            Arc::clone(&self.paused),
+            failover.clone(),
+            bearer,
+        )?);
+
+        // Record the server's certificate chain for the report; proxied runs skip it
+        if let (Some(state), "https", None) = (&self.shared_state, url.scheme(), &self.config.proxy)
+        {
+            let tls = Arc::new(client_config(&self.config)?);
+            let pinned = pinned_addrs(&pinned_hosts, &url);
+            record_chain(Arc::clone(&state.state), tls, url.clone(), pinned);
         }
-    }
-}
 
-/// Create an HTTP client with optimal configuration for load testing
-fn create_http_client(config: &TestConfig) -> Client {
-    let mut client_builder = Client::builder();
+        // URLs jobs are sent to, indexed like `TestConfig::targets`
+        let job_urls = if mix_urls.is_empty() {
+            vec![url.clone()]
+        } else {
+            mix_urls
+        };
 
-    // Configure proxy if specified
-    if let Some(proxy) = &config.proxy {
-        let proxy_url = format!("http://{proxy}");
-        if let Ok(proxy) = reqwest::Proxy::http(&proxy_url) {
-            client_builder = client_builder.proxy(proxy);
-        }
-    }
+        // Create a channel for job completion with much larger capacity
+        let (job_tx, job_rx) = mpsc::channel::<RequestMetric>(self.config.concurrent * 50);
 
-    // Configure HTTP options
-    if config.disable_compression {
-        client_builder = client_builder.no_gzip().no_brotli().no_deflate();
-    }
+        // Spawn load test task
+        tokio::spawn(run_load(
+            self.config.clone(),
+            clients,
+            job_urls,
+            worker_options,
+            job_tx,
+            Arc::clone(&self.is_running),
+            self.shared_state.clone(),
+        ));
 
-    if config.disable_keepalive {
-        client_builder = client_builder.tcp_nodelay(true).pool_max_idle_per_host(0);
-    }
+        // Spawn metrics processing task
+        let metrics_task = MetricsTask {
+            metrics: self.metrics.clone(),
+            state: self.shared_state.clone(),
+            hooks: Arc::clone(&self.hooks),
+            is_running: Arc::clone(&self.is_running),
+            breaker: self.config.circuit_breaker.map(LatencyBreaker::new),
+            error_breaker: self.config.abort_after_errors.map(ErrorBreaker::new),
+            failover,
+            exporters,
+            listener: self.tx.clone(),
+            finish_now: Arc::clone(&self.finish_now),
+        };
+        self.metrics_task = Some(tokio::spawn(metrics_task.run(job_rx)));
 
-    if config.disable_redirects {
-        client_builder = client_builder.redirect(reqwest::redirect::Policy::none());
-    }
+        // Start metrics processor task
+        tokio::spawn(process_metrics(
+            Arc::clone(&self.metrics.metrics),
+            Arc::clone(&self.hooks),
+        ));
 
-    // Optimize connection pooling
-    client_builder = client_builder
-        .pool_max_idle_per_host(config.concurrent * 2)
-        .pool_idle_timeout(Duration::from_secs(300))
-        .tcp_keepalive(Duration::from_secs(60));
-
-    // Build client
-    client_builder.build().unwrap_or_else(|_| {
-        // Fallback to default client if build fails
-        Client::new()
-    })
+        Ok(())
+    }
 }
@@ -0,0 +1,178 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Context, Result};
+
+use super::error::parse_target_url;
+use super::stagger::XorShift;
+
+/// A URL and its share of the traffic relative to the others
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedUrl {
+    pub url: String,
+    pub weight: u32,
+}
+
+/// URLs the test spreads its requests across, in proportion to their weights
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlMix {
+    pub urls: Vec<WeightedUrl>,
+}
+
+impl UrlMix {
+    /// Read a file listing one URL per line
+    pub fn load(path: &str) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read URL file '{path}'"))?;
+        Self::parse(&text).with_context(|| format!("Invalid URL file '{path}'"))
+    }
+
+    /// Parse lines of `URL [WEIGHT]`; the weight defaults to 1, and blank lines and
+    /// `#` comments are skipped
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut urls = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let url = fields.next().unwrap_or_default();
+            parse_target_url(url).with_context(|| format!("Line {}", index + 1))?;
+            let weight = match fields.next() {
+                Some(weight) => weight
+                    .parse()
+                    .ok()
+                    .filter(|&weight| weight > 0)
+                    .ok_or_else(|| {
+                        anyhow!("Line {}: weight must be a whole number above 0", index + 1)
+                    })?,
+                None => 1,
+            };
+            if fields.next().is_some() {
+                return Err(anyhow!("Line {}: expected URL [WEIGHT]", index + 1));
+            }
+
+            urls.push(WeightedUrl {
+                url: url.to_string(),
+                weight,
+            });
+        }
+
+        if urls.is_empty() {
+            return Err(anyhow!("No URLs"));
+        }
+        Ok(UrlMix { urls })
+    }
+}
+
+/// How the URLs of a mix take turns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlSelection {
+    /// Interleave the URLs, each getting its weight's share of every round
+    RoundRobin,
+    /// A random URL for each request, chosen in proportion to the weights
+    Random,
+    /// Each URL gets its weight's worth of requests in a row before the next one
+    Sequential,
+}
+
+impl UrlSelection {
+    /// Parse `round-robin`, `random` or `sequential`
+    pub fn parse(selection: &str) -> Result<Self> {
+        match selection.trim().to_ascii_lowercase().as_str() {
+            "round-robin" | "roundrobin" | "rr" => Ok(UrlSelection::RoundRobin),
+            "random" => Ok(UrlSelection::Random),
+            "sequential" => Ok(UrlSelection::Sequential),
+            _ => Err(anyhow!(
+                "Invalid URL selection: '{selection}'. Expected 'round-robin', 'random' or 'sequential'"
+            )),
+        }
+    }
+}
+
+/// Picks which URL of a mix each request goes to
+pub struct UrlPicker {
+    weights: Vec<u64>,
+    selection: UrlSelection,
+    /// Smooth weighted round-robin credit: each turn every URL earns its weight
+    /// and the richest is picked and pays back the total
+    credit: Vec<i64>,
+    /// The URL sequential selection is on and how many requests it has had
+    position: (usize, u64),
+    rng: XorShift,
+}
+
+impl UrlPicker {
+    pub fn new(mix: &UrlMix, selection: UrlSelection) -> Self {
+        let weights: Vec<u64> = mix.urls.iter().map(|url| u64::from(url.weight)).collect();
+        UrlPicker {
+            credit: vec![0; weights.len()],
+            weights,
+            selection,
+            position: (0, 0),
+            rng: XorShift::new(0),
+        }
+    }
+
+    /// Index into the mix of the URL for the next request
+    pub fn next_index(&mut self) -> usize {
+        let total: u64 = self.weights.iter().sum();
+        match self.selection {
+            UrlSelection::RoundRobin => {
+                for (credit, weight) in self.credit.iter_mut().zip(&self.weights) {
+                    *credit += *weight as i64;
+                }
+                let (index, _) = self
+                    .credit
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .max_by_key(|(_, credit)| **credit)
+                    .unwrap_or((0, &0));
+                self.credit[index] -= total as i64;
+                index
+            }
+            UrlSelection::Random => {
+                let mut point = self.rng.next() % total.max(1);
+                self.weights
+                    .iter()
+                    .position(|&weight| {
+                        let hit = point < weight;
+                        point = point.saturating_sub(weight);
+                        hit
+                    })
+                    .unwrap_or(0)
+            }
+            UrlSelection::Sequential => {
+                let (index, sent) = &mut self.position;
+                if *sent == self.weights[*index] {
+                    *index = (*index + 1) % self.weights.len();
+                    *sent = 0;
+                }
+                *sent += 1;
+                *index
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use reqwest::Client;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use super::connection::timed;
+use super::cookies::CookieJar;
+use super::pacer::Pacer;
+use super::request::execute_request;
+use super::scenario::ScenarioUser;
+use super::sse::execute_sse;
+use super::stagger::{Jitter, Thinker};
+use super::types::RequestMetric;
+use super::worker_options::WorkerOptions;
+use super::worker_pool::RequestJob;
+
+/// Main worker processing loop
+pub(super) async fn worker_loop(
+    client: Client,
+    job_receiver: Arc<tokio::sync::Mutex<mpsc::Receiver<RequestJob>>>,
+    metric_sender: mpsc::Sender<RequestMetric>,
+    is_running: Arc<AtomicBool>,
+    sem: Arc<tokio::sync::Semaphore>,
+    options: Arc<WorkerOptions>,
+    worker_id: usize,
+) {
+    // Spread worker start times so they don't all fire at once
+    let mut jitter = options
+        .stagger
+        .map(|stagger| Jitter::new(stagger, worker_id));
+    if let Some(jitter) = &mut jitter {
+        tokio::time::sleep(jitter.next_delay()).await;
+    }
+    let mut pacer: Option<Pacer> = None;
+    let mut thinker = options
+        .think_time
+        .map(|think| Thinker::new(think, worker_id));
+    let mut user = options.scenario.as_ref().map(|(base, steps)| {
+        ScenarioUser::new(base.clone(), Arc::clone(steps))
+            .with_extractions(Arc::clone(&options.extractions))
+    });
+    let mut jar = options.cookies.then(CookieJar::default);
+
+    while is_running.load(Ordering::SeqCst) {
+        // Idle while paused, checking back for a resume or a stop
+        if options.paused.load(Ordering::SeqCst) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            continue;
+        }
+
+        // Get the next job with timeout to check for stop condition
+        let job_result = {
+            let mut receiver = job_receiver.lock().await;
+            tokio::select! {
+                job = receiver.recv() => job,
+                _ = tokio::time::sleep(Duration::from_millis(100)) => {
+                    // Check if we should stop
+                    if !is_running.load(Ordering::SeqCst) {
+                        None
+                    } else {
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let mut job = match job_result {
+            Some(job) => job,
+            None => break, // No more jobs or stopping
+        };
+
+        // A scenario user sends its own next step in place of the queued request
+        let step = user.as_mut().map(|user| {
+            let request = user.next_request();
+            job.method = request.method;
+            job.url = request.url;
+            job.body = request.body.map(String::into_bytes);
+            for (_, value) in &mut job.headers {
+                *value = user.substitute(value);
+            }
+            job.headers.extend(request.headers);
+            request.step
+        });
+        if let Some(bearer) = &options.bearer {
+            job.headers
+                .push(("Authorization".to_string(), bearer.header_value()));
+        }
+
+        // Pause between requests like a real user reading the last response
+        if let Some(thinker) = &mut thinker {
+            let pause = thinker.next_pause();
+            if !pause.is_zero() {
+                tokio::time::sleep(pause).await;
+            }
+        }
+
+        // Apply rate limiting if configured, at whatever the rate is now
+        let rate = options.rate_limit.get();
+        if rate > 0.0 {
+            let pacer = pacer.get_or_insert_with(|| Pacer::new(rate));
+            pacer.set_rate(rate);
+            let delay = pacer.next_delay(Instant::now());
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+
+        // Add per-request jitter so workers drift apart instead of bursting together
+        if let Some(jitter) = &mut jitter {
+            tokio::time::sleep(jitter.next_delay()).await;
+        }
+
+        // Acquire a permit from the semaphore
+        let _permit = sem.acquire().await.unwrap();
+
+        // Send to the fallback instead once failover has happened
+        let (url, target) = match &options.failover {
+            Some(failover) => {
+                let (url, target) = failover.target(&job.url);
+                (url.clone(), target)
+            }
+            None => (job.url, job.target),
+        };
+        if let Some(cookie) = jar.as_ref().and_then(|jar| jar.header_value(&url)) {
+            job.headers.push(("Cookie".to_string(), cookie));
+        }
+
+        // Execute the request, timing any connection it has to open
+        let (mut result, connection) = timed(async {
+            match (&options.grpc, options.sse) {
+                (Some((grpc_client, call)), _) => {
+                    grpc_client
+                        .execute(&url, call, &job.headers, job.timeout, job.start_time)
+                        .await
+                }
+                (None, Some(sse)) => {
+                    execute_sse(
+                        &client,
+                        url,
+                        job.method,
+                        &job.headers,
+                        job.basic_auth,
+                        job.timeout,
+                        sse,
+                        &options.classifier,
+                        &is_running,
+                        job.start_time,
+                    )
+                    .await
+                }
+                (None, None) => {
+                    execute_request(
+                        &client,
+                        url,
+                        job.method,
+                        &job.headers,
+                        job.body,
+                        job.basic_auth,
+                        job.timeout,
+                        job.start_time,
+                        &options,
+                        user.as_mut().zip(step),
+                        jar.as_mut(),
+                    )
+                    .await
+                }
+            }
+        })
+        .await;
+        result.target = target;
+        result.step = step;
+        result.dns_lookup_ms = connection.dns_lookup_ms;
+        result.connect_ms = connection.connect_ms;
+        result.tls_handshake_ms = connection.tls_handshake_ms;
+        // Whatever the headers took beyond connection setup was the server's
+        if result.body_read_ms.is_some() {
+            result.ttfb_ms = Some((result.latency_ms - connection.setup_ms()).max(0.0));
+        }
+
+        // Send the result metric
+        let _ = metric_sender.send(result).await;
+    }
+}
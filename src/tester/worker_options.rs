@@ -0,0 +1,133 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::Result;
+use std::sync::{atomic::AtomicBool, Arc};
+use url::Url;
+
+use super::assertions::{BodyChecksum, JsonAssertion};
+use super::capture::{FailureDump, ResponseSample};
+use super::export::OutputSink;
+use super::extract::Extraction;
+use super::failover::Failover;
+use super::grpc::{GrpcCall, GrpcClient};
+use super::oauth::BearerToken;
+use super::pacer::LiveRate;
+use super::proxy::forwarded_proxy_headers;
+use super::scenario::ScenarioStep;
+use super::sse::SseOptions;
+use super::stagger::{Stagger, ThinkTime};
+use super::types::{ResponseClassifier, TestConfig};
+
+/// Per-request behaviour shared by every worker
+pub struct WorkerOptions {
+    /// Rate limit in queries per second per worker (0 for no limit), which may
+    /// change while the test runs
+    pub rate_limit: LiveRate,
+    /// Rules deciding whether a response counts as a success
+    pub classifier: ResponseClassifier,
+    /// Checks evaluated against every response body
+    pub json_assertions: Vec<JsonAssertion>,
+    /// Digest every response body must hash to
+    pub expected_body_sha256: Option<BodyChecksum>,
+    /// Switches requests to a fallback URL after sustained connection errors
+    pub failover: Option<Arc<Failover>>,
+    /// Random start offset and per-request delay for each worker
+    pub stagger: Option<Stagger>,
+    pub think_time: Option<ThinkTime>,
+    /// Test URL and the steps each worker cycles through
+    pub scenario: Option<(Url, Arc<[ScenarioStep]>)>,
+    /// Values each scenario user saves from step responses
+    pub extractions: Arc<[Extraction]>,
+    /// Unary gRPC call sent instead of an HTTP request, with the client to send it
+    pub grpc: Option<(GrpcClient, GrpcCall)>,
+    /// Hold requests open as event streams instead of reading one response
+    pub sse: Option<SseOptions>,
+    /// Extra proxy headers, added to plain HTTP requests a forward proxy reads
+    pub proxy_headers: Vec<(String, String)>,
+    /// Stop downloading each response body after this many bytes
+    pub max_body_bytes: Option<usize>,
+    /// OAuth2 token sent as a Bearer Authorization header
+    pub bearer: Option<BearerToken>,
+    /// Keep a cookie jar per worker, so each is a session of its own
+    pub cookies: bool,
+    /// Workers take no new jobs while this is set
+    pub paused: Arc<AtomicBool>,
+    /// Record why each failed request failed, for `--log-errors`
+    pub log_errors: bool,
+    /// Where the first failed responses are saved, for `--save-failures`
+    pub failure_dump: Option<FailureDump>,
+    /// Where the first responses of the run are written, for `--sample-responses`
+    pub sample: Option<ResponseSample>,
+}
+
+impl WorkerOptions {
+    /// Gather what workers share from the config, with `url` the test URL scenario
+    /// steps resolve against; fails if the `--save-failures` or
+    /// `--sample-responses` output can't be created
+    pub fn new(
+        config: &TestConfig,
+        url: &Url,
+        rate_limit: LiveRate,
+        paused: Arc<AtomicBool>,
+        failover: Option<Arc<Failover>>,
+        bearer: Option<BearerToken>,
+    ) -> Result<Self> {
+        let failure_dump = match &config.save_failures {
+            Some(dir) => Some(FailureDump::create(dir, config.save_failures_limit)?),
+            None => None,
+        };
+        let sample = match config.sample_responses {
+            0 => None,
+            limit => Some(ResponseSample::create(&config.sample_file, limit)?),
+        };
+
+        Ok(WorkerOptions {
+            rate_limit,
+            classifier: config.classifier.clone(),
+            json_assertions: config.json_assertions.clone(),
+            expected_body_sha256: config.expected_body_sha256,
+            failover,
+            stagger: config.stagger,
+            think_time: config.think_time,
+            scenario: (!config.scenario.is_empty())
+                .then(|| (url.clone(), config.scenario.clone().into())),
+            extractions: config.extractions.clone().into(),
+            grpc: config.grpc.clone().map(|call| (GrpcClient::new(), call)),
+            sse: config.sse,
+            proxy_headers: match &config.proxy {
+                Some(_) => forwarded_proxy_headers(&config.proxy_headers),
+                None => Vec::new(),
+            },
+            max_body_bytes: config.max_body_bytes,
+            bearer,
+            cookies: config.cookies,
+            paused,
+            log_errors: config
+                .outputs
+                .iter()
+                .any(|sink| matches!(sink, OutputSink::ErrorLog(_))),
+            failure_dump,
+            sample,
+        })
+    }
+}
@@ -0,0 +1,186 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use reqwest::Client;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use url::Url;
+
+use super::types::{HttpMethod, RequestMetric};
+use super::worker::worker_loop;
+use super::worker_options::WorkerOptions;
+
+/// A request job to be processed by a worker
+pub struct RequestJob {
+    /// URL to send the request to
+    pub url: Url,
+    /// Index into `TestConfig::targets` of the URL
+    pub target: usize,
+    /// HTTP headers to include
+    pub headers: Vec<(String, String)>,
+    /// Request body data
+    pub body: Option<Vec<u8>>,
+    /// Basic authentication credentials
+    pub basic_auth: Option<(String, String)>,
+    /// HTTP method to use
+    pub method: HttpMethod,
+    /// Request timeout in seconds
+    pub timeout: u64,
+    /// The start time of the test (for timestamp calculation)
+    pub start_time: Instant,
+}
+
+/// A worker pool for efficiently processing HTTP requests
+pub struct WorkerPool {
+    #[allow(dead_code)]
+    clients: Vec<Client>,
+    /// Dropped by `drain` so workers exit once the queue is empty
+    job_sender: parking_lot::Mutex<Option<mpsc::Sender<RequestJob>>>,
+    worker_handles: parking_lot::Mutex<Vec<tokio::task::JoinHandle<()>>>,
+    is_running: Arc<AtomicBool>,
+}
+
+impl WorkerPool {
+    /// Create a new worker pool with the given configuration, handing the
+    /// clients out to workers round-robin
+    pub fn new(
+        clients: Vec<Client>,
+        concurrency: usize,
+        metric_sender: mpsc::Sender<RequestMetric>,
+        is_running: Arc<AtomicBool>,
+        options: Arc<WorkerOptions>,
+    ) -> Self {
+        // Create a channel for distributing jobs with much larger buffer
+        let (job_sender, job_receiver) = mpsc::channel::<RequestJob>(concurrency * 100);
+
+        // Share the job receiver among workers
+        let job_receiver = Arc::new(tokio::sync::Mutex::new(job_receiver));
+
+        // Create worker tasks
+        let mut worker_handles = Vec::with_capacity(concurrency);
+
+        for worker_id in 0..concurrency {
+            let worker_client = clients[worker_id % clients.len()].clone();
+            let worker_job_receiver = job_receiver.clone();
+            let worker_metric_sender = metric_sender.clone();
+            let worker_is_running = Arc::clone(&is_running);
+            let worker_options = Arc::clone(&options);
+
+            // Create a semaphore for this worker to control its own concurrency
+            let worker_sem = Arc::new(tokio::sync::Semaphore::new(1));
+
+            // Spawn the worker task
+            let handle = tokio::spawn(async move {
+                worker_loop(
+                    worker_client,
+                    worker_job_receiver,
+                    worker_metric_sender,
+                    worker_is_running,
+                    worker_sem,
+                    worker_options,
+                    worker_id,
+                )
+                .await;
+            });
+
+            worker_handles.push(handle);
+        }
+
+        WorkerPool {
+            clients,
+            job_sender: parking_lot::Mutex::new(Some(job_sender)),
+            worker_handles: parking_lot::Mutex::new(worker_handles),
+            is_running,
+        }
+    }
+
+    /// Submit a job to the worker pool
+    pub async fn submit_job(&self, job: RequestJob) {
+        // Send the job to the worker pool
+        let sender = self.job_sender.lock().clone();
+        if let (true, Some(sender)) = (self.is_running.load(Ordering::SeqCst), sender) {
+            let _ = sender.send(job).await;
+        }
+    }
+
+    /// Try to submit a job to the worker pool without awaiting
+    /// Returns true if the job was submitted, false otherwise
+    #[allow(dead_code)]
+    pub fn try_submit_job(&self, job: RequestJob) -> bool {
+        // Check if we're still running
+        if !self.is_running.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        // Try to send the job to the worker pool
+        self.job_sender
+            .lock()
+            .as_ref()
+            .is_some_and(|sender| sender.try_send(job).is_ok())
+    }
+
+    /// Stop the worker pool
+    pub fn stop(&self) {
+        self.is_running.store(false, Ordering::SeqCst);
+    }
+
+    /// Wait until workers have taken every queued job, or the pool is stopped
+    pub async fn wait_dispatched(&self) {
+        while self.is_running.load(Ordering::SeqCst) {
+            let queued = self
+                .job_sender
+                .lock()
+                .as_ref()
+                .is_some_and(|sender| sender.capacity() < sender.max_capacity());
+            if !queued {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    /// Accept no more jobs and give workers up to `timeout` to finish the
+    /// requests they have in flight. Returns how many were still running.
+    pub async fn drain(&self, timeout: Duration) -> usize {
+        self.job_sender.lock().take();
+
+        let mut handles = std::mem::take(&mut *self.worker_handles.lock());
+        let _ = tokio::time::timeout(timeout, futures::future::join_all(handles.iter_mut())).await;
+        let running = handles
+            .iter()
+            .filter(|handle| !handle.is_finished())
+            .count();
+        *self.worker_handles.lock() = handles;
+        running
+    }
+
+    /// Cancel any workers still running, dropping their requests
+    pub fn abort(&self) {
+        for handle in self.worker_handles.lock().iter() {
+            handle.abort();
+        }
+    }
+}
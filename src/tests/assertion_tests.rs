@@ -0,0 +1,194 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    BodyChecksum, JsonAssertion, SharedState, TestConfig, TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::sleep;
+
+const BODY: &[u8] = br#"{"status": "ok", "items": [{"id": 42, "tags": ["a"]}], "count": 1.5}"#;
+
+#[test]
+fn test_json_assertion_matches_values() {
+    for expression in [
+        r#"$.status == "ok""#,
+        "$.status == ok",
+        "$.items[0].id == 42",
+        r#"$.items[0].tags[0] == "a""#,
+        "$.count == 1.5",
+        "$.status != down",
+        r#"$.status != "a==b""#,
+    ] {
+        let assertion = JsonAssertion::parse(expression).unwrap();
+        assert!(assertion.check(BODY), "{expression} should pass");
+    }
+}
+
+#[test]
+fn test_json_assertion_failures() {
+    for expression in [
+        "$.status == down",
+        "$.items[0].id == 43",
+        "$.items[3].id == 42",
+        "$.missing == 1",
+        "$.status != ok",
+    ] {
+        let assertion = JsonAssertion::parse(expression).unwrap();
+        assert!(!assertion.check(BODY), "{expression} should fail");
+    }
+
+    // Bodies that are not JSON never satisfy an assertion
+    let assertion = JsonAssertion::parse("$.status == ok").unwrap();
+    assert!(!assertion.check(b"Hello, World!"));
+}
+
+#[test]
+fn test_json_assertion_parse_errors() {
+    assert!(JsonAssertion::parse("$.status").is_err());
+    assert!(JsonAssertion::parse("status == ok").is_err());
+    assert!(JsonAssertion::parse("$..status == ok").is_err());
+    assert!(JsonAssertion::parse("$.items[x] == 1").is_err());
+    assert!(JsonAssertion::parse("$.items[0 == 1").is_err());
+    assert!(JsonAssertion::parse(r#"$.my key == 1"#).is_err());
+    assert!(JsonAssertion::parse(r#"$."status" == ok"#).is_err());
+
+    // The value may contain the other operator
+    let assertion = JsonAssertion::parse(r#"$.msg == "a!=b""#).unwrap();
+    assert!(assertion.check(br#"{"msg": "a!=b"}"#));
+    assert_eq!(assertion.expression(), r#"$.msg == "a!=b""#);
+}
+
+#[test]
+fn test_assert_json_arg() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from([
+        "test",
+        "http://example.com",
+        "--assert-json",
+        "$.status == ok",
+        "--assert-json",
+        "$.count == 2",
+    ]);
+    assert_eq!(args.checks.assert_json.len(), 2);
+    assert_eq!(args.checks.assert_json[0].expression(), "$.status == ok");
+
+    assert!(Args::try_parse_from(["test", "http://example.com", "--assert-json", "$.x"]).is_err());
+}
+
+#[tokio::test]
+async fn test_runner_counts_assertion_failures_separately() {
+    let server = MockServer::start().await;
+    server.set_response_body(r#"{"status": "degraded"}"#);
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 10,
+        concurrent: 2,
+        timeout: 1,
+        interactive: false,
+        json_assertions: vec![JsonAssertion::parse("$.status == ok").unwrap()],
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert!(test_state.completed_requests > 0);
+    assert_eq!(test_state.error_count, 0);
+    assert_eq!(test_state.assertion_failures, test_state.completed_requests);
+}
+
+/// SHA-256 of the mock server's default "Hello, World!" body
+const HELLO_SHA256: &str = "dffd6021bb2bd5b0af676290809ec3a53191dd81c7f70a4b28688a362182986f";
+
+#[test]
+fn test_body_checksum() {
+    let checksum = BodyChecksum::parse(HELLO_SHA256).unwrap();
+    assert!(checksum.matches(b"Hello, World!"));
+    assert!(!checksum.matches(b"Hello, World"));
+
+    // Upper-case hex is accepted too
+    assert_eq!(
+        BodyChecksum::parse(&HELLO_SHA256.to_uppercase()).unwrap(),
+        checksum
+    );
+
+    assert!(BodyChecksum::parse("abc").is_err());
+    assert!(BodyChecksum::parse(&"zz".repeat(32)).is_err());
+}
+
+#[tokio::test]
+async fn test_runner_flags_checksum_mismatches() {
+    let server = MockServer::start().await;
+    server.set_response_body("Hello, Wor");
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 10,
+        concurrent: 2,
+        timeout: 1,
+        interactive: false,
+        expected_body_sha256: Some(BodyChecksum::parse(HELLO_SHA256).unwrap()),
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert!(test_state.completed_requests > 0);
+    assert_eq!(test_state.error_count, 0);
+    assert_eq!(
+        test_state.checksum_mismatches,
+        test_state.completed_requests
+    );
+}
@@ -0,0 +1,103 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{CircuitBreaker, ErrorBreaker, LatencyBreaker, RequestMetric, StopReason};
+
+fn metric(timestamp: f64, latency_ms: f64) -> RequestMetric {
+    RequestMetric {
+        timestamp,
+        latency_ms,
+        status_code: 200,
+        is_error: false,
+        bytes_sent: 0,
+        bytes_received: 0,
+        ..Default::default()
+    }
+}
+
+fn breaker(consecutive_secs: u64) -> LatencyBreaker {
+    LatencyBreaker::new(CircuitBreaker {
+        p99_limit_ms: 100.0,
+        consecutive_secs,
+    })
+}
+
+#[test]
+fn test_breaker_trips_after_consecutive_slow_windows() {
+    let mut breaker = breaker(2);
+
+    assert!(breaker.observe(&metric(0.1, 150.0)).is_none());
+    assert!(breaker.observe(&metric(1.1, 150.0)).is_none());
+    // Closing the second slow window trips the breaker
+    let reason = breaker.observe(&metric(2.1, 150.0));
+    assert_eq!(
+        reason,
+        Some(StopReason::LatencyBreaker {
+            limit_ms: 100.0,
+            seconds: 2
+        })
+    );
+    assert!(breaker.is_tripped());
+}
+
+#[test]
+fn test_breaker_resets_on_fast_window() {
+    let mut breaker = breaker(2);
+
+    breaker.observe(&metric(0.1, 150.0));
+    breaker.observe(&metric(1.1, 10.0));
+    breaker.observe(&metric(2.1, 150.0));
+    breaker.observe(&metric(3.1, 150.0));
+
+    // Window 0 was slow, window 1 fast, window 2 slow: no two consecutive yet
+    assert!(!breaker.is_tripped());
+}
+
+#[test]
+fn test_breaker_ignores_within_window_spikes() {
+    let mut breaker = breaker(1);
+
+    // A single slow request among many fast ones keeps p99 under the limit
+    for i in 0..200 {
+        breaker.observe(&metric(0.001 * i as f64, 5.0));
+    }
+    breaker.observe(&metric(0.5, 500.0));
+
+    assert!(breaker.observe(&metric(1.5, 5.0)).is_none());
+}
+
+#[test]
+fn test_error_breaker_trips_at_limit() {
+    let mut breaker = ErrorBreaker::new(2);
+    let failed = RequestMetric {
+        is_error: true,
+        ..metric(0.1, 5.0)
+    };
+
+    assert!(breaker.observe(&failed).is_none());
+    // Successes in between don't reset the count
+    assert!(breaker.observe(&metric(0.2, 5.0)).is_none());
+    assert_eq!(
+        breaker.observe(&failed),
+        Some(StopReason::ErrorLimit { errors: 2 })
+    );
+}
@@ -0,0 +1,88 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{HttpMethod, TestConfig};
+
+#[test]
+fn test_builder_starts_from_defaults() {
+    let config = TestConfig::builder()
+        .url("http://localhost:8080/")
+        .build()
+        .unwrap();
+    let defaults = TestConfig::default();
+
+    assert_eq!(config.url, "http://localhost:8080/");
+    assert_eq!(config.requests, defaults.requests);
+    assert_eq!(config.concurrent, defaults.concurrent);
+    assert_eq!(config.timeout, defaults.timeout);
+    assert_eq!(config.user_agent, defaults.user_agent);
+}
+
+#[test]
+fn test_builder_sets_fields() {
+    let config = TestConfig::builder()
+        .url("https://example.com/api")
+        .method(HttpMethod::POST)
+        .requests(0)
+        .concurrent(4)
+        .duration("2m")
+        .timeout("5s")
+        .rate_limit(12.5)
+        .header("X-Trace", "1")
+        .body("{}")
+        .basic_auth("user:p:ss")
+        .interactive(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.method, HttpMethod::POST);
+    assert_eq!(config.requests, 0);
+    assert_eq!(config.concurrent, 4);
+    assert_eq!(config.duration, 120);
+    assert_eq!(config.timeout, 5);
+    assert_eq!(config.rate_limit, 12.5);
+    assert_eq!(
+        config.headers,
+        vec![("X-Trace".to_string(), "1".to_string())]
+    );
+    assert_eq!(config.body, Some(b"{}".to_vec()));
+    assert_eq!(
+        config.basic_auth,
+        Some(("user".to_string(), "p:ss".to_string()))
+    );
+    assert!(!config.interactive);
+}
+
+#[test]
+fn test_builder_rejects_invalid_settings() {
+    let base = || TestConfig::builder().url("http://localhost/");
+
+    assert!(TestConfig::builder().build().is_err());
+    assert!(TestConfig::builder()
+        .url("ftp://localhost/")
+        .build()
+        .is_err());
+    assert!(base().duration("ten").build().is_err());
+    assert!(base().timeout("5x").build().is_err());
+    assert!(base().basic_auth("nopassword").build().is_err());
+    assert!(base().concurrent(0).build().is_err());
+    assert!(base().rate_limit(-1.0).build().is_err());
+}
@@ -0,0 +1,148 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::capacity::{self, print_capacity_report, CapacitySearch, SearchStrategy};
+use crate::headless::Verbosity;
+use crate::sweep::StageResult;
+use crate::tester::TestConfig;
+use crate::tests::MockServer;
+
+/// Run a search against a server that copes with up to `capacity` concurrent requests
+fn search(strategy: SearchStrategy, max: usize, capacity: usize) -> (Vec<usize>, Option<usize>) {
+    let mut search = CapacitySearch::new(strategy, max);
+    let mut tried = Vec::new();
+    while let Some(level) = search.next_level() {
+        assert!(!tried.contains(&level), "{level} tried twice");
+        tried.push(level);
+        search.record(level, level <= capacity);
+    }
+    (tried, search.best())
+}
+
+fn result(p99_ms: f64, errors: usize) -> StageResult {
+    StageResult {
+        label: String::new(),
+        completed: 100,
+        errors,
+        requests_per_sec: 250.0,
+        average_ms: p99_ms / 2.0,
+        p99_ms,
+    }
+}
+
+#[test]
+fn test_search_strategy_parse() {
+    assert_eq!(
+        SearchStrategy::parse("binary").unwrap(),
+        SearchStrategy::Binary
+    );
+    assert_eq!(SearchStrategy::parse("aimd").unwrap(), SearchStrategy::Aimd);
+    assert!(SearchStrategy::parse("linear").is_err());
+}
+
+#[test]
+fn test_binary_search_finds_capacity() {
+    let (tried, best) = search(SearchStrategy::Binary, 100, 37);
+    assert_eq!(best, Some(37));
+    assert_eq!(tried[0], 50);
+    // A binary search over 100 levels needs no more than 7 steps
+    assert!(tried.len() <= 7, "{tried:?}");
+}
+
+#[test]
+fn test_aimd_search_finds_capacity() {
+    let (tried, best) = search(SearchStrategy::Aimd, 100, 37);
+    assert_eq!(best, Some(37));
+    assert_eq!(&tried[..3], &[1, 11, 21]);
+}
+
+#[test]
+fn test_search_edges() {
+    for strategy in [SearchStrategy::Binary, SearchStrategy::Aimd] {
+        assert_eq!(search(strategy, 100, 0).1, None);
+        assert_eq!(search(strategy, 100, 1000).1, Some(100));
+        assert_eq!(search(strategy, 1, 1).1, Some(1));
+    }
+}
+
+#[test]
+fn test_meets_slo() {
+    assert!(capacity::meets_slo(&result(150.0, 1), 200.0));
+    assert!(!capacity::meets_slo(&result(250.0, 0), 200.0));
+    // More than 1% errors breaks the SLO however fast the responses were
+    assert!(!capacity::meets_slo(&result(10.0, 2), 200.0));
+}
+
+#[test]
+fn test_print_capacity_report() {
+    let results = [(50, result(300.0, 0)), (25, result(120.0, 0))];
+
+    let mut out = Vec::new();
+    print_capacity_report(&mut out, 200.0, &results, Some(25)).unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    assert!(out.contains("Capacity search (p99 <= 200 ms):"));
+    assert!(out.contains("  [c=50]\t250.0000 req/s, p99 300.000 ms, 0 errors\tover SLO"));
+    assert!(out.contains("  [c=25]\t250.0000 req/s, p99 120.000 ms, 0 errors\tok"));
+    assert!(out.contains("Capacity: 25 concurrent, 250.0000 req/s at p99 120.000 ms"));
+
+    let mut out = Vec::new();
+    print_capacity_report(&mut out, 200.0, &results[..1], None).unwrap();
+    assert!(String::from_utf8(out)
+        .unwrap()
+        .contains("no concurrency level met the SLO"));
+}
+
+#[test]
+fn test_capacity_args() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from(["test", "http://example.com", "--find-capacity", "200"]);
+    assert_eq!(args.sweeps.find_capacity, Some(200.0));
+    assert_eq!(args.sweeps.capacity_search, SearchStrategy::Binary);
+
+    // The search strategy only makes sense with a capacity search
+    assert!(
+        Args::try_parse_from(["test", "http://example.com", "--capacity-search", "aimd"]).is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_capacity_search_runs_each_level() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        concurrent: 2,
+        duration: 1,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    capacity::run(config, 1000.0, SearchStrategy::Binary, Verbosity::Quiet)
+        .await
+        .expect("Capacity search failed");
+
+    // Levels 1 and 2 both run for their full second
+    assert!(server.request_count() > 2);
+}
@@ -0,0 +1,190 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    format_response, FailureDump, ResponseHead, ResponseSample, SharedState, TestConfig, TestState,
+    UnifiedRunner,
+};
+use crate::tests::MockServer;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{StatusCode, Version};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::sleep;
+
+fn temp_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("whambam-{}-{name}", std::process::id()))
+}
+
+fn json_head(status: StatusCode) -> ResponseHead {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    ResponseHead {
+        version: Version::HTTP_11,
+        status,
+        headers,
+    }
+}
+
+async fn run_to_completion(config: TestConfig) {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+}
+
+#[test]
+fn test_format_response() {
+    let response = format_response(
+        &json_head(StatusCode::SERVICE_UNAVAILABLE),
+        br#"{"error":"overloaded"}"#,
+    );
+    assert_eq!(
+        String::from_utf8(response).unwrap(),
+        "HTTP/1.1 503 Service Unavailable\ncontent-type: application/json\n\n{\"error\":\"overloaded\"}"
+    );
+}
+
+#[test]
+fn test_failure_dump_stops_at_limit() {
+    let dir = temp_dir("claim");
+    let dump = FailureDump::create(&dir, 2).unwrap();
+    assert!(dir.is_dir());
+
+    assert_eq!(
+        dump.claim(StatusCode::NOT_FOUND),
+        Some(dir.join("failure-0001-404.txt"))
+    );
+    assert_eq!(
+        dump.claim(StatusCode::BAD_GATEWAY),
+        Some(dir.join("failure-0002-502.txt"))
+    );
+    assert_eq!(dump.claim(StatusCode::BAD_GATEWAY), None);
+
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_failure_dump_rejects_unusable_directory() {
+    let err = FailureDump::create("/proc/whambam-failures".as_ref(), 1).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Failed to create failure directory"));
+}
+
+#[tokio::test]
+async fn test_runner_saves_failed_responses() {
+    let server = MockServer::start().await;
+    server.set_response_status(500);
+    server.set_response_body("database unavailable");
+    server.add_response_header("X-Request-Id: abc123");
+    let dir = temp_dir("failures");
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 5,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        save_failures: Some(dir.clone()),
+        save_failures_limit: 3,
+        ..Default::default()
+    };
+    run_to_completion(config).await;
+
+    let mut saved: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    saved.sort();
+    assert_eq!(saved.len(), 3);
+    assert!(saved[0].ends_with("failure-0001-500.txt"));
+
+    let response = fs::read_to_string(&saved[0]).unwrap();
+    assert!(response.starts_with("HTTP/1.1 500 Internal Server Error\n"));
+    assert!(response.contains("x-request-id: abc123\n"));
+    assert!(response.ends_with("\n\ndatabase unavailable"));
+
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_response_sample_numbers_entries() {
+    let path = temp_dir("sample.txt");
+    let sample = ResponseSample::create(&path, 1).unwrap();
+
+    let number = sample.claim().unwrap();
+    assert_eq!(number, 1);
+    assert_eq!(sample.claim(), None);
+
+    sample.write(
+        number,
+        "GET http://example.com/",
+        &json_head(StatusCode::OK),
+        b"{}",
+    );
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "### 1: GET http://example.com/\nHTTP/1.1 200 OK\ncontent-type: application/json\n\n{}\n\n"
+    );
+
+    let _ = fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn test_runner_samples_first_responses() {
+    let server = MockServer::start().await;
+    server.set_response_body("hello");
+    let path = temp_dir("samples.txt");
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 5,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        sample_responses: 2,
+        sample_file: path.clone(),
+        ..Default::default()
+    };
+    run_to_completion(config).await;
+
+    let samples = fs::read_to_string(&path).unwrap();
+    assert_eq!(samples.matches("HTTP/1.1 200 OK\n").count(), 2);
+    assert!(samples.starts_with(&format!("### 1: GET {}", server.url())));
+    assert!(samples.contains("\n\nhello\n\n### 2: GET "));
+
+    let _ = fs::remove_file(path);
+}
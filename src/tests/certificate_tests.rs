@@ -0,0 +1,96 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{print_hey_format_report, CertificateInfo, TestConfig, TestReport, TestState};
+use crate::tests::tls_server::SERVER_CERT;
+use chrono::{Duration, TimeZone, Utc};
+
+fn server_cert_der() -> Vec<u8> {
+    rustls_pemfile::certs(&mut SERVER_CERT.as_bytes())
+        .unwrap()
+        .remove(0)
+}
+
+#[test]
+fn test_parse_certificate() {
+    let cert = CertificateInfo::from_der(&server_cert_der()).unwrap();
+    assert_eq!(cert.subject, "CN=localhost");
+    assert_eq!(cert.issuer, "CN=whambam test CA");
+    assert_eq!(
+        cert.not_after,
+        Utc.with_ymd_and_hms(2126, 9, 22, 1, 12, 4).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_rejects_truncated_certificate() {
+    let der = server_cert_der();
+    assert!(CertificateInfo::from_der(&der[..der.len() / 2]).is_err());
+    assert!(CertificateInfo::from_der(&[]).is_err());
+}
+
+#[test]
+fn test_report_warns_about_expiring_certificates() {
+    let mut state = TestState::new(&TestConfig::default());
+    let now = Utc::now();
+    state.certificates = vec![
+        CertificateInfo {
+            subject: "CN=api.example.com".to_string(),
+            issuer: "CN=Example CA".to_string(),
+            not_after: now + Duration::days(5) + Duration::hours(1),
+        },
+        CertificateInfo {
+            subject: "CN=Example CA".to_string(),
+            issuer: "CN=Example CA".to_string(),
+            not_after: now + Duration::days(3650),
+        },
+    ];
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(output.contains("Certificate chain:"));
+    assert!(output.contains("  [0] CN=api.example.com\n      Issuer:\tCN=Example CA"));
+    assert!(output.contains("  [1] CN=Example CA"));
+    assert!(output.contains("  Warning: CN=api.example.com expires in 5 days"));
+    assert!(!output.contains("Warning: CN=Example CA"));
+}
+
+#[test]
+fn test_report_json_keeps_certificates() {
+    let mut state = TestState::new(&TestConfig::default());
+    state.certificates = vec![CertificateInfo::from_der(&server_cert_der()).unwrap()];
+    state.record_event("Switched to fallback");
+
+    let report = TestReport::from_state(&state);
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        json["certificates"][0]["not_after"],
+        "2126-09-22T01:12:04+00:00"
+    );
+    assert_eq!(json["events"][0]["message"], "Switched to fallback");
+
+    let parsed: TestReport = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.certificates, report.certificates);
+    assert_eq!(parsed.events, report.events);
+}
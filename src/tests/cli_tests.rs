@@ -20,9 +20,9 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::parse_http_method;
-use crate::HttpMethod;
-use clap::Parser;
+use crate::args::parse_http_method;
+use crate::tester::{HttpMethod, Theme};
+use clap::{CommandFactory, Parser};
 
 #[test]
 fn test_parse_http_method() {
@@ -34,13 +34,27 @@ fn test_parse_http_method() {
     assert_eq!(parse_http_method("DELETE").unwrap(), HttpMethod::DELETE);
     assert_eq!(parse_http_method("HEAD").unwrap(), HttpMethod::HEAD);
     assert_eq!(parse_http_method("OPTIONS").unwrap(), HttpMethod::OPTIONS);
+    assert_eq!(parse_http_method("patch").unwrap(), HttpMethod::PATCH);
+    assert_eq!(parse_http_method("TRACE").unwrap(), HttpMethod::TRACE);
 
-    assert!(parse_http_method("INVALID").is_err());
+    // Unknown verbs pass through, upper-cased
+    assert_eq!(
+        parse_http_method("purge").unwrap(),
+        HttpMethod::Custom("PURGE".into())
+    );
+    assert_eq!(
+        parse_http_method("VERSION-CONTROL").unwrap().to_string(),
+        "VERSION-CONTROL"
+    );
+
+    assert!(parse_http_method("").is_err());
+    assert!(parse_http_method("NOT A METHOD").is_err());
+    assert!(parse_http_method("GET/1").is_err());
 }
 
 #[test]
 fn test_parse_duration() {
-    use crate::parse_duration;
+    use crate::tester::parse_duration;
 
     // Test seconds
     assert_eq!(parse_duration("10s").unwrap(), 10);
@@ -68,6 +82,11 @@ fn test_parse_duration() {
     assert!(parse_duration("5.5s").is_err()); // Fractional seconds
     assert!(parse_duration("m").is_err()); // Missing number
     assert!(parse_duration("").is_err()); // Empty string
+    assert!(parse_duration("5é").is_err()); // Multi-byte unit
+    assert_eq!(
+        parse_duration("9999999999999999h").unwrap_err().to_string(),
+        "Duration too large: 9999999999999999h"
+    );
 }
 
 #[test]
@@ -77,17 +96,18 @@ fn test_args_default_values() {
     let args = Args::parse_from(["test", "http://example.com"]);
 
     assert_eq!(args.url, "http://example.com");
-    assert_eq!(args.requests, 200);
+    assert_eq!(args.requests, None);
     assert_eq!(args.concurrent, 50);
     assert_eq!(args.duration_str, "0");
     assert_eq!(args.rate_limit, 0.0);
     assert_eq!(args.headers.len(), 0);
     assert_eq!(args.timeout, 20);
-    assert!(!args.no_ui);
-    assert_eq!(args.content_type, "text/html");
-    assert!(!args.disable_compression);
-    assert!(!args.disable_keepalive);
-    assert!(!args.disable_redirects);
+    assert!(!args.output.no_ui);
+    assert_eq!(args.body.content_type, "text/html");
+    assert!(!args.connection.disable_compression);
+    assert!(!args.connection.disable_keepalive);
+    assert!(!args.connection.disable_redirects);
+    assert!(!args.connection.http2_prior_knowledge);
 }
 
 #[test]
@@ -128,19 +148,501 @@ fn test_args_custom_values() {
     ]);
 
     assert_eq!(args.url, "https://example.org");
-    assert_eq!(args.requests, 100);
+    assert_eq!(args.requests, Some(100));
     assert_eq!(args.concurrent, 25);
     assert_eq!(args.duration_str, "30s");
     assert_eq!(args.rate_limit, 10.5);
     assert_eq!(args.headers, vec!["X-Test: value".to_string()]);
     assert_eq!(args.timeout, 30);
-    assert!(args.no_ui);
-    assert_eq!(args.content_type, "application/json");
-    assert!(args.disable_compression);
-    assert!(args.disable_keepalive);
-    assert!(args.disable_redirects);
-    assert_eq!(args.body, Some("test-body".to_string()));
+    assert!(args.output.no_ui);
+    assert_eq!(args.body.content_type, "application/json");
+    assert!(args.connection.disable_compression);
+    assert!(args.connection.disable_keepalive);
+    assert!(args.connection.disable_redirects);
+    assert_eq!(args.body.body, Some("test-body".to_string()));
     assert_eq!(args.accept, Some("application/json".to_string()));
-    assert_eq!(args.basic_auth, Some("user:pass".to_string()));
-    assert_eq!(args.proxy, Some("localhost:8080".to_string()));
+    assert_eq!(args.auth.basic_auth, Some("user:pass".to_string()));
+    assert_eq!(args.proxy.proxy, Some("localhost:8080".to_string()));
+}
+
+#[test]
+fn test_grpc_subcommand() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "grpc",
+        "http://localhost:50051",
+        "--call",
+        "echo.Echo/Say",
+        "-d",
+        r#"{"text":"hi"}"#,
+        "-c",
+        "4",
+    ]);
+
+    let Some(crate::args::Command::Grpc(grpc)) = args.command else {
+        panic!("expected the grpc subcommand");
+    };
+    assert_eq!(grpc.url, "http://localhost:50051");
+    assert_eq!(grpc.call, "echo.Echo/Say");
+    assert_eq!(grpc.data, r#"{"text":"hi"}"#);
+    assert_eq!(grpc.concurrent, 4);
+    assert_eq!(grpc.proto, None);
+    assert_eq!(grpc.requests, None);
+
+    // The URL is still required without a subcommand
+    assert!(crate::Args::try_parse_from(["whambam"]).is_err());
+}
+
+#[test]
+fn test_option_groups_are_one_command() {
+    let command = crate::Args::command();
+    command.clone().debug_assert();
+
+    let heading = |id: &str| {
+        let arg = command.get_arguments().find(|arg| arg.get_id() == id);
+        arg.unwrap().get_help_heading()
+    };
+    assert_eq!(heading("url_order"), None);
+    assert_eq!(heading("insecure"), Some("TLS"));
+    assert_eq!(heading("burst"), Some("Load shaping"));
+    assert_eq!(heading("json"), Some("Output"));
+    assert_eq!(heading("body_random"), Some("Request body"));
+}
+
+#[test]
+fn test_insecure_flag() {
+    let args = crate::Args::parse_from(["whambam", "https://staging.internal", "-k"]);
+    assert!(args.tls.insecure);
+
+    let args = crate::Args::parse_from(["whambam", "https://staging.internal"]);
+    assert!(!args.tls.insecure);
+}
+
+#[test]
+fn test_dns_cache_flags() {
+    let args = crate::Args::parse_from(["whambam", "https://x", "--dns-cache-ttl", "30s"]);
+    assert_eq!(args.dns.dns_cache_ttl, Some(30));
+    assert!(!args.dns.no_dns_cache);
+
+    assert!(crate::Args::try_parse_from([
+        "whambam",
+        "https://x",
+        "--dns-cache-ttl",
+        "30s",
+        "--no-dns-cache"
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_stages_replace_duration_and_requests() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--stage",
+        "60s:100rps",
+        "--stage",
+        "2m:500rps",
+    ]);
+    assert_eq!(args.load.stages.len(), 2);
+
+    for conflicting in [["-z", "10s"], ["-n", "50"]] {
+        let mut argv = vec!["whambam", "http://x", "--stage", "60s:100rps"];
+        argv.extend(conflicting);
+        assert!(crate::Args::try_parse_from(argv).is_err());
+    }
+}
+
+#[test]
+fn test_burst_flags() {
+    let args = crate::Args::parse_from(["whambam", "http://x", "--burst", "50"]);
+    assert_eq!(args.load.burst, Some(50));
+    assert_eq!(args.load.burst_interval, 5);
+
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--burst",
+        "50",
+        "--burst-interval",
+        "1m",
+    ]);
+    assert_eq!(args.load.burst_interval, 60);
+
+    assert!(crate::Args::try_parse_from(["whambam", "http://x", "--burst", "0"]).is_err());
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--burst-interval", "10s"]).is_err()
+    );
+}
+
+#[test]
+fn test_pattern_flag() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--pattern",
+        "sine:period=60s,min=50,max=500",
+    ]);
+    assert!(args.load.pattern.is_some());
+
+    assert!(crate::Args::try_parse_from([
+        "whambam",
+        "http://x",
+        "--pattern",
+        "sine:period=60s,min=50,max=500",
+        "--burst",
+        "10"
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_local_address_repeats() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--local-address",
+        "10.0.0.1",
+        "--local-address",
+        "::1",
+    ]);
+    assert_eq!(args.dns.local_address.len(), 2);
+    assert!(args.dns.local_address[1].is_ipv6());
+
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--local-address", "nope"]).is_err()
+    );
+}
+
+#[test]
+fn test_client_cert_needs_key() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "https://mtls.internal",
+        "--cert",
+        "client.pem",
+        "--key",
+        "client.key",
+    ]);
+    assert_eq!(args.tls.cert, Some("client.pem".into()));
+    assert_eq!(args.tls.key, Some("client.key".into()));
+
+    assert!(crate::Args::try_parse_from(["whambam", "https://x", "--cert", "c.pem"]).is_err());
+    assert!(crate::Args::try_parse_from(["whambam", "https://x", "--key", "c.key"]).is_err());
+}
+
+#[test]
+fn test_data_urlencode_builds_form_body() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--data-urlencode",
+        "name=Jane Doe",
+        "--data-urlencode",
+        "q=a&b=c",
+        "--data-urlencode",
+        "=café",
+    ]);
+    assert_eq!(
+        args.body.form_body().as_deref(),
+        Some("name=Jane+Doe&q=a%26b%3Dc&caf%C3%A9")
+    );
+    assert_eq!(
+        args.body.content_type(),
+        "application/x-www-form-urlencoded"
+    );
+
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert_eq!(args.body.form_body(), None);
+    assert_eq!(args.body.content_type(), "text/html");
+
+    for conflict in [["-d", "raw"], ["-T", "text/plain"]] {
+        let mut argv = vec!["whambam", "http://x", "--data-urlencode", "a=b"];
+        argv.extend(conflict);
+        assert!(crate::Args::try_parse_from(argv).is_err());
+    }
+}
+
+#[test]
+fn test_compress_body_flag() {
+    use crate::tester::BodyEncoding;
+
+    let args =
+        crate::Args::parse_from(["whambam", "http://x", "-d", "hi", "--compress-body", "br"]);
+    assert_eq!(args.body.compress_body, Some(BodyEncoding::Brotli));
+
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--compress-body", "lz4"]).is_err()
+    );
+    assert!(crate::Args::try_parse_from([
+        "whambam",
+        "http://x",
+        "--compress-body",
+        "gzip",
+        "--step",
+        "home GET /",
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_body_random_flags() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--body-random",
+        "16KB",
+        "--body-random-seed",
+        "9",
+        "--body-random-per-request",
+    ]);
+    assert_eq!(args.body.body_random, Some(16 * 1024));
+    assert_eq!(args.body.body_random_seed, Some(9));
+    assert!(args.body.body_random_per_request);
+
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--body-random-seed", "9"]).is_err()
+    );
+    assert!(crate::Args::try_parse_from([
+        "whambam",
+        "http://x",
+        "--body-random",
+        "1KB",
+        "-d",
+        "x"
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_oauth_flags_go_together() {
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--oauth-token-url",
+        "https://auth.example/token",
+        "--oauth-client-id",
+        "app",
+        "--oauth-client-secret",
+        "s3cret",
+        "--oauth-scope",
+        "read",
+    ]);
+    assert_eq!(args.auth.oauth_scopes, ["read"]);
+
+    let incomplete = ["whambam", "http://x", "--oauth-token-url", "https://a/t"];
+    assert!(crate::Args::try_parse_from(incomplete).is_err());
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--oauth-client-id", "app"]).is_err()
+    );
+}
+
+#[test]
+fn test_session_flags() {
+    let args =
+        crate::Args::parse_from(["whambam", "http://x", "--cookies", "--isolate-connections"]);
+    assert!(args.connection.cookies);
+    assert!(args.connection.isolate_connections);
+
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert!(!args.connection.cookies);
+    assert!(!args.connection.isolate_connections);
+}
+
+#[test]
+fn test_user_agent_flag() {
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert_eq!(args.user_agent, crate::tester::DEFAULT_USER_AGENT);
+
+    let args = crate::Args::parse_from(["whambam", "http://x", "-U", "probe/1"]);
+    assert_eq!(args.user_agent, "probe/1");
+}
+
+#[test]
+fn test_user_agent_file_replaces_user_agent() {
+    let args = crate::Args::parse_from(["whambam", "http://x", "--user-agent-file", "agents.txt"]);
+    assert_eq!(args.user_agent_file, Some("agents.txt".into()));
+
+    let both = [
+        "whambam",
+        "http://x",
+        "-U",
+        "a",
+        "--user-agent-file",
+        "agents.txt",
+    ];
+    assert!(crate::Args::try_parse_from(both).is_err());
+}
+
+#[test]
+fn test_host_flag() {
+    let args = crate::Args::parse_from(["whambam", "http://10.0.0.5", "--host", "shop.example"]);
+    assert_eq!(args.host.as_deref(), Some("shop.example"));
+}
+
+#[test]
+fn test_headers_from_file() {
+    use crate::cli::parse_headers;
+
+    let path = std::env::temp_dir().join(format!("whambam-{}-headers.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "# API headers\nAuthorization: Bearer abc\n\nX-Tenant: acme\nnot a header\n",
+    )
+    .unwrap();
+
+    let headers = parse_headers(&[
+        "Accept: application/json".to_string(),
+        format!("@{}", path.display()),
+    ])
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        headers,
+        [
+            ("Accept".to_string(), "application/json".to_string()),
+            ("Authorization".to_string(), "Bearer abc".to_string()),
+            ("X-Tenant".to_string(), "acme".to_string()),
+        ]
+    );
+
+    assert!(parse_headers(&["@/nonexistent/whambam-headers.txt".to_string()]).is_err());
+}
+
+#[test]
+fn test_theme_flags() {
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert_eq!(args.output.theme, Theme::Default);
+    assert!(!args.output.no_color);
+
+    let args = crate::Args::parse_from(["whambam", "http://x", "--theme", "light"]);
+    assert_eq!(args.output.theme, Theme::Light);
+
+    let args = crate::Args::parse_from(["whambam", "http://x", "--no-color"]);
+    assert!(args.output.no_color);
+
+    assert!(crate::Args::try_parse_from(["whambam", "http://x", "--theme", "neon"]).is_err());
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--theme", "dark", "--no-color"])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_interval_flag() {
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert_eq!(args.output.interval, 5);
+
+    let args = crate::Args::parse_from(["whambam", "http://x", "--no-ui", "--interval", "1m"]);
+    assert_eq!(args.output.interval, 60);
+
+    for argv in [
+        ["whambam", "http://x", "--interval", "0"],
+        ["whambam", "http://x", "--interval", "soon"],
+    ] {
+        assert!(crate::Args::try_parse_from(argv).is_err());
+    }
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--quiet", "--interval", "2s"])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_save_failures_flags() {
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert_eq!(args.output.save_failures, None);
+    assert_eq!(args.output.save_failures_limit, 10);
+
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--save-failures",
+        "failures",
+        "--save-failures-limit",
+        "3",
+    ]);
+    assert_eq!(args.output.save_failures, Some("failures".into()));
+    assert_eq!(args.output.save_failures_limit, 3);
+
+    // A limit on its own saves nothing, so it needs a directory
+    assert!(
+        crate::Args::try_parse_from(["whambam", "http://x", "--save-failures-limit", "3"]).is_err()
+    );
+}
+
+#[test]
+fn test_sample_responses_flags() {
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert_eq!(args.output.sample_responses, 0);
+    assert_eq!(
+        args.output.sample_file,
+        std::path::PathBuf::from("whambam-samples.txt")
+    );
+
+    let args = crate::Args::parse_from([
+        "whambam",
+        "http://x",
+        "--sample-responses",
+        "3",
+        "--sample-file",
+        "first.txt",
+    ]);
+    assert_eq!(args.output.sample_responses, 3);
+    assert_eq!(
+        args.output.sample_file,
+        std::path::PathBuf::from("first.txt")
+    );
+}
+
+#[test]
+fn test_zero_requests_is_unlimited() {
+    // -n 0 without -z runs indefinitely rather than sending -c requests
+    let args = crate::Args::parse_from(["whambam", "http://x", "-n", "0", "-c", "50"]);
+    assert_eq!(
+        crate::cli::request_limit(args.requests, args.concurrent, 0),
+        0
+    );
+
+    // A positive count is still raised to at least the concurrency
+    let args = crate::Args::parse_from(["whambam", "http://x", "-n", "10", "-c", "50"]);
+    assert_eq!(
+        crate::cli::request_limit(args.requests, args.concurrent, 0),
+        50
+    );
+
+    let args = crate::Args::parse_from(["whambam", "http://x"]);
+    assert_eq!(
+        crate::cli::request_limit(args.requests, args.concurrent, 0),
+        crate::validate::DEFAULT_REQUESTS
+    );
+}
+
+#[test]
+fn test_subcommand_zero_requests_is_unlimited() {
+    let args = crate::Args::parse_from(["whambam", "replay", "run.har", "-n", "0", "-c", "50"]);
+    let Some(crate::args::Command::Replay(replay)) = args.command else {
+        panic!("expected the replay subcommand");
+    };
+    assert_eq!(
+        crate::cli::request_limit(replay.requests, replay.concurrent, 0),
+        0
+    );
+}
+
+#[test]
+fn test_request_limit_with_duration() {
+    let limit = |argv: &[&str], duration_secs| {
+        let args = crate::Args::parse_from([&["whambam", "http://x"][..], argv].concat());
+        crate::cli::request_limit(args.requests, args.concurrent, duration_secs)
+    };
+
+    // Zero means unlimited with or without -z
+    assert_eq!(limit(&["-n", "0", "-z", "10s"], 10), 0);
+    assert_eq!(limit(&["-n", "0"], 0), 0);
+
+    // -z alone has no request limit; with -n as well, both limits apply
+    assert_eq!(limit(&["-z", "10s"], 10), 0);
+    assert_eq!(limit(&["-n", "500", "-z", "10s"], 10), 500);
+    assert_eq!(limit(&["-n", "5", "-c", "50", "-z", "10s"], 10), 50);
 }
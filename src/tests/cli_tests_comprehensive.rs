@@ -20,8 +20,8 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::tester::HttpMethod;
 use crate::Args;
-use crate::HttpMethod;
 use clap::Parser;
 
 #[test]
@@ -66,7 +66,7 @@ fn test_comprehensive_command_line() {
 
     // Verify all arguments were parsed correctly
     assert_eq!(args.url, "https://api.example.com/test/endpoint");
-    assert_eq!(args.requests, 1000);
+    assert_eq!(args.requests, Some(1000));
     assert_eq!(args.concurrent, 50);
     assert_eq!(args.duration_str, "60s");
     assert_eq!(args.rate_limit, 100.5);
@@ -81,16 +81,19 @@ fn test_comprehensive_command_line() {
 
     // Check other HTTP options
     assert_eq!(args.accept, Some("application/json".to_string()));
-    assert_eq!(args.basic_auth, Some("username:password123".to_string()));
-    assert_eq!(args.body, Some("{\"test\":\"data\"}".to_string()));
-    assert_eq!(args.content_type, "application/json");
-    assert_eq!(args.proxy, Some("proxy.example.com:8080".to_string()));
+    assert_eq!(
+        args.auth.basic_auth,
+        Some("username:password123".to_string())
+    );
+    assert_eq!(args.body.body, Some("{\"test\":\"data\"}".to_string()));
+    assert_eq!(args.body.content_type, "application/json");
+    assert_eq!(args.proxy.proxy, Some("proxy.example.com:8080".to_string()));
 
     // Check flags
-    assert!(args.disable_compression);
-    assert!(args.disable_keepalive);
-    assert!(args.disable_redirects);
-    assert!(args.no_ui);
+    assert!(args.connection.disable_compression);
+    assert!(args.connection.disable_keepalive);
+    assert!(args.connection.disable_redirects);
+    assert!(args.output.no_ui);
 }
 
 #[test]
@@ -100,7 +103,7 @@ fn test_minimum_command_line() {
 
     // Verify defaults are applied correctly
     assert_eq!(args.url, "http://example.com");
-    assert_eq!(args.requests, 200);
+    assert_eq!(args.requests, None);
     assert_eq!(args.concurrent, 50);
     assert_eq!(args.duration_str, "0");
     assert_eq!(args.rate_limit, 0.0);
@@ -108,13 +111,13 @@ fn test_minimum_command_line() {
     assert_eq!(args.timeout, 20);
     assert_eq!(args.headers.len(), 0);
     assert_eq!(args.accept, None);
-    assert_eq!(args.basic_auth, None);
-    assert_eq!(args.body, None);
-    assert_eq!(args.body_file, None);
-    assert_eq!(args.content_type, "text/html");
-    assert_eq!(args.proxy, None);
-    assert!(!args.disable_compression);
-    assert!(!args.disable_keepalive);
-    assert!(!args.disable_redirects);
-    assert!(!args.no_ui);
+    assert_eq!(args.auth.basic_auth, None);
+    assert_eq!(args.body.body, None);
+    assert_eq!(args.body.body_file, None);
+    assert_eq!(args.body.content_type, "text/html");
+    assert_eq!(args.proxy.proxy, None);
+    assert!(!args.connection.disable_compression);
+    assert!(!args.connection.disable_keepalive);
+    assert!(!args.connection.disable_redirects);
+    assert!(!args.output.no_ui);
 }
@@ -116,8 +116,8 @@ fn test_body_and_body_file_mutual_exclusion() {
         "/path/to/file.txt",
     ]);
 
-    assert_eq!(args.body, Some("direct-body-content".to_string()));
-    assert_eq!(args.body_file, Some("/path/to/file.txt".to_string()));
+    assert_eq!(args.body.body, Some("direct-body-content".to_string()));
+    assert_eq!(args.body.body_file, Some("/path/to/file.txt".to_string()));
 
     // And the reverse order
     let args = Args::parse_from([
@@ -129,8 +129,8 @@ fn test_body_and_body_file_mutual_exclusion() {
         "direct-body-content",
     ]);
 
-    assert_eq!(args.body_file, Some("/path/to/file.txt".to_string()));
-    assert_eq!(args.body, Some("direct-body-content".to_string()));
+    assert_eq!(args.body.body_file, Some("/path/to/file.txt".to_string()));
+    assert_eq!(args.body.body, Some("direct-body-content".to_string()));
 }
 
 #[test]
@@ -151,7 +151,7 @@ fn test_args_extreme_values() {
         "0", // Infinite timeout
     ]);
 
-    assert_eq!(args.requests, 0);
+    assert_eq!(args.requests, Some(0));
     assert_eq!(args.concurrent, 10000);
     assert_eq!(args.duration_str, "86400");
     assert_eq!(args.rate_limit, 100000.0);
@@ -162,9 +162,9 @@ fn test_args_extreme_values() {
 fn test_args_no_ui_flag() {
     // Test default (UI mode)
     let args_default = Args::parse_from(["test", "http://example.com"]);
-    assert!(!args_default.no_ui);
+    assert!(!args_default.output.no_ui);
 
     // Test explicit no-ui flag
     let args_no_ui = Args::parse_from(["test", "http://example.com", "--no-ui"]);
-    assert!(args_no_ui.no_ui);
+    assert!(args_no_ui.output.no_ui);
 }
@@ -0,0 +1,141 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::args::Command;
+use crate::compare_cli::comparison;
+use crate::tester::{
+    compare, json_summary, Delta, RequestMetric, RunSummary, TestConfig, TestReport, TestState,
+};
+use crate::Args;
+use clap::Parser;
+use serde_json::json;
+
+fn summary(requests_per_sec: f64, p99_ms: f64, error_rate: f64) -> RunSummary {
+    RunSummary {
+        requests_per_sec,
+        p50_ms: 10.0,
+        p95_ms: 20.0,
+        p99_ms,
+        error_rate,
+    }
+}
+
+#[test]
+fn test_compare_args() {
+    let args = Args::parse_from(["whambam", "compare", "old.json", "new.json"]);
+    let Some(Command::Compare(compare)) = args.command else {
+        panic!("expected the compare command");
+    };
+    assert_eq!(compare.baseline, std::path::PathBuf::from("old.json"));
+    assert_eq!(compare.current, std::path::PathBuf::from("new.json"));
+    assert_eq!(compare.tolerance, 5.0);
+
+    assert!(Args::try_parse_from(["whambam", "compare", "old.json"]).is_err());
+}
+
+#[test]
+fn test_summary_reads_both_report_formats() {
+    let config = TestConfig {
+        url: "http://example.com".to_string(),
+        ..Default::default()
+    };
+    let mut state = TestState::new(&config);
+    for (status_code, is_error) in [(200, false), (200, false), (200, false), (500, true)] {
+        state.update(RequestMetric {
+            latency_ms: 10.0,
+            status_code,
+            is_error,
+            ..Default::default()
+        });
+    }
+
+    let snapshot = serde_json::to_value(TestReport::from_state(&state)).unwrap();
+    for report in [json_summary(&state), snapshot] {
+        let summary = RunSummary::from_json(&report).unwrap();
+        assert_eq!(summary.error_rate, 25.0);
+        assert!((summary.p95_ms - 10.0).abs() < 0.1);
+    }
+
+    let err =
+        RunSummary::from_json(&json!({"completed_requests": 1, "error_count": 0})).unwrap_err();
+    assert_eq!(err.to_string(), "missing requests_per_sec");
+
+    // p95 is required like the other percentiles
+    let err = RunSummary::from_json(&json!({
+        "completed_requests": 10,
+        "error_count": 0,
+        "requests_per_sec": 100.0,
+        "latency_ms": {"p50": 10.0, "p99": 50.0},
+    }))
+    .unwrap_err();
+    assert_eq!(err.to_string(), "missing latency_ms.p95");
+}
+
+#[test]
+fn test_delta_direction() {
+    let deltas = compare(&summary(100.0, 50.0, 0.0), &summary(90.0, 52.0, 1.5));
+    let find = |name| *deltas.iter().find(|delta| delta.name == name).unwrap();
+
+    // Throughput falling is the regression, latency rising is
+    let throughput = find("Requests/sec");
+    assert_eq!(throughput.change(), Some(-10.0));
+    assert!(throughput.regressed(5.0));
+    assert!(!throughput.regressed(10.0));
+
+    let p99 = find("p99 latency");
+    assert!((p99.change().unwrap() - 4.0).abs() < 1e-9);
+    assert!(!p99.regressed(5.0));
+
+    // Error rates move in percentage points, so a zero baseline still compares
+    let errors = find("Error rate");
+    assert_eq!(errors.change(), Some(1.5));
+    assert!(!errors.regressed(5.0));
+    assert!(errors.regressed(1.0));
+}
+
+#[test]
+fn test_delta_from_zero_baseline() {
+    let delta = Delta {
+        name: "p50 latency",
+        baseline: 0.0,
+        current: 3.0,
+        in_points: false,
+        higher_is_better: false,
+    };
+    assert_eq!(delta.change(), None);
+    assert!(delta.regressed(5.0));
+}
+
+#[test]
+fn test_comparison_verdict() {
+    let same = compare(&summary(100.0, 50.0, 0.0), &summary(101.0, 50.0, 0.0));
+    let (table, regressed) = comparison(&same, 5.0);
+    assert!(regressed.is_empty());
+    assert!(table.contains("+1.0%"));
+    assert!(table.ends_with("PASS: nothing got worse by more than 5%\n"));
+
+    let worse = compare(&summary(100.0, 50.0, 0.0), &summary(100.0, 60.0, 0.0));
+    let (table, regressed) = comparison(&worse, 5.0);
+    assert_eq!(regressed, vec!["p99 latency"]);
+    assert!(table.contains("+20.0%  regressed"));
+    assert!(table.ends_with("FAIL: p99 latency got worse by more than 5%\n"));
+}
@@ -0,0 +1,146 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{BodyEncoding, HttpMethod, SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use flate2::read::GzDecoder;
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn decompress(encoding: BodyEncoding, body: &[u8]) -> Vec<u8> {
+    let mut plain = Vec::new();
+    match encoding {
+        BodyEncoding::Gzip => GzDecoder::new(body).read_to_end(&mut plain),
+        BodyEncoding::Brotli => brotli::Decompressor::new(body, 4096).read_to_end(&mut plain),
+    }
+    .unwrap();
+    plain
+}
+
+#[test]
+fn test_parse_body_encoding() {
+    assert_eq!(BodyEncoding::parse("gzip").unwrap(), BodyEncoding::Gzip);
+    assert_eq!(BodyEncoding::parse("BR").unwrap(), BodyEncoding::Brotli);
+    assert_eq!(BodyEncoding::parse("brotli").unwrap(), BodyEncoding::Brotli);
+    assert!(BodyEncoding::parse("zstd").is_err());
+}
+
+#[test]
+fn test_compress_round_trips() {
+    let body = "{\"items\": [1, 2, 3]}".repeat(100).into_bytes();
+    for encoding in [BodyEncoding::Gzip, BodyEncoding::Brotli] {
+        let compressed = encoding.compress(&body);
+        assert!(compressed.len() < body.len());
+        assert_eq!(decompress(encoding, &compressed), body);
+    }
+}
+
+#[tokio::test]
+async fn test_compressed_body_is_sent_with_content_encoding() {
+    let server = MockServer::start().await;
+    let body = b"hello hello hello hello".to_vec();
+
+    let state = run(TestConfig {
+        url: server.url(),
+        method: HttpMethod::POST,
+        requests: 2,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        body: Some(body.clone()),
+        compress_body: Some(BodyEncoding::Brotli),
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(state.lock().unwrap().error_count, 0);
+    assert_eq!(
+        server.get_received_headers().get("content-encoding"),
+        Some(&vec!["br".to_string(), "br".to_string()])
+    );
+    for received in server.request_bodies() {
+        assert_eq!(decompress(BodyEncoding::Brotli, &received), body);
+    }
+}
+
+#[tokio::test]
+async fn test_templated_body_is_compressed_after_filling() {
+    let server = MockServer::start().await;
+
+    run(TestConfig {
+        url: server.url(),
+        method: HttpMethod::POST,
+        requests: 2,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        body: Some(b"request ${SEQ}".to_vec()),
+        compress_body: Some(BodyEncoding::Gzip),
+        ..Default::default()
+    })
+    .await;
+
+    let mut bodies: Vec<Vec<u8>> = server
+        .request_bodies()
+        .iter()
+        .map(|received| decompress(BodyEncoding::Gzip, received))
+        .collect();
+    bodies.sort();
+    assert_eq!(bodies, [b"request 0".to_vec(), b"request 1".to_vec()]);
+}
+
+#[tokio::test]
+async fn test_compression_without_a_body_sends_no_encoding() {
+    let server = MockServer::start().await;
+
+    run(TestConfig {
+        url: server.url(),
+        requests: 1,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        compress_body: Some(BodyEncoding::Gzip),
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(server.request_count(), 1);
+    assert!(!server
+        .get_received_headers()
+        .contains_key("content-encoding"));
+}
@@ -0,0 +1,116 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::config_file::{parse_yaml, try_parse_from};
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("whambam-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn test_parse_yaml() {
+    let entries = parse_yaml(
+        "# comment\n\
+         url: \"http://example.com/a b\"\n\
+         concurrent: 5 # inline comment\n\
+         body: 'it''s'\n\
+         header:\n  - \"Accept: text/plain\"\n  - X-Plain\n\
+         outputs: []\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        entries,
+        vec![
+            (
+                "url".to_string(),
+                vec!["http://example.com/a b".to_string()]
+            ),
+            ("concurrent".to_string(), vec!["5".to_string()]),
+            ("body".to_string(), vec!["it's".to_string()]),
+            (
+                "header".to_string(),
+                vec!["Accept: text/plain".to_string(), "X-Plain".to_string()]
+            ),
+            ("outputs".to_string(), vec![]),
+        ]
+    );
+
+    assert!(parse_yaml("- orphan\n").is_err());
+    assert!(parse_yaml("no colon here\n").is_err());
+    assert!(parse_yaml("body: \"unterminated\n").is_err());
+}
+
+#[test]
+fn test_saved_config_replays_the_run() {
+    let path = temp_path("saved.yaml");
+    let path_arg = path.to_str().unwrap();
+
+    let saved = try_parse_from([
+        "whambam",
+        "http://example.com/api",
+        "-c",
+        "7",
+        "-H",
+        "Accept: application/json",
+        "-H",
+        "X-Trace: 1",
+        "--burst",
+        "20",
+        "--save-config",
+        path_arg,
+    ])
+    .unwrap();
+    let yaml = std::fs::read_to_string(&path).unwrap();
+    // Defaults are written out too, so the file describes the whole run
+    assert!(yaml.contains("concurrent: 7\n"));
+    assert!(yaml.contains("timeout: 20\n"));
+    assert!(yaml.contains("header:\n  - \"Accept: application/json\"\n"));
+    assert!(!yaml.contains("\nsave-config:"));
+
+    let replayed = try_parse_from(["whambam", "--config", path_arg]).unwrap();
+    assert_eq!(replayed.url, saved.url);
+    assert_eq!(replayed.concurrent, 7);
+    assert_eq!(replayed.headers, saved.headers);
+    assert_eq!(replayed.load.burst, Some(20));
+    assert_eq!(replayed.requests, None);
+
+    // The command line wins over the file
+    let overridden = try_parse_from(["whambam", "--config", path_arg, "-c", "3"]).unwrap();
+    assert_eq!(overridden.concurrent, 3);
+    assert_eq!(overridden.url, "http://example.com/api");
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_config_errors() {
+    let path = temp_path("unknown.yaml");
+    std::fs::write(&path, "url: http://example.com\nconcurency: 5\n").unwrap();
+    let err = try_parse_from(["whambam", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{err:#}").contains("Unknown option 'concurency'"));
+    std::fs::remove_file(&path).unwrap();
+
+    let missing = temp_path("missing.yaml");
+    assert!(try_parse_from(["whambam", "--config", missing.to_str().unwrap()]).is_err());
+}
@@ -20,11 +20,14 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::tester::{HttpMethod, RequestMetric, TestConfig, TestState};
+use crate::tester::{
+    is_success_status, HttpMethod, RequestMetric, ResponseClassifier, TestConfig, TestState,
+};
 use std::time::Instant;
 
 #[test]
 fn test_test_config_initialization() {
+    #[allow(deprecated)]
     let config = TestConfig {
         url: "http://example.com".to_string(),
         method: HttpMethod::GET,
@@ -34,7 +37,7 @@ fn test_test_config_initialization() {
         rate_limit: 5.0,
         headers: vec![("Content-Type".to_string(), "application/json".to_string())],
         timeout: 20,
-        body: Some("test body".to_string()),
+        body: Some(b"test body".to_vec()),
         content_type: "application/json".to_string(),
         basic_auth: Some(("username".to_string(), "password".to_string())),
         proxy: Some("localhost:8080".to_string()),
@@ -43,6 +46,7 @@ fn test_test_config_initialization() {
         disable_redirects: true,
         interactive: true,
         output_format: "ui".to_string(),
+        ..Default::default()
     };
 
     assert_eq!(config.url, "http://example.com");
@@ -55,7 +59,7 @@ fn test_test_config_initialization() {
     assert_eq!(config.headers[0].0, "Content-Type");
     assert_eq!(config.headers[0].1, "application/json");
     assert_eq!(config.timeout, 20);
-    assert_eq!(config.body, Some("test body".to_string()));
+    assert_eq!(config.body, Some(b"test body".to_vec()));
     assert_eq!(config.content_type, "application/json");
     assert_eq!(
         config.basic_auth,
@@ -69,6 +73,7 @@ fn test_test_config_initialization() {
 
 #[test]
 fn test_test_state_initialization() {
+    #[allow(deprecated)]
     let config = TestConfig {
         url: "http://example.com".to_string(),
         method: HttpMethod::GET,
@@ -78,7 +83,7 @@ fn test_test_state_initialization() {
         rate_limit: 5.0,
         headers: vec![("Content-Type".to_string(), "application/json".to_string())],
         timeout: 20,
-        body: Some("test body".to_string()),
+        body: Some(b"test body".to_vec()),
         content_type: "application/json".to_string(),
         basic_auth: Some(("username".to_string(), "password".to_string())),
         proxy: Some("localhost:8080".to_string()),
@@ -87,6 +92,7 @@ fn test_test_state_initialization() {
         disable_redirects: true,
         interactive: true,
         output_format: "ui".to_string(),
+        ..Default::default()
     };
 
     let test_state = TestState::new(&config);
@@ -118,6 +124,7 @@ fn test_test_state_initialization() {
 
 #[test]
 fn test_test_state_update() {
+    #[allow(deprecated)]
     let config = TestConfig {
         url: "http://example.com".to_string(),
         method: HttpMethod::GET,
@@ -136,6 +143,7 @@ fn test_test_state_update() {
         disable_redirects: false,
         interactive: true,
         output_format: "ui".to_string(),
+        ..Default::default()
     };
 
     let mut test_state = TestState::new(&config);
@@ -148,6 +156,7 @@ fn test_test_state_update() {
         is_error: false,
         bytes_sent: 100,
         bytes_received: 500,
+        ..Default::default()
     };
 
     test_state.update(metric_success);
@@ -171,6 +180,7 @@ fn test_test_state_update() {
         is_error: true,
         bytes_sent: 150,
         bytes_received: 200,
+        ..Default::default()
     };
 
     test_state.update(metric_error);
@@ -189,6 +199,7 @@ fn test_test_state_update() {
 
 #[test]
 fn test_test_state_reset() {
+    #[allow(deprecated)]
     let config = TestConfig {
         url: "http://example.com".to_string(),
         method: HttpMethod::GET,
@@ -207,6 +218,7 @@ fn test_test_state_reset() {
         disable_redirects: false,
         interactive: true,
         output_format: "ui".to_string(),
+        ..Default::default()
     };
 
     let mut test_state = TestState::new(&config);
@@ -219,6 +231,7 @@ fn test_test_state_reset() {
         is_error: false,
         bytes_sent: 120,
         bytes_received: 800,
+        ..Default::default()
     };
 
     test_state.update(metric);
@@ -257,3 +270,108 @@ fn test_http_method_display() {
     assert_eq!(HttpMethod::HEAD.to_string(), "HEAD");
     assert_eq!(HttpMethod::OPTIONS.to_string(), "OPTIONS");
 }
+
+#[test]
+fn test_is_success_status() {
+    // Default: any 2xx
+    assert!(is_success_status(200, &[]));
+    assert!(is_success_status(204, &[]));
+    assert!(!is_success_status(301, &[]));
+    assert!(!is_success_status(404, &[]));
+
+    // Explicit list replaces the default
+    let statuses = [200, 301, 404];
+    assert!(is_success_status(301, &statuses));
+    assert!(is_success_status(404, &statuses));
+    assert!(!is_success_status(204, &statuses));
+}
+
+#[test]
+fn test_success_status_arg() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from(["test", "http://example.com"]);
+    assert!(args.checks.success_status.is_empty());
+
+    let args = Args::parse_from([
+        "test",
+        "http://example.com",
+        "--success-status",
+        "200,301,404",
+    ]);
+    assert_eq!(args.checks.success_status, vec![200, 301, 404]);
+}
+
+#[test]
+fn test_response_classifier_rules() {
+    let classifier = ResponseClassifier {
+        success_statuses: vec![200],
+        max_latency_ms: Some(100.0),
+        body_contains: Some("ok".to_string()),
+    };
+
+    assert!(classifier.is_success(200, 50.0, b"status: ok"));
+    assert!(!classifier.is_success(500, 50.0, b"status: ok"));
+    assert!(!classifier.is_success(200, 150.0, b"status: ok"));
+    assert!(!classifier.is_success(200, 50.0, b"status: down"));
+
+    // Without extra rules only the status code matters
+    let default = ResponseClassifier::default();
+    assert!(default.is_success(204, 10_000.0, b""));
+    assert!(!default.is_success(404, 1.0, b"ok"));
+}
+
+#[test]
+fn test_classifier_args() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from([
+        "test",
+        "http://example.com",
+        "--max-latency",
+        "250",
+        "--body-contains",
+        "healthy",
+    ]);
+    assert_eq!(args.checks.max_latency, Some(250.0));
+    assert_eq!(args.checks.body_contains.as_deref(), Some("healthy"));
+}
+
+#[test]
+fn test_rebaseline_charts_keeps_cumulative_stats() {
+    let config = TestConfig {
+        url: "http://example.com".to_string(),
+        requests: 100,
+        ..Default::default()
+    };
+    let mut state = TestState::new(&config);
+    for _ in 0..5 {
+        state.update(RequestMetric {
+            latency_ms: 10.0,
+            status_code: 200,
+            ..Default::default()
+        });
+    }
+    assert!(!state.throughput_data.is_empty());
+
+    state.rebaseline_charts();
+
+    assert!(state.throughput_data.is_empty());
+    assert!(state.latency_data.is_empty());
+    assert!(state.throughput_history.is_empty());
+    assert!(state.recent_latencies.is_empty());
+    assert_eq!(state.completed_requests, 5);
+    assert_eq!(state.status_counts[&200], 5);
+    assert_eq!(state.latency_histogram.len(), 5);
+    assert_eq!(state.events.len(), 1);
+
+    // The series picks up again with the next request
+    state.update(RequestMetric {
+        latency_ms: 10.0,
+        status_code: 200,
+        ..Default::default()
+    });
+    assert_eq!(state.throughput_data.len(), 1);
+}
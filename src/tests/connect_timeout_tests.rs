@@ -0,0 +1,92 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+#[tokio::test]
+async fn test_stalled_handshake_is_a_connect_timeout() {
+    // Accepts TCP connections but never answers the TLS ClientHello
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let stalled = tokio::spawn(async move {
+        let mut sockets = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            sockets.push(socket);
+        }
+    });
+
+    let state = run(TestConfig {
+        url: format!("https://{addr}/"),
+        requests: 2,
+        concurrent: 1,
+        timeout: 5,
+        connect_timeout: Some(Duration::from_millis(200)),
+        ..Default::default()
+    })
+    .await;
+    stalled.abort();
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.completed_requests, 2);
+    assert_eq!(state.error_count, 2);
+    assert_eq!(state.connect_timeouts, 2);
+}
+
+#[tokio::test]
+async fn test_slow_response_is_not_a_connect_timeout() {
+    let server = MockServer::start().await;
+    server.set_response_delay(1500);
+
+    let state = run(TestConfig {
+        url: server.url(),
+        requests: 1,
+        concurrent: 1,
+        timeout: 1,
+        connect_timeout: Some(Duration::from_millis(500)),
+        ..Default::default()
+    })
+    .await;
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 1);
+    assert_eq!(state.connect_timeouts, 0);
+}
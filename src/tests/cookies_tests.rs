@@ -0,0 +1,130 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{CookieJar, SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use reqwest::header::{HeaderMap, HeaderValue, SET_COOKIE};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn set_cookies(cookies: &[&str]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for cookie in cookies {
+        headers.append(SET_COOKIE, HeaderValue::from_str(cookie).unwrap());
+    }
+    headers
+}
+
+#[test]
+fn test_jar_sends_cookies_back_to_their_host() {
+    let site = Url::parse("https://shop.example/login").unwrap();
+    let mut jar = CookieJar::default();
+    assert_eq!(jar.header_value(&site), None);
+
+    jar.store(
+        &site,
+        &set_cookies(&["session=abc; Path=/; HttpOnly", "theme=dark"]),
+    );
+    let cart = Url::parse("https://shop.example/cart").unwrap();
+    assert_eq!(
+        jar.header_value(&cart).as_deref(),
+        Some("session=abc; theme=dark")
+    );
+    let other = Url::parse("https://other.example/").unwrap();
+    assert_eq!(jar.header_value(&other), None);
+}
+
+#[test]
+fn test_jar_replaces_and_expires_cookies() {
+    let site = Url::parse("https://shop.example/").unwrap();
+    let mut jar = CookieJar::default();
+    jar.store(
+        &site,
+        &set_cookies(&["session=abc", "theme=dark", "cart=1"]),
+    );
+    jar.store(
+        &site,
+        &set_cookies(&[
+            "session=def",
+            "theme=; Max-Age=0",
+            "cart=; Expires=Thu, 01 Jan 1970 00:00:00 GMT",
+        ]),
+    );
+    assert_eq!(jar.header_value(&site).as_deref(), Some("session=def"));
+}
+
+#[test]
+fn test_jars_are_separate_sessions() {
+    let site = Url::parse("https://shop.example/").unwrap();
+    let mut alice = CookieJar::default();
+    let mut bob = CookieJar::default();
+    alice.store(&site, &set_cookies(&["session=alice"]));
+    bob.store(&site, &set_cookies(&["session=bob"]));
+    assert_eq!(alice.header_value(&site).as_deref(), Some("session=alice"));
+    assert_eq!(bob.header_value(&site).as_deref(), Some("session=bob"));
+}
+
+async fn cookies_received(cookies: bool) -> Option<Vec<String>> {
+    let server = MockServer::start().await;
+    server.add_response_header("Set-Cookie: session=abc; Path=/");
+
+    run(TestConfig {
+        url: server.url(),
+        requests: 3,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        cookies,
+        isolate_connections: true,
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(server.request_count(), 3);
+    server.get_received_headers().remove("cookie")
+}
+
+#[tokio::test]
+async fn test_runner_keeps_session_cookies() {
+    // The first request has nothing to send back yet
+    assert_eq!(
+        cookies_received(true).await,
+        Some(vec!["session=abc".to_string(), "session=abc".to_string()])
+    );
+    assert_eq!(cookies_received(false).await, None);
+}
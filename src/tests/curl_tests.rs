@@ -0,0 +1,136 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::config_file::try_parse_from;
+use crate::curl::parse_curl;
+use crate::tester::HttpMethod;
+
+fn entry(key: &str, values: &[&str]) -> (String, Vec<String>) {
+    (
+        key.to_string(),
+        values.iter().map(|value| value.to_string()).collect(),
+    )
+}
+
+#[test]
+fn test_parse_browser_copy() {
+    let command = r#"curl 'https://api.example.com/items' \
+  -H 'accept: application/json' \
+  -H 'content-type: application/json' \
+  --data-raw '{"name":"it'\''s"}' \
+  --compressed"#;
+    assert_eq!(
+        parse_curl(command).unwrap(),
+        [
+            entry("url", &["https://api.example.com/items"]),
+            entry("method", &["POST"]),
+            entry("header", &["accept: application/json"]),
+            entry("body", &[r#"{"name":"it's"}"#]),
+            entry("content-type", &["application/json"]),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_options() {
+    let entries = parse_curl(
+        r#"curl -sSL -XPUT --url=http://example.com/a -u user:pass -k -m 2.5 -A "agent \"x\"" -d a=1 -d b=2"#,
+    )
+    .unwrap();
+    assert_eq!(
+        entries,
+        [
+            entry("url", &["http://example.com/a"]),
+            entry("method", &["PUT"]),
+            entry("header", &["User-Agent: agent \"x\""]),
+            entry("body", &["a=1&b=2"]),
+            entry("content-type", &["application/x-www-form-urlencoded"]),
+            entry("auth", &["user:pass"]),
+            entry("insecure", &["true"]),
+            entry("timeout", &["3"]),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_get_data_and_files() {
+    assert_eq!(
+        parse_curl("curl -G http://example.com/search?x=1 -d q=rust").unwrap(),
+        [
+            entry("url", &["http://example.com/search?x=1&q=rust"]),
+            entry("method", &["GET"]),
+        ]
+    );
+    assert_eq!(
+        parse_curl("curl http://example.com --data-binary @payload.bin").unwrap()[2],
+        entry("body-file", &["payload.bin"])
+    );
+    assert_eq!(
+        parse_curl("curl http://example.com -H $'x-note: a\\tb'").unwrap()[1],
+        entry("header", &["x-note: a\tb"])
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    for command in [
+        "wget http://example.com",
+        "curl -H 'accept: */*'",
+        "curl http://example.com --proxy-ntlm",
+        "curl 'http://example.com",
+        "curl http://example.com -H",
+    ] {
+        assert!(parse_curl(command).is_err(), "{command}");
+    }
+}
+
+#[test]
+fn test_from_curl_arg() {
+    let args = try_parse_from([
+        "whambam",
+        "--from-curl",
+        "curl -X POST http://example.com/a -H 'X-Token: t' -d 'x=1' -H 'Content-Type: text/plain'",
+        "-c",
+        "3",
+    ])
+    .unwrap();
+    assert_eq!(args.url, "http://example.com/a");
+    assert_eq!(args.method, HttpMethod::POST);
+    assert_eq!(args.headers, ["X-Token: t"]);
+    assert_eq!(args.body.body.as_deref(), Some("x=1"));
+    assert_eq!(args.body.content_type, "text/plain");
+    assert_eq!(args.concurrent, 3);
+
+    // The command line wins over the curl command
+    let args = try_parse_from([
+        "whambam",
+        "--from-curl=curl -X POST http://example.com/a",
+        "-m",
+        "PUT",
+        "http://example.com/b",
+    ])
+    .unwrap();
+    assert_eq!(args.method, HttpMethod::PUT);
+    assert_eq!(args.url, "http://example.com/b");
+
+    assert!(try_parse_from(["whambam", "--from-curl", "curl --bogus http://a.test"]).is_err());
+}
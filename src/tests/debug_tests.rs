@@ -0,0 +1,129 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::args::Command;
+use crate::debug_cli::transcript;
+use crate::tester::{probe, HttpMethod, Probe, RequestError, ResponseHead, TestConfig};
+use crate::tests::MockServer;
+use crate::Args;
+use clap::Parser;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{StatusCode, Version};
+
+#[test]
+fn test_debug_args() {
+    let args = Args::parse_from(["whambam", "debug", "http://x", "-H", "X-A: 1"]);
+    let Some(Command::Debug(debug)) = args.command else {
+        panic!("expected the debug command");
+    };
+    assert_eq!(debug.url, "http://x");
+    assert_eq!(debug.count, 3);
+    assert_eq!(debug.method, HttpMethod::GET);
+    assert_eq!(debug.headers, vec!["X-A: 1"]);
+
+    let args = Args::parse_from(["whambam", "probe", "http://x", "-n", "1", "-m", "post"]);
+    let Some(Command::Debug(debug)) = args.command else {
+        panic!("expected probe to alias the debug command");
+    };
+    assert_eq!(debug.count, 1);
+    assert_eq!(debug.method, HttpMethod::POST);
+}
+
+#[test]
+fn test_transcript() {
+    let config = TestConfig {
+        url: "http://example.com/".to_string(),
+        user_agent: "whambam/test".to_string(),
+        headers: vec![("X-A".to_string(), "1".to_string())],
+        ..Default::default()
+    };
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+    let probe = Probe {
+        remote_addr: Some("127.0.0.1:80".parse().unwrap()),
+        head: ResponseHead {
+            version: Version::HTTP_11,
+            status: StatusCode::OK,
+            headers,
+        },
+        timing: Default::default(),
+        first_byte_ms: 1.5,
+        total_ms: 2.0,
+        body_bytes: 5,
+    };
+
+    assert_eq!(
+        transcript(&config, &Ok(probe)),
+        "> GET http://example.com/\n\
+         > user-agent: whambam/test\n\
+         > X-A: 1\n\
+         * Connected to 127.0.0.1:80\n\
+         < HTTP/1.1 200 OK\n\
+         < content-type: text/plain\n\
+         * 5 bytes; first byte 1.50 ms, total 2.00 ms\n"
+    );
+
+    let failed = Err(RequestError {
+        url: config.url.clone(),
+        kind: "connect",
+        message: "connection refused".to_string(),
+    });
+    assert!(transcript(&config, &failed).ends_with("* Failed (connect): connection refused\n"));
+}
+
+#[tokio::test]
+async fn test_probe_sends_each_request() {
+    let server = MockServer::start().await;
+    server.set_response_body("hello");
+    let config = TestConfig {
+        url: server.url(),
+        concurrent: 1,
+        timeout: 1,
+        ..Default::default()
+    };
+
+    let results = probe(&config, 2).await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(server.request_count(), 2);
+    for result in results {
+        let probe = result.unwrap();
+        assert_eq!(probe.head.status, StatusCode::OK);
+        assert_eq!(probe.body_bytes, 5);
+        assert!(probe.remote_addr.unwrap().ip().is_loopback());
+        assert!(probe.total_ms >= probe.first_byte_ms);
+    }
+}
+
+#[tokio::test]
+async fn test_probe_reports_connection_errors() {
+    let config = TestConfig {
+        url: "http://127.0.0.1:1/".to_string(),
+        concurrent: 1,
+        timeout: 1,
+        ..Default::default()
+    };
+
+    let results = probe(&config, 1).await.unwrap();
+    let error = results[0].as_ref().unwrap_err();
+    assert_eq!(error.kind, "connect");
+    assert_eq!(error.url, "http://127.0.0.1:1/");
+}
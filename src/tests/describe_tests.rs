@@ -0,0 +1,90 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{HttpMethod, TestConfig, TestState};
+
+fn value<'a>(rows: &'a [(&'static str, String)], name: &str) -> Option<&'a str> {
+    rows.iter()
+        .find(|(row, _)| *row == name)
+        .map(|(_, value)| value.as_str())
+}
+
+#[test]
+fn test_describe_lists_core_settings() {
+    let config = TestConfig {
+        url: "https://example.com/api".to_string(),
+        method: HttpMethod::POST,
+        requests: 0,
+        concurrent: 4,
+        duration: 30,
+        rate_limit: 10.0,
+        body: Some(b"{\"id\":1}".to_vec()),
+        insecure: true,
+        ..Default::default()
+    };
+    let rows = config.describe();
+
+    assert_eq!(value(&rows, "Method"), Some("POST"));
+    assert_eq!(value(&rows, "URL"), Some("https://example.com/api"));
+    assert_eq!(value(&rows, "Requests"), Some("unlimited"));
+    assert_eq!(value(&rows, "Concurrency"), Some("4"));
+    assert_eq!(value(&rows, "Duration"), Some("30s"));
+    assert_eq!(
+        value(&rows, "Rate limit"),
+        Some("10 QPS per worker (40 total)")
+    );
+    assert_eq!(value(&rows, "Timeout"), Some("20s"));
+    assert_eq!(value(&rows, "Body"), Some("8 bytes"));
+    assert_eq!(value(&rows, "Verify TLS"), Some("off"));
+    assert_eq!(value(&rows, "Proxy"), None);
+}
+
+#[test]
+fn test_describe_masks_credentials() {
+    let config = TestConfig {
+        url: "http://localhost/".to_string(),
+        headers: vec![
+            ("Authorization".to_string(), "Bearer secret".to_string()),
+            ("X-Trace".to_string(), "abc".to_string()),
+        ],
+        basic_auth: Some(("admin".to_string(), "hunter2".to_string())),
+        ..Default::default()
+    };
+    let rows = config.describe();
+
+    let headers: Vec<&str> = rows
+        .iter()
+        .filter(|(name, _)| *name == "Header")
+        .map(|(_, value)| value.as_str())
+        .collect();
+    assert_eq!(headers, ["Authorization: ***", "X-Trace: abc"]);
+    assert_eq!(value(&rows, "Basic auth"), Some("admin:***"));
+    assert!(rows.iter().all(|(_, value)| !value.contains("hunter2")));
+}
+
+#[test]
+fn test_state_keeps_settings() {
+    let config = TestConfig {
+        url: "http://localhost/".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(TestState::new(&config).settings, config.describe());
+}
@@ -0,0 +1,188 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    encode_query, lookup, parse_dns_server, parse_response, DnsCache, SharedState, TestConfig,
+    TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+/// A DNS server that answers every A query with a CNAME to 127.0.0.1 and has no AAAA records
+struct FakeDnsServer {
+    addr: SocketAddr,
+    queries: Arc<AtomicUsize>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl FakeDnsServer {
+    async fn start() -> Self {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        let queries = Arc::new(AtomicUsize::new(0));
+        let count = Arc::clone(&queries);
+        let task = tokio::spawn(async move {
+            let mut buffer = [0; 512];
+            while let Ok((len, peer)) = socket.recv_from(&mut buffer).await {
+                count.fetch_add(1, Ordering::SeqCst);
+                let _ = socket.send_to(&answer(&buffer[..len]), peer).await;
+            }
+        });
+        FakeDnsServer {
+            addr,
+            queries,
+            task,
+        }
+    }
+}
+
+impl Drop for FakeDnsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn answer(query: &[u8]) -> Vec<u8> {
+    let is_a = query[query.len() - 3] == 1;
+    let mut packet = query[..2].to_vec();
+    packet.extend_from_slice(&[0x81, 0x80, 0, 1, 0, if is_a { 2 } else { 0 }, 0, 0, 0, 0]);
+    packet.extend_from_slice(&query[12..]);
+    if is_a {
+        // CNAME pointing back at the question name, then the address
+        packet.extend_from_slice(&[0xc0, 12, 0, 5, 0, 1, 0, 0, 0, 60, 0, 2, 0xc0, 12]);
+        packet.extend_from_slice(&[0xc0, 12, 0, 1, 0, 1, 0, 0, 0, 30, 0, 4, 127, 0, 0, 1]);
+    }
+    packet
+}
+
+#[test]
+fn test_parse_dns_server() {
+    assert_eq!(
+        parse_dns_server("10.0.0.2").unwrap(),
+        "10.0.0.2:53".parse::<SocketAddr>().unwrap()
+    );
+    assert_eq!(parse_dns_server("[::1]:5353").unwrap().port(), 5353);
+    assert_eq!(parse_dns_server("::1").unwrap().port(), 53);
+    assert!(parse_dns_server("dns.example.com").is_err());
+}
+
+#[test]
+fn test_encode_and_parse() {
+    let query = encode_query(0x1234, "api.example.com", 1).unwrap();
+    assert_eq!(&query[..2], &[0x12, 0x34]);
+    assert_eq!(&query[12..16], b"\x03api");
+    assert_eq!(query.len(), 12 + 17 + 4);
+    assert!(encode_query(1, "bad..name", 1).is_err());
+
+    let records = parse_response(&answer(&query)).unwrap();
+    assert_eq!(records, vec![(IpAddr::V4(Ipv4Addr::LOCALHOST), 30)]);
+    assert!(parse_response(&answer(&query)[..40]).is_err());
+
+    let mut refused = answer(&query);
+    refused[3] |= 5;
+    assert!(parse_response(&refused).is_err());
+}
+
+#[tokio::test]
+async fn test_lookup_uses_dns_server() {
+    let server = FakeDnsServer::start().await;
+    let addrs = lookup(Some(server.addr), "backend.whambam.test")
+        .await
+        .unwrap();
+    assert_eq!(addrs, vec![SocketAddr::from((Ipv4Addr::LOCALHOST, 0))]);
+    // One A and one AAAA query
+    assert_eq!(server.queries.load(Ordering::SeqCst), 2);
+}
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn dns_config(dns: &FakeDnsServer, server: &MockServer) -> TestConfig {
+    TestConfig {
+        url: server.url().replace("127.0.0.1", "backend.whambam.test"),
+        dns_server: Some(dns.addr),
+        requests: 3,
+        concurrent: 1,
+        timeout: 2,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_runner_resolves_with_dns_server() {
+    let dns = FakeDnsServer::start().await;
+    let server = MockServer::start().await;
+
+    // The mock server closes every connection, so each request looks the host up
+    let state = run(dns_config(&dns, &server)).await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 0);
+    assert_eq!(server.request_count(), 3);
+    assert_eq!(state.dns_lookup.count(), 3);
+    assert_eq!(dns.queries.load(Ordering::SeqCst), 6);
+}
+
+#[tokio::test]
+async fn test_dns_cache_ttl_reuses_resolutions() {
+    let dns = FakeDnsServer::start().await;
+    let server = MockServer::start().await;
+
+    let state = run(TestConfig {
+        dns_cache_ttl: Some(Duration::from_secs(60)),
+        ..dns_config(&dns, &server)
+    })
+    .await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 0);
+    assert_eq!(state.dns_lookup.count(), 1);
+    assert_eq!(dns.queries.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_dns_cache_expires() {
+    let addrs = [SocketAddr::from((Ipv4Addr::LOCALHOST, 0))];
+    let cache = DnsCache::new(Duration::from_secs(60));
+    assert_eq!(cache.get("example.com"), None);
+    cache.insert("example.com", &addrs);
+    assert_eq!(cache.get("example.com"), Some(addrs.to_vec()));
+
+    let expired = DnsCache::new(Duration::ZERO);
+    expired.insert("example.com", &addrs);
+    assert_eq!(expired.get("example.com"), None);
+}
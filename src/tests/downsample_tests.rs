@@ -0,0 +1,120 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    lttb, SeriesHistory, StackedHistory, TestConfig, TestReport, TestState, HISTORY_LEN,
+};
+
+fn series(len: usize) -> Vec<(f64, f64)> {
+    (0..len).map(|i| (i as f64, (i % 7) as f64)).collect()
+}
+
+#[test]
+fn test_lttb_keeps_short_series_as_is() {
+    let points = series(10);
+    assert_eq!(lttb(&points, 10), points);
+    assert_eq!(lttb(&points, 50), points);
+    assert_eq!(lttb(&points, 2), points);
+}
+
+#[test]
+fn test_lttb_keeps_ends_and_threshold() {
+    let points = series(1000);
+    let sampled = lttb(&points, 100);
+
+    assert_eq!(sampled.len(), 100);
+    assert_eq!(sampled.first(), points.first());
+    assert_eq!(sampled.last(), points.last());
+    assert!(sampled.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn test_lttb_keeps_spikes() {
+    let mut points: Vec<(f64, f64)> = (0..1000).map(|i| (i as f64, 1.0)).collect();
+    points[437].1 = 500.0;
+
+    let sampled = lttb(&points, 50);
+    assert!(sampled.contains(&(437.0, 500.0)));
+}
+
+#[test]
+fn test_series_history_compacts_when_full() {
+    let mut history = SeriesHistory::default();
+    for point in series(HISTORY_LEN) {
+        history.push(point);
+    }
+    assert_eq!(history.len(), HISTORY_LEN);
+
+    history.push((HISTORY_LEN as f64, 0.0));
+    assert_eq!(history.len(), HISTORY_LEN / 2);
+    assert_eq!(history.points()[0], (0.0, 0.0));
+    assert_eq!(history.points().last(), Some(&(HISTORY_LEN as f64, 0.0)));
+}
+
+#[test]
+fn test_report_series_cover_the_whole_test() {
+    let config = TestConfig {
+        url: "http://example.com".to_string(),
+        ..Default::default()
+    };
+    let mut state = TestState::new(&config);
+    for point in series(2 * HISTORY_LEN) {
+        state.throughput_history.push(point);
+        state.latency_history.push(point);
+    }
+
+    let report = TestReport::from_state(&state);
+    assert_eq!(report.throughput_series.len(), 1000);
+    assert_eq!(report.throughput_series[0], (0.0, 0.0));
+    assert_eq!(
+        report.latency_series.last().map(|p| p.0),
+        Some((2 * HISTORY_LEN - 1) as f64)
+    );
+}
+
+#[test]
+fn test_stacked_history_adds_up_the_bands() {
+    let mut history = StackedHistory::<3>::default();
+    history.push(0.0, [1.0, 2.0, 3.0]);
+    history.push(1.0, [4.0, 0.0, 1.0]);
+
+    let [first, second, third] = history.stacked(100);
+    assert_eq!(first, vec![(0.0, 1.0), (1.0, 4.0)]);
+    assert_eq!(second, vec![(0.0, 3.0), (1.0, 4.0)]);
+    assert_eq!(third, vec![(0.0, 6.0), (1.0, 5.0)]);
+}
+
+#[test]
+fn test_stacked_history_averages_when_downsampling() {
+    let mut history = StackedHistory::<2>::default();
+    for i in 0..=HISTORY_LEN {
+        history.push(i as f64, [1.0, (i % 2) as f64]);
+    }
+    // Compacting halves the points without changing the average rate
+    assert_eq!(history.len(), HISTORY_LEN / 2 + 1);
+    assert_eq!(history.points()[0], (0.5, [1.0, 0.5]));
+
+    let [bottom, top] = history.stacked(10);
+    assert!(bottom.len() <= 10);
+    assert!(bottom.iter().all(|&(_, y)| y == 1.0));
+    assert!(top.iter().all(|&(_, y)| (y - 1.5).abs() < 0.01));
+}
@@ -0,0 +1,86 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+#[tokio::test]
+async fn test_duration_run_waits_for_in_flight_requests() {
+    let server = MockServer::start().await;
+    server.set_response_delay(400);
+
+    let state = run(TestConfig {
+        url: server.url(),
+        duration: 1,
+        concurrent: 2,
+        ..Default::default()
+    })
+    .await;
+
+    let state = state.lock().unwrap();
+    assert!(state.is_complete);
+    assert!(state.events.is_empty());
+    // Requests started before the deadline are finished and counted, not dropped
+    assert_eq!(state.completed_requests, server.request_count());
+    assert_eq!(state.error_count, 0);
+}
+
+#[tokio::test]
+async fn test_drain_timeout_abandons_slow_requests() {
+    let server = MockServer::start().await;
+    server.set_response_delay(3000);
+
+    let state = run(TestConfig {
+        url: server.url(),
+        duration: 1,
+        concurrent: 2,
+        drain_timeout: Duration::from_millis(200),
+        ..Default::default()
+    })
+    .await;
+
+    let state = state.lock().unwrap();
+    assert!(state.is_complete);
+    assert_eq!(state.completed_requests, 0);
+    assert!(state
+        .events
+        .iter()
+        .any(|event| event.message.contains("2 requests still in flight")));
+}
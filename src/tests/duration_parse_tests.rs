@@ -20,7 +20,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{parse_duration, Args};
+use crate::{tester::parse_duration, Args};
 use clap::Parser;
 
 #[test]
@@ -78,7 +78,7 @@ fn test_duration_combined_with_requests() {
     // Test that duration and requests work together
     let args = Args::parse_from(["test", "http://example.com", "-n", "1000", "-z", "60s"]);
 
-    assert_eq!(args.requests, 1000);
+    assert_eq!(args.requests, Some(1000));
     assert_eq!(args.duration_str, "60s");
     let parsed_duration = parse_duration(&args.duration_str).unwrap();
     assert_eq!(parsed_duration, 60);
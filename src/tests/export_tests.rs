@@ -0,0 +1,282 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    error_entry, json_summary, OutputSink, RequestError, RequestMetric, SharedState, StopReason,
+    TestConfig, TestReport, TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::sleep;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("whambam-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn test_json_summary_fields() {
+    let config = TestConfig {
+        url: "http://example.com".to_string(),
+        requests: 2,
+        ..Default::default()
+    };
+    let mut state = TestState::new(&config);
+    for latency_ms in [10.0, 30.0] {
+        state.update(RequestMetric {
+            latency_ms,
+            status_code: 200,
+            bytes_received: 100,
+            ..Default::default()
+        });
+    }
+    state.mark_complete();
+
+    let summary = json_summary(&state);
+    assert_eq!(summary["url"], "http://example.com");
+    assert_eq!(summary["method"], "GET");
+    assert_eq!(summary["completed_requests"], 2);
+    assert_eq!(summary["error_count"], 0);
+    assert_eq!(summary["bytes_received"], 200);
+    assert_eq!(summary["status_counts"]["200"], 2);
+    assert!(summary["grpc_status_counts"]
+        .as_object()
+        .unwrap()
+        .is_empty());
+    assert!((summary["latency_ms"]["average"].as_f64().unwrap() - 20.0).abs() < 0.1);
+    assert!((summary["latency_ms"]["min"].as_f64().unwrap() - 10.0).abs() < 0.1);
+    assert!(summary["stop_reason"].is_null());
+    assert!(summary["clock_skew_ms"].is_null());
+    assert!(summary["dns_lookup_ms"].is_null());
+    assert!(summary["tls_handshake_ms"].is_null());
+
+    // --json and the UI's snapshot write the same report
+    assert_eq!(
+        summary,
+        serde_json::to_value(TestReport::from_state(&state)).unwrap()
+    );
+}
+
+#[test]
+fn test_error_entry() {
+    let metric = RequestMetric {
+        latency_ms: 12.5,
+        is_error: true,
+        error: Some(Box::new(RequestError {
+            url: "http://example.com/".to_string(),
+            kind: "connect",
+            message: "error sending request: connection refused".to_string(),
+        })),
+        ..Default::default()
+    };
+    let entry = error_entry(&metric);
+    assert_eq!(entry["url"], "http://example.com/");
+    assert_eq!(entry["kind"], "connect");
+    assert_eq!(entry["error"], "error sending request: connection refused");
+    assert_eq!(entry["latency_ms"], 12.5);
+
+    // Without details, the status code is all there is to go on
+    let entry = error_entry(&RequestMetric {
+        status_code: 503,
+        is_error: true,
+        ..Default::default()
+    });
+    assert!(entry["url"].is_null());
+    assert_eq!(entry["kind"], "status");
+    assert_eq!(entry["error"], "HTTP 503");
+}
+
+#[test]
+fn test_output_args() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from(["test", "http://example.com"]);
+    assert!(args.output.outputs().is_empty());
+
+    let args = Args::parse_from([
+        "test",
+        "http://example.com",
+        "--json",
+        "out.json",
+        "--csv",
+        "raw.csv",
+        "--log-errors",
+        "errors.jsonl",
+    ]);
+    assert_eq!(
+        args.output.outputs(),
+        vec![
+            OutputSink::Json("out.json".into()),
+            OutputSink::Csv("raw.csv".into()),
+            OutputSink::ErrorLog("errors.jsonl".into())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_runner_writes_all_outputs() {
+    let server = MockServer::start().await;
+    let json_path = temp_path("out.json");
+    let csv_path = temp_path("raw.csv");
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 5,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        outputs: vec![
+            OutputSink::Json(json_path.clone()),
+            OutputSink::Csv(csv_path.clone()),
+        ],
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let summary: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert_eq!(summary["completed_requests"], 5);
+
+    let csv = fs::read_to_string(&csv_path).unwrap();
+    let mut lines = csv.lines();
+    assert!(lines
+        .next()
+        .unwrap()
+        .starts_with("timestamp,latency_ms,status_code"));
+    assert_eq!(lines.filter(|line| line.contains(",200,false,")).count(), 5);
+
+    let _ = fs::remove_file(json_path);
+    let _ = fs::remove_file(csv_path);
+}
+
+#[tokio::test]
+async fn test_runner_logs_failed_requests() {
+    let log_path = temp_path("errors.jsonl");
+    let config = TestConfig {
+        url: "http://127.0.0.1:1/missing".to_string(),
+        requests: 3,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        outputs: vec![OutputSink::ErrorLog(log_path.clone())],
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let log = fs::read_to_string(&log_path).unwrap();
+    let entries: Vec<serde_json::Value> = log
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(entries.len(), 3);
+    for entry in entries {
+        assert_eq!(entry["url"], "http://127.0.0.1:1/missing");
+        assert_eq!(entry["kind"], "connect");
+        assert_eq!(entry["status_code"], 0);
+        // The underlying cause is spelled out, not just "error sending request"
+        assert!(entry["error"].as_str().unwrap().contains(": "));
+    }
+
+    let _ = fs::remove_file(log_path);
+}
+
+#[tokio::test]
+async fn test_interrupted_runner_writes_json() {
+    let server = MockServer::start().await;
+    server.set_response_delay(5000);
+    let json_path = temp_path("interrupted.json");
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 0,
+        concurrent: 2,
+        timeout: 30,
+        interactive: false,
+        outputs: vec![OutputSink::Json(json_path.clone())],
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    sleep(Duration::from_millis(200)).await;
+
+    // Ctrl-C in text mode finishes the run while requests are still in flight
+    state.lock().unwrap().stop_reason = Some(StopReason::Interrupted);
+    tokio::time::timeout(Duration::from_secs(2), runner.finish())
+        .await
+        .expect("finish waited for requests in flight");
+
+    assert!(state.lock().unwrap().is_complete);
+    let summary: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert_eq!(summary["completed_requests"], 0);
+    assert_eq!(summary["stop_reason"], StopReason::Interrupted.to_string());
+
+    let _ = fs::remove_file(json_path);
+}
+
+#[tokio::test]
+async fn test_runner_rejects_unwritable_output() {
+    let config = TestConfig {
+        url: "http://127.0.0.1:1".to_string(),
+        outputs: vec![OutputSink::Csv("/nonexistent-dir/raw.csv".into())],
+        ..Default::default()
+    };
+
+    let err = UnifiedRunner::new(config).start().await.unwrap_err();
+    assert!(err.to_string().contains("Failed to create output file"));
+}
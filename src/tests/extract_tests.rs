@@ -0,0 +1,195 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    substitute, Extraction, HttpMethod, ScenarioStep, ScenarioUser, SharedState, TestConfig,
+    TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use reqwest::header::{HeaderMap, HeaderValue};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+fn step(name: &str, method: HttpMethod, url: &str, body: Option<&str>) -> ScenarioStep {
+    ScenarioStep {
+        name: name.to_string(),
+        method,
+        url: url.to_string(),
+        body: body.map(str::to_string),
+        headers: Vec::new(),
+    }
+}
+
+fn extractions(specs: &[&str]) -> Vec<Extraction> {
+    specs
+        .iter()
+        .map(|spec| Extraction::parse(spec).unwrap())
+        .collect()
+}
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+#[test]
+fn test_parse_extraction() {
+    let extraction = Extraction::parse("login:token=$.auth.token").unwrap();
+    assert_eq!(extraction.step, "login");
+    assert_eq!(extraction.variable, "token");
+
+    assert!(Extraction::parse("login:next=header:Location").is_ok());
+    assert!(Extraction::parse(r"create:id=regex:id=(\d+)").is_ok());
+
+    assert!(Extraction::parse("login=$.token").is_err());
+    assert!(Extraction::parse("login:token").is_err());
+    assert!(Extraction::parse("login:token=token").is_err());
+    assert!(Extraction::parse("login:token=$.items[x]").is_err());
+    assert!(Extraction::parse("login:token=regex:(").is_err());
+}
+
+#[test]
+fn test_extract_sources() {
+    let mut headers = HeaderMap::new();
+    headers.insert("location", HeaderValue::from_static("/items/7"));
+    let body = br#"{"token": "abc", "items": [{"id": 42}]}"#;
+
+    let [token, id, location, pattern, missing] = [
+        "a:v=$.token",
+        "a:v=$.items[0].id",
+        "a:v=header:Location",
+        r#"a:v=regex:"token": "(\w+)""#,
+        "a:v=$.missing",
+    ]
+    .map(|spec| Extraction::parse(spec).unwrap().extract(&headers, body));
+
+    assert_eq!(token.as_deref(), Some("abc"));
+    assert_eq!(id.as_deref(), Some("42"));
+    assert_eq!(location.as_deref(), Some("/items/7"));
+    assert_eq!(pattern.as_deref(), Some("abc"));
+    assert_eq!(missing, None);
+}
+
+#[test]
+fn test_substitute() {
+    let variables = HashMap::from([("id".to_string(), "42".to_string())]);
+    assert_eq!(substitute("/items/{{id}}", &variables), "/items/42");
+    assert_eq!(substitute("{{ id }}-{{id}}", &variables), "42-42");
+    assert_eq!(
+        substitute("/items/{{other}}", &variables),
+        "/items/{{other}}"
+    );
+    assert_eq!(substitute("/items/{{id", &variables), "/items/{{id");
+}
+
+#[test]
+fn test_user_keeps_its_own_variables() {
+    let base = Url::parse("http://example.com").unwrap();
+    let steps: Arc<[ScenarioStep]> = vec![
+        step("login", HttpMethod::POST, "/login", None),
+        step(
+            "fetch",
+            HttpMethod::GET,
+            "/items/{{id}}",
+            Some(r#"{"id": {{id}}}"#),
+        ),
+    ]
+    .into();
+    let extracted: Arc<[Extraction]> = extractions(&["login:id=$.id"]).into();
+    let mut user = ScenarioUser::new(base.clone(), Arc::clone(&steps))
+        .with_extractions(Arc::clone(&extracted));
+    let mut other = ScenarioUser::new(base, steps).with_extractions(extracted);
+
+    assert!(user.extracts_from(0));
+    assert!(!user.extracts_from(1));
+    user.next_request();
+    user.capture(0, &HeaderMap::new(), br#"{"id": 7}"#);
+    let request = user.next_request();
+    assert_eq!(request.url.path(), "/items/7");
+    assert_eq!(request.body.as_deref(), Some(r#"{"id": 7}"#));
+
+    // A value missing from a later response keeps the earlier one
+    user.capture(0, &HeaderMap::new(), b"not json");
+    assert_eq!(user.variables()["id"], "7");
+
+    other.next_request();
+    assert_eq!(other.next_request().url.path(), "/items/%7B%7Bid%7D%7D");
+}
+
+#[tokio::test]
+async fn test_runner_chains_extracted_values() {
+    let server = MockServer::start().await;
+    server.set_response_body(r#"{"id": 42}"#);
+    run(TestConfig {
+        url: server.url(),
+        requests: 4,
+        concurrent: 1,
+        timeout: 2,
+        interactive: false,
+        headers: vec![("X-Item".to_string(), "{{id}}".to_string())],
+        scenario: vec![
+            step("create", HttpMethod::POST, "/items", None),
+            step("fetch", HttpMethod::GET, "/items/{{id}}", None),
+        ],
+        extractions: extractions(&["create:id=$.id"]),
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(
+        server.request_lines(),
+        ["POST /items HTTP/1.1", "GET /items/42 HTTP/1.1"].repeat(2)
+    );
+    let headers = server.get_received_headers();
+    assert_eq!(
+        headers["x-item"].first().map(String::as_str),
+        Some("{{id}}")
+    );
+    assert_eq!(headers["x-item"].last().map(String::as_str), Some("42"));
+}
+
+#[tokio::test]
+async fn test_runner_rejects_unknown_extraction_step() {
+    let config = TestConfig {
+        url: "http://example.com".to_string(),
+        scenario: vec![step("login", HttpMethod::POST, "/login", None)],
+        extractions: extractions(&["signin:token=$.token"]),
+        ..Default::default()
+    };
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let mut runner = UnifiedRunner::with_state(config, SharedState { state });
+    let err = runner.start().await.unwrap_err();
+    assert!(err.to_string().contains("unknown step 'signin'"));
+}
@@ -0,0 +1,150 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    Failover, RequestMetric, SharedState, TestConfig, TestState, UnifiedRunner, UrlMix,
+    FALLBACK_TARGET, PRIMARY_TARGET,
+};
+use crate::tests::MockServer;
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::sleep;
+use url::Url;
+
+fn connection_error() -> RequestMetric {
+    RequestMetric {
+        is_error: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_failover_switches_once_after_threshold() {
+    let primary = Url::parse("http://primary.test/").unwrap();
+    let failover = Failover::new(Url::parse("http://fallback.test/").unwrap(), 3);
+
+    assert!(!failover.observe(&connection_error()));
+    assert!(!failover.observe(&connection_error()));
+    assert_eq!(failover.target(&primary).1, PRIMARY_TARGET);
+
+    assert!(failover.observe(&connection_error()));
+    assert!(failover.is_active());
+    let (url, target) = failover.target(&primary);
+    assert_eq!(url.as_str(), "http://fallback.test/");
+    assert_eq!(target, FALLBACK_TARGET);
+
+    // The switch is reported exactly once
+    assert!(!failover.observe(&connection_error()));
+}
+
+#[test]
+fn test_failover_needs_consecutive_errors() {
+    let failover = Failover::new(Url::parse("http://fallback.test/").unwrap(), 2);
+    let ok = RequestMetric {
+        status_code: 200,
+        ..Default::default()
+    };
+
+    assert!(!failover.observe(&connection_error()));
+    assert!(!failover.observe(&ok));
+    assert!(!failover.observe(&connection_error()));
+    assert!(!failover.is_active());
+}
+
+#[test]
+fn test_targets_include_fallback() {
+    let config = TestConfig {
+        url: "http://primary.test/".to_string(),
+        fallback_url: Some("http://fallback.test/".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        config.targets(),
+        vec!["http://primary.test/", "http://fallback.test/"]
+    );
+    assert_eq!(TestState::new(&config).targets.len(), 2);
+}
+
+#[tokio::test]
+async fn test_runner_rejects_fallback_with_url_mix() {
+    // The mix takes the fallback's target index, so the two can't be combined
+    let config = TestConfig {
+        url: "http://primary.test/".to_string(),
+        fallback_url: Some("http://fallback.test/".to_string()),
+        url_mix: Some(UrlMix::parse("http://primary.test/").unwrap()),
+        interactive: false,
+        ..Default::default()
+    };
+
+    let err = UnifiedRunner::new(config).start().await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "A fallback URL can't be combined with a URL mix"
+    );
+}
+
+#[tokio::test]
+async fn test_runner_fails_over_to_fallback() {
+    // Nothing listens on the primary port once the listener is dropped
+    let closed_port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let server = MockServer::start().await;
+
+    let config = TestConfig {
+        url: format!("http://127.0.0.1:{closed_port}/"),
+        fallback_url: Some(server.url()),
+        failover_after: 3,
+        requests: 20,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert_eq!(test_state.events.len(), 1);
+    assert!(test_state.targets[PRIMARY_TARGET].error_count >= 3);
+    assert!(test_state.targets[FALLBACK_TARGET].completed_requests > 0);
+    assert_eq!(test_state.targets[FALLBACK_TARGET].error_count, 0);
+    assert!(server.request_count() > 0);
+}
@@ -0,0 +1,128 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    expand, DataFeed, FeedCursor, FeedOrder, SharedState, TestConfig, TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const USERS: &str = "username,id\nalice,1\n\"bob, jr\",2\n\"say \"\"hi\"\"\",3\n";
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+#[test]
+fn test_parse_feed_order() {
+    assert_eq!(FeedOrder::parse("cycle").unwrap(), FeedOrder::Cycle);
+    assert_eq!(FeedOrder::parse("Random").unwrap(), FeedOrder::Random);
+    assert!(FeedOrder::parse("shuffle").is_err());
+}
+
+#[test]
+fn test_parse_csv() {
+    let feed = DataFeed::parse(USERS, FeedOrder::Cycle).unwrap();
+    assert_eq!(feed.columns(), ["username", "id"]);
+    assert_eq!(feed.len(), 3);
+
+    let mut cursor = FeedCursor::new(Arc::new(feed));
+    let names: Vec<String> = (0..4)
+        .map(|_| cursor.next_row().get("username").unwrap().to_string())
+        .collect();
+    assert_eq!(names, ["alice", "bob, jr", "say \"hi\"", "alice"]);
+}
+
+#[test]
+fn test_parse_csv_errors() {
+    assert!(DataFeed::parse("", FeedOrder::Cycle).is_err());
+    assert!(DataFeed::parse("username,id\n", FeedOrder::Cycle).is_err());
+    let err = DataFeed::parse("username,id\nalice,1\nbob\n", FeedOrder::Cycle).unwrap_err();
+    assert!(err.to_string().contains("Line 3 has 1 fields, expected 2"));
+}
+
+#[test]
+fn test_random_rows_come_from_the_feed() {
+    let feed = Arc::new(DataFeed::parse(USERS, FeedOrder::Random).unwrap());
+    let mut cursor = FeedCursor::new(feed);
+    for _ in 0..20 {
+        let id: usize = cursor.next_row().get("id").unwrap().parse().unwrap();
+        assert!((1..=3).contains(&id));
+    }
+}
+
+#[test]
+fn test_row_fills_placeholders() {
+    let mut cursor = FeedCursor::new(Arc::new(DataFeed::parse(USERS, FeedOrder::Cycle).unwrap()));
+    let row = cursor.next_row();
+    assert_eq!(
+        row.fill("/users/${id}?name=${username}&x=${missing}"),
+        "/users/1?name=alice&x=${missing}"
+    );
+    assert_eq!(
+        expand("${unclosed", |_| Some("x".to_string())),
+        "${unclosed"
+    );
+}
+
+#[tokio::test]
+async fn test_runner_feeds_rows_into_requests() {
+    let server = MockServer::start().await;
+    let feed = DataFeed::parse("username,id\nalice,1\nbob,2\n", FeedOrder::Cycle).unwrap();
+    run(TestConfig {
+        url: format!("{}/users/${{id}}", server.url()),
+        requests: 3,
+        concurrent: 1,
+        timeout: 2,
+        interactive: false,
+        headers: vec![("X-User".to_string(), "${username}".to_string())],
+        data_feed: Some(Arc::new(feed)),
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(
+        server.request_lines(),
+        [
+            "GET /users/1 HTTP/1.1",
+            "GET /users/2 HTTP/1.1",
+            "GET /users/1 HTTP/1.1"
+        ]
+    );
+    assert_eq!(
+        server.get_received_headers()["x-user"],
+        ["alice", "bob", "alice"]
+    );
+}
@@ -0,0 +1,276 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::grpc::{fetch_schema, frame, parse_proto, GrpcCall, GrpcClient};
+use crate::tester::{print_hey_format_report, SharedState, TestConfig, TestState, UnifiedRunner};
+use hyper::header::HeaderValue;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, HeaderMap, Request, Response, Server};
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+const SHOP_PROTO: &str = r#"
+syntax = "proto3";
+package shop.v1;
+
+import "google/protobuf/empty.proto";
+
+/* Orders placed at the till */
+message Order {
+  string id = 1;
+  repeated int32 quantities = 2 [packed = false];
+  Status status = 3; // declared below
+  Item item = 4;
+  map<string, int64> prices = 5;
+  oneof payment {
+    string card = 6;
+    bool cash = 7;
+  }
+  enum Status { UNKNOWN = 0; PAID = 1; }
+  message Item { sint32 delta = 1; }
+}
+
+service Orders {
+  rpc Place (Order) returns (Order) {
+    option (google.api.http) = { post: "/v1/orders" };
+  }
+}
+"#;
+
+/// A cleartext HTTP/2 server that answers every call with a fixed gRPC status
+struct GrpcServer {
+    url: Url,
+    status: Arc<AtomicU16>,
+    paths: Arc<Mutex<Vec<String>>>,
+}
+
+impl GrpcServer {
+    /// Start a server; reflection calls are answered with `descriptor`
+    async fn start(descriptor: Vec<u8>) -> Self {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        let status = Arc::new(AtomicU16::new(0));
+        let paths = Arc::new(Mutex::new(Vec::new()));
+
+        let (server_status, server_paths) = (Arc::clone(&status), Arc::clone(&paths));
+        let make_service = make_service_fn(move |_| {
+            let (status, paths, descriptor) = (
+                Arc::clone(&server_status),
+                Arc::clone(&server_paths),
+                descriptor.clone(),
+            );
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let path = request.uri().path().to_string();
+                    paths.lock().unwrap().push(path.clone());
+                    let (reply, status) = if path.contains("ServerReflection") {
+                        (reflection_response(&descriptor), 0)
+                    } else {
+                        (Vec::new(), status.load(Ordering::SeqCst))
+                    };
+                    reply_with(reply, status)
+                }))
+            }
+        });
+        let server = Server::from_tcp(listener)
+            .unwrap()
+            .http2_only(true)
+            .serve(make_service);
+        tokio::spawn(server);
+
+        GrpcServer { url, status, paths }
+    }
+}
+
+async fn reply_with(message: Vec<u8>, status: u16) -> Result<Response<Body>, Infallible> {
+    let (mut sender, body) = Body::channel();
+    tokio::spawn(async move {
+        let _ = sender.send_data(frame(&message).into()).await;
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", HeaderValue::from(status));
+        let _ = sender.send_trailers(trailers).await;
+    });
+    Ok(Response::builder()
+        .header("content-type", "application/grpc")
+        .body(body)
+        .unwrap())
+}
+
+/// A length-delimited protobuf field
+fn field(number: u8, bytes: &[u8]) -> Vec<u8> {
+    let mut out = vec![(number << 3) | 2, bytes.len() as u8];
+    out.extend_from_slice(bytes);
+    out
+}
+
+/// ServerReflectionResponse { file_descriptor_response { file_descriptor_proto } }
+fn reflection_response(descriptor: &[u8]) -> Vec<u8> {
+    field(4, &field(1, descriptor))
+}
+
+/// FileDescriptorProto for `package echo; message Ping { string text = 1; }`
+/// and `service Echo { rpc Say (Ping) returns (Ping); }`
+fn echo_descriptor() -> Vec<u8> {
+    let text_field = [field(1, b"text"), vec![0x18, 1, 0x20, 1, 0x28, 9]].concat();
+    let ping = [field(1, b"Ping"), field(2, &text_field)].concat();
+    let say = [
+        field(1, b"Say"),
+        field(2, b".echo.Ping"),
+        field(3, b".echo.Ping"),
+    ]
+    .concat();
+    let service = [field(1, b"Echo"), field(2, &say)].concat();
+    [field(2, b"echo"), field(4, &ping), field(6, &service)].concat()
+}
+
+async fn run_calls(url: &Url, requests: usize) -> Arc<Mutex<TestState>> {
+    let config = TestConfig {
+        url: url.to_string(),
+        requests,
+        concurrent: 2,
+        timeout: 5,
+        grpc: Some(GrpcCall {
+            method: "echo.Echo/Say".to_string(),
+            message: vec![0x0a, 2, b'h', b'i'],
+        }),
+        ..Default::default()
+    };
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+#[test]
+fn test_proto_file_encodes_json_requests() {
+    let schema = parse_proto(SHOP_PROTO).unwrap();
+    let input = schema.input_type("shop.v1.Orders/Place").unwrap();
+    assert_eq!(input, "shop.v1.Order");
+
+    let request = serde_json::json!({
+        "id": "a",
+        "quantities": [1, 2],
+        "status": "PAID",
+        "item": { "delta": -1 },
+        "prices": { "x": "5" },
+        "cash": true,
+    });
+    let encoded = schema.encode(input, &request).unwrap();
+
+    // serde_json orders keys alphabetically: cash, id, item, prices, quantities, status
+    let expected = [
+        vec![0x38, 1],
+        vec![0x0a, 1, b'a'],
+        vec![0x22, 2, 0x08, 1],
+        vec![0x2a, 5, 0x0a, 1, b'x', 0x10, 5],
+        vec![0x10, 1, 0x10, 2],
+        vec![0x18, 1],
+    ]
+    .concat();
+    assert_eq!(encoded, expected);
+}
+
+#[test]
+fn test_schema_reports_unknown_methods_and_fields() {
+    let schema = parse_proto(SHOP_PROTO).unwrap();
+
+    let err = schema.input_type("shop.v1.Orders/Cancel").unwrap_err();
+    assert!(err.to_string().contains("shop.v1.Orders/Place"));
+
+    let err = schema
+        .encode("shop.v1.Order", &serde_json::json!({ "total": 3 }))
+        .unwrap_err();
+    assert!(err.to_string().contains("no field 'total'"));
+
+    assert!(parse_proto("message Broken { string id = ; }").is_err());
+}
+
+#[tokio::test]
+async fn test_reflection_builds_schema() {
+    let server = GrpcServer::start(echo_descriptor()).await;
+
+    let schema = fetch_schema(&GrpcClient::new(), &server.url, "echo.Echo", &[], 5)
+        .await
+        .unwrap();
+    let input = schema.input_type("echo.Echo/Say").unwrap();
+    assert_eq!(input, "echo.Ping");
+    let encoded = schema
+        .encode(input, &serde_json::json!({ "text": "hi" }))
+        .unwrap();
+    assert_eq!(encoded, vec![0x0a, 2, b'h', b'i']);
+
+    let paths = server.paths.lock().unwrap();
+    assert_eq!(
+        paths.as_slice(),
+        ["/grpc.reflection.v1.ServerReflection/ServerReflectionInfo"]
+    );
+}
+
+#[tokio::test]
+async fn test_grpc_calls_count_grpc_statuses() {
+    let server = GrpcServer::start(Vec::new()).await;
+
+    let state = run_calls(&server.url, 6).await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.completed_requests, 6);
+    assert_eq!(state.error_count, 0);
+    assert_eq!(state.grpc_status_counts.get(&0), Some(&6));
+    assert_eq!(state.status_counts.get(&200), Some(&6));
+    assert!(server
+        .paths
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|path| path == "/echo.Echo/Say"));
+}
+
+#[tokio::test]
+async fn test_failing_grpc_status_is_an_error_in_the_report() {
+    let server = GrpcServer::start(Vec::new()).await;
+    server.status.store(5, Ordering::SeqCst);
+
+    let state = run_calls(&server.url, 4).await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 4);
+    assert_eq!(state.grpc_status_counts.get(&5), Some(&4));
+
+    let mut report = Vec::new();
+    print_hey_format_report(&mut report, &state).unwrap();
+    let report = String::from_utf8(report).unwrap();
+    assert!(report.contains("gRPC status distribution:"));
+    assert!(report.contains("[5 NOT_FOUND]\t4 responses"));
+    assert!(!report.contains("Status code distribution:"));
+}
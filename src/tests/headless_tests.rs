@@ -0,0 +1,115 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::headless::{interval_warning, progress_line, Progress, Verbosity};
+use crate::tester::{HttpMethod, RequestMetric, TestConfig, TestState};
+use crate::Args;
+use clap::Parser;
+
+fn test_state() -> TestState {
+    #[allow(deprecated)]
+    let config = TestConfig {
+        url: "http://localhost".to_string(),
+        method: HttpMethod::GET,
+        requests: 100,
+        concurrent: 1,
+        duration: 0,
+        rate_limit: 0.0,
+        headers: vec![],
+        timeout: 20,
+        body: None,
+        content_type: "text/html".to_string(),
+        basic_auth: None,
+        proxy: None,
+        disable_compression: false,
+        disable_keepalive: false,
+        disable_redirects: false,
+        interactive: false,
+        output_format: String::new(),
+        ..Default::default()
+    };
+    TestState::new(&config)
+}
+
+fn record(state: &mut TestState, status_code: u16, is_error: bool) {
+    state.update(RequestMetric {
+        timestamp: 0.0,
+        latency_ms: 5.0,
+        status_code,
+        is_error,
+        bytes_sent: 0,
+        bytes_received: 0,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn test_verbosity_flags() {
+    let args = Args::parse_from(["test", "http://example.com"]);
+    assert_eq!(args.output.verbosity(), Verbosity::Normal);
+
+    let args = Args::parse_from(["test", "http://example.com", "--quiet"]);
+    assert_eq!(args.output.verbosity(), Verbosity::Quiet);
+
+    let args = Args::parse_from(["test", "http://example.com", "--normal"]);
+    assert_eq!(args.output.verbosity(), Verbosity::Normal);
+
+    let args = Args::parse_from(["test", "http://example.com", "--verbose"]);
+    assert_eq!(args.output.verbosity(), Verbosity::Verbose);
+
+    // The levels are mutually exclusive
+    assert!(Args::try_parse_from(["test", "http://example.com", "--quiet", "--verbose"]).is_err());
+}
+
+#[test]
+fn test_progress_line() {
+    let mut state = test_state();
+    record(&mut state, 200, false);
+    record(&mut state, 500, true);
+
+    let line = progress_line(&state, &Progress::default());
+    assert!(line.contains("2 requests"));
+    assert!(line.contains("p99 5.000 ms"));
+    assert!(line.contains("1 errors"));
+}
+
+#[test]
+fn test_interval_warning_only_for_new_errors() {
+    let mut state = test_state();
+    record(&mut state, 500, true);
+
+    let last = Progress {
+        elapsed: 0.0,
+        completed: 0,
+        errors: 0,
+    };
+    let warning = interval_warning(&state, &last).unwrap();
+    assert!(warning.contains("1 of 1 requests failed"));
+
+    let last = Progress {
+        elapsed: 0.0,
+        completed: 1,
+        errors: 1,
+    };
+    record(&mut state, 200, false);
+    assert!(interval_warning(&state, &last).is_none());
+}
@@ -0,0 +1,108 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    Health, HealthWindow, RequestMetric, TestConfig, TestState, FALLBACK_TARGET, PRIMARY_TARGET,
+};
+
+#[test]
+fn test_health_grades_error_rate_and_p99() {
+    let mut window = HealthWindow::default();
+    // Second 1: all fast successes
+    for _ in 0..100 {
+        window.record(1, 10.0, false);
+    }
+    // Second 2: 2% errors
+    for i in 0..100 {
+        window.record(2, 10.0, i < 2);
+    }
+    // Second 3: slow tail
+    for i in 0..100 {
+        window.record(3, if i < 5 { 1500.0 } else { 10.0 }, false);
+    }
+    // Second 5: p99 between the two latency thresholds
+    window.record(5, 700.0, false);
+
+    let row = window.row(5);
+    assert_eq!(row.len(), 10);
+    assert_eq!(
+        row[5..],
+        [
+            Health::Healthy,
+            Health::Degraded,
+            Health::Failing,
+            Health::Idle,
+            Health::Degraded
+        ]
+    );
+    assert!(row[..5].iter().all(|health| *health == Health::Idle));
+}
+
+#[test]
+fn test_health_window_keeps_only_recent_seconds() {
+    let mut window = HealthWindow::default();
+    window.record(0, 10.0, true);
+    window.record(12, 10.0, false);
+    // A late result for an earlier second still lands in its own column
+    window.record(11, 10.0, true);
+
+    let row = window.row(12);
+    assert_eq!(row[9], Health::Healthy);
+    assert_eq!(row[8], Health::Failing);
+    assert!(row[..8].iter().all(|health| *health == Health::Idle));
+    // Second 0 fell out of the window once second 12 arrived
+    assert_eq!(window.row(9)[0], Health::Idle);
+    assert_eq!(window.row(20)[..2], [Health::Failing, Health::Healthy]);
+}
+
+#[test]
+fn test_targets_are_graded_on_the_same_clock() {
+    let config = TestConfig {
+        url: "http://primary".to_string(),
+        fallback_url: Some("http://fallback".to_string()),
+        ..Default::default()
+    };
+    let mut state = TestState::new(&config);
+
+    for (target, timestamp, is_error) in [
+        (PRIMARY_TARGET, 0.5, false),
+        (PRIMARY_TARGET, 1.5, true),
+        (FALLBACK_TARGET, 2.2, false),
+    ] {
+        state.update(RequestMetric {
+            timestamp,
+            latency_ms: 5.0,
+            status_code: if is_error { 500 } else { 200 },
+            is_error,
+            target,
+            ..Default::default()
+        });
+    }
+
+    let primary = state.targets[PRIMARY_TARGET].health.row(2);
+    let fallback = state.targets[FALLBACK_TARGET].health.row(2);
+    assert_eq!(
+        primary[7..],
+        [Health::Healthy, Health::Failing, Health::Idle]
+    );
+    assert_eq!(fallback[7..], [Health::Idle, Health::Idle, Health::Healthy]);
+}
@@ -0,0 +1,117 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{
+    FnHooks, LockFreeMetrics, RequestMetric, RunnerHooks, TestConfig, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+async fn wait_for(count: &AtomicUsize) {
+    for _ in 0..100 {
+        if count.load(Ordering::SeqCst) > 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+#[derive(Default)]
+struct Counts {
+    requests: AtomicUsize,
+    ticks: AtomicUsize,
+    completions: AtomicUsize,
+    completed_at_end: AtomicUsize,
+}
+
+struct Counter(Arc<Counts>);
+
+impl RunnerHooks for Counter {
+    fn on_request_complete(&mut self, metric: &RequestMetric) {
+        assert_eq!(metric.status_code, 200);
+        self.0.requests.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn on_tick(&mut self, _metrics: &LockFreeMetrics) {
+        self.0.ticks.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn on_complete(&mut self, metrics: &LockFreeMetrics) {
+        let completed = metrics.completed_requests();
+        self.0.completed_at_end.store(completed, Ordering::SeqCst);
+        self.0.completions.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn test_hooks_see_every_request_and_the_end() {
+    let server = MockServer::start().await;
+    let counts = Arc::new(Counts::default());
+
+    let mut runner = UnifiedRunner::new(TestConfig {
+        url: server.url(),
+        requests: 6,
+        concurrent: 2,
+        timeout: 1,
+        ..Default::default()
+    });
+    runner.add_hooks(Counter(Arc::clone(&counts)));
+    runner.start().await.unwrap();
+    wait_for(&counts.completions).await;
+
+    assert_eq!(counts.requests.load(Ordering::SeqCst), 6);
+    assert_eq!(counts.completions.load(Ordering::SeqCst), 1);
+    assert_eq!(counts.completed_at_end.load(Ordering::SeqCst), 6);
+}
+
+#[tokio::test]
+async fn test_closure_hooks_tick_while_running() {
+    let server = MockServer::start().await;
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let done = Arc::new(AtomicUsize::new(0));
+
+    let mut runner = UnifiedRunner::new(TestConfig {
+        url: server.url(),
+        requests: 0,
+        duration: 2,
+        concurrent: 1,
+        rate_limit: 20.0,
+        timeout: 1,
+        ..Default::default()
+    });
+    let (tick_count, done_count) = (Arc::clone(&ticks), Arc::clone(&done));
+    runner.add_hooks(
+        FnHooks::default()
+            .on_tick(move |_| {
+                tick_count.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_complete(move |_| {
+                done_count.fetch_add(1, Ordering::SeqCst);
+            }),
+    );
+    runner.start().await.unwrap();
+    wait_for(&done).await;
+
+    assert_eq!(done.load(Ordering::SeqCst), 1);
+    assert!(ticks.load(Ordering::SeqCst) >= 1);
+}
@@ -0,0 +1,81 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn local_config(server: &MockServer, local_address: &[&str]) -> TestConfig {
+    TestConfig {
+        url: server.url(),
+        requests: 4,
+        concurrent: 2,
+        timeout: 2,
+        local_address: local_address
+            .iter()
+            .map(|addr| addr.parse::<IpAddr>().unwrap())
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_requests_sent_from_each_local_address() {
+    let server = MockServer::start().await;
+
+    // Every address in 127.0.0.0/8 is local, so both workers can bind
+    let state = run(local_config(&server, &["127.0.0.1", "127.0.0.2"])).await;
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.completed_requests, 4);
+    assert_eq!(state.error_count, 0);
+}
+
+#[tokio::test]
+async fn test_unassigned_local_address_fails_requests() {
+    let server = MockServer::start().await;
+
+    // TEST-NET-1 is never assigned to an interface, so binding to it fails
+    let state = run(local_config(&server, &["192.0.2.1"])).await;
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.completed_requests, 4);
+    assert_eq!(state.error_count, 4);
+}
@@ -1,3 +1,5 @@
+// whambam - A high-performance HTTP load testing tool
+//
 // Copyright (c) 2025 Stephen Harrison
 //
 // Permission is hereby granted, free of charge, to any person obtaining a copy
@@ -18,11 +20,12 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{print_hey_format_report, tester::{HttpMethod, TestConfig, TestState}};
+use crate::tester::{print_hey_format_report, HttpMethod, TestConfig, TestState};
 use std::time::{Duration, Instant};
 
 #[test]
 fn test_print_hey_format_report_basic() {
+    #[allow(deprecated)]
     let config = TestConfig {
         url: "http://localhost".to_string(),
         method: HttpMethod::GET,
@@ -41,11 +44,12 @@ fn test_print_hey_format_report_basic() {
         disable_redirects: false,
         interactive: false,
         output_format: "hey".to_string(),
+        ..Default::default()
     };
     let mut test_state = TestState::new(&config);
     test_state.is_complete = true;
-        test_state.start_time = Instant::now() - Duration::from_secs(10);
-        test_state.end_time = Some(Instant::now());
+    test_state.start_time = Instant::now() - Duration::from_secs(10);
+    test_state.end_time = Some(Instant::now());
     test_state.completed_requests = 100;
     test_state.total_bytes_received = 10240;
     test_state.min_latency = 10.0;
@@ -72,6 +76,7 @@ fn test_print_hey_format_report_basic() {
 
 #[test]
 fn test_print_hey_format_report_no_requests() {
+    #[allow(deprecated)]
     let config = TestConfig {
         url: "http://localhost".to_string(),
         method: HttpMethod::GET,
@@ -90,6 +95,7 @@ fn test_print_hey_format_report_no_requests() {
         disable_redirects: false,
         interactive: false,
         output_format: "hey".to_string(),
+        ..Default::default()
     };
     let mut test_state = TestState::new(&config);
     test_state.is_complete = true;
@@ -106,4 +112,4 @@ fn test_print_hey_format_report_no_requests() {
     assert!(output.contains("Requests/sec:"));
     // Ensure it handles division by zero gracefully
     assert!(output.contains("Average:\t0.0000 secs"));
-}
\ No newline at end of file
+}
@@ -30,8 +30,12 @@ use tokio::time::sleep;
 struct ServerState {
     request_count: AtomicUsize,
     headers: Mutex<HashMap<String, Vec<String>>>,
+    request_lines: Mutex<Vec<String>>,
+    request_bodies: Mutex<Vec<Vec<u8>>>,
     status_code: AtomicUsize,
     delay_ms: AtomicUsize,
+    body: Mutex<String>,
+    response_headers: Mutex<Vec<String>>,
 }
 
 impl ServerState {
@@ -39,8 +43,12 @@ impl ServerState {
         ServerState {
             request_count: AtomicUsize::new(0),
             headers: Mutex::new(HashMap::new()),
+            request_lines: Mutex::new(Vec::new()),
+            request_bodies: Mutex::new(Vec::new()),
             status_code: AtomicUsize::new(200),
             delay_ms: AtomicUsize::new(0),
+            body: Mutex::new("Hello, World!".to_string()),
+            response_headers: Mutex::new(Vec::new()),
         }
     }
 }
@@ -94,9 +102,32 @@ impl MockServer {
             .store(delay_ms as usize, Ordering::SeqCst);
     }
 
+    pub fn set_response_body(&self, body: &str) {
+        *self.state.body.lock().unwrap() = body.to_string();
+    }
+
+    /// Send an extra `Name: Value` header with every response
+    pub fn add_response_header(&self, header: &str) {
+        self.state
+            .response_headers
+            .lock()
+            .unwrap()
+            .push(header.to_string());
+    }
+
     pub fn get_received_headers(&self) -> HashMap<String, Vec<String>> {
         self.state.headers.lock().unwrap().clone()
     }
+
+    /// Request lines such as `GET /path HTTP/1.1`, in the order they arrived
+    pub fn request_lines(&self) -> Vec<String> {
+        self.state.request_lines.lock().unwrap().clone()
+    }
+
+    /// Request bodies, in the order they arrived
+    pub fn request_bodies(&self) -> Vec<Vec<u8>> {
+        self.state.request_bodies.lock().unwrap().clone()
+    }
 }
 
 impl Drop for MockServer {
@@ -144,9 +175,28 @@ async fn handle_connection(mut stream: TcpStream, state: Arc<ServerState>) {
         }
     }
 
+    // Read the body the Content-Length header announces
+    let length = headers
+        .iter()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    if stream.read_exact(&mut body).await.is_ok() {
+        state.request_bodies.lock().unwrap().push(body);
+    }
+
     // Process headers - Do this inside a block to ensure the mutex is dropped before the await
     {
         let mut header_map = state.headers.lock().unwrap();
+        if let Some(request_line) = headers.first() {
+            state
+                .request_lines
+                .lock()
+                .unwrap()
+                .push(request_line.clone());
+        }
 
         for line in headers.iter().skip(1) {
             // Skip the request line
@@ -187,13 +237,23 @@ async fn handle_connection(mut stream: TcpStream, state: Arc<ServerState>) {
         _ => "Unknown",
     };
 
+    let body = state.body.lock().unwrap().clone();
+    let extra_headers: String = state
+        .response_headers
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|header| format!("{header}\r\n"))
+        .collect();
     let response = format!(
         "HTTP/1.1 {status} {status_text}\r\n\
          Content-Type: text/plain\r\n\
+         {extra_headers}\
          Connection: close\r\n\
-         Content-Length: 13\r\n\
+         Content-Length: {}\r\n\
          \r\n\
-         Hello, World!"
+         {body}",
+        body.len()
     );
 
     let _ = stream.write_all(response.as_bytes()).await;
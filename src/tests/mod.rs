@@ -20,14 +20,86 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+#[cfg(feature = "cli")]
+mod assertion_tests;
+mod breaker_tests;
+mod builder_tests;
+#[cfg(feature = "cli")]
+mod capacity_tests;
+mod capture_tests;
+mod certificate_tests;
+#[cfg(feature = "cli")]
 mod cli_tests;
+#[cfg(feature = "cli")]
 mod cli_tests_comprehensive;
+#[cfg(feature = "cli")]
 mod cli_tests_invalid;
+#[cfg(feature = "cli")]
+mod compare_tests;
+mod compress_tests;
+#[cfg(feature = "cli")]
+mod config_file_tests;
+#[cfg(feature = "cli")]
 mod config_tests;
+mod connect_timeout_tests;
+mod cookies_tests;
+#[cfg(feature = "cli")]
+mod curl_tests;
+#[cfg(feature = "cli")]
+mod debug_tests;
+mod describe_tests;
+mod dns_tests;
+mod downsample_tests;
+mod drain_tests;
+#[cfg(feature = "cli")]
 mod duration_parse_tests;
+#[cfg(feature = "cli")]
+mod export_tests;
+mod extract_tests;
+mod failover_tests;
+mod feeder_tests;
+mod grpc_tests;
+#[cfg(feature = "cli")]
+mod headless_tests;
+mod health_tests;
+mod hooks_tests;
+mod local_address_tests;
+mod main_tests;
 mod mock_server;
+mod oauth_tests;
+mod pacer_tests;
+mod proxy_tests;
+mod random_body_tests;
+#[cfg(feature = "cli")]
+mod replay_tests;
+mod report_tests;
+mod resolve_tests;
 mod runner_tests;
+#[cfg(feature = "cli")]
+mod scenario_tests;
+mod schedule_tests;
+#[cfg(feature = "cli")]
+mod sitemap_tests;
+mod skew_tests;
+mod sse_tests;
+#[cfg(feature = "cli")]
+mod stagger_tests;
+#[cfg(feature = "cli")]
+mod sweep_tests;
+mod template_tests;
+mod test_report_tests;
+mod theme_tests;
+#[cfg(feature = "cli")]
+mod think_time_tests;
+mod tls_server;
+mod tls_tests;
+#[cfg(feature = "cli")]
+mod url_mix_tests;
 mod url_tests;
+mod user_agent_tests;
+#[cfg(feature = "cli")]
+mod validate_tests;
 
 // Re-export MockServer for integration tests
 pub use mock_server::MockServer;
+pub use tls_server::TlsMockServer;
@@ -0,0 +1,181 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{
+    refresh_after, BearerToken, OAuthClient, SharedState, TestConfig, TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use reqwest::Client;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn oauth_client(server: &MockServer) -> OAuthClient {
+    OAuthClient {
+        token_url: format!("{}/token", server.url()),
+        client_id: "app".to_string(),
+        client_secret: "s3cret".to_string(),
+        scopes: vec!["read".to_string(), "write".to_string()],
+    }
+}
+
+#[test]
+fn test_refresh_after() {
+    assert_eq!(
+        refresh_after(Duration::from_secs(3600)),
+        Duration::from_secs(3240)
+    );
+    assert_eq!(
+        refresh_after(Duration::from_secs(0)),
+        Duration::from_secs(1)
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_token_uses_client_credentials() {
+    let server = MockServer::start().await;
+    server.set_response_body(r#"{"access_token":"abc","token_type":"Bearer","expires_in":60}"#);
+
+    let (token, lifetime) = oauth_client(&server)
+        .fetch_token(&Client::new())
+        .await
+        .unwrap();
+    assert_eq!(token, "abc");
+    assert_eq!(lifetime, Some(Duration::from_secs(60)));
+
+    assert_eq!(server.request_lines(), ["POST /token HTTP/1.1"]);
+    // "app:s3cret", base64-encoded
+    assert_eq!(
+        server.get_received_headers()["authorization"],
+        ["Basic YXBwOnMzY3JldA=="]
+    );
+    assert_eq!(
+        server.request_bodies(),
+        [b"grant_type=client_credentials&scope=read+write".to_vec()]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_token_errors() {
+    let server = MockServer::start().await;
+    server.set_response_body(r#"{"error":"invalid_client"}"#);
+    let err = oauth_client(&server)
+        .fetch_token(&Client::new())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no access_token"));
+
+    server.set_response_status(401);
+    assert!(oauth_client(&server)
+        .fetch_token(&Client::new())
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_token_is_refreshed_before_it_expires() {
+    let server = MockServer::start().await;
+    server.set_response_body(r#"{"access_token":"abc","expires_in":1}"#);
+    let is_running = Arc::new(AtomicBool::new(true));
+
+    let bearer = BearerToken::start(
+        oauth_client(&server),
+        Client::new(),
+        Arc::clone(&is_running),
+    )
+    .await
+    .unwrap();
+    assert_eq!(bearer.header_value(), "Bearer abc");
+
+    server.set_response_body(r#"{"access_token":"xyz","expires_in":1}"#);
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    assert_eq!(bearer.header_value(), "Bearer xyz");
+    assert_eq!(server.request_count(), 2);
+}
+
+#[tokio::test]
+async fn test_stopped_token_is_not_refreshed() {
+    let server = MockServer::start().await;
+    server.set_response_body(r#"{"access_token":"abc","expires_in":1}"#);
+    let is_running = Arc::new(AtomicBool::new(true));
+
+    let bearer = BearerToken::start(oauth_client(&server), Client::new(), is_running)
+        .await
+        .unwrap();
+    bearer.stop();
+
+    server.set_response_body(r#"{"access_token":"xyz","expires_in":1}"#);
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    assert_eq!(bearer.header_value(), "Bearer abc");
+    assert_eq!(server.request_count(), 1);
+}
+
+#[tokio::test]
+async fn test_runner_sends_bearer_token() {
+    let server = MockServer::start().await;
+    server.set_response_body(r#"{"access_token":"abc"}"#);
+
+    let state = run(TestConfig {
+        url: server.url(),
+        requests: 2,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        oauth: Some(oauth_client(&server)),
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(state.lock().unwrap().error_count, 0);
+    let authorization = &server.get_received_headers()["authorization"];
+    assert_eq!(authorization[1..], ["Bearer abc", "Bearer abc"]);
+}
+
+#[tokio::test]
+async fn test_runner_fails_without_a_token() {
+    let server = MockServer::start().await;
+    server.set_response_status(500);
+
+    let config = TestConfig {
+        url: server.url(),
+        interactive: false,
+        oauth: Some(oauth_client(&server)),
+        ..Default::default()
+    };
+    assert!(UnifiedRunner::new(config).start().await.is_err());
+    assert_eq!(server.request_count(), 1);
+}
@@ -0,0 +1,90 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{LiveRate, Pacer, RATE_STEP};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_pacer_supports_sub_millisecond_intervals() {
+    let mut pacer = Pacer::new(4000.0);
+    let start = Instant::now();
+
+    // Deadlines stay on the 250µs grid even when every wake-up is exact
+    let mut now = start;
+    for _ in 0..4000 {
+        now += pacer.next_delay(now);
+    }
+    assert_eq!(now - start, Duration::from_micros(250 * 3999));
+}
+
+#[test]
+fn test_pacer_absorbs_late_wake_ups() {
+    let mut pacer = Pacer::new(100.0);
+    let start = Instant::now();
+    assert_eq!(pacer.next_delay(start), Duration::ZERO);
+
+    // Waking 4ms late shortens the following wait instead of drifting
+    let late = start + Duration::from_millis(14);
+    assert_eq!(pacer.next_delay(late), Duration::ZERO);
+    assert_eq!(pacer.next_delay(late), Duration::from_millis(6));
+}
+
+#[test]
+fn test_pacer_drops_missed_slots_after_a_stall() {
+    let mut pacer = Pacer::new(100.0);
+    let start = Instant::now();
+    pacer.next_delay(start);
+
+    // A worker stuck for a second does not get to send the 100 requests it missed
+    let resumed = start + Duration::from_secs(1);
+    assert_eq!(pacer.next_delay(resumed), Duration::ZERO);
+    assert_eq!(pacer.next_delay(resumed), Duration::from_millis(10));
+}
+
+#[test]
+fn test_pacer_follows_rate_changes() {
+    let mut pacer = Pacer::new(100.0);
+    let start = Instant::now();
+    pacer.next_delay(start);
+
+    // The slot already booked keeps its spacing; later ones use the new rate
+    pacer.set_rate(10.0);
+    assert_eq!(pacer.next_delay(start), Duration::from_millis(10));
+    assert_eq!(pacer.next_delay(start), Duration::from_millis(110));
+}
+
+#[test]
+fn test_live_rate_steps() {
+    let rate = LiveRate::new(100.0);
+    let shared = rate.clone();
+
+    assert_eq!(rate.step(true, 0.0), Some(100.0 * RATE_STEP));
+    assert_eq!(shared.get(), 100.0 * RATE_STEP);
+    assert_eq!(rate.step(false, 0.0), Some(100.0));
+
+    // Without a limit there is nothing to raise, and lowering starts from the measured rate
+    let unlimited = LiveRate::default();
+    assert_eq!(unlimited.step(true, 40.0), None);
+    assert_eq!(unlimited.step(false, 0.0), None);
+    assert_eq!(unlimited.step(false, 40.0), Some(40.0 / RATE_STEP));
+    assert_eq!(unlimited.get(), 40.0 / RATE_STEP);
+}
@@ -0,0 +1,103 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{
+    BodyGenerator, HttpMethod, RandomBody, SharedState, TestConfig, TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn random_body(size: usize, seed: Option<u64>, per_request: bool) -> RandomBody {
+    RandomBody {
+        size,
+        seed,
+        per_request,
+    }
+}
+
+#[test]
+fn test_generator_makes_bodies_of_the_requested_size() {
+    for size in [0, 1, 7, 8, 1000] {
+        let mut generator = BodyGenerator::new(random_body(size, None, false));
+        assert_eq!(generator.next_body().len(), size);
+    }
+}
+
+#[test]
+fn test_seed_makes_bodies_repeatable() {
+    let body = |seed| BodyGenerator::new(random_body(64, Some(seed), false)).next_body();
+    assert_eq!(body(42), body(42));
+    assert_ne!(body(42), body(43));
+
+    let mut generator = BodyGenerator::new(random_body(64, Some(42), false));
+    assert_ne!(generator.next_body(), generator.next_body());
+}
+
+async fn received_bodies(per_request: bool) -> Vec<Vec<u8>> {
+    let server = MockServer::start().await;
+    let state = run(TestConfig {
+        url: server.url(),
+        method: HttpMethod::POST,
+        requests: 3,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        random_body: Some(random_body(1024, Some(7), per_request)),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(state.lock().unwrap().error_count, 0);
+    server.request_bodies()
+}
+
+#[tokio::test]
+async fn test_random_body_once_per_test() {
+    let bodies = received_bodies(false).await;
+    assert_eq!(bodies.len(), 3);
+    assert_eq!(bodies[0].len(), 1024);
+    assert!(bodies.iter().all(|body| *body == bodies[0]));
+}
+
+#[tokio::test]
+async fn test_random_body_per_request() {
+    let mut bodies = received_bodies(true).await;
+    assert_eq!(bodies.len(), 3);
+    assert!(bodies.iter().all(|body| body.len() == 1024));
+    bodies.dedup();
+    assert_eq!(bodies.len(), 3);
+}
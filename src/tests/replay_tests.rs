@@ -0,0 +1,157 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::args::Command;
+use crate::replay_cli::parse_har;
+use crate::tester::{group_steps, HttpMethod, SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use crate::Args;
+use clap::Parser;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn har(base: &str) -> String {
+    json!({
+        "log": {
+            "version": "1.2",
+            "entries": [
+                {"request": {
+                    "method": "GET",
+                    "url": format!("{base}/items?page=1"),
+                    "headers": [
+                        {"name": ":authority", "value": "example.com"},
+                        {"name": "Host", "value": "example.com"},
+                        {"name": "X-Trace", "value": "abc"}
+                    ]
+                }},
+                {"request": {"method": "GET", "url": "data:image/png;base64,AAAA", "headers": []}},
+                {"request": {
+                    "method": "POST",
+                    "url": format!("{base}/items"),
+                    "headers": [{"name": "Content-Length", "value": "13"}],
+                    "postData": {"mimeType": "application/json", "text": "{\"name\":\"a\"}"}
+                }},
+                {"request": {"method": "GET", "url": format!("{base}/items?page=2"), "headers": []}}
+            ]
+        }
+    })
+    .to_string()
+}
+
+#[test]
+fn test_parse_har() {
+    let (steps, skipped) = parse_har(&har("http://example.com")).unwrap();
+    assert_eq!(skipped, 1);
+    assert_eq!(steps.len(), 3);
+
+    assert_eq!(steps[0].name, "/items");
+    assert_eq!(steps[0].method, HttpMethod::GET);
+    assert_eq!(steps[0].url, "http://example.com/items?page=1");
+    assert_eq!(
+        steps[0].headers,
+        [("X-Trace".to_string(), "abc".to_string())]
+    );
+
+    assert_eq!(steps[1].method, HttpMethod::POST);
+    assert_eq!(steps[1].body.as_deref(), Some(r#"{"name":"a"}"#));
+    assert_eq!(
+        steps[1].headers,
+        [("Content-Type".to_string(), "application/json".to_string())]
+    );
+}
+
+#[test]
+fn test_parse_har_errors() {
+    assert!(parse_har("not json").is_err());
+    assert!(parse_har(r#"{"log": {}}"#).is_err());
+    assert!(parse_har(r#"{"log": {"entries": [{}]}}"#).is_err());
+    assert_eq!(parse_har(r#"{"log": {"entries": []}}"#).unwrap().0, []);
+}
+
+#[test]
+fn test_steps_sharing_a_name_share_stats() {
+    let (steps, _) = parse_har(&har("http://example.com")).unwrap();
+    let (stats, groups) = group_steps(&steps);
+    assert_eq!(stats.len(), 1);
+    assert_eq!(groups, [0, 0, 0]);
+}
+
+#[test]
+fn test_replay_args() {
+    let args = Args::try_parse_from(["whambam", "replay", "session.har", "-c", "2"]).unwrap();
+    let Some(Command::Replay(replay)) = args.command else {
+        panic!("expected the replay subcommand");
+    };
+    assert_eq!(replay.file.to_str(), Some("session.har"));
+    assert_eq!(replay.concurrent, 2);
+    assert!(Args::try_parse_from(["whambam", "replay"]).is_err());
+}
+
+#[tokio::test]
+async fn test_runner_replays_har_entries() {
+    let server = MockServer::start().await;
+    let (steps, _) = parse_har(&har(&server.url())).unwrap();
+    let state = run(TestConfig {
+        url: steps[0].url.clone(),
+        requests: 6,
+        concurrent: 1,
+        timeout: 2,
+        interactive: false,
+        scenario: steps,
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(
+        server.request_lines(),
+        [
+            "GET /items?page=1 HTTP/1.1",
+            "POST /items HTTP/1.1",
+            "GET /items?page=2 HTTP/1.1"
+        ]
+        .repeat(2)
+    );
+    let headers = server.get_received_headers();
+    assert_eq!(headers["x-trace"].len(), 2);
+    assert_eq!(headers["content-type"], ["application/json"; 2]);
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.steps.len(), 1);
+    assert_eq!(state.steps[0].latency.count(), 6);
+}
@@ -0,0 +1,371 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    print_hey_format_report, print_partial_summary, EndReason, HttpMethod, Protocol, RequestMetric,
+    StopReason, TestConfig, TestState, REQUEST_LOG_LEN, STATUS_CLASSES,
+};
+
+fn test_state(requests: usize) -> TestState {
+    #[allow(deprecated)]
+    let config = TestConfig {
+        url: "http://localhost".to_string(),
+        method: HttpMethod::GET,
+        requests,
+        concurrent: 1,
+        duration: 0,
+        rate_limit: 0.0,
+        headers: vec![],
+        timeout: 20,
+        body: None,
+        content_type: "text/html".to_string(),
+        basic_auth: None,
+        proxy: None,
+        disable_compression: false,
+        disable_keepalive: false,
+        disable_redirects: false,
+        interactive: true,
+        output_format: String::new(),
+        ..Default::default()
+    };
+    TestState::new(&config)
+}
+
+fn metric(latency_ms: f64, status_code: u16, is_error: bool) -> RequestMetric {
+    RequestMetric {
+        timestamp: 0.0,
+        latency_ms,
+        status_code,
+        is_error,
+        bytes_sent: 10,
+        bytes_received: 20,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_partial_summary_contents() {
+    let mut state = test_state(100);
+    state.update(metric(10.0, 200, false));
+    state.update(metric(20.0, 200, false));
+    state.update(metric(30.0, 500, true));
+    state.update(metric(40.0, 200, false));
+
+    let mut buf = Vec::new();
+    print_partial_summary(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(output.contains("Test stopped before completion"));
+    assert!(output.contains("Completed:\t4 of 100 requests"));
+    assert!(output.contains("Errors:\t1 (25.0%)"));
+    assert!(output.contains("p50 20.000 ms"));
+    assert!(output.contains("p99 40.000 ms"));
+}
+
+#[test]
+fn test_partial_summary_without_requests() {
+    let state = test_state(0);
+
+    let mut buf = Vec::new();
+    print_partial_summary(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(output.contains("Completed:\t0 requests"));
+    assert!(output.contains("Errors:\t0 (0.0%)"));
+    assert!(output.contains("p50 0.000 ms, p99 0.000 ms"));
+}
+
+#[test]
+fn test_report_names_shutdown_signal() {
+    for (reason, text) in [
+        (StopReason::Interrupted, "Test aborted: interrupted"),
+        (StopReason::Terminated, "Test aborted: terminated"),
+    ] {
+        let mut state = test_state(100);
+        state.update(metric(10.0, 200, false));
+        state.stop_reason = Some(reason);
+
+        let mut buf = Vec::new();
+        print_hey_format_report(&mut buf, &state).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+
+        assert!(output.contains(text));
+    }
+}
+
+#[test]
+fn test_report_protocol_distribution() {
+    let mut state = test_state(3);
+    for protocol in [Protocol::Http11, Protocol::Http3, Protocol::Http3] {
+        state.update(RequestMetric {
+            protocol: Some(protocol),
+            ..metric(10.0, 200, false)
+        });
+    }
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(output.contains("Protocol distribution:"));
+    assert!(output.contains("  [HTTP/1.1]\t1 responses"));
+    assert!(output.contains("  [HTTP/3]\t2 responses"));
+}
+
+#[test]
+fn test_report_hides_plain_http11_protocol() {
+    let mut state = test_state(1);
+    state.update(RequestMetric {
+        protocol: Some(Protocol::Http11),
+        ..metric(10.0, 200, false)
+    });
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(!output.contains("Protocol distribution:"));
+    assert_eq!(state.protocol_counts[&Protocol::Http11], 1);
+}
+
+#[test]
+fn test_protocol_from_version() {
+    assert_eq!(
+        Protocol::from_version(reqwest::Version::HTTP_2),
+        Some(Protocol::Http2)
+    );
+    assert_eq!(
+        Protocol::from_version(reqwest::Version::HTTP_3),
+        Some(Protocol::Http3)
+    );
+    assert_eq!(Protocol::Http3.to_string(), "HTTP/3");
+}
+
+#[test]
+fn test_report_shows_connection_details() {
+    let mut state = test_state(3);
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    assert!(!String::from_utf8(buf).unwrap().contains("Details"));
+
+    // Only requests that opened a connection carry a handshake time
+    for tls_handshake_ms in [Some(12.0), None, Some(8.0)] {
+        state.update(RequestMetric {
+            dns_lookup_ms: tls_handshake_ms.map(|_| 1.0),
+            tls_handshake_ms,
+            ..metric(20.0, 200, false)
+        });
+    }
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(output.contains("Details (average, fastest, slowest):"));
+    assert!(output.contains("  DNS-lookup:\t0.0010 secs, 0.0010 secs, 0.0010 secs"));
+    assert!(output.contains("  TLS handshake:\t0.0100 secs, 0.0080 secs, 0.0120 secs"));
+    assert!(output.contains("Details (50%, 90%, 99%):"));
+    assert!(output.contains("  TLS handshake:\t0.0080 secs, 0.0120 secs, 0.0120 secs"));
+    assert!(!output.contains("Resp read"));
+    assert_eq!(state.tls_handshake.count(), 2);
+}
+
+#[test]
+fn test_report_separates_connect_timeouts() {
+    let mut state = test_state(3);
+    state.update(RequestMetric {
+        connect_timeout: true,
+        ..metric(3000.0, 0, true)
+    });
+    state.update(metric(5.0, 0, true));
+    state.update(metric(5.0, 200, false));
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(output.contains("Error distribution:"));
+    assert!(output.contains("  [1]\tconnect timeouts"));
+    assert!(output.contains("  [1]\tconnection errors"));
+    assert_eq!(state.connect_timeouts, 1);
+}
+
+#[test]
+fn test_report_names_the_limit_that_ended_the_run() {
+    let mut state = test_state(2);
+    state.update(metric(5.0, 200, false));
+    state.update(metric(5.0, 200, false));
+    assert_eq!(state.end_reason, Some(EndReason::RequestLimit(2)));
+
+    // With only -n set there is nothing to choose between
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    assert!(!String::from_utf8(buf).unwrap().contains("Test ended"));
+
+    state.duration = 10;
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("Test ended: all 2 requests completed"));
+}
+
+#[test]
+fn test_latency_buckets_show_both_peaks() {
+    let mut state = test_state(0);
+    assert!(state.latency_buckets(10).is_empty());
+
+    for _ in 0..30 {
+        state.update(metric(2.0, 200, false));
+    }
+    for _ in 0..10 {
+        state.update(metric(200.0, 200, false));
+    }
+
+    let buckets = state.latency_buckets(10);
+    assert_eq!(buckets.len(), 10);
+    assert_eq!(buckets.iter().map(|&(_, count)| count).sum::<u64>(), 40);
+    assert_eq!(buckets[0].1, 30);
+    assert_eq!(buckets[9].1, 10);
+    assert!(buckets[1..9].iter().all(|&(_, count)| count == 0));
+    assert!(buckets.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!((buckets[9].0 - 200.0).abs() < 1.0);
+}
+
+#[test]
+fn test_latency_buckets_with_one_latency() {
+    let mut state = test_state(0);
+    state.update(metric(5.0, 200, false));
+    state.update(metric(5.0, 200, false));
+
+    let buckets = state.latency_buckets(10);
+    assert_eq!(buckets.len(), 1);
+    assert_eq!(buckets[0].1, 2);
+}
+
+#[test]
+fn test_request_log_keeps_the_latest_requests() {
+    let mut state = test_state(0);
+    for i in 0..REQUEST_LOG_LEN + 5 {
+        state.update(metric(i as f64, 200, false));
+    }
+
+    assert_eq!(state.request_log.len(), REQUEST_LOG_LEN);
+    assert_eq!(state.request_log.front().unwrap().latency_ms, 5.0);
+    assert_eq!(
+        state.request_log.back().unwrap().latency_ms,
+        (REQUEST_LOG_LEN + 4) as f64
+    );
+
+    state.reset();
+    assert!(state.request_log.is_empty());
+}
+
+#[test]
+fn test_percentile_series_use_each_interval_alone() {
+    let mut state = test_state(1000);
+    state.update(metric(10.0, 200, false));
+    for _ in 0..97 {
+        state.update(metric(20.0, 200, false));
+    }
+    state.update(metric(500.0, 200, false));
+    state.update(metric(500.0, 200, false));
+
+    // The next request after a second closes the interval
+    state.start_time -= std::time::Duration::from_secs(2);
+    state.update(metric(20.0, 200, false));
+
+    let p50: Vec<f64> = state.p50_history.points().iter().map(|p| p.1).collect();
+    let p99: Vec<f64> = state.p99_history.points().iter().map(|p| p.1).collect();
+    assert_eq!(p50.len(), 2);
+    assert!((p50[0] - 10.0).abs() < 0.1);
+    assert!((p99[0] - 10.0).abs() < 0.1);
+    assert!((p50[1] - 20.0).abs() < 0.1);
+    assert!((p99[1] - 500.0).abs() < 1.0);
+    assert!(state.interval_histogram.is_empty());
+
+    state.rebaseline_charts();
+    assert!(state.p99_history.is_empty());
+}
+
+#[test]
+fn test_status_classes_split_throughput() {
+    let mut state = test_state(1000);
+    state.update(metric(10.0, 200, false));
+    for status in [200, 301, 404, 503, 503] {
+        state.update(metric(10.0, status, status >= 400));
+    }
+
+    // The next request after a second closes the interval
+    state.start_time -= std::time::Duration::from_secs(2);
+    state.update(metric(10.0, 0, true));
+
+    let points = state.status_classes.points();
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].1, [1.0, 0.0, 0.0, 0.0, 0.0]);
+    let counts = points[1].1.map(|rate| rate * (points[1].0 - points[0].0));
+    let expected = [1.0, 1.0, 1.0, 2.0, 1.0];
+    assert!(counts
+        .iter()
+        .zip(expected)
+        .all(|(a, b)| (a - b).abs() < 1e-6));
+    assert_eq!(state.interval_status_classes, [0; STATUS_CLASSES.len()]);
+}
+
+#[test]
+fn test_progress_and_eta_from_request_limit() {
+    let mut state = test_state(100);
+    assert_eq!(state.progress(), Some(0.0));
+    assert_eq!(state.eta_secs(), None);
+
+    state.start_time -= std::time::Duration::from_secs(10);
+    for _ in 0..25 {
+        state.update(metric(10.0, 200, false));
+    }
+    assert_eq!(state.progress(), Some(0.25));
+    // 25 requests in 10s leaves 75 for about 30s more
+    let eta = state.eta_secs().unwrap();
+    assert!((eta - 30.0).abs() < 0.5, "{eta}");
+}
+
+#[test]
+fn test_progress_takes_the_nearer_limit() {
+    let mut state = test_state(0);
+    assert_eq!(state.progress(), None);
+    assert_eq!(state.eta_secs(), None);
+
+    state.duration = 100;
+    state.start_time -= std::time::Duration::from_secs(40);
+    let progress = state.progress().unwrap();
+    assert!((progress - 0.4).abs() < 0.01, "{progress}");
+    let eta = state.eta_secs().unwrap();
+    assert!((eta - 60.0).abs() < 0.5, "{eta}");
+
+    // Requests running ahead of the clock end the test first
+    state.target_requests = 100;
+    state.completed_requests = 80;
+    assert_eq!(state.progress(), Some(0.8));
+    let eta = state.eta_secs().unwrap();
+    assert!((eta - 10.0).abs() < 0.5, "{eta}");
+
+    state.mark_complete();
+    assert_eq!(state.eta_secs(), None);
+}
@@ -0,0 +1,126 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{HostOverride, SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+#[test]
+fn test_parse_resolve_entry() {
+    let entry = HostOverride::parse("API.example.com:443:10.0.0.5").unwrap();
+    assert_eq!(entry.host, "api.example.com");
+    assert_eq!(entry.port, 443);
+    assert_eq!(entry.addrs, vec!["10.0.0.5".parse::<IpAddr>().unwrap()]);
+
+    let entry = HostOverride::parse("example.com:8443:[::1],127.0.0.1").unwrap();
+    assert_eq!(entry.addrs.len(), 2);
+    assert_eq!(entry.to_string(), "example.com:8443:[::1],127.0.0.1");
+
+    for invalid in [
+        "example.com",
+        "example.com:443",
+        ":443:10.0.0.5",
+        "example.com:https:10.0.0.5",
+        "example.com:443:not-an-ip",
+    ] {
+        assert!(HostOverride::parse(invalid).is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn test_resolve_entry_matches_host_and_port() {
+    let entry = HostOverride::parse("example.com:443:10.0.0.5").unwrap();
+    assert!(entry.matches(&Url::parse("https://example.com/path").unwrap()));
+    assert!(!entry.matches(&Url::parse("http://example.com/").unwrap()));
+    assert!(!entry.matches(&Url::parse("https://other.example.com/").unwrap()));
+}
+
+#[tokio::test]
+async fn test_resolve_sends_to_given_address() {
+    let server = MockServer::start().await;
+    let port = Url::parse(&server.url()).unwrap().port().unwrap();
+
+    let config = TestConfig {
+        url: format!("http://backend.whambam.invalid:{port}/"),
+        resolve: vec![
+            HostOverride::parse(&format!("backend.whambam.invalid:{port}:127.0.0.1")).unwrap(),
+        ],
+        requests: 3,
+        concurrent: 1,
+        timeout: 2,
+        ..Default::default()
+    };
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 0);
+    assert_eq!(state.dns_lookup.count(), 0);
+    assert_eq!(server.request_count(), 3);
+    let headers = server.get_received_headers();
+    assert!(headers["host"][0].starts_with("backend.whambam.invalid"));
+}
+
+#[tokio::test]
+async fn test_host_header_overrides_connection_target() {
+    let server = MockServer::start().await;
+
+    let config = TestConfig {
+        url: server.url(),
+        headers: vec![("Host".to_string(), "shop.example".to_string())],
+        requests: 2,
+        concurrent: 1,
+        timeout: 2,
+        ..Default::default()
+    };
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    assert_eq!(state.lock().unwrap().error_count, 0);
+    assert_eq!(
+        server.get_received_headers()["host"],
+        ["shop.example", "shop.example"]
+    );
+}
@@ -20,7 +20,10 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::tester::{HttpMethod, SharedState, TestConfig, UnifiedRunner as TestRunner};
+use crate::tester::{
+    CircuitBreaker, EndReason, HttpMethod, ResponseClassifier, SharedState, StopReason, TestConfig,
+    UnifiedRunner as TestRunner,
+};
 use crate::tests::MockServer;
 use std::{
     sync::{Arc, Mutex},
@@ -34,6 +37,7 @@ async fn test_runner_basic_functionality() {
     let server = MockServer::start().await;
 
     // Create test config
+    #[allow(deprecated)]
     let config = TestConfig {
         url: server.url(),
         method: HttpMethod::GET,
@@ -52,6 +56,7 @@ async fn test_runner_basic_functionality() {
         disable_redirects: false,
         interactive: false,
         output_format: "hey".to_string(),
+        ..Default::default()
     };
 
     // Create shared state and test runner
@@ -114,6 +119,7 @@ async fn test_runner_with_errors() {
     server.set_response_status(500);
 
     // Create test config
+    #[allow(deprecated)]
     let config = TestConfig {
         url: server.url(),
         method: HttpMethod::GET,
@@ -132,6 +138,7 @@ async fn test_runner_with_errors() {
         disable_redirects: false,
         interactive: false,
         output_format: "hey".to_string(),
+        ..Default::default()
     };
 
     // Create shared state and test runner
@@ -190,6 +197,7 @@ async fn test_runner_duration_limit() {
     server.set_response_delay(100);
 
     // Create test config with 1 second duration limit
+    #[allow(deprecated)]
     let config = TestConfig {
         url: server.url(),
         method: HttpMethod::GET,
@@ -208,6 +216,7 @@ async fn test_runner_duration_limit() {
         disable_redirects: false,
         interactive: false,
         output_format: "hey".to_string(),
+        ..Default::default()
     };
 
     // Create shared state and test runner
@@ -247,7 +256,469 @@ async fn test_runner_duration_limit() {
     // assert!(test_state.is_complete);
     assert!(test_state.completed_requests < 100); // Should not have completed all requests
     assert!(test_state.completed_requests > 0); // But should have completed some
+    assert_eq!(test_state.end_reason, Some(EndReason::DurationLimit(1)));
 
     // Verify server received some requests (but don't require an exact match)
     assert!(server.request_count() > 0);
 }
+
+#[tokio::test]
+async fn test_runner_request_limit_before_duration() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        requests: 5,
+        concurrent: 1,
+        duration: 30,
+        timeout: 2,
+        interactive: false,
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    TestRunner::with_state(config, shared_state)
+        .start()
+        .await
+        .expect("Runner failed to start");
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    // The request count runs out long before the 30 second duration
+    let test_state = state.lock().unwrap();
+    assert!(test_state.is_complete);
+    assert_eq!(test_state.completed_requests, 5);
+    assert_eq!(test_state.end_reason, Some(EndReason::RequestLimit(5)));
+}
+
+#[tokio::test]
+async fn test_runner_latency_circuit_breaker() {
+    // Start a slow mock server
+    let server = MockServer::start().await;
+    server.set_response_delay(50);
+
+    // Trip as soon as one full second has p99 above 10ms
+    let config = TestConfig {
+        url: server.url(),
+        requests: 0,
+        concurrent: 2,
+        duration: 30,
+        timeout: 2,
+        interactive: false,
+        circuit_breaker: Some(CircuitBreaker {
+            p99_limit_ms: 10.0,
+            consecutive_secs: 1,
+        }),
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    // The breaker should stop the test long before the 30 second duration
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert!(test_state.is_complete);
+    assert!(matches!(
+        test_state.stop_reason,
+        Some(StopReason::LatencyBreaker { seconds: 1, .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_runner_aborts_after_errors() {
+    let server = MockServer::start().await;
+    server.set_response_status(500);
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 0,
+        concurrent: 1,
+        duration: 30,
+        timeout: 2,
+        interactive: false,
+        abort_after_errors: Some(5),
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    // Only the request already in flight when the limit was hit gets through
+    let test_state = state.lock().unwrap();
+    assert!(test_state.is_complete);
+    assert!(test_state.error_count >= 5 && test_state.error_count <= 6);
+    assert_eq!(
+        test_state.stop_reason,
+        Some(StopReason::ErrorLimit { errors: 5 })
+    );
+}
+
+#[tokio::test]
+async fn test_runner_custom_success_statuses() {
+    // A not-found endpoint is the expected outcome for this test
+    let server = MockServer::start().await;
+    server.set_response_status(404);
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 10,
+        concurrent: 2,
+        timeout: 1,
+        interactive: false,
+        classifier: ResponseClassifier {
+            success_statuses: vec![200, 404],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert!(test_state.completed_requests > 0);
+    assert_eq!(test_state.error_count, 0);
+    assert_eq!(
+        test_state.status_counts[&404],
+        test_state.completed_requests
+    );
+}
+
+#[tokio::test]
+async fn test_runner_sends_custom_method() {
+    let server = MockServer::start().await;
+
+    let config = TestConfig {
+        url: server.url(),
+        method: HttpMethod::Custom("PURGE".into()),
+        requests: 3,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert_eq!(test_state.completed_requests, 3);
+    assert_eq!(test_state.error_count, 0);
+    assert_eq!(test_state.method.to_string(), "PURGE");
+}
+
+#[tokio::test]
+async fn test_runner_times_request_phases() {
+    let server = MockServer::start().await;
+
+    // IP literals skip the resolver; the mock server closes every connection
+    for (url, lookups) in [
+        (server.url(), 0),
+        (server.url().replace("127.0.0.1", "localhost"), 3),
+    ] {
+        let config = TestConfig {
+            url,
+            requests: 3,
+            concurrent: 1,
+            timeout: 1,
+            interactive: false,
+            ..Default::default()
+        };
+
+        let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+        let shared_state = SharedState {
+            state: Arc::clone(&state),
+        };
+        let mut runner = TestRunner::with_state(config, shared_state);
+        runner.start().await.expect("Runner failed to start");
+
+        for _ in 0..50 {
+            if state.lock().unwrap().is_complete {
+                break;
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+
+        let test_state = state.lock().unwrap();
+        assert_eq!(test_state.error_count, 0);
+        assert_eq!(test_state.dns_lookup.count(), lookups);
+        // Plain HTTP has no handshake to end the connect phase, so it folds into the wait
+        assert_eq!(test_state.connect.count(), 0);
+        assert_eq!(test_state.ttfb.count(), 3);
+    }
+}
+
+#[tokio::test]
+async fn test_runner_caps_body_bytes() {
+    let server = MockServer::start().await;
+    server.set_response_body(&"x".repeat(10_000));
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 2,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        max_body_bytes: Some(1000),
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert_eq!(test_state.error_count, 0);
+    assert_eq!(test_state.status_counts.get(&200), Some(&2));
+    assert_eq!(test_state.total_bytes_received, 2000);
+    assert_eq!(test_state.ttfb.count(), 2);
+}
+
+#[tokio::test]
+async fn test_runner_sends_binary_body_untouched() {
+    let server = MockServer::start().await;
+    // Not valid UTF-8, and the placeholder must not be filled in
+    let body = vec![0x1f, 0x8b, 0x00, 0xff, b'$', b'{', b'S', b'E', b'Q', b'}'];
+
+    let config = TestConfig {
+        url: server.url(),
+        method: HttpMethod::POST,
+        requests: 2,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        body: Some(body.clone()),
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    assert_eq!(state.lock().unwrap().error_count, 0);
+    assert_eq!(server.request_bodies(), vec![body.clone(), body]);
+}
+
+#[tokio::test]
+async fn test_runner_http2_prior_knowledge() {
+    // The mock server only speaks HTTP/1.1, so an h2-only client cannot get a response
+    let server = MockServer::start().await;
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 3,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        http2_prior_knowledge: true,
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let test_state = state.lock().unwrap();
+    assert_eq!(test_state.completed_requests, 3);
+    assert_eq!(test_state.error_count, 3);
+    assert!(test_state.status_counts.is_empty());
+}
+
+#[cfg(not(feature = "http3"))]
+#[tokio::test]
+async fn test_runner_http3_requires_feature() {
+    let config = TestConfig {
+        url: "https://example.com".to_string(),
+        http3: true,
+        ..Default::default()
+    };
+
+    let err = TestRunner::new(config).start().await.unwrap_err();
+    assert!(err.to_string().contains("--features http3"));
+}
+
+#[tokio::test]
+async fn test_runner_stops_from_stop_handle() {
+    let server = MockServer::start().await;
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 0,
+        concurrent: 2,
+        rate_limit: 50.0,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    let stop = runner.stop_handle();
+    sleep(Duration::from_millis(300)).await;
+
+    // Another task can stop an unlimited run, which then drains and completes
+    stop.store(false, std::sync::atomic::Ordering::SeqCst);
+    for _ in 0..50 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let state = state.lock().unwrap();
+    assert!(state.is_complete);
+    assert!(state.completed_requests > 0);
+}
+
+#[tokio::test]
+async fn test_runner_pauses_and_resumes() {
+    let server = MockServer::start().await;
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 0,
+        concurrent: 2,
+        rate_limit: 50.0,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = TestRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    sleep(Duration::from_millis(300)).await;
+
+    // Pausing through the shared state, as the UI does, stops new requests
+    let paused = Arc::clone(&state.lock().unwrap().paused);
+    paused.store(true, std::sync::atomic::Ordering::SeqCst);
+    sleep(Duration::from_millis(200)).await;
+    let held = server.request_count();
+    sleep(Duration::from_millis(500)).await;
+    assert_eq!(server.request_count(), held);
+    assert!(!state.lock().unwrap().is_complete);
+
+    runner.resume();
+    sleep(Duration::from_millis(300)).await;
+    assert!(server.request_count() > held);
+    runner.stop();
+}
+
+#[tokio::test]
+async fn test_runner_rate_limit_changes_while_running() {
+    let server = MockServer::start().await;
+
+    let config = TestConfig {
+        url: server.url(),
+        requests: 0,
+        concurrent: 1,
+        rate_limit: 5.0,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    let runner = {
+        let state = Arc::new(Mutex::new(crate::tester::TestState::new(&config)));
+        let mut runner = TestRunner::with_state(config, SharedState { state });
+        runner.start().await.expect("Runner failed to start");
+        runner
+    };
+    sleep(Duration::from_millis(1000)).await;
+    let slow = server.request_count();
+
+    runner.set_rate_limit(100.0);
+    sleep(Duration::from_millis(1000)).await;
+    let fast = server.request_count() - slow;
+    runner.stop();
+
+    assert!(slow <= 7, "{slow} requests at 5 QPS");
+    assert!(fast >= 30, "{fast} requests at 100 QPS");
+}
@@ -0,0 +1,155 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::args::parse_step;
+use crate::tester::{
+    print_hey_format_report, HttpMethod, ScenarioStep, ScenarioUser, SharedState, TestConfig,
+    TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+fn steps(specs: &[&str]) -> Vec<ScenarioStep> {
+    specs.iter().map(|spec| parse_step(spec).unwrap()).collect()
+}
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+#[test]
+fn test_parse_step() {
+    assert_eq!(
+        parse_step(r#"login POST /api/login {"user": "demo"}"#).unwrap(),
+        ScenarioStep {
+            name: "login".to_string(),
+            method: HttpMethod::POST,
+            url: "/api/login".to_string(),
+            body: Some(r#"{"user": "demo"}"#.to_string()),
+            headers: Vec::new(),
+        }
+    );
+    assert_eq!(parse_step("fetch get /items").unwrap().body, None);
+
+    assert!(parse_step("fetch GET").is_err());
+    assert!(parse_step("fetch G3T /items").is_err());
+}
+
+#[test]
+fn test_user_cycles_through_steps() {
+    let base = Url::parse("http://example.com/app/").unwrap();
+    let scenario = steps(&[
+        "login POST login",
+        "fetch GET /items",
+        "other GET http://other.test/x",
+    ]);
+    let mut user = ScenarioUser::new(base, scenario.into());
+
+    let urls: Vec<_> = (0..4)
+        .map(|_| {
+            let request = user.next_request();
+            (request.step, request.url.to_string())
+        })
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            (0, "http://example.com/app/login".to_string()),
+            (1, "http://example.com/items".to_string()),
+            (2, "http://other.test/x".to_string()),
+            (0, "http://example.com/app/login".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_runner_tracks_each_step() {
+    let server = MockServer::start().await;
+    let state = run(TestConfig {
+        url: server.url(),
+        requests: 6,
+        concurrent: 1,
+        timeout: 2,
+        interactive: false,
+        scenario: steps(&["login POST /login {}", "fetch GET /items?page=1"]),
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(
+        server.request_lines(),
+        ["POST /login HTTP/1.1", "GET /items?page=1 HTTP/1.1",].repeat(3)
+    );
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.steps.len(), 2);
+    assert!(state.steps.iter().all(|step| step.latency.count() == 3));
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("Scenario steps:"));
+    assert!(output.contains("  [login]\t3 requests, 0 errors"));
+}
+
+#[tokio::test]
+async fn test_runner_rejects_bad_step_url() {
+    let config = TestConfig {
+        url: "http://example.com".to_string(),
+        scenario: steps(&["bad GET http://[::1"]),
+        ..Default::default()
+    };
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let mut runner = UnifiedRunner::with_state(config, SharedState { state });
+    let err = runner.start().await.unwrap_err();
+    assert!(err.to_string().contains("step 'bad'"));
+}
+
+#[test]
+fn test_scenario_in_config_file() {
+    let path = std::env::temp_dir().join(format!("whambam-{}-scenario.yaml", std::process::id()));
+    std::fs::write(
+        &path,
+        "url: http://example.com\nstep:\n  - \"login POST /login {\\\"user\\\": \\\"demo\\\"}\"\n  - fetch GET /items\n",
+    )
+    .unwrap();
+
+    let args = crate::config_file::try_parse_from(["whambam", "--config", path.to_str().unwrap()])
+        .unwrap();
+    assert_eq!(args.steps.len(), 2);
+    assert_eq!(args.steps[0].body.as_deref(), Some(r#"{"user": "demo"}"#));
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,175 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    stages_duration, ArrivalSchedule, Burst, BurstSchedule, LoadPattern, LoadStage,
+    PatternSchedule, SharedState, StageSchedule, TestConfig, TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn stage(secs: u64, rate: f64) -> LoadStage {
+    LoadStage {
+        duration: Duration::from_secs(secs),
+        rate,
+    }
+}
+
+#[test]
+fn test_stage_parse() {
+    assert_eq!(LoadStage::parse("60s:100rps").unwrap(), stage(60, 100.0));
+    assert_eq!(LoadStage::parse("2m:500").unwrap(), stage(120, 500.0));
+    assert_eq!(LoadStage::parse("1h:0.5rps").unwrap(), stage(3600, 0.5));
+    assert_eq!(LoadStage::parse("30s:0rps").unwrap(), stage(30, 0.0));
+
+    assert!(LoadStage::parse("60s").is_err());
+    assert!(LoadStage::parse("60:100rps").is_err());
+    assert!(LoadStage::parse("0s:100rps").is_err());
+    assert!(LoadStage::parse("60s:-5rps").is_err());
+    assert!(LoadStage::parse("60s:fastrps").is_err());
+    assert!(LoadStage::parse("5é:10").is_err());
+    assert!(LoadStage::parse("9999999999999999h:10").is_err());
+}
+
+#[test]
+fn test_schedule_follows_each_stage() {
+    let stages = vec![stage(1, 2.0), stage(2, 0.0), stage(1, 4.0)];
+    assert_eq!(stages_duration(&stages), Duration::from_secs(4));
+
+    let mut schedule = StageSchedule::new(stages);
+    let sends: Vec<u128> = std::iter::from_fn(|| schedule.next_send())
+        .map(|at| at.as_millis())
+        .collect();
+
+    // Two per second, nothing during the pause, then four per second
+    assert_eq!(sends, vec![0, 500, 3000, 3250, 3500, 3750]);
+}
+
+#[test]
+fn test_burst_schedule_groups_sends() {
+    let mut schedule = BurstSchedule::new(Burst {
+        size: 3,
+        interval: Duration::from_secs(5),
+    });
+    let sends: Vec<u64> = (0..7)
+        .map(|_| schedule.next_send().unwrap().as_secs())
+        .collect();
+    assert_eq!(sends, vec![0, 0, 0, 5, 5, 5, 10]);
+}
+
+#[test]
+fn test_pattern_parse() {
+    assert_eq!(
+        LoadPattern::parse("sine:period=60s,min=50,max=500").unwrap(),
+        LoadPattern::Sine {
+            period: Duration::from_secs(60),
+            min: 50.0,
+            max: 500.0,
+        }
+    );
+    assert!(LoadPattern::parse("sine:period=2m,max=10rps,min=0").is_ok());
+
+    assert!(LoadPattern::parse("square:period=60s,min=1,max=2").is_err());
+    assert!(LoadPattern::parse("sine:period=60s,min=50").is_err());
+    assert!(LoadPattern::parse("sine:period=60s,min=500,max=50").is_err());
+    assert!(LoadPattern::parse("sine:period=0s,min=1,max=2").is_err());
+    assert!(LoadPattern::parse("sine:period=60s,min=1,max=2,phase=3").is_err());
+}
+
+#[test]
+fn test_sine_pattern_rate_and_schedule() {
+    let pattern = LoadPattern::Sine {
+        period: Duration::from_secs(60),
+        min: 0.0,
+        max: 100.0,
+    };
+    assert!(pattern.rate_at(Duration::ZERO).abs() < 1e-9);
+    assert!((pattern.rate_at(Duration::from_secs(15)) - 50.0).abs() < 1e-9);
+    assert!((pattern.rate_at(Duration::from_secs(30)) - 100.0).abs() < 1e-9);
+
+    // Over one full period the wave averages (min + max) / 2
+    let mut schedule = PatternSchedule::new(pattern);
+    let sent = std::iter::from_fn(|| schedule.next_send())
+        .take_while(|at| *at < Duration::from_secs(60))
+        .count();
+    assert!((2950..=3050).contains(&sent), "sent {sent} in one period");
+}
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let duration_secs = config.duration;
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let mut runner = UnifiedRunner::with_state(
+        config,
+        SharedState {
+            state: Arc::clone(&state),
+        },
+    );
+    runner.start().await.expect("Runner failed to start");
+
+    // Timed runs end without a final metric, so wait out the duration instead
+    tokio::time::sleep(Duration::from_secs(duration_secs) + Duration::from_millis(500)).await;
+    state
+}
+
+#[tokio::test]
+async fn test_runner_sends_at_stage_rate() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        duration: 1,
+        requests: 0,
+        concurrent: 2,
+        timeout: 1,
+        stages: vec![stage(1, 20.0)],
+        ..Default::default()
+    };
+
+    let state = run(config).await;
+
+    let completed = state.lock().unwrap().completed_requests;
+    assert!((18..=20).contains(&completed), "sent {completed} requests");
+}
+
+#[tokio::test]
+async fn test_runner_fires_bursts() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        duration: 1,
+        requests: 0,
+        concurrent: 5,
+        timeout: 1,
+        burst: Some(Burst {
+            size: 5,
+            interval: Duration::from_secs(10),
+        }),
+        ..Default::default()
+    };
+
+    // Only the first burst fits in a one second run
+    let state = run(config).await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.completed_requests, 5);
+    assert_eq!(state.bursts.len(), 1);
+    assert!(state.bursts[0] < 0.5);
+}
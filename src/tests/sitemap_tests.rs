@@ -0,0 +1,131 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{fetch_sitemap, parse_sitemap, UrlSelection, WeightedUrl};
+use crate::tests::MockServer;
+use crate::Args;
+use clap::Parser;
+
+const URLSET: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"
+        xmlns:image="http://www.google.com/schemas/sitemap-image/1.1">
+  <url>
+    <loc>https://example.com/</loc>
+    <priority>1.0</priority>
+  </url>
+  <url>
+    <loc> https://example.com/search?q=a&amp;page=2 </loc>
+    <image:image><image:loc>https://example.com/a.png</image:loc></image:image>
+  </url>
+  <url><loc>https://example.com/old</loc><priority>0.0</priority></url>
+</urlset>"#;
+
+fn page(url: &str, weight: u32) -> WeightedUrl {
+    WeightedUrl {
+        url: url.to_string(),
+        weight,
+    }
+}
+
+#[test]
+fn test_parse_urlset() {
+    let sitemap = parse_sitemap(URLSET);
+    assert_eq!(
+        sitemap.pages,
+        [
+            page("https://example.com/", 10),
+            page("https://example.com/search?q=a&page=2", 5),
+            page("https://example.com/old", 1),
+        ]
+    );
+    assert!(sitemap.sitemaps.is_empty());
+}
+
+#[test]
+fn test_parse_sitemap_index() {
+    let sitemap = parse_sitemap(
+        "<sitemapindex><sitemap><loc>https://example.com/a.xml</loc></sitemap>\
+         <sitemap><loc><![CDATA[https://example.com/b.xml]]></loc></sitemap></sitemapindex>",
+    );
+    assert!(sitemap.pages.is_empty());
+    assert_eq!(
+        sitemap.sitemaps,
+        ["https://example.com/a.xml", "https://example.com/b.xml"]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_sitemap() {
+    let server = MockServer::start().await;
+    server.set_response_body(&format!(
+        "<urlset><url><loc>{0}/a</loc></url><url><loc>mailto:x@example.com</loc></url>\
+         <url><loc>{0}/b</loc><priority>0.8</priority></url></urlset>",
+        server.url()
+    ));
+
+    let mix = fetch_sitemap(&format!("{}/sitemap.xml", server.url()), 5, "whambam-test")
+        .await
+        .unwrap();
+    assert_eq!(
+        mix.urls,
+        [
+            page(&format!("{}/a", server.url()), 5),
+            page(&format!("{}/b", server.url()), 8)
+        ]
+    );
+
+    server.set_response_body("<urlset></urlset>");
+    assert!(fetch_sitemap(&server.url(), 5, "whambam-test")
+        .await
+        .is_err());
+}
+
+#[test]
+fn test_sitemap_arg() {
+    let args = Args::try_parse_from([
+        "whambam",
+        "--sitemap",
+        "https://example.com/sitemap.xml",
+        "--url-order",
+        "round-robin",
+    ])
+    .unwrap();
+    assert_eq!(args.target_url(), "https://example.com/sitemap.xml");
+    assert_eq!(args.url_order, UrlSelection::RoundRobin);
+
+    assert!(Args::try_parse_from([
+        "whambam",
+        "--sitemap",
+        "https://example.com/sitemap.xml",
+        "--url-file",
+        "urls.txt",
+    ])
+    .is_err());
+    assert!(Args::try_parse_from([
+        "whambam",
+        "--sitemap",
+        "https://example.com/sitemap.xml",
+        "--fallback-url",
+        "https://backup.example.com",
+    ])
+    .is_err());
+}
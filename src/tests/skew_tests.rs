@@ -0,0 +1,93 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{estimate_skew_ms, print_hey_format_report, ClockSkew, RequestMetric};
+use crate::tester::{TestConfig, TestState};
+use chrono::{TimeZone, Utc};
+use std::time::Duration;
+
+#[test]
+fn test_estimate_skew_ms() {
+    let sent_at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+    // In sync: the server's second started when we sent, 200ms round trip
+    let skew = estimate_skew_ms(
+        "Wed, 01 Jan 2025 12:00:00 GMT",
+        sent_at,
+        Duration::from_millis(200),
+    )
+    .unwrap();
+    assert_eq!(skew, 400.0);
+
+    // Server ten seconds ahead
+    let skew = estimate_skew_ms(
+        "Wed, 01 Jan 2025 12:00:10 GMT",
+        sent_at,
+        Duration::from_millis(1000),
+    )
+    .unwrap();
+    assert_eq!(skew, 10_000.0);
+
+    assert!(estimate_skew_ms("yesterday", sent_at, Duration::ZERO).is_none());
+}
+
+#[test]
+fn test_clock_skew_tracks_drift() {
+    let mut skew = ClockSkew::default();
+    for sample in [1000.0, 500.0, 3000.0, 2500.0] {
+        skew.observe(sample);
+    }
+
+    assert_eq!(skew.samples, 4);
+    assert_eq!(skew.first_ms, 1000.0);
+    assert_eq!(skew.last_ms, 2500.0);
+    assert_eq!(skew.min_ms, 500.0);
+    assert_eq!(skew.max_ms, 3000.0);
+    assert_eq!(skew.drift_ms(), 1500.0);
+}
+
+#[test]
+fn test_report_clock_skew() {
+    let config = TestConfig {
+        url: "http://localhost".to_string(),
+        requests: 2,
+        ..Default::default()
+    };
+    let mut state = TestState::new(&config);
+    for clock_skew_ms in [-2000.0, -1000.0] {
+        state.update(RequestMetric {
+            latency_ms: 1.0,
+            status_code: 200,
+            clock_skew_ms: Some(clock_skew_ms),
+            ..Default::default()
+        });
+    }
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+
+    assert!(output.contains("Clock skew (server - client, from Date headers):"));
+    assert!(output.contains("  Initial:\t-2.0 secs"));
+    assert!(output.contains("  Final:\t-1.0 secs"));
+    assert!(output.contains("  Drift:\t+1.0 secs"));
+}
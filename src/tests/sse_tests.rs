@@ -0,0 +1,124 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    print_hey_format_report, EventCounter, SharedState, SseOptions, SseStats, TestConfig,
+    TestState, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run_streams(server: &MockServer, requests: usize, timeout: u64) -> Arc<Mutex<TestState>> {
+    let config = TestConfig {
+        url: server.url(),
+        requests,
+        concurrent: 2,
+        timeout,
+        sse: Some(SseOptions::default()),
+        ..Default::default()
+    };
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+#[test]
+fn test_event_counter_handles_split_chunks_and_line_endings() {
+    let mut counter = EventCounter::default();
+    assert_eq!(counter.feed(b"data: one\n"), 0);
+    assert_eq!(counter.feed(b"\ndata: tw"), 1);
+    assert_eq!(counter.feed(b"o\r"), 1);
+    // The "\n" completing "\r\n" is not a second, blank line
+    assert_eq!(counter.feed(b"\nid: 3\r\n\r\n"), 2);
+    assert_eq!(counter.feed(b"event: ping\rdata: x\r\r"), 3);
+}
+
+#[test]
+fn test_event_counter_ignores_comments_and_extra_blank_lines() {
+    let mut counter = EventCounter::default();
+    assert_eq!(counter.feed(b": keep-alive\n\n\n\n"), 0);
+    assert_eq!(counter.feed(b": note\ndata: real\n\n"), 1);
+    assert_eq!(counter.events(), 1);
+}
+
+#[test]
+fn test_sse_stats_track_spread_and_first_event() {
+    let mut stats = SseStats::default();
+    stats.observe(3, Some(10.0));
+    stats.observe(1, Some(30.0));
+    stats.observe(5, None);
+
+    assert_eq!(stats.connections, 3);
+    assert_eq!(stats.events, 9);
+    assert_eq!((stats.min_events, stats.max_events), (1, 5));
+    assert_eq!(stats.events_per_connection(), 3.0);
+    assert_eq!(stats.first_event.len(), 2);
+    assert!((stats.first_event_at_quantile(1.0) - 30.0).abs() < 0.1);
+}
+
+#[tokio::test]
+async fn test_sse_run_counts_events_per_stream() {
+    let server = MockServer::start().await;
+    server.set_response_body("data: a\n\n: comment\n\ndata: b\nid: 2\n\n");
+
+    let state = run_streams(&server, 4, 5).await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.completed_requests, 4);
+    assert_eq!(state.error_count, 0);
+    assert_eq!(state.sse.connections, 4);
+    assert_eq!(state.sse.events, 8);
+    assert_eq!(state.sse.first_event.len(), 4);
+    assert_eq!(
+        server.get_received_headers().get("accept"),
+        Some(&vec!["text/event-stream".to_string(); 4])
+    );
+
+    let mut report = Vec::new();
+    print_hey_format_report(&mut report, &state).unwrap();
+    let report = String::from_utf8(report).unwrap();
+    assert!(report.contains("Server-sent events:"));
+    assert!(report.contains("Per stream:\t2.0 average, 2 min, 2 max"));
+}
+
+#[tokio::test]
+async fn test_sse_stream_without_events_has_no_first_event() {
+    let server = MockServer::start().await;
+    server.set_response_body("no events here");
+
+    let state = run_streams(&server, 2, 1).await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.sse.connections, 2);
+    assert_eq!(state.sse.events, 0);
+    assert!(state.sse.first_event.is_empty());
+}
@@ -0,0 +1,128 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{Jitter, SharedState, Stagger, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn test_stagger_parse() {
+    assert_eq!(
+        Stagger::parse("0-50ms").unwrap(),
+        Stagger {
+            min: ms(0),
+            max: ms(50)
+        }
+    );
+    assert_eq!(
+        Stagger::parse("50ms").unwrap(),
+        Stagger {
+            min: ms(0),
+            max: ms(50)
+        }
+    );
+    assert_eq!(
+        Stagger::parse("10ms-1s").unwrap(),
+        Stagger {
+            min: ms(10),
+            max: ms(1000)
+        }
+    );
+
+    assert!(Stagger::parse("").is_err());
+    assert!(Stagger::parse("50").is_err());
+    assert!(Stagger::parse("50ms-10ms").is_err());
+    assert!(Stagger::parse("a-b").is_err());
+}
+
+#[test]
+fn test_jitter_stays_in_range() {
+    let stagger = Stagger::parse("5-10ms").unwrap();
+    let mut jitter = Jitter::new(stagger, 3);
+
+    let delays: Vec<_> = (0..1000).map(|_| jitter.next_delay()).collect();
+    assert!(delays.iter().all(|d| *d >= ms(5) && *d <= ms(10)));
+
+    // The delays actually vary
+    assert!(delays.iter().any(|d| *d != delays[0]));
+}
+
+#[test]
+fn test_workers_get_different_sequences() {
+    let stagger = Stagger::parse("0-1s").unwrap();
+    let first: Vec<_> = {
+        let mut jitter = Jitter::new(stagger, 0);
+        (0..5).map(|_| jitter.next_delay()).collect()
+    };
+    let second: Vec<_> = {
+        let mut jitter = Jitter::new(stagger, 1);
+        (0..5).map(|_| jitter.next_delay()).collect()
+    };
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_stagger_arg() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from(["test", "http://example.com", "--stagger", "0-50ms"]);
+    assert_eq!(args.load.stagger, Some(Stagger::parse("0-50ms").unwrap()));
+    assert!(Args::try_parse_from(["test", "http://example.com", "--stagger", "fast"]).is_err());
+}
+
+#[tokio::test]
+async fn test_runner_applies_stagger() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        requests: 4,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        stagger: Some(Stagger::parse("50-50ms").unwrap()),
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let started = Instant::now();
+    UnifiedRunner::with_state(config, shared_state)
+        .start()
+        .await
+        .expect("Runner failed to start");
+
+    while !state.lock().unwrap().is_complete && started.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(ms(10)).await;
+    }
+
+    // One start offset plus one delay per request, all fixed at 50ms
+    assert_eq!(state.lock().unwrap().completed_requests, 4);
+    assert!(started.elapsed() >= ms(250));
+}
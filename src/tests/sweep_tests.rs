@@ -0,0 +1,253 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::args::parse_size;
+use crate::headless::Verbosity;
+use crate::sweep::{
+    self, format_size, knee, print_cache_comparison, print_stage_table, synthetic_body, StageResult,
+};
+use crate::tester::{cache_busted_url, HttpMethod, TestConfig};
+use crate::tests::MockServer;
+
+#[test]
+fn test_parse_size() {
+    assert_eq!(parse_size("512").unwrap(), 512);
+    assert_eq!(parse_size("512B").unwrap(), 512);
+    assert_eq!(parse_size("1KB").unwrap(), 1024);
+    assert_eq!(parse_size("10kb").unwrap(), 10 * 1024);
+    assert_eq!(parse_size("1MB").unwrap(), 1024 * 1024);
+    assert_eq!(parse_size("2GB").unwrap(), 2 << 30);
+
+    assert!(parse_size("").is_err());
+    assert!(parse_size("KB").is_err());
+    assert!(parse_size("1TB").is_err());
+}
+
+#[test]
+fn test_parse_size_overflow_is_rejected() {
+    use clap::Parser;
+
+    let err = parse_size("99999999999999GB").unwrap_err();
+    assert!(err.to_string().contains("Size too large"));
+    assert!(crate::Args::try_parse_from([
+        "whambam",
+        "http://x",
+        "--body-size-sweep",
+        "99999999999999GB"
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_format_size_round_trips() {
+    for size in ["1KB", "10KB", "100KB", "1MB", "1GB", "700B"] {
+        assert_eq!(format_size(parse_size(size).unwrap()), size);
+    }
+    assert_eq!(format_size(1536), "1536B");
+}
+
+#[test]
+fn test_synthetic_body_has_exact_size() {
+    assert_eq!(synthetic_body(0).len(), 0);
+    assert_eq!(synthetic_body(10 * 1024).len(), 10 * 1024);
+}
+
+#[test]
+fn test_sweep_args() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from([
+        "test",
+        "http://example.com",
+        "--body-size-sweep",
+        "1KB,10KB,1MB",
+    ]);
+    assert_eq!(
+        args.sweeps.body_size_sweep,
+        vec![1024, 10 * 1024, 1024 * 1024]
+    );
+
+    assert!(
+        Args::try_parse_from(["test", "http://example.com", "--body-size-sweep", "big"]).is_err()
+    );
+}
+
+#[test]
+fn test_print_sweep_report() {
+    let results = [StageResult {
+        label: "1KB".to_string(),
+        completed: 100,
+        errors: 2,
+        requests_per_sec: 50.0,
+        average_ms: 1.5,
+        p99_ms: 4.25,
+    }];
+
+    let mut out = Vec::new();
+    print_stage_table(&mut out, "Body size sweep:", &results).unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    assert!(out.contains("Body size sweep:"));
+    assert!(out.contains(
+        "  [1KB]\t100 requests, 50.0000 req/s, average 1.500 ms, p99 4.250 ms, 2 errors"
+    ));
+}
+
+#[tokio::test]
+async fn test_sweep_runs_one_stage_per_size() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        method: HttpMethod::POST,
+        requests: 5,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    sweep::run(config, &[16, 1024], Verbosity::Quiet)
+        .await
+        .expect("Sweep failed");
+
+    assert_eq!(server.request_count(), 10);
+}
+
+#[test]
+fn test_knee_is_where_throughput_levels_off() {
+    let stage = |requests_per_sec: f64| StageResult {
+        label: String::new(),
+        completed: 100,
+        errors: 0,
+        requests_per_sec,
+        average_ms: 1.0,
+        p99_ms: 2.0,
+    };
+
+    let levelling = [stage(100.0), stage(800.0), stage(1500.0), stage(1550.0)];
+    assert_eq!(knee(&levelling), Some(2));
+
+    let rising = [stage(100.0), stage(200.0), stage(400.0)];
+    assert_eq!(knee(&rising), None);
+    assert_eq!(knee(&[]), None);
+}
+
+#[test]
+fn test_concurrency_sweep_args() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from(["test", "http://example.com", "--sweep", "1,10,50"]);
+    assert_eq!(args.sweeps.sweep, vec![1, 10, 50]);
+
+    assert!(Args::try_parse_from([
+        "test",
+        "http://example.com",
+        "--sweep",
+        "1,10",
+        "--body-size-sweep",
+        "1KB"
+    ])
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_concurrency_sweep_runs_one_stage_per_level() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        requests: 4,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    sweep::run_concurrency(config, &[1, 2, 8], Verbosity::Quiet)
+        .await
+        .expect("Sweep failed");
+
+    // The last level raises the request count to keep all eight workers busy
+    assert_eq!(server.request_count(), 4 + 4 + 8);
+}
+
+#[test]
+fn test_cache_busted_url_is_unique_per_key() {
+    let url = url::Url::parse("http://example.com/item?id=7").unwrap();
+
+    let first = cache_busted_url(&url, 0xab, 1);
+    let second = cache_busted_url(&url, 0xab, 2);
+    assert_eq!(
+        first.as_str(),
+        "http://example.com/item?id=7&whambam_nonce=ab-1"
+    );
+    assert_ne!(first, second);
+
+    // Another run sends different URLs for the same requests
+    assert_ne!(first, cache_busted_url(&url, 0xcd, 1));
+}
+
+#[test]
+fn test_print_cache_comparison() {
+    let stage = |label: &str, average_ms: f64, requests_per_sec: f64| StageResult {
+        label: label.to_string(),
+        completed: 100,
+        errors: 0,
+        requests_per_sec,
+        average_ms,
+        p99_ms: average_ms * 2.0,
+    };
+
+    let mut out = Vec::new();
+    print_cache_comparison(
+        &mut out,
+        &stage("cold", 10.0, 100.0),
+        &stage("warm", 2.5, 400.0),
+    )
+    .unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    assert!(out.contains("Cache comparison:"));
+    assert!(out.contains("  [cold]\t"));
+    assert!(out.contains("  [warm]\t"));
+    assert!(out.contains("0.25x average latency, 0.25x p99 latency, 4.00x req/s"));
+}
+
+#[tokio::test]
+async fn test_cache_comparison_runs_both_phases() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        requests: 5,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        ..Default::default()
+    };
+
+    sweep::run_cache_comparison(config, Verbosity::Quiet)
+        .await
+        .expect("Cache comparison failed");
+
+    assert_eq!(server.request_count(), 10);
+}
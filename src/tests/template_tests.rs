@@ -0,0 +1,125 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{expand, Builtins, SharedState, TestConfig, TestState, UnifiedRunner};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn is_uuid_v4(text: &str) -> bool {
+    let groups: Vec<&str> = text.split('-').collect();
+    groups.iter().map(|group| group.len()).eq([8, 4, 4, 4, 12])
+        && groups
+            .iter()
+            .all(|group| group.chars().all(|c| c.is_ascii_hexdigit()))
+        && groups[2].starts_with('4')
+        && groups[3].starts_with(['8', '9', 'a', 'b'])
+}
+
+#[test]
+fn test_builtin_values() {
+    let mut builtins = Builtins::new();
+    assert_eq!(builtins.resolve("SEQ", 7).as_deref(), Some("7"));
+
+    let first = builtins.resolve("UUID", 0).unwrap();
+    let second = builtins.resolve("UUID", 0).unwrap();
+    assert!(is_uuid_v4(&first), "{first}");
+    assert_ne!(first, second);
+
+    let now = builtins.resolve("NOW_ISO", 0).unwrap();
+    assert!(chrono::DateTime::parse_from_rfc3339(&now).is_ok(), "{now}");
+
+    for _ in 0..100 {
+        let value: i64 = builtins
+            .resolve("RAND_INT(1, 3)", 0)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((1..=3).contains(&value));
+    }
+    assert_eq!(builtins.resolve("RAND_INT(5,5)", 0).as_deref(), Some("5"));
+    assert!(builtins
+        .resolve("RAND_INT(-9223372036854775808,9223372036854775807)", 0)
+        .is_some());
+}
+
+#[test]
+fn test_unknown_builtins_are_left_as_written() {
+    let mut builtins = Builtins::new();
+    for name in [
+        "RAND_INT(3,1)",
+        "RAND_INT(1)",
+        "RAND_INT(a,b)",
+        "uuid",
+        "OTHER",
+    ] {
+        assert_eq!(builtins.resolve(name, 0), None, "{name}");
+    }
+    assert_eq!(
+        expand("/items/${SEQ}/${OTHER}", |name| builtins.resolve(name, 2)),
+        "/items/2/${OTHER}"
+    );
+}
+
+#[tokio::test]
+async fn test_runner_expands_builtins_per_request() {
+    let server = MockServer::start().await;
+    run(TestConfig {
+        url: format!("{}/items/${{SEQ}}", server.url()),
+        requests: 3,
+        concurrent: 1,
+        timeout: 2,
+        interactive: false,
+        headers: vec![("X-Request-Id".to_string(), "${UUID}".to_string())],
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(
+        server.request_lines(),
+        [
+            "GET /items/0 HTTP/1.1",
+            "GET /items/1 HTTP/1.1",
+            "GET /items/2 HTTP/1.1"
+        ]
+    );
+    let ids = &server.get_received_headers()["x-request-id"];
+    assert_eq!(ids.len(), 3);
+    assert!(ids.iter().all(|id| is_uuid_v4(id)));
+    assert_ne!(ids[0], ids[1]);
+}
@@ -0,0 +1,146 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{save_snapshot, EndReason, HttpMethod, RequestMetric, TestConfig, TestState};
+use crate::tests::MockServer;
+use crate::{run_test, TestReport};
+
+#[tokio::test]
+async fn test_run_test_returns_report() {
+    let server = MockServer::start().await;
+
+    let report = run_test(TestConfig {
+        url: server.url(),
+        requests: 5,
+        concurrent: 1,
+        timeout: 1,
+        interactive: true,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(report.url, server.url());
+    assert_eq!(report.method, HttpMethod::GET);
+    assert_eq!(report.completed_requests, 5);
+    assert_eq!(report.error_count, 0);
+    assert_eq!(report.status_counts.get(&200), Some(&5));
+    assert_eq!(report.end_reason, Some(EndReason::RequestLimit(5)));
+    assert!(report.requests_per_sec > 0.0);
+    assert!(report.bytes_received > 0);
+    let latency = report.latency_ms;
+    assert!(latency.p50 <= latency.p90 && latency.p90 <= latency.p95);
+    assert!(latency.p95 <= latency.p99 && latency.p99 <= latency.max);
+}
+
+#[tokio::test]
+async fn test_run_test_counts_errors() {
+    let server = MockServer::start().await;
+    server.set_response_status(500);
+
+    let report = run_test(TestConfig {
+        url: server.url(),
+        requests: 3,
+        concurrent: 1,
+        timeout: 1,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(report.completed_requests, 3);
+    assert_eq!(report.status_counts.get(&500), Some(&3));
+}
+
+#[tokio::test]
+async fn test_run_test_rejects_bad_url() {
+    let config = TestConfig {
+        url: "ftp://example.com".to_string(),
+        ..Default::default()
+    };
+    assert!(run_test(config).await.is_err());
+}
+
+#[test]
+fn test_report_of_empty_state() {
+    let state = TestState::new(&TestConfig::default());
+    let report = TestReport::from_state(&state);
+    assert_eq!(report.completed_requests, 0);
+    assert_eq!(report.latency_ms.average, 0.0);
+    assert_eq!(report.requests_per_sec, 0.0);
+}
+
+#[tokio::test]
+async fn test_report_round_trips_through_json() {
+    let server = MockServer::start().await;
+
+    let report = run_test(TestConfig {
+        url: server.url(),
+        requests: 4,
+        concurrent: 1,
+        timeout: 1,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    let json = serde_json::to_string(&report).unwrap();
+    let parsed: TestReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.completed_requests, 4);
+    assert_eq!(parsed.status_counts.get(&200), Some(&4));
+    assert_eq!(parsed.latency_ms.max, report.latency_ms.max);
+    assert_eq!(parsed.end_reason, Some(EndReason::RequestLimit(4)));
+    assert_eq!(
+        parsed.throughput_series.len(),
+        report.throughput_series.len()
+    );
+}
+
+#[test]
+fn test_save_snapshot_writes_json_and_text() {
+    let dir = std::env::temp_dir().join(format!("whambam-{}-snapshot", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut state = TestState::new(&TestConfig {
+        url: "http://localhost/".to_string(),
+        ..Default::default()
+    });
+    state.update(RequestMetric {
+        latency_ms: 12.0,
+        status_code: 200,
+        ..Default::default()
+    });
+
+    let stem = save_snapshot(&state, &dir).unwrap();
+    let json = std::fs::read_to_string(stem.with_extension("json")).unwrap();
+    let text = std::fs::read_to_string(stem.with_extension("txt")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let report: TestReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(report.completed_requests, 1);
+    assert_eq!(report.status_counts.get(&200), Some(&1));
+    assert!(text.contains("Summary:"));
+    assert!(stem
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("whambam-"));
+}
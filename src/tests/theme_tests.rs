@@ -0,0 +1,53 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{TestConfig, Theme};
+
+#[test]
+fn test_theme_parse() {
+    assert_eq!(Theme::parse("default").unwrap(), Theme::Default);
+    assert_eq!(Theme::parse("Dark").unwrap(), Theme::Dark);
+    assert_eq!(Theme::parse(" light ").unwrap(), Theme::Light);
+    assert_eq!(Theme::parse("monochrome").unwrap(), Theme::Monochrome);
+    assert_eq!(Theme::parse("mono").unwrap(), Theme::Monochrome);
+
+    let err = Theme::parse("neon").unwrap_err().to_string();
+    assert!(err.contains("neon"), "{err}");
+}
+
+#[test]
+fn test_theme_round_trips_through_display() {
+    for theme in [Theme::Default, Theme::Dark, Theme::Light, Theme::Monochrome] {
+        assert_eq!(Theme::parse(&theme.to_string()).unwrap(), theme);
+    }
+}
+
+#[test]
+fn test_builder_sets_theme() {
+    let config = TestConfig::builder()
+        .url("http://example.com")
+        .theme(Theme::Light)
+        .build()
+        .unwrap();
+    assert_eq!(config.theme, Theme::Light);
+    assert_eq!(TestConfig::default().theme, Theme::Default);
+}
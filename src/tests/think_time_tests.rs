@@ -0,0 +1,126 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    SharedState, TestConfig, TestState, ThinkDistribution, ThinkTime, Thinker, UnifiedRunner,
+};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+/// Mean of `count` pauses after the first, which is always zero
+fn mean_pause(think: &str, count: u32) -> Duration {
+    let mut thinker = Thinker::new(ThinkTime::parse(think).unwrap(), 0);
+    assert_eq!(thinker.next_pause(), Duration::ZERO);
+    (0..count).map(|_| thinker.next_pause()).sum::<Duration>() / count
+}
+
+#[test]
+fn test_think_time_parse() {
+    assert_eq!(
+        ThinkTime::parse("200ms").unwrap(),
+        ThinkTime {
+            mean: ms(200),
+            distribution: ThinkDistribution::Fixed
+        }
+    );
+    assert_eq!(
+        ThinkTime::parse("1s:uniform").unwrap().distribution,
+        ThinkDistribution::Uniform
+    );
+    assert_eq!(
+        ThinkTime::parse("50ms:exponential").unwrap().distribution,
+        ThinkDistribution::Exponential
+    );
+
+    assert!(ThinkTime::parse("").is_err());
+    assert!(ThinkTime::parse("200").is_err());
+    assert!(ThinkTime::parse("200ms:normal").is_err());
+}
+
+#[test]
+fn test_fixed_think_time() {
+    let mut thinker = Thinker::new(ThinkTime::parse("200ms").unwrap(), 0);
+    assert_eq!(thinker.next_pause(), Duration::ZERO);
+    assert!((0..10).all(|_| thinker.next_pause() == ms(200)));
+}
+
+#[test]
+fn test_random_think_times_keep_their_mean() {
+    let mut thinker = Thinker::new(ThinkTime::parse("100ms:uniform").unwrap(), 1);
+    thinker.next_pause();
+    assert!((0..1000).all(|_| thinker.next_pause() <= ms(200)));
+
+    for think in ["100ms:uniform", "100ms:exponential"] {
+        let mean = mean_pause(think, 10_000);
+        assert!(mean > ms(90) && mean < ms(110), "{think}: {mean:?}");
+    }
+}
+
+#[test]
+fn test_think_time_arg() {
+    use crate::Args;
+    use clap::Parser;
+
+    let args = Args::parse_from(["test", "http://example.com", "--think-time", "200ms"]);
+    assert_eq!(
+        args.load.think_time,
+        Some(ThinkTime::parse("200ms").unwrap())
+    );
+    assert!(Args::try_parse_from(["test", "http://example.com", "--think-time", "slow"]).is_err());
+}
+
+#[tokio::test]
+async fn test_runner_pauses_between_requests() {
+    let server = MockServer::start().await;
+    let config = TestConfig {
+        url: server.url(),
+        requests: 3,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        think_time: Some(ThinkTime::parse("100ms").unwrap()),
+        ..Default::default()
+    };
+
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let started = Instant::now();
+    UnifiedRunner::with_state(config, shared_state)
+        .start()
+        .await
+        .expect("Runner failed to start");
+
+    while !state.lock().unwrap().is_complete && started.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(ms(10)).await;
+    }
+
+    // No pause before the first request, then one before each of the other two
+    assert_eq!(state.lock().unwrap().completed_requests, 3);
+    assert!(started.elapsed() >= ms(200));
+}
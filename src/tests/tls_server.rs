@@ -0,0 +1,146 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::io::BufReader;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_rustls::rustls::server::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::version::{TLS12, TLS13};
+use tokio_rustls::rustls::{
+    Certificate, PrivateKey, RootCertStore, ServerConfig, SupportedProtocolVersion,
+};
+use tokio_rustls::TlsAcceptor;
+
+/// Leaf certificate for 127.0.0.1 and localhost
+pub const SERVER_CERT: &str = include_str!("fixtures/server.pem");
+const SERVER_KEY: &str = include_str!("fixtures/server.key");
+
+/// CA that signed the server and client certificates
+const CA_CERT: &str = include_str!("fixtures/ca.pem");
+
+/// An HTTPS server that answers every request with `200 OK`
+pub struct TlsMockServer {
+    port: u16,
+    request_count: Arc<AtomicUsize>,
+    server_task: tokio::task::JoinHandle<()>,
+}
+
+impl TlsMockServer {
+    pub async fn start() -> Self {
+        Self::start_with_versions(&[&TLS12, &TLS13]).await
+    }
+
+    /// Start a server that only speaks the given TLS versions
+    pub async fn start_with_versions(versions: &[&'static SupportedProtocolVersion]) -> Self {
+        let config = ServerConfig::builder()
+            .with_safe_default_cipher_suites()
+            .with_safe_default_kx_groups()
+            .with_protocol_versions(versions)
+            .unwrap()
+            .with_no_client_auth()
+            .with_single_cert(certs(SERVER_CERT), key(SERVER_KEY))
+            .unwrap();
+        Self::with_config(config).await
+    }
+
+    /// Start a server that only completes handshakes with clients the test CA signed
+    pub async fn start_requiring_client_cert() -> Self {
+        let mut roots = RootCertStore::empty();
+        for cert in certs(CA_CERT) {
+            roots.add(&cert).unwrap();
+        }
+        let config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(Arc::new(AllowAnyAuthenticatedClient::new(roots)))
+            .with_single_cert(certs(SERVER_CERT), key(SERVER_KEY))
+            .unwrap();
+        Self::with_config(config).await
+    }
+
+    async fn with_config(config: ServerConfig) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+        let request_count = Arc::new(AtomicUsize::new(0));
+
+        let count = Arc::clone(&request_count);
+        let server_task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (acceptor, count) = (acceptor.clone(), Arc::clone(&count));
+                tokio::spawn(async move {
+                    // Handshake failures are what the tests are looking for; just drop them
+                    let Ok(mut stream) = acceptor.accept(stream).await else {
+                        return;
+                    };
+                    let mut request = Vec::new();
+                    let mut buffer = [0; 1024];
+                    while !request.ends_with(b"\r\n\r\n") {
+                        match stream.read(&mut buffer).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buffer[..n]),
+                        }
+                    }
+                    count.fetch_add(1, Ordering::SeqCst);
+                    let response =
+                        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok";
+                    let _ = stream.write_all(response.as_bytes()).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+
+        TlsMockServer {
+            port,
+            request_count,
+            server_task,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://127.0.0.1:{}", self.port)
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.request_count.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for TlsMockServer {
+    fn drop(&mut self) {
+        self.server_task.abort();
+    }
+}
+
+fn certs(pem: &str) -> Vec<Certificate> {
+    rustls_pemfile::certs(&mut BufReader::new(pem.as_bytes()))
+        .unwrap()
+        .into_iter()
+        .map(Certificate)
+        .collect()
+}
+
+fn key(pem: &str) -> PrivateKey {
+    let keys = rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(pem.as_bytes())).unwrap();
+    PrivateKey(keys.into_iter().next().unwrap())
+}
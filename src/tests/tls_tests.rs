@@ -0,0 +1,278 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{parse_sni, SharedState, TestConfig, TestState, TlsVersion, UnifiedRunner};
+use crate::tests::TlsMockServer;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_rustls::rustls::version::{TLS12, TLS13};
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/tests/fixtures")
+        .join(name)
+}
+
+fn tls_config(server: &TlsMockServer) -> TestConfig {
+    TestConfig {
+        url: server.url(),
+        requests: 3,
+        concurrent: 1,
+        timeout: 5,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_untrusted_certificate_is_rejected() {
+    let server = TlsMockServer::start().await;
+
+    let state = run(tls_config(&server)).await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 3);
+    assert!(state.status_counts.is_empty());
+    assert_eq!(server.request_count(), 0);
+}
+
+#[tokio::test]
+async fn test_insecure_accepts_untrusted_certificate() {
+    let server = TlsMockServer::start().await;
+
+    let state = run(TestConfig {
+        insecure: true,
+        ..tls_config(&server)
+    })
+    .await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 0);
+    assert_eq!(state.status_counts.get(&200), Some(&3));
+    assert_eq!(server.request_count(), 3);
+}
+
+#[tokio::test]
+async fn test_cacert_trusts_internal_ca() {
+    let server = TlsMockServer::start().await;
+
+    let state = run(TestConfig {
+        ca_cert: Some(fixture("ca.pem")),
+        ..tls_config(&server)
+    })
+    .await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 0);
+    assert_eq!(state.status_counts.get(&200), Some(&3));
+}
+
+#[tokio::test]
+async fn test_unreadable_cacert_fails_before_sending() {
+    let server = TlsMockServer::start().await;
+
+    for ca_cert in [fixture("missing.pem"), fixture("../tls_tests.rs")] {
+        let config = TestConfig {
+            ca_cert: Some(ca_cert),
+            ..tls_config(&server)
+        };
+        let mut runner = UnifiedRunner::new(config);
+        assert!(runner.start().await.is_err());
+    }
+    assert_eq!(server.request_count(), 0);
+}
+
+#[tokio::test]
+async fn test_client_certificate_for_mutual_tls() {
+    let server = TlsMockServer::start_requiring_client_cert().await;
+    let trusted = TestConfig {
+        ca_cert: Some(fixture("ca.pem")),
+        ..tls_config(&server)
+    };
+
+    // Without a client certificate the handshake is refused
+    let state = run(trusted.clone()).await;
+    assert_eq!(state.lock().unwrap().error_count, 3);
+    assert_eq!(server.request_count(), 0);
+
+    let state = run(TestConfig {
+        client_cert: Some((fixture("client.pem"), fixture("client.key"))),
+        ..trusted
+    })
+    .await;
+    let state = state.lock().unwrap();
+    assert_eq!(state.error_count, 0);
+    assert_eq!(server.request_count(), 3);
+}
+
+#[test]
+fn test_tls_version_parse() {
+    assert_eq!(TlsVersion::parse("1.2").unwrap(), TlsVersion::Tls12);
+    assert_eq!(TlsVersion::parse("TLS1.3").unwrap(), TlsVersion::Tls13);
+    assert_eq!(TlsVersion::parse("tlsv1.3").unwrap(), TlsVersion::Tls13);
+    assert!(TlsVersion::parse("1.1").is_err());
+    assert!(TlsVersion::Tls12 < TlsVersion::Tls13);
+}
+
+#[tokio::test]
+async fn test_tls_version_pinning() {
+    let tls13_only = TlsMockServer::start_with_versions(&[&TLS13]).await;
+    let tls12_only = TlsMockServer::start_with_versions(&[&TLS12]).await;
+
+    for (server, tls_min, tls_max, errors) in [
+        (&tls13_only, None, Some(TlsVersion::Tls12), 3),
+        (&tls13_only, Some(TlsVersion::Tls13), None, 0),
+        (&tls12_only, Some(TlsVersion::Tls13), None, 3),
+        (&tls12_only, None, Some(TlsVersion::Tls12), 0),
+    ] {
+        let state = run(TestConfig {
+            insecure: true,
+            tls_min,
+            tls_max,
+            ..tls_config(server)
+        })
+        .await;
+        assert_eq!(
+            state.lock().unwrap().error_count,
+            errors,
+            "{tls_min:?}..{tls_max:?} against {}",
+            server.url()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_handshake_timed_per_connection() {
+    for version in [TlsVersion::Tls12, TlsVersion::Tls13] {
+        let server = TlsMockServer::start().await;
+        let state = run(TestConfig {
+            insecure: true,
+            tls_min: Some(version),
+            tls_max: Some(version),
+            ..tls_config(&server)
+        })
+        .await;
+
+        // The server closes every connection, so each request pays for a handshake
+        let state = state.lock().unwrap();
+        assert_eq!(state.error_count, 0);
+        assert_eq!(state.tls_handshake.count(), 3, "{version}");
+        assert_eq!(state.connect.count(), 3, "{version}");
+        let (average, fastest, slowest) = state.tls_handshake.average_min_max_ms();
+        assert!(fastest > 0.0 && fastest <= average && average <= slowest);
+        assert!(slowest < 5000.0);
+
+        // Every response has a wait and a read, whether or not it opened a connection
+        assert_eq!(state.ttfb.count(), 3);
+        assert_eq!(state.body_read.count(), 3);
+    }
+}
+
+#[tokio::test]
+async fn test_sni_overrides_certificate_name() {
+    let server = TlsMockServer::start().await;
+
+    // Still connects to 127.0.0.1, but the certificate must match the SNI name
+    for (sni, errors) in [("localhost", 0), ("backend.example", 3)] {
+        let state = run(TestConfig {
+            ca_cert: Some(fixture("ca.pem")),
+            sni: Some(sni.to_string()),
+            ..tls_config(&server)
+        })
+        .await;
+        assert_eq!(state.lock().unwrap().error_count, errors, "{sni}");
+    }
+    assert_eq!(server.request_count(), 3);
+}
+
+#[test]
+fn test_sni_parse() {
+    assert_eq!(parse_sni("API.example.com").unwrap(), "api.example.com");
+    assert!(parse_sni("10.0.0.5").is_err());
+    assert!(parse_sni("not a name").is_err());
+}
+
+#[tokio::test]
+async fn test_tls_keylog_file() {
+    let server = TlsMockServer::start().await;
+    let path = std::env::temp_dir().join(format!("whambam-{}-keylog", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    for version in [TlsVersion::Tls12, TlsVersion::Tls13] {
+        let state = run(TestConfig {
+            insecure: true,
+            tls_min: Some(version),
+            tls_max: Some(version),
+            tls_keylog: Some(path.clone()),
+            ..tls_config(&server)
+        })
+        .await;
+        assert_eq!(state.lock().unwrap().error_count, 0);
+    }
+
+    let keylog = std::fs::read_to_string(&path).unwrap();
+    for line in keylog.lines() {
+        let fields: Vec<_> = line.split(' ').collect();
+        assert_eq!(fields.len(), 3, "{line}");
+        assert_eq!(fields[1].len(), 64, "{line}");
+    }
+    assert!(keylog.contains("CLIENT_RANDOM "));
+    assert!(keylog.contains("CLIENT_HANDSHAKE_TRAFFIC_SECRET "));
+    assert!(keylog.contains("CLIENT_TRAFFIC_SECRET_0 "));
+    let _ = std::fs::remove_file(path);
+
+    let mut runner = UnifiedRunner::new(TestConfig {
+        tls_keylog: Some(fixture("missing/keylog.txt")),
+        ..tls_config(&server)
+    });
+    assert!(runner.start().await.is_err());
+}
+
+#[tokio::test]
+async fn test_certificate_chain_captured_once() {
+    let server = TlsMockServer::start().await;
+    let state = run(TestConfig {
+        ca_cert: Some(fixture("ca.pem")),
+        ..tls_config(&server)
+    })
+    .await;
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.certificates.len(), 1);
+    assert_eq!(state.certificates[0].subject, "CN=localhost");
+    // The probe handshake sends no request
+    assert_eq!(server.request_count(), 3);
+}
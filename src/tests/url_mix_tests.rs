@@ -0,0 +1,188 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tester::{
+    print_hey_format_report, SharedState, TestConfig, TestState, UnifiedRunner, UrlMix, UrlPicker,
+    UrlSelection, WeightedUrl,
+};
+use crate::tests::MockServer;
+use crate::Args;
+use clap::Parser;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+fn write_url_file(name: &str, text: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("whambam-{}-{name}.txt", std::process::id()));
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+#[test]
+fn test_parse_url_mix() {
+    let mix = UrlMix::parse(
+        "# hot paths\nhttp://example.com/a 3\n\n  http://example.com/b\nhttps://example.com/c 1\n",
+    )
+    .unwrap();
+    assert_eq!(
+        mix.urls,
+        [
+            ("http://example.com/a", 3),
+            ("http://example.com/b", 1),
+            ("https://example.com/c", 1)
+        ]
+        .map(|(url, weight)| WeightedUrl {
+            url: url.to_string(),
+            weight
+        })
+    );
+}
+
+#[test]
+fn test_parse_url_mix_errors() {
+    assert!(UrlMix::parse("# nothing\n\n").is_err());
+    assert!(UrlMix::parse("ftp://example.com").is_err());
+    assert!(UrlMix::parse("http://example.com 1 2").is_err());
+    let err = UrlMix::parse("http://example.com/a\nhttp://example.com/b 0").unwrap_err();
+    assert!(err.to_string().contains("Line 2"));
+    assert!(UrlMix::parse("http://example.com/a -1").is_err());
+}
+
+#[test]
+fn test_picker_follows_weights() {
+    let mix = UrlMix::parse("http://example.com/a 3\nhttp://example.com/b 1").unwrap();
+    let mut picker = UrlPicker::new(&mix, UrlSelection::Random);
+    let mut counts = [0; 2];
+    for _ in 0..4000 {
+        counts[picker.next_index()] += 1;
+    }
+    assert!((2700..3300).contains(&counts[0]), "{counts:?}");
+}
+
+fn picks(selection: UrlSelection, count: usize) -> Vec<usize> {
+    let mix = UrlMix::parse("http://example.com/a 2\nhttp://example.com/b\nhttp://example.com/c")
+        .unwrap();
+    let mut picker = UrlPicker::new(&mix, selection);
+    (0..count).map(|_| picker.next_index()).collect()
+}
+
+#[test]
+fn test_parse_url_selection() {
+    assert_eq!(
+        UrlSelection::parse("round-robin").unwrap(),
+        UrlSelection::RoundRobin
+    );
+    assert_eq!(
+        UrlSelection::parse("Sequential").unwrap(),
+        UrlSelection::Sequential
+    );
+    assert_eq!(UrlSelection::parse("random").unwrap(), UrlSelection::Random);
+    assert!(UrlSelection::parse("weighted").is_err());
+}
+
+#[test]
+fn test_round_robin_interleaves_by_weight() {
+    assert_eq!(picks(UrlSelection::RoundRobin, 8), [0, 1, 2, 0, 0, 1, 2, 0]);
+}
+
+#[test]
+fn test_sequential_sends_each_weight_in_a_row() {
+    assert_eq!(
+        picks(UrlSelection::Sequential, 9),
+        [0, 0, 1, 2, 0, 0, 1, 2, 0]
+    );
+}
+
+#[test]
+fn test_url_file_arg() {
+    let path = write_url_file(
+        "url-file-arg",
+        "http://example.com/a 2\nhttp://example.com/b\n",
+    );
+    let path = path.to_str().unwrap();
+
+    let args = Args::try_parse_from(["whambam", "--url-file", path]).unwrap();
+    assert_eq!(args.target_url(), "http://example.com/a");
+    assert_eq!(args.url_order, UrlSelection::Random);
+    assert_eq!(args.url_file.unwrap().urls.len(), 2);
+
+    let args =
+        Args::try_parse_from(["whambam", "--url-file", path, "--url-order", "sequential"]).unwrap();
+    assert_eq!(args.url_order, UrlSelection::Sequential);
+    assert!(
+        Args::try_parse_from(["whambam", "http://a.test", "--url-order", "sequential"]).is_err()
+    );
+
+    let both = Args::try_parse_from(["whambam", "http://example.com", "--url-file", path]);
+    assert!(crate::validate::check(&both.unwrap(), 0).is_err());
+    assert!(Args::try_parse_from(["whambam", "--url-file", "/nonexistent/urls.txt"]).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn test_runner_spreads_requests_across_urls() {
+    let server = MockServer::start().await;
+    let mix = UrlMix::parse(&format!("{0}/a 1\n{0}/b 1\n", server.url())).unwrap();
+    let state = run(TestConfig {
+        url: mix.urls[0].url.clone(),
+        url_mix: Some(mix),
+        requests: 40,
+        concurrent: 2,
+        timeout: 2,
+        interactive: false,
+        ..Default::default()
+    })
+    .await;
+
+    let lines = server.request_lines();
+    let to_a = lines
+        .iter()
+        .filter(|line| line.starts_with("GET /a "))
+        .count();
+    assert_eq!(lines.len(), 40);
+    assert!(to_a > 0 && to_a < 40, "{to_a} of 40 went to /a");
+
+    let state = state.lock().unwrap();
+    assert_eq!(state.targets[0].completed_requests, to_a);
+    assert_eq!(state.targets[1].completed_requests, 40 - to_a);
+
+    let mut buf = Vec::new();
+    print_hey_format_report(&mut buf, &state).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("Targets:"));
+    assert!(output.contains(&format!("  {}/b\t{} requests", server.url(), 40 - to_a)));
+}
@@ -20,6 +20,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::tester::{parse_target_url, ConfigError, TestConfig, UnifiedRunner};
 use url::Url;
 
 #[test]
@@ -81,3 +82,46 @@ fn test_url_components() {
     assert_eq!(url.query(), Some("query=value"));
     assert_eq!(url.fragment(), Some("fragment"));
 }
+
+#[test]
+fn test_parse_target_url_schemes() {
+    assert!(parse_target_url("http://example.com").is_ok());
+    assert!(parse_target_url("https://example.com").is_ok());
+
+    let err = parse_target_url("ftp://example.com").unwrap_err();
+    assert_eq!(
+        err,
+        ConfigError::UnsupportedScheme {
+            url: "ftp://example.com".to_string(),
+            scheme: "ftp".to_string(),
+        }
+    );
+    assert!(err.to_string().contains("Supported schemes: http, https"));
+    assert_eq!(err.suggestion(), None);
+}
+
+#[test]
+fn test_config_error_suggestions() {
+    let err = parse_target_url("wss://example.com/socket").unwrap_err();
+    assert!(err.suggestion().unwrap().contains("WebSocket"));
+
+    let err = parse_target_url("example.com").unwrap_err();
+    assert!(matches!(err, ConfigError::InvalidUrl { .. }));
+    assert!(err
+        .to_string()
+        .contains("did you mean http://example.com ?"));
+}
+
+#[tokio::test]
+async fn test_runner_returns_typed_config_error() {
+    let config = TestConfig {
+        url: "ws://example.com".to_string(),
+        ..Default::default()
+    };
+
+    let err = UnifiedRunner::new(config).start().await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ConfigError>(),
+        Some(ConfigError::UnsupportedScheme { scheme, .. }) if scheme == "ws"
+    ));
+}
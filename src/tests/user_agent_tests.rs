@@ -0,0 +1,120 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+use crate::tester::{
+    parse_user_agents, SharedState, TestConfig, TestState, UnifiedRunner, DEFAULT_USER_AGENT,
+};
+use crate::tests::MockServer;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn run(config: TestConfig) -> Arc<Mutex<TestState>> {
+    let state = Arc::new(Mutex::new(TestState::new(&config)));
+    let shared_state = SharedState {
+        state: Arc::clone(&state),
+    };
+    let mut runner = UnifiedRunner::with_state(config, shared_state);
+    runner.start().await.expect("Runner failed to start");
+    for _ in 0..100 {
+        if state.lock().unwrap().is_complete {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    state
+}
+
+async fn user_agents_received(config: TestConfig) -> Vec<String> {
+    let server = MockServer::start().await;
+    run(TestConfig {
+        url: server.url(),
+        requests: 4,
+        concurrent: 1,
+        timeout: 1,
+        interactive: false,
+        ..config
+    })
+    .await;
+    server
+        .get_received_headers()
+        .remove("user-agent")
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_default_user_agent_names_the_version() {
+    assert_eq!(
+        DEFAULT_USER_AGENT,
+        format!("whambam/{}", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[tokio::test]
+async fn test_default_user_agent_is_sent() {
+    assert_eq!(
+        user_agents_received(TestConfig::default()).await,
+        [DEFAULT_USER_AGENT; 4]
+    );
+}
+
+#[tokio::test]
+async fn test_user_agent_can_be_set() {
+    let config = TestConfig {
+        user_agent: "checkout-probe/2".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(user_agents_received(config).await, ["checkout-probe/2"; 4]);
+}
+
+#[tokio::test]
+async fn test_user_agent_header_takes_precedence() {
+    let config = TestConfig {
+        headers: vec![("User-Agent".to_string(), "from-header".to_string())],
+        ..Default::default()
+    };
+    assert_eq!(user_agents_received(config).await, ["from-header"; 4]);
+}
+
+#[test]
+fn test_parse_user_agents() {
+    let text = "# browsers\nMozilla/5.0 (X11; Linux x86_64)\n\n  curl/8.4.0  \nGooglebot/2.1\n";
+    assert_eq!(
+        parse_user_agents(text).unwrap(),
+        [
+            "Mozilla/5.0 (X11; Linux x86_64)",
+            "curl/8.4.0",
+            "Googlebot/2.1"
+        ]
+    );
+    assert!(parse_user_agents("# nothing here\n\n").is_err());
+}
+
+#[tokio::test]
+async fn test_user_agents_rotate_across_requests() {
+    let config = TestConfig {
+        user_agents: vec!["bot/1".to_string(), "browser/2".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        user_agents_received(config).await,
+        ["bot/1", "browser/2", "bot/1", "browser/2"]
+    );
+}
@@ -22,28 +22,58 @@
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
+use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
 use std::io;
-use std::sync::Arc;
+use std::path::Path;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
 
-use super::widgets::ui;
-use crate::tester::{SharedState, TestConfig};
+use super::status_codes::status_row_count;
+use super::theme::Palette;
+use super::widgets::{tab_at, ui};
+use crate::tester::{save_snapshot, SharedState, TestConfig, UnifiedRunner};
 
 /// The UI application
 pub struct App {
     shared_state: SharedState,
+    /// Settings of the run being watched, reused when restarting it
+    config: TestConfig,
     ui_state: UiState,
+    /// Runs restarted from the UI
+    restarted: Vec<Restarted>,
+}
+
+/// A run restarted from the UI, starting in the background
+struct Restarted {
+    is_running: Arc<AtomicBool>,
+    runner: JoinHandle<UnifiedRunner>,
 }
 
 /// UI-specific state
 pub struct UiState {
     pub show_help: bool,
     pub selected_tab: usize,
+    /// Whether the request log is shown in place of the selected tab
+    pub show_log: bool,
+    /// Rows the request log is scrolled back from the newest request
+    pub log_scroll: usize,
+    /// Highlighted row of the status codes table
+    pub status_row: usize,
+    /// Colours to draw with
+    pub palette: Palette,
+    /// Workers a restarted test will use
+    pub restart_concurrency: usize,
 }
 
 impl Default for UiState {
@@ -57,25 +87,124 @@ impl UiState {
         UiState {
             show_help: false,
             selected_tab: 0,
+            show_log: false,
+            log_scroll: 0,
+            status_row: 0,
+            palette: Palette::default(),
+            restart_concurrency: 1,
         }
     }
 }
 
 impl App {
-    /// Used to signal the main thread to exit
-    pub fn should_exit() {
-        std::process::exit(0);
-    }
-
-    /// Create a new UI application
-    pub fn new(shared_state: SharedState) -> Self {
+    /// Create a new UI application watching a test run with `config`
+    pub fn new(shared_state: SharedState, config: TestConfig) -> Self {
+        let ui_state = UiState {
+            palette: Palette::new(config.theme),
+            restart_concurrency: config.concurrent,
+            ..UiState::new()
+        };
         App {
             shared_state,
-            ui_state: UiState::new(),
+            config,
+            ui_state,
+            restarted: Vec::new(),
+        }
+    }
+
+    /// Run the test again with the original settings, bar any change to the
+    /// number of workers
+    fn restart(&mut self) {
+        let mut config = self.config.clone();
+        config.concurrent = self.ui_state.restart_concurrency;
+        // As on the command line, every worker gets at least one request
+        if config.requests > 0 {
+            config.requests = config.requests.max(config.concurrent);
+        }
+
+        {
+            let mut app_state = self.shared_state.state.lock().unwrap();
+            app_state.reset();
+            app_state.concurrent_requests = config.concurrent;
+            app_state.target_requests = config.requests;
+            app_state.settings = config.describe();
+            app_state.record_event(format!("Restarted with {} workers", config.concurrent));
         }
+
+        let shared_state = self.shared_state.clone();
+        let mut runner = UnifiedRunner::with_state(config, shared_state.clone());
+        let is_running = runner.stop_handle();
+        let runner = tokio::spawn(async move {
+            // A bad CA file or output path would otherwise leave an empty run unexplained
+            if let Err(e) = runner.start().await {
+                let mut app_state = shared_state.state.lock().unwrap();
+                app_state.record_event(format!("Restart failed: {e:#}"));
+                app_state.mark_complete();
+            }
+            runner
+        });
+        self.restarted.push(Restarted { is_running, runner });
     }
 
-    /// Run the UI
+    /// Show tab `tab`, closing the request log if it is open
+    fn select_tab(&mut self, tab: usize) {
+        self.ui_state.selected_tab = tab;
+        self.ui_state.show_log = false;
+    }
+
+    /// Move the request log towards newer requests, or the status codes
+    /// table's highlight up a row
+    fn scroll_up(&mut self) {
+        if self.ui_state.show_log {
+            self.ui_state.log_scroll = self.ui_state.log_scroll.saturating_sub(1);
+        } else if self.ui_state.selected_tab == 2 {
+            self.ui_state.status_row = self.ui_state.status_row.saturating_sub(1);
+        }
+    }
+
+    /// Move the request log towards older requests, or the status codes
+    /// table's highlight down a row
+    fn scroll_down(&mut self) {
+        if self.ui_state.show_log {
+            let len = self.shared_state.state.lock().unwrap().request_log.len();
+            self.ui_state.log_scroll = (self.ui_state.log_scroll + 1).min(len.saturating_sub(1));
+        } else if self.ui_state.selected_tab == 2 {
+            let rows = status_row_count(&self.shared_state.state.lock().unwrap());
+            self.ui_state.status_row = (self.ui_state.status_row + 1).min(rows.saturating_sub(1));
+        }
+    }
+
+    /// Switch tabs on a click on a tab title and scroll with the wheel
+    fn handle_mouse(&mut self, mouse: MouseEvent, screen: Rect) {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(tab) = tab_at(screen, mouse.column, mouse.row) {
+                    self.select_tab(tab);
+                }
+            }
+            MouseEventKind::ScrollUp => self.scroll_up(),
+            MouseEventKind::ScrollDown => self.scroll_down(),
+            _ => {}
+        }
+    }
+
+    /// Stop any runs restarted from the UI; the caller stops the run it started
+    pub fn stop_restarted(&self) {
+        for restarted in &self.restarted {
+            restarted.is_running.store(false, Ordering::SeqCst);
+        }
+    }
+
+    /// Finish the runs restarted from the UI, completing their output files
+    pub async fn finish_restarted(&mut self) {
+        for restarted in self.restarted.drain(..) {
+            if let Ok(mut runner) = restarted.runner.await {
+                runner.finish().await;
+            }
+        }
+    }
+
+    /// Run the UI until the user quits, leaving the terminal as it was found
     pub fn run(&mut self) -> Result<()> {
         // Set up terminal
         enable_raw_mode()?;
@@ -90,7 +219,7 @@ impl App {
 
         loop {
             // Minimize the time we hold the lock - get a snapshot of the state
-            let should_quit;
+            let mut should_quit;
 
             {
                 // CRITICAL: Lock for as little time as possible to avoid blocking the test runner
@@ -109,83 +238,84 @@ impl App {
                 .unwrap_or_else(|| Duration::from_secs(0));
 
             if event::poll(timeout)? {
-                if let Event::Key(key) = event::read()? {
+                let event = event::read()?;
+                if let Event::Mouse(mouse) = event {
+                    self.handle_mouse(mouse, terminal.size()?);
+                }
+                if let Event::Key(key) = event {
                     match (key.code, key.modifiers) {
                         (KeyCode::Char('q'), _)
                         | (KeyCode::Esc, _)
                         | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
                             // Mark as quit but only hold the lock briefly
-                            {
-                                let mut app_state = self.shared_state.state.lock().unwrap();
-                                app_state.should_quit = true;
-                            }
-
-                            // Restore terminal before exiting
-                            disable_raw_mode()?;
-                            execute!(
-                                terminal.backend_mut(),
-                                LeaveAlternateScreen,
-                                DisableMouseCapture
-                            )?;
-                            terminal.show_cursor()?;
-
-                            // Exit the entire application
-                            Self::should_exit();
+                            self.shared_state.state.lock().unwrap().should_quit = true;
+                            should_quit = true;
                         }
                         (KeyCode::Char('h'), _) | (KeyCode::Char('?'), _) => {
                             self.ui_state.show_help = !self.ui_state.show_help;
                         }
-                        (KeyCode::Char('1'), _) => {
-                            self.ui_state.selected_tab = 0;
+                        (KeyCode::Char(tab @ '1'..='6'), _) => {
+                            self.select_tab(tab as usize - '1' as usize);
                         }
-                        (KeyCode::Char('2'), _) => {
-                            self.ui_state.selected_tab = 1;
+                        (KeyCode::Char('p'), _) => {
+                            // Hold load while investigating the target, then carry on
+                            let mut app_state = self.shared_state.state.lock().unwrap();
+                            if !app_state.is_complete {
+                                let paused = !app_state.paused.fetch_xor(true, Ordering::SeqCst);
+                                app_state.record_event(if paused { "Paused" } else { "Resumed" });
+                            }
                         }
-                        (KeyCode::Char('3'), _) => {
-                            self.ui_state.selected_tab = 2;
+                        (KeyCode::Char(key @ ('[' | ']')), _) => {
+                            // Probe other load levels without restarting the run
+                            let mut app_state = self.shared_state.state.lock().unwrap();
+                            let workers = app_state.concurrent_requests.max(1) as f64;
+                            let measured = app_state.current_throughput / workers;
+                            let stepped = (!app_state.is_complete)
+                                .then(|| app_state.rate_limit.step(key == ']', measured))
+                                .flatten();
+                            if let Some(rate) = stepped {
+                                app_state.record_event(format!(
+                                    "Rate limit set to {rate:.1} QPS per worker"
+                                ));
+                            }
                         }
-                        (KeyCode::Char('r'), _) => {
-                            // Restart the test
+                        (KeyCode::Char('s'), _) => {
+                            // Keep the numbers after the terminal session is gone
                             let mut app_state = self.shared_state.state.lock().unwrap();
-                            if app_state.is_complete {
-                                // Reset test state for a new run
-                                app_state.reset();
-
-                                // Create and launch a new test runner
-                                #[allow(deprecated)]
-                                let config = TestConfig {
-                                    url: app_state.url.clone(),
-                                    method: app_state.method,
-                                    requests: app_state.target_requests,
-                                    concurrent: app_state.concurrent_requests,
-                                    duration: app_state.duration,
-                                    rate_limit: 0.0, // Default no rate limit
-                                    headers: app_state.headers.clone(),
-                                    timeout: 20, // Default timeout
-                                    body: None,  // No body
-                                    content_type: "text/html".to_string(),
-                                    basic_auth: None, // No auth
-                                    proxy: None,      // No proxy
-                                    disable_compression: false,
-                                    disable_keepalive: false,
-                                    disable_redirects: false,
-                                    interactive: true,
-                                    output_format: String::new(), // Deprecated field
-                                };
-
-                                let state_clone = Arc::clone(&self.shared_state.state);
-
-                                // Spawn a new test runner task
-                                tokio::spawn(async move {
-                                    // Create a test runner inside the task with the shared state
-                                    let mut runner = crate::tester::UnifiedRunner::with_state(
-                                        config,
-                                        crate::tester::SharedState { state: state_clone },
-                                    );
-                                    let _ = runner.start().await;
-                                });
+                            let message = match save_snapshot(&app_state, Path::new(".")) {
+                                Ok(stem) => {
+                                    format!("Saved report to {}.json and .txt", stem.display())
+                                }
+                                Err(e) => format!("Failed to save report: {e}"),
+                            };
+                            app_state.record_event(message);
+                        }
+                        (KeyCode::Char('l'), _) => {
+                            self.ui_state.show_log = !self.ui_state.show_log;
+                            self.ui_state.log_scroll = 0;
+                        }
+                        (KeyCode::Up, _) => self.scroll_up(),
+                        (KeyCode::Down, _) => self.scroll_down(),
+                        (KeyCode::Char('x'), _) => {
+                            // Restart the chart series, e.g. after a warm-up
+                            self.shared_state.state.lock().unwrap().rebaseline_charts();
+                        }
+                        (KeyCode::Char('r'), _) => {
+                            // Restart a finished test with the same settings
+                            let is_complete = self.shared_state.state.lock().unwrap().is_complete;
+                            if is_complete {
+                                self.restart();
                             }
                         }
+                        (KeyCode::Char(key @ ('+' | '=' | '-')), _) => {
+                            // Pick the number of workers for the next restart
+                            let workers = &mut self.ui_state.restart_concurrency;
+                            *workers = if key == '-' {
+                                workers.saturating_sub(1).max(1)
+                            } else {
+                                *workers + 1
+                            };
+                        }
                         _ => {}
                     }
                 }
@@ -0,0 +1,123 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    symbols, Frame,
+};
+
+use super::percentiles::render_percentile_chart;
+use super::status_classes::render_status_class_chart;
+use super::theme::Palette;
+use super::time_series::{
+    burst_markers, create_latency_chart, create_throughput_chart, ChartConfig,
+};
+use crate::tester::TestState;
+
+/// Render the charts tab
+pub(super) fn render_charts<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Ratio(1, 3), // Throughput charts
+            Constraint::Ratio(1, 3), // Latency chart
+            Constraint::Ratio(1, 3), // Latency percentiles chart
+        ])
+        .split(area);
+    let throughput_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(50), // Throughput chart
+            Constraint::Percentage(50), // Status class chart
+        ])
+        .split(chunks[0]);
+
+    // Braille packs two points into each column, so more would not show
+    let resolution = usize::from(area.width) * 2;
+
+    // Throughput chart, covering the whole test
+    let throughput_data = app_state.throughput_history.downsampled(resolution);
+    let max_throughput = throughput_data
+        .iter()
+        .map(|&(_, y)| y)
+        .fold(1.0f64, |max, y| max.max(y));
+
+    // Create axis labels with more detail for the full-size chart
+    let x_min = throughput_data.first().map(|&(x, _)| x).unwrap_or(0.0);
+    let x_max = throughput_data.last().map(|&(x, _)| x).unwrap_or(60.0);
+    let y_max = max_throughput * 1.1;
+
+    let throughput_markers = burst_markers(&app_state.bursts, x_min, x_max, y_max);
+    // Create throughput chart with Braille markers and more labels
+    let throughput_chart = create_throughput_chart(ChartConfig {
+        data: &throughput_data,
+        title: "Throughput over time",
+        marker: symbols::Marker::Braille,
+        x_min,
+        x_max,
+        y_max,
+        num_x_labels: 6, // More x-axis labels for full chart
+        num_y_labels: 6, // More y-axis labels for full chart
+        markers: &throughput_markers,
+        palette,
+    });
+
+    f.render_widget(throughput_chart, throughput_chunks[0]);
+    render_status_class_chart(f, app_state, palette, throughput_chunks[1]);
+
+    // Latency chart (full size), covering the whole test
+    let latency_data = app_state.latency_history.downsampled(resolution);
+    let max_latency = latency_data
+        .iter()
+        .map(|&(_, y)| y)
+        .fold(1.0f64, |max, y| max.max(y));
+
+    // Create axis labels with more detail for the full-size chart
+    let l_x_min = latency_data.first().map(|&(x, _)| x).unwrap_or(0.0);
+    let l_x_max = latency_data.last().map(|&(x, _)| x).unwrap_or(60.0);
+    let l_y_max = max_latency * 1.1;
+
+    let latency_markers = burst_markers(&app_state.bursts, l_x_min, l_x_max, l_y_max);
+    // Create latency chart with Braille markers and more labels
+    let latency_chart = create_latency_chart(ChartConfig {
+        data: &latency_data,
+        title: "Latency over time",
+        marker: symbols::Marker::Braille,
+        x_min: l_x_min,
+        x_max: l_x_max,
+        y_max: l_y_max,
+        num_x_labels: 6, // More x-axis labels for full chart
+        num_y_labels: 6, // More y-axis labels for full chart
+        markers: &latency_markers,
+        palette,
+    });
+
+    f.render_widget(latency_chart, chunks[1]);
+
+    render_percentile_chart(f, app_state, palette, chunks[2]);
+}
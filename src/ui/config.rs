@@ -0,0 +1,61 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table},
+    Frame,
+};
+
+use super::theme::Palette;
+use crate::tester::TestState;
+
+/// Render the config tab: every effective setting, so a screenshot of the UI
+/// says what load produced the numbers
+pub fn render_config<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    let rows = app_state.settings.iter().map(|(name, value)| {
+        Row::new(vec![
+            Cell::from(*name).style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from(value.clone()),
+        ])
+    });
+
+    let table = Table::new(rows)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    "Test Configuration",
+                    Style::default().fg(palette.text),
+                ))
+                .borders(Borders::ALL),
+        )
+        .widths(&[Constraint::Length(20), Constraint::Min(20)]);
+    f.render_widget(table, area);
+}
@@ -0,0 +1,285 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    symbols,
+    text::Span,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::theme::Palette;
+use super::time_series::{
+    burst_markers, create_latency_chart, create_throughput_chart, ChartConfig,
+};
+use crate::tester::TestState;
+
+/// Render the dashboard tab
+pub(super) fn render_dashboard<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(40), // Stats
+            Constraint::Percentage(60), // Mini charts
+        ])
+        .split(area);
+
+    // Stats section
+    let stat_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(33), // Throughput stats
+            Constraint::Percentage(33), // Latency stats
+            Constraint::Percentage(34), // Byte stats
+        ])
+        .split(chunks[0]);
+
+    // Throughput stats
+    let completed = app_state.completed_requests;
+    let errors = app_state.error_count;
+    let success_rate = if completed > 0 {
+        100.0 * (completed - errors) as f64 / completed as f64
+    } else {
+        100.0
+    };
+
+    // Get elapsed time - same as title calculation for consistency
+    let elapsed = app_state.elapsed_secs();
+
+    let overall_tps = if elapsed > 0.0 {
+        completed as f64 / elapsed
+    } else {
+        0.0
+    };
+
+    let throughput_stats = [
+        format!("Completed Requests: {completed}"),
+        format!("Error Count: {errors}"),
+        format!("Success Rate: {success_rate:.1}%"),
+        format!(
+            "Current Throughput: {:.1} req/s",
+            app_state.current_throughput
+        ),
+        format!("Overall Throughput: {overall_tps:.1} req/s"),
+        format!("Elapsed Time: {elapsed:.1}s"),
+    ];
+
+    let throughput_block = Block::default()
+        .title(Span::styled(
+            "Throughput snapshot",
+            Style::default().fg(palette.throughput),
+        ))
+        .borders(Borders::ALL);
+
+    let throughput_stats_str = throughput_stats.join("\n");
+    let throughput_text = Paragraph::new(throughput_stats_str.as_str())
+        .style(Style::default().fg(palette.text))
+        .block(throughput_block);
+
+    f.render_widget(throughput_text, stat_chunks[0]);
+
+    // Latency stats
+    let min = if app_state.min_latency == f64::MAX {
+        0.0
+    } else {
+        app_state.min_latency
+    };
+
+    // Helper function to format latency with appropriate units and hide trailing zeros
+    let format_latency = |latency_ms: f64| -> String {
+        let (value, unit) = if latency_ms < 1.0 {
+            // Microseconds
+            (latency_ms * 1000.0, "μs")
+        } else if latency_ms < 1000.0 {
+            // Milliseconds
+            (latency_ms, "ms")
+        } else {
+            // Seconds
+            (latency_ms / 1000.0, "s")
+        };
+
+        // Check if the fractional part is zero
+        if value.fract() == 0.0 {
+            format!("{} {}", value as i64, unit)
+        } else {
+            format!("{value:.3} {unit}")
+        }
+    };
+
+    let latency_stats = [
+        format!("Min Latency: {}", format_latency(min)),
+        format!("Max Latency: {}", format_latency(app_state.max_latency)),
+        format!("P50 Latency: {}", format_latency(app_state.p50_latency)),
+        format!("P90 Latency: {}", format_latency(app_state.p90_latency)),
+        format!("P95 Latency: {}", format_latency(app_state.p95_latency)),
+        format!("P99 Latency: {}", format_latency(app_state.p99_latency)),
+    ];
+
+    let latency_block = Block::default()
+        .title(Span::styled(
+            "Latency snapshot",
+            Style::default().fg(palette.latency),
+        ))
+        .borders(Borders::ALL);
+
+    let latency_stats_str = latency_stats.join("\n");
+    let latency_text = Paragraph::new(latency_stats_str.as_str())
+        .style(Style::default().fg(palette.text))
+        .block(latency_block);
+
+    f.render_widget(latency_text, stat_chunks[1]);
+
+    // Byte stats
+    let format_bytes = |bytes: u64| -> String {
+        if bytes < 1024 {
+            format!("{bytes} B")
+        } else if bytes < 1024 * 1024 {
+            format!("{:.2} KB", bytes as f64 / 1024.0)
+        } else if bytes < 1024 * 1024 * 1024 {
+            format!("{:.2} MB", bytes as f64 / (1024.0 * 1024.0))
+        } else {
+            format!("{:.2} GB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+        }
+    };
+
+    let byte_stats = [
+        format!("Bytes Sent: {}", format_bytes(app_state.total_bytes_sent)),
+        format!(
+            "Bytes Received: {}",
+            format_bytes(app_state.total_bytes_received)
+        ),
+        format!(
+            "Total Bytes: {}",
+            format_bytes(app_state.total_bytes_sent + app_state.total_bytes_received)
+        ),
+        format!(
+            "Avg Req Size: {}",
+            if completed > 0 {
+                format_bytes(app_state.total_bytes_sent / completed as u64)
+            } else {
+                "0 B".to_string()
+            }
+        ),
+        format!(
+            "Avg Resp Size: {}",
+            if completed > 0 {
+                format_bytes(app_state.total_bytes_received / completed as u64)
+            } else {
+                "0 B".to_string()
+            }
+        ),
+    ];
+
+    let byte_block = Block::default()
+        .title(Span::styled(
+            "Data Transfer snapshot",
+            Style::default().fg(palette.transfer),
+        ))
+        .borders(Borders::ALL);
+
+    let byte_stats_str = byte_stats.join("\n");
+    let byte_text = Paragraph::new(byte_stats_str.as_str())
+        .style(Style::default().fg(palette.text))
+        .block(byte_block);
+
+    f.render_widget(byte_text, stat_chunks[2]);
+
+    // Mini charts
+    let chart_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(50), // Throughput chart
+            Constraint::Percentage(50), // Latency chart
+        ])
+        .split(chunks[1]);
+
+    // Throughput mini chart
+    let throughput_data: Vec<(f64, f64)> = app_state.throughput_data.clone().into();
+    let max_throughput = throughput_data
+        .iter()
+        .map(|&(_, y)| y)
+        .fold(1.0f64, |max, y| max.max(y));
+
+    // Create axis labels for mini chart (fewer labels for smaller space)
+    let mini_x_min = throughput_data.first().map(|&(x, _)| x).unwrap_or(0.0);
+    let mini_x_max = throughput_data.last().map(|&(x, _)| x).unwrap_or(60.0);
+    let mini_y_max = max_throughput * 1.1;
+
+    let throughput_markers = burst_markers(&app_state.bursts, mini_x_min, mini_x_max, mini_y_max);
+    // Create throughput chart with Braille markers and fewer labels
+    let throughput_chart = create_throughput_chart(ChartConfig {
+        data: &throughput_data,
+        title: "Throughput over time",
+        marker: symbols::Marker::Braille,
+        x_min: mini_x_min,
+        x_max: mini_x_max,
+        y_max: mini_y_max,
+        num_x_labels: 3, // Fewer x-axis labels for mini chart
+        num_y_labels: 3, // Fewer y-axis labels for mini chart
+        markers: &throughput_markers,
+        palette,
+    });
+
+    f.render_widget(throughput_chart, chart_chunks[0]);
+
+    // Latency mini chart
+    let latency_data: Vec<(f64, f64)> = app_state.latency_data.clone().into();
+    let max_latency = latency_data
+        .iter()
+        .map(|&(_, y)| y)
+        .fold(1.0f64, |max, y| max.max(y));
+
+    // Create axis labels for mini latency chart
+    let mini_lat_x_min = latency_data.first().map(|&(x, _)| x).unwrap_or(0.0);
+    let mini_lat_x_max = latency_data.last().map(|&(x, _)| x).unwrap_or(60.0);
+    let mini_lat_y_max = max_latency * 1.1;
+
+    let latency_markers = burst_markers(
+        &app_state.bursts,
+        mini_lat_x_min,
+        mini_lat_x_max,
+        mini_lat_y_max,
+    );
+    // Create latency chart with Braille markers and fewer labels
+    let latency_chart = create_latency_chart(ChartConfig {
+        data: &latency_data,
+        title: "Latency over time",
+        marker: symbols::Marker::Braille,
+        x_min: mini_lat_x_min,
+        x_max: mini_lat_x_max,
+        y_max: mini_lat_y_max,
+        num_x_labels: 3, // Fewer x-axis labels for mini chart
+        num_y_labels: 3, // Fewer y-axis labels for mini chart
+        markers: &latency_markers,
+        palette,
+    });
+
+    f.render_widget(latency_chart, chart_chunks[1]);
+}
@@ -0,0 +1,129 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use super::theme::Palette;
+use crate::tester::{
+    Health, TestState, DEGRADED_ERROR_RATE, DEGRADED_P99_MS, FAILING_ERROR_RATE, FAILING_P99_MS,
+    HEALTH_WINDOW_SECS,
+};
+
+/// Render the health tab: one row per target, one column per recent second
+pub fn render_health_matrix<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(area);
+
+    // Every row is read against the same clock so targets line up second by second
+    let now = app_state.elapsed_secs() as u64;
+
+    let header = Row::new(std::iter::once("Target".to_string()).chain(
+        (0..HEALTH_WINDOW_SECS).rev().map(|ago| {
+            if ago == 0 {
+                "now".to_string()
+            } else {
+                format!("-{ago}s")
+            }
+        }),
+    ));
+    let rows = app_state.targets.iter().map(|target| {
+        let cells = target
+            .health
+            .row(now)
+            .into_iter()
+            .map(|health| health_cell(health, palette));
+        Row::new(std::iter::once(Cell::from(target.url.clone())).chain(cells))
+    });
+
+    let widths: Vec<Constraint> = std::iter::once(Constraint::Min(20))
+        .chain((0..HEALTH_WINDOW_SECS).map(|_| Constraint::Length(4)))
+        .collect();
+    let table = Table::new(rows)
+        .header(header)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    format!("Target Health (last {HEALTH_WINDOW_SECS} seconds)"),
+                    Style::default().fg(palette.text),
+                ))
+                .borders(Borders::ALL),
+        )
+        .widths(&widths);
+    f.render_widget(table, chunks[0]);
+
+    let legend = Line::from(vec![
+        health_span(Health::Healthy, palette),
+        Span::raw(" healthy  "),
+        health_span(Health::Degraded, palette),
+        Span::raw(format!(
+            " ≥{}% errors or p99 ≥{}ms  ",
+            DEGRADED_ERROR_RATE * 100.0,
+            DEGRADED_P99_MS
+        )),
+        health_span(Health::Failing, palette),
+        Span::raw(format!(
+            " ≥{}% errors or p99 ≥{}ms  ",
+            FAILING_ERROR_RATE * 100.0,
+            FAILING_P99_MS
+        )),
+        health_span(Health::Idle, palette),
+        Span::raw(" no requests"),
+    ]);
+    let legend = Paragraph::new(legend).block(Block::default().borders(Borders::ALL));
+    f.render_widget(legend, chunks[1]);
+}
+
+fn health_cell(health: Health, palette: &Palette) -> Cell<'static> {
+    Cell::from(health_span(health, palette))
+}
+
+fn health_span(health: Health, palette: &Palette) -> Span<'static> {
+    let color = match health {
+        Health::Idle => palette.idle,
+        Health::Healthy => palette.success,
+        Health::Degraded => palette.warning,
+        Health::Failing => palette.error,
+    };
+    // Without colour, denser shading marks a worse second
+    let symbol = match health {
+        Health::Idle => "··",
+        _ if !palette.is_monochrome() => "██",
+        Health::Healthy => "░░",
+        Health::Degraded => "▒▒",
+        Health::Failing => "██",
+    };
+    Span::styled(symbol, Style::default().fg(color))
+}
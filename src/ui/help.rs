@@ -0,0 +1,98 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::Span,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::theme::Palette;
+
+/// Render the help overlay
+pub(super) fn render_help<B: Backend>(f: &mut Frame<B>, palette: &Palette, area: Rect) {
+    // Calculate centered box area
+    let help_area = centered_rect(50, 40, area);
+
+    // Create a simple block with a clean look
+    let help_block = Block::default()
+        .title(Span::styled(" Help ", Style::default().fg(palette.text)))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(palette.background).fg(palette.text));
+
+    // Help text content
+    let help_text = [
+        "Press 'q' to quit",
+        "Press 'r' to restart completed test, '+'/'-' to change its workers",
+        "Press 'p' to pause or resume sending",
+        "Press '[' or ']' to lower or raise the rate limit",
+        "Press 's' to save the results so far to JSON and text files",
+        "Press 'x' to clear the charts and start them over",
+        "Press 'l' to show the latest requests, ↑/↓ to scroll",
+        "Press ↑/↓ on Status Codes to move through the rows",
+        "Click a tab to open it, scroll the wheel to scroll",
+        "Press 'h' to toggle this help overlay",
+        "Press '1' to view Dashboard",
+        "Press '2' to view Charts",
+        "Press '3' to view Status Codes",
+        "Press '4' to view target Health",
+        "Press '5' to view the Latency distribution",
+        "Press '6' to view the test Config",
+    ]
+    .join("\n");
+
+    // Create paragraph inside the block
+    let help_paragraph = Paragraph::new(help_text)
+        .block(help_block)
+        .style(Style::default().bg(palette.background).fg(palette.text))
+        .alignment(Alignment::Center);
+
+    // Clear the area first so the background shows
+    f.render_widget(Clear, help_area);
+
+    // Then render the help text with block
+    f.render_widget(help_paragraph, help_area);
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -0,0 +1,80 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::theme::Palette;
+use crate::tester::TestState;
+
+/// Most rows the histogram is split into, however tall the terminal
+const MAX_BUCKETS: usize = 30;
+
+/// Render the histogram tab: one bar per latency range, so a second peak that
+/// percentiles average away stays visible
+pub fn render_latency_histogram<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    let block = Block::default()
+        .title(Span::styled(
+            "Latency Distribution",
+            Style::default().fg(palette.text),
+        ))
+        .borders(Borders::ALL);
+
+    let rows = (area.height.saturating_sub(2) as usize).min(MAX_BUCKETS);
+    let buckets = app_state.latency_buckets(rows);
+    let largest = buckets.iter().map(|&(_, count)| count).max().unwrap_or(0);
+    let total: u64 = buckets.iter().map(|&(_, count)| count).sum();
+
+    // Room left for the bar once the bound and count columns are drawn
+    let bar_width = (area.width as usize).saturating_sub(34);
+    let lines: Vec<Line> = if buckets.is_empty() {
+        vec![Line::from("No responses yet")]
+    } else {
+        buckets
+            .iter()
+            .map(|&(upper_ms, count)| {
+                let length = (count * bar_width as u64).div_ceil(largest.max(1)) as usize;
+                Line::from(vec![
+                    Span::raw(format!("≤ {upper_ms:>10.3} ms │")),
+                    Span::styled("█".repeat(length), Style::default().fg(palette.latency)),
+                    Span::raw(format!(
+                        " {count} ({:.1}%)",
+                        100.0 * count as f64 / total as f64
+                    )),
+                ])
+            })
+            .collect()
+    };
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -21,6 +21,20 @@
 // SOFTWARE.
 
 mod app;
+mod charts;
+mod config;
+mod dashboard;
+mod health;
+mod help;
+mod histogram;
+mod percentiles;
+mod progress;
+mod request_log;
+mod status_classes;
+mod status_codes;
+mod theme;
+mod time_series;
 mod widgets;
 
 pub use app::*;
+pub use theme::Palette;
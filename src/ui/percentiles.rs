@@ -0,0 +1,96 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::Style,
+    symbols,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset},
+    Frame,
+};
+
+use super::theme::Palette;
+use super::time_series::{
+    burst_dataset, burst_markers, create_latency_axis_labels, create_time_axis_labels,
+};
+use crate::tester::TestState;
+
+/// Render p50/p95/p99 latency over time, each point taken from the requests
+/// of one interval, so a degrading tail shows even while the average holds
+pub fn render_percentile_chart<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    // Braille packs two points into each column, so more would not show
+    let resolution = usize::from(area.width) * 2;
+    let p50 = app_state.p50_history.downsampled(resolution);
+    let p95 = app_state.p95_history.downsampled(resolution);
+    let p99 = app_state.p99_history.downsampled(resolution);
+
+    let x_min = p99.first().map(|&(x, _)| x).unwrap_or(0.0);
+    let x_max = p99.last().map(|&(x, _)| x).unwrap_or(60.0);
+    let y_max = p99.iter().map(|&(_, y)| y).fold(1.0f64, f64::max) * 1.1;
+    let markers = burst_markers(&app_state.bursts, x_min, x_max, y_max);
+
+    let series = |name, data, color| {
+        Dataset::default()
+            .name(name)
+            .marker(symbols::Marker::Braille)
+            .style(Style::default().fg(color))
+            .data(data)
+    };
+    let mut datasets = vec![
+        series("p50", &p50, palette.success),
+        series("p95", &p95, palette.warning),
+        series("p99", &p99, palette.error),
+    ];
+    datasets.extend(burst_dataset(&markers, palette));
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    "Latency percentiles over time",
+                    Style::default().fg(palette.error),
+                ))
+                .borders(Borders::ALL),
+        )
+        .x_axis(
+            Axis::default()
+                .title(Span::styled("Time (s)", Style::default().fg(palette.muted)))
+                .style(Style::default().fg(palette.muted))
+                .bounds([x_min, x_max])
+                .labels(create_time_axis_labels(x_min, x_max, 6, palette)),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(palette.muted))
+                .bounds([0.0, y_max])
+                .labels(create_latency_axis_labels(0.0, y_max, 4, palette)),
+        );
+
+    f.render_widget(chart, area);
+}
@@ -0,0 +1,74 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Gauge},
+    Frame,
+};
+
+use super::theme::Palette;
+use crate::tester::TestState;
+
+/// Width of the progress gauge beside the title
+pub const PROGRESS_WIDTH: u16 = 30;
+
+/// Render how far the test is toward its `-n` or `-z` limit, with the time
+/// left, for a test that has one
+pub fn render_progress<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    let Some(progress) = app_state.progress() else {
+        return;
+    };
+    let label = match app_state.eta_secs() {
+        Some(eta) => format!("{:.0}% · ETA {}", progress * 100.0, format_eta(eta)),
+        None => format!("{:.0}%", progress * 100.0),
+    };
+    let color = if app_state.is_complete {
+        palette.info
+    } else {
+        palette.success
+    };
+
+    let gauge = Gauge::default()
+        .block(Block::default().title("Progress").borders(Borders::ALL))
+        .gauge_style(Style::default().fg(color).bg(palette.background))
+        .ratio(progress)
+        .label(label);
+    f.render_widget(gauge, area);
+}
+
+/// Seconds as "1h 02m", "3m 05s" or "42s"
+fn format_eta(secs: f64) -> String {
+    let secs = secs.ceil() as u64;
+    match (secs / 3600, secs / 60 % 60, secs % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m {s:02}s"),
+        (h, m, _) => format!("{h}h {m:02}m"),
+    }
+}
@@ -0,0 +1,93 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table},
+    Frame,
+};
+
+use super::app::UiState;
+use crate::tester::TestState;
+
+/// Render the request log: the latest requests, newest first, scrolled back
+/// `ui_state.log_scroll` rows
+pub fn render_request_log<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    ui_state: &UiState,
+    area: Rect,
+) {
+    let palette = &ui_state.palette;
+    let log = &app_state.request_log;
+    let scroll = ui_state.log_scroll.min(log.len().saturating_sub(1));
+    let visible = (area.height as usize).saturating_sub(3);
+
+    let header = Row::new(["Time (s)", "Status", "Latency (ms)", "Bytes", "Target"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let rows = log.iter().rev().skip(scroll).take(visible).map(|metric| {
+        let status = match metric.status_code {
+            0 => "error".to_string(),
+            code => code.to_string(),
+        };
+        let color = if metric.is_error {
+            palette.error
+        } else {
+            palette.success
+        };
+        let target = app_state
+            .targets
+            .get(metric.target)
+            .map_or(String::new(), |target| target.url.clone());
+        Row::new(vec![
+            Cell::from(format!("{:.3}", metric.timestamp)),
+            Cell::from(status).style(Style::default().fg(color)),
+            Cell::from(format!("{:.3}", metric.latency_ms)),
+            Cell::from(metric.bytes_received.to_string()),
+            Cell::from(target),
+        ])
+    });
+
+    let title = format!(
+        "Request Log (latest {}, {} back; ↑/↓ to scroll, 'l' to close)",
+        log.len(),
+        scroll
+    );
+    let table = Table::new(rows)
+        .header(header)
+        .block(
+            Block::default()
+                .title(Span::styled(title, Style::default().fg(palette.text)))
+                .borders(Borders::ALL),
+        )
+        .widths(&[
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(14),
+            Constraint::Length(10),
+            Constraint::Min(20),
+        ]);
+    f.render_widget(table, area);
+}
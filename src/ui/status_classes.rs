@@ -0,0 +1,107 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::Style,
+    symbols,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset},
+    Frame,
+};
+
+use super::theme::Palette;
+use super::time_series::{
+    burst_dataset, burst_markers, create_throughput_axis_labels, create_time_axis_labels,
+};
+use crate::tester::{TestState, STATUS_CLASSES};
+
+/// Render requests per second split by status class, each line the top of its
+/// band stacked on the classes below, so error bursts show in time context
+pub fn render_status_class_chart<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    palette: &Palette,
+    area: Rect,
+) {
+    // Braille packs two points into each column, so more would not show
+    let bands = app_state
+        .status_classes
+        .stacked(usize::from(area.width) * 2);
+    let total = &bands[bands.len() - 1];
+
+    let x_min = total.first().map(|&(x, _)| x).unwrap_or(0.0);
+    let x_max = total.last().map(|&(x, _)| x).unwrap_or(60.0);
+    let y_max = total.iter().map(|&(_, y)| y).fold(1.0f64, f64::max) * 1.1;
+    let markers = burst_markers(&app_state.bursts, x_min, x_max, y_max);
+
+    // Colour of each of `STATUS_CLASSES`
+    let colors = [
+        palette.success,
+        palette.info,
+        palette.warning,
+        palette.error,
+        palette.marker,
+    ];
+
+    // Top band first so its legend entry lines up with the top of the stack
+    let mut datasets: Vec<Dataset> = bands
+        .iter()
+        .zip(STATUS_CLASSES.iter().zip(colors))
+        .rev()
+        .map(|(band, (name, color))| {
+            Dataset::default()
+                .name(*name)
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(color))
+                .data(band)
+        })
+        .collect();
+    datasets.extend(burst_dataset(&markers, palette));
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    "Throughput by status class",
+                    Style::default().fg(palette.success),
+                ))
+                .borders(Borders::ALL),
+        )
+        .x_axis(
+            Axis::default()
+                .title(Span::styled("Time (s)", Style::default().fg(palette.muted)))
+                .style(Style::default().fg(palette.muted))
+                .bounds([x_min, x_max])
+                .labels(create_time_axis_labels(x_min, x_max, 4, palette)),
+        )
+        .y_axis(
+            Axis::default()
+                .title(Span::styled("Req/s", Style::default().fg(palette.muted)))
+                .style(Style::default().fg(palette.muted))
+                .bounds([0.0, y_max])
+                .labels(create_throughput_axis_labels(0.0, y_max, 4, palette)),
+        );
+
+    f.render_widget(chart, area);
+}
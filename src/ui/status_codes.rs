@@ -0,0 +1,182 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+use super::app::UiState;
+use super::theme::Palette;
+use crate::tester::grpc::grpc_status_name;
+use crate::tester::TestState;
+
+/// Render the status codes tab
+pub(super) fn render_status_codes<B: Backend>(
+    f: &mut Frame<B>,
+    app_state: &TestState,
+    ui_state: &UiState,
+    area: Rect,
+) {
+    let palette = &ui_state.palette;
+    let status_rows = status_rows(app_state, palette);
+    let grpc = !app_state.grpc_status_counts.is_empty();
+
+    // Keep the selected row in view, scrolling when there are more rows than fit
+    let mut table_state = TableState::default();
+    if !status_rows.is_empty() {
+        table_state.select(Some(ui_state.status_row.min(status_rows.len() - 1)));
+    }
+
+    let header_cells = ["Status Code", "Count", "Percentage"]
+        .iter()
+        .map(|h| (*h).to_string());
+
+    let header = Row::new(header_cells).style(Style::default()).height(1);
+
+    let table = Table::new(status_rows)
+        .header(header)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    if grpc {
+                        "gRPC Status Codes"
+                    } else {
+                        "HTTP Status Codes"
+                    },
+                    Style::default().fg(palette.text),
+                ))
+                .borders(Borders::ALL),
+        )
+        .widths(&[
+            Constraint::Percentage(40),
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+        ])
+        .column_spacing(1)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+/// Number of rows in the status codes table
+pub fn status_row_count(app_state: &TestState) -> usize {
+    status_rows(app_state, &Palette::default()).len()
+}
+
+/// One row per status code seen, then one per kind of request that got no response
+fn status_rows(app_state: &TestState, palette: &Palette) -> Vec<Row<'static>> {
+    let mut status_rows = Vec::new();
+    let grpc = !app_state.grpc_status_counts.is_empty();
+    let counts = if grpc {
+        &app_state.grpc_status_counts
+    } else {
+        &app_state.status_counts
+    };
+    let mut status_codes: Vec<u16> = counts.keys().cloned().collect();
+    status_codes.sort();
+
+    let total_requests = app_state.completed_requests as f64;
+
+    for status in status_codes {
+        let count = *counts.get(&status).unwrap_or(&0);
+        let percentage = if total_requests > 0.0 {
+            (count as f64 / total_requests) * 100.0
+        } else {
+            0.0
+        };
+
+        let status_class = status / 100;
+        let color = match status_class {
+            _ if grpc && status == 0 => palette.success,
+            _ if grpc => palette.error,
+            2 => palette.success,
+            3 => palette.info,
+            4 => palette.warning,
+            5 => palette.error,
+            _ => palette.text,
+        };
+
+        // Mark non-success status codes as errors in the UI as well
+        let is_error = if grpc {
+            status != 0
+        } else {
+            !app_state.classifier.is_success_status(status)
+        };
+        let style = if is_error {
+            Style::default().fg(color).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(color)
+        };
+
+        let label = if grpc {
+            format!("{status} {}", grpc_status_name(status))
+        } else {
+            status.to_string()
+        };
+        let status_text = Span::styled(label, style);
+
+        status_rows.push(Row::new(vec![
+            Cell::from(status_text),
+            Cell::from(format!("{}", count)),
+            Cell::from(format!("{:.1}%", percentage)),
+        ]));
+    }
+
+    // Add rows for requests that never got a response
+    let responses: usize = app_state.status_counts.values().sum();
+    let unanswered = app_state.completed_requests.saturating_sub(responses);
+    let connection_errors = unanswered.saturating_sub(app_state.connect_timeouts);
+    for (label, count) in [
+        ("Connect Timeout", app_state.connect_timeouts),
+        ("Connection Error", connection_errors),
+    ] {
+        if count == 0 {
+            continue;
+        }
+        let error_percentage = if total_requests > 0.0 {
+            (count as f64 / total_requests) * 100.0
+        } else {
+            0.0
+        };
+
+        let error_text = Span::styled(
+            label,
+            Style::default()
+                .fg(palette.error)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        status_rows.push(Row::new(vec![
+            Cell::from(error_text),
+            Cell::from(format!("{}", count)),
+            Cell::from(format!("{:.1}%", error_percentage)),
+        ]));
+    }
+
+    status_rows
+}
@@ -0,0 +1,136 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::style::Color;
+
+use crate::tester::Theme;
+
+/// The colours each part of the UI is drawn in under a `Theme`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Body text
+    pub text: Color,
+    /// Axis labels and other secondary text
+    pub muted: Color,
+    /// Background of the help overlay
+    pub background: Color,
+    /// Throughput charts and stats
+    pub throughput: Color,
+    /// Latency charts and stats
+    pub latency: Color,
+    /// Data transfer stats
+    pub transfer: Color,
+    /// 2xx responses, healthy targets, and a running test
+    pub success: Color,
+    /// 3xx responses and a completed test
+    pub info: Color,
+    /// 4xx responses, degraded targets, and the selected tab
+    pub warning: Color,
+    /// 5xx responses, failures, and failing targets
+    pub error: Color,
+    /// Burst markers and requests that got no response
+    pub marker: Color,
+    /// Targets with nothing to report yet
+    pub idle: Color,
+}
+
+impl Palette {
+    /// The palette for `theme`, or monochrome when `NO_COLOR` is set
+    pub fn new(theme: Theme) -> Self {
+        match theme.effective() {
+            Theme::Default => DEFAULT,
+            Theme::Dark => DARK,
+            Theme::Light => LIGHT,
+            Theme::Monochrome => MONOCHROME,
+        }
+    }
+
+    /// Whether this palette draws without colour
+    pub fn is_monochrome(&self) -> bool {
+        *self == MONOCHROME
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::new(Theme::Default)
+    }
+}
+
+const DEFAULT: Palette = Palette {
+    text: Color::White,
+    muted: Color::Gray,
+    background: Color::Black,
+    throughput: Color::Cyan,
+    latency: Color::Yellow,
+    transfer: Color::Magenta,
+    success: Color::Green,
+    info: Color::Blue,
+    warning: Color::Yellow,
+    error: Color::Red,
+    marker: Color::Magenta,
+    idle: Color::DarkGray,
+};
+
+const DARK: Palette = Palette {
+    throughput: Color::LightCyan,
+    latency: Color::LightYellow,
+    transfer: Color::LightMagenta,
+    success: Color::LightGreen,
+    info: Color::LightBlue,
+    warning: Color::LightYellow,
+    error: Color::LightRed,
+    marker: Color::LightMagenta,
+    ..DEFAULT
+};
+
+// Yellow and white wash out on a light background, so they become dark
+// orange and black from the 256-colour palette
+const LIGHT: Palette = Palette {
+    text: Color::Black,
+    muted: Color::DarkGray,
+    background: Color::White,
+    throughput: Color::Blue,
+    latency: Color::Indexed(130),
+    transfer: Color::Magenta,
+    success: Color::Indexed(28),
+    info: Color::Blue,
+    warning: Color::Indexed(130),
+    error: Color::Red,
+    marker: Color::Magenta,
+    idle: Color::Gray,
+};
+
+const MONOCHROME: Palette = Palette {
+    text: Color::Reset,
+    muted: Color::Reset,
+    background: Color::Reset,
+    throughput: Color::Reset,
+    latency: Color::Reset,
+    transfer: Color::Reset,
+    success: Color::Reset,
+    info: Color::Reset,
+    warning: Color::Reset,
+    error: Color::Reset,
+    marker: Color::Reset,
+    idle: Color::Reset,
+};
@@ -0,0 +1,252 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use ratatui::{
+    style::Style,
+    symbols,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset},
+};
+
+use super::theme::Palette;
+
+/// Helper function to create time axis labels
+pub(super) fn create_time_axis_labels(
+    min: f64,
+    max: f64,
+    num_labels: usize,
+    palette: &Palette,
+) -> Vec<Span<'static>> {
+    let mut labels = Vec::with_capacity(num_labels);
+    let range = max - min;
+
+    for i in 0..num_labels {
+        let value = min + (range * i as f64) / (num_labels - 1) as f64;
+        // Round to whole seconds for time
+        let formatted = format!("{}", value.round() as i64);
+        labels.push(Span::styled(formatted, Style::default().fg(palette.muted)));
+    }
+
+    labels
+}
+
+/// Helper function to create throughput axis labels
+pub(super) fn create_throughput_axis_labels(
+    min: f64,
+    max: f64,
+    num_labels: usize,
+    palette: &Palette,
+) -> Vec<Span<'static>> {
+    let mut labels = Vec::with_capacity(num_labels);
+    let range = max - min;
+
+    for i in 0..num_labels {
+        let value = min + (range * i as f64) / (num_labels - 1) as f64;
+        // Use sensible rounding based on the value range
+        let formatted = if max <= 10.0 {
+            // For small values, show 1 decimal place
+            format!("{value:.1}")
+        } else if max <= 100.0 {
+            // For medium values, round to whole numbers
+            format!("{}", value.round() as i64)
+        } else {
+            // For large values, round to nearest 10
+            format!("{}", ((value / 10.0).round() * 10.0) as i64)
+        };
+
+        labels.push(Span::styled(formatted, Style::default().fg(palette.muted)));
+    }
+
+    labels
+}
+
+/// Helper function to create latency axis labels with appropriate units
+pub(super) fn create_latency_axis_labels(
+    min: f64,
+    max: f64,
+    num_labels: usize,
+    palette: &Palette,
+) -> Vec<Span<'static>> {
+    let mut labels = Vec::with_capacity(num_labels);
+    let range = max - min;
+
+    for i in 0..num_labels {
+        let value = min + (range * i as f64) / (num_labels - 1) as f64;
+
+        // Always display with 1 decimal place and appropriate units
+        let (value_adj, unit) = if value < 1.0 {
+            // Microseconds
+            (value * 1000.0, "μs")
+        } else if value < 1000.0 {
+            // Milliseconds
+            (value, "ms")
+        } else {
+            // Seconds
+            (value / 1000.0, "s")
+        };
+
+        // Always use 1 decimal place
+        let formatted = format!("{value_adj:.1}{unit}");
+
+        labels.push(Span::styled(formatted, Style::default().fg(palette.muted)));
+    }
+
+    labels
+}
+
+/// Configuration for chart creation
+pub(super) struct ChartConfig<'a> {
+    pub(super) data: &'a [(f64, f64)],
+    pub(super) title: &'a str,
+    pub(super) marker: symbols::Marker,
+    pub(super) x_min: f64,
+    pub(super) x_max: f64,
+    pub(super) y_max: f64,
+    pub(super) num_x_labels: usize,
+    pub(super) num_y_labels: usize,
+    /// Points drawing a vertical line at each burst
+    pub(super) markers: &'a [(f64, f64)],
+    pub(super) palette: &'a Palette,
+}
+
+/// Vertical dotted lines at each burst that falls within the chart's time range
+pub(super) fn burst_markers(bursts: &[f64], x_min: f64, x_max: f64, y_max: f64) -> Vec<(f64, f64)> {
+    const STEPS: usize = 16;
+    bursts
+        .iter()
+        .filter(|&&t| t >= x_min && t <= x_max)
+        .flat_map(|&t| (0..=STEPS).map(move |i| (t, y_max * i as f64 / STEPS as f64)))
+        .collect()
+}
+
+/// The dataset that marks bursts on a chart, if there were any
+pub(super) fn burst_dataset<'a>(
+    markers: &'a [(f64, f64)],
+    palette: &Palette,
+) -> Option<Dataset<'a>> {
+    (!markers.is_empty()).then(|| {
+        Dataset::default()
+            .name("Burst")
+            .marker(symbols::Marker::Dot)
+            .style(Style::default().fg(palette.marker))
+            .data(markers)
+    })
+}
+
+/// Create a throughput chart with the given parameters
+pub(super) fn create_throughput_chart<'a>(config: ChartConfig<'a>) -> Chart<'a> {
+    let mut throughput_dataset = vec![Dataset::default()
+        .name("Throughput (req/s)")
+        .marker(config.marker)
+        .style(Style::default().fg(config.palette.throughput))
+        .data(config.data)];
+    throughput_dataset.extend(burst_dataset(config.markers, config.palette));
+
+    // Create axis labels
+    let x_labels = create_time_axis_labels(
+        config.x_min,
+        config.x_max,
+        config.num_x_labels,
+        config.palette,
+    );
+    let y_labels =
+        create_throughput_axis_labels(0.0, config.y_max, config.num_y_labels, config.palette);
+
+    // Create and return the chart
+    Chart::new(throughput_dataset)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    config.title,
+                    Style::default().fg(config.palette.throughput),
+                ))
+                .borders(Borders::ALL),
+        )
+        .x_axis(
+            Axis::default()
+                .title(Span::styled(
+                    "Time (s)",
+                    Style::default().fg(config.palette.muted),
+                ))
+                .style(Style::default().fg(config.palette.muted))
+                .bounds([config.x_min, config.x_max])
+                .labels(x_labels),
+        )
+        .y_axis(
+            Axis::default()
+                .title(Span::styled(
+                    "Req/s",
+                    Style::default().fg(config.palette.muted),
+                ))
+                .style(Style::default().fg(config.palette.muted))
+                .bounds([0.0, config.y_max])
+                .labels(y_labels),
+        )
+}
+
+/// Create a latency chart with the given parameters
+pub(super) fn create_latency_chart<'a>(config: ChartConfig<'a>) -> Chart<'a> {
+    let mut latency_dataset = vec![Dataset::default()
+        .name("Latency (ms)")
+        .marker(config.marker)
+        .style(Style::default().fg(config.palette.latency))
+        .data(config.data)];
+    latency_dataset.extend(burst_dataset(config.markers, config.palette));
+
+    // Create axis labels
+    let x_labels = create_time_axis_labels(
+        config.x_min,
+        config.x_max,
+        config.num_x_labels,
+        config.palette,
+    );
+    let y_labels =
+        create_latency_axis_labels(0.0, config.y_max, config.num_y_labels, config.palette);
+
+    // Create and return the chart
+    Chart::new(latency_dataset)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    config.title,
+                    Style::default().fg(config.palette.latency),
+                ))
+                .borders(Borders::ALL),
+        )
+        .x_axis(
+            Axis::default()
+                .title(Span::styled(
+                    "Time (s)",
+                    Style::default().fg(config.palette.muted),
+                ))
+                .style(Style::default().fg(config.palette.muted))
+                .bounds([config.x_min, config.x_max])
+                .labels(x_labels),
+        )
+        .y_axis(
+            Axis::default()
+                .title(Span::styled("", Style::default().fg(config.palette.muted)))
+                .style(Style::default().fg(config.palette.muted))
+                .bounds([0.0, config.y_max])
+                .labels(y_labels),
+        )
+}
@@ -0,0 +1,218 @@
+// whambam - A high-performance HTTP load testing tool
+//
+// Copyright (c) 2025 Stephen Harrison
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use anyhow::{anyhow, Result};
+use url::Url;
+
+use crate::tester::parse_proxy_url;
+use crate::Args;
+
+/// Concurrency above which opening a new connection per request risks exhausting local ports
+const HIGH_CONCURRENCY: usize = 1000;
+
+/// The request count used when neither -n nor -z is given
+pub(crate) const DEFAULT_REQUESTS: usize = 200;
+
+/// Check option combinations, rejecting impossible ones and describing surprising ones
+pub fn check(args: &Args, duration_secs: u64) -> Result<Vec<String>> {
+    let mut warnings = check_load(
+        args.requests,
+        args.concurrent,
+        duration_secs,
+        args.timeout,
+        args.rate_limit,
+    )?;
+
+    // Not a clap conflict: that would also stop clap enforcing --url-order's requirement
+    if (args.url_file.is_some() || args.sitemap.is_some()) && !args.url.is_empty() {
+        return Err(anyhow!(
+            "Give either a URL, --url-file or --sitemap, not more than one"
+        ));
+    }
+    // Caught here because the UI would otherwise swallow the runner's error
+    for (option, path) in [
+        ("--cacert", &args.tls.cacert),
+        ("--cert", &args.tls.cert),
+        ("--key", &args.tls.key),
+    ] {
+        if let Some(path) = path.as_ref().filter(|path| !path.is_file()) {
+            return Err(anyhow!("{option} file {} does not exist", path.display()));
+        }
+    }
+
+    if let (Some(min), Some(max)) = (args.tls.tls_min, args.tls.tls_max) {
+        if min > max {
+            return Err(anyhow!("--tls-min {min} is newer than --tls-max {max}"));
+        }
+    }
+
+    if args.tls.sni.is_some() && !args.target_url().starts_with("https://") {
+        return Err(anyhow!("--sni needs an https:// URL"));
+    }
+
+    if let Some(proxy) = &args.proxy.proxy {
+        parse_proxy_url(proxy)?;
+    }
+
+    if args.load.burst.is_some() && args.load.burst_interval == 0 {
+        return Err(anyhow!("--burst-interval must be at least 1s"));
+    }
+
+    if args.sweeps.sweep.contains(&0) {
+        return Err(anyhow!("--sweep levels must be at least 1"));
+    }
+
+    if args
+        .sweeps
+        .find_capacity
+        .is_some_and(|p99_ms| p99_ms <= 0.0)
+    {
+        return Err(anyhow!("--find-capacity must be above 0 ms"));
+    }
+
+    // reqwest would quietly ignore entries for other hosts or ports
+    let targets: Vec<Url> = target_urls(args)
+        .into_iter()
+        .filter_map(|url| Url::parse(url).ok())
+        .collect();
+    for entry in &args.dns.resolve {
+        if !targets.iter().any(|url| entry.matches(url)) {
+            warnings.push(format!(
+                "--resolve {entry} matches neither the URL nor --fallback-url and is unused"
+            ));
+        }
+    }
+
+    // CONNECT tunnels carry no custom headers and the rest would reach the server
+    let forwarded = args
+        .proxy_headers
+        .iter()
+        .filter_map(|header| header.split_once(':'))
+        .any(|(name, _)| !name.trim().eq_ignore_ascii_case("proxy-authorization"));
+    if forwarded && args.target_url().starts_with("https://") {
+        warnings.push(
+            "--proxy-header values other than Proxy-Authorization are not sent for https:// URLs"
+                .to_string(),
+        );
+    }
+
+    if let Some(connect_timeout) = args.connect_timeout {
+        if args.timeout > 0 && connect_timeout >= args.timeout {
+            warnings.push(format!(
+                "--connect-timeout {connect_timeout}s is not shorter than -t {}s, so slow connections hit the request timeout first",
+                args.timeout
+            ));
+        }
+    }
+
+    if args.max_body_bytes.is_some() {
+        for (option, used) in [
+            ("--assert-json", !args.checks.assert_json.is_empty()),
+            (
+                "--expect-body-sha256",
+                args.checks.expect_body_sha256.is_some(),
+            ),
+            ("--body-contains", args.checks.body_contains.is_some()),
+        ] {
+            if used {
+                warnings.push(format!(
+                    "{option} checks bodies cut short by --max-body-bytes and may fail on larger responses"
+                ));
+            }
+        }
+    }
+
+    if let Some(burst) = args
+        .load
+        .burst
+        .filter(|&burst| burst as usize > args.concurrent)
+    {
+        warnings.push(format!(
+            "--burst {burst} is above -c {}; only {} requests can be in flight at once, so each burst is spread out",
+            args.concurrent, args.concurrent
+        ));
+    }
+
+    if args.connection.disable_keepalive && args.concurrent > HIGH_CONCURRENCY {
+        warnings.push(format!(
+            "--disable-keepalive opens a new connection per request; {} concurrent connections may exhaust local ports",
+            args.concurrent
+        ));
+    }
+
+    Ok(warnings)
+}
+
+/// Check the load settings every command that runs a test shares
+pub(crate) fn check_load(
+    requests: Option<usize>,
+    concurrent: usize,
+    duration_secs: u64,
+    timeout: u64,
+    rate_limit: f64,
+) -> Result<Vec<String>> {
+    if concurrent == 0 {
+        return Err(anyhow!("--concurrent must be at least 1"));
+    }
+    if rate_limit < 0.0 {
+        return Err(anyhow!("--rate-limit cannot be negative"));
+    }
+
+    let mut warnings = Vec::new();
+
+    // With -z, a missing -n leaves the duration as the only limit, and -n 0 is unlimited
+    let requests = match requests {
+        None if duration_secs > 0 => None,
+        requests => Some(requests.unwrap_or(DEFAULT_REQUESTS)),
+    };
+    if let Some(requests) = requests.filter(|&requests| requests > 0 && requests < concurrent) {
+        warnings.push(format!(
+            "-n {requests} is below -c {concurrent}; {concurrent} requests will be sent instead. Use -z to run for a fixed time"
+        ));
+    }
+
+    if duration_secs == 0 && timeout == 0 {
+        warnings.push(
+            "-t 0 disables request timeouts, so a stalled server can hang the test forever"
+                .to_string(),
+        );
+    }
+
+    if rate_limit > 0.0 && concurrent > 1 {
+        warnings.push(format!(
+            "--rate-limit applies to each connection: {concurrent} connections at {rate_limit} QPS allow up to {} QPS",
+            rate_limit * concurrent as f64
+        ));
+    }
+
+    Ok(warnings)
+}
+
+/// Every URL the test may send requests to
+fn target_urls(args: &Args) -> Vec<&str> {
+    match &args.url_file {
+        Some(mix) => mix.urls.iter().map(|url| url.url.as_str()).collect(),
+        None => std::iter::once(args.target_url())
+            .chain(args.fallback_url.as_deref())
+            .collect(),
+    }
+}
@@ -18,11 +18,21 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+#![cfg(feature = "cli")]
+
+use assert_cmd::assert::Assert;
 use assert_cmd::prelude::*;
+use predicates::prelude::*;
 use predicates::str::contains;
 use std::process::Command;
 use test_utils::MockServer;
 
+async fn run_blocking(mut cmd: Command) -> Assert {
+    tokio::task::spawn_blocking(move || cmd.assert())
+        .await
+        .unwrap()
+}
+
 #[tokio::test]
 async fn test_no_ui_option_integration() {
     let server = MockServer::start().await;
@@ -35,8 +45,32 @@ async fn test_no_ui_option_integration() {
         .arg("2")
         .arg("--no-ui");
 
-    cmd.assert()
+    // Run the blocking command off the runtime so the mock server can keep serving
+    run_blocking(cmd)
+        .await
+        .success()
+        .stdout(contains("Summary:"))
+        .stdout(contains("Status code distribution:"))
+        .stdout(contains("[200]\t10 responses"));
+}
+
+#[tokio::test]
+async fn test_no_ui_quiet_prints_only_summary() {
+    let server = MockServer::start().await;
+
+    let mut cmd = Command::cargo_bin("whambam").unwrap();
+    cmd.arg(server.url())
+        .arg("-n")
+        .arg("10")
+        .arg("-c")
+        .arg("2")
+        .arg("--no-ui")
+        .arg("--quiet");
+
+    // Run the blocking command off the runtime so the mock server can keep serving
+    run_blocking(cmd)
+        .await
         .success()
-        .stdout(contains("The --no-ui option is currently not supported."))
-        .stdout(contains("The UI interface is required for this version."));
+        .stdout(contains("Summary:"))
+        .stdout(contains("requests,").not());
 }